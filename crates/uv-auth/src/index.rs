@@ -59,6 +59,9 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// Whether the index opted in to authentication with tokens from the
+    /// [`OidcTokenProvider`](crate::OidcTokenProvider).
+    pub oidc: bool,
 }
 
 impl Index {
@@ -107,6 +110,11 @@ impl Indexes {
             .unwrap_or(AuthPolicy::Auto)
     }
 
+    /// Whether a URL belongs to an index that opted in to OIDC token authentication.
+    pub fn oidc_for(&self, url: &Url) -> bool {
+        self.find_prefix_index(url).is_some_and(|index| index.oidc)
+    }
+
     fn find_prefix_index(&self, url: &Url) -> Option<&Index> {
         self.0.iter().find(|&index| index.is_prefix_for(url))
     }
@@ -7,6 +7,7 @@ pub use credentials::Credentials;
 pub use index::{AuthPolicy, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
+pub use oidc::OidcTokenProvider;
 use realm::Realm;
 use uv_redacted::DisplaySafeUrl;
 
@@ -15,6 +16,7 @@ mod credentials;
 mod index;
 mod keyring;
 mod middleware;
+mod oidc;
 mod providers;
 mod realm;
 
@@ -7,6 +7,7 @@ use reqwest::{Request, Response};
 use reqwest_middleware::{Error, Middleware, Next};
 use tracing::{debug, trace, warn};
 
+use crate::OidcTokenProvider;
 use crate::providers::HuggingFaceProvider;
 use crate::{
     CREDENTIALS_CACHE, CredentialsCache, KeyringProvider,
@@ -58,6 +59,7 @@ impl NetrcMode {
 pub struct AuthMiddleware {
     netrc: NetrcMode,
     keyring: Option<KeyringProvider>,
+    oidc: Option<OidcTokenProvider>,
     cache: Option<CredentialsCache>,
     /// Auth policies for specific URLs.
     indexes: Indexes,
@@ -71,6 +73,7 @@ impl AuthMiddleware {
         Self {
             netrc: NetrcMode::default(),
             keyring: None,
+            oidc: None,
             cache: None,
             indexes: Indexes::new(),
             only_authenticated: false,
@@ -97,6 +100,13 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`OidcTokenProvider`] to use for index requests.
+    #[must_use]
+    pub fn with_oidc(mut self, oidc: Option<OidcTokenProvider>) -> Self {
+        self.oidc = oidc;
+        self
+    }
+
     /// Configure the [`CredentialsCache`] to use.
     #[must_use]
     pub fn with_cache(mut self, cache: CredentialsCache) -> Self {
@@ -204,6 +214,17 @@ impl Middleware for AuthMiddleware {
                     .await;
             }
 
+            // If the index opted in to OIDC tokens, use them instead of searching for credentials
+            if let Some(oidc) = self
+                .oidc
+                .as_ref()
+                .filter(|_| self.indexes.oidc_for(request.url()))
+            {
+                return self
+                    .complete_request_with_oidc(oidc, request, extensions, next, &url, auth_policy)
+                    .await;
+            }
+
             // We have no credentials
             trace!("Request for {url} is unauthenticated, checking cache");
 
@@ -241,14 +262,8 @@ impl Middleware for AuthMiddleware {
                 trace!("Attempting partially authenticated request for {url}");
             }
 
-            // <https://github.com/TrueLayer/reqwest-middleware/blob/abdf1844c37092d323683c2396b7eefda1418d3c/reqwest-retry/src/middleware.rs#L141-L149>
             // Clone the request so we can retry it on authentication failure
-            let retry_request = request.try_clone().ok_or_else(|| {
-                Error::Middleware(anyhow!(
-                    "Request object is not cloneable. Are you passing a streaming body?"
-                        .to_string()
-                ))
-            })?;
+            let retry_request = clone_request(&request)?;
 
             let response = next.clone().run(request, extensions).await?;
 
@@ -462,6 +477,48 @@ impl AuthMiddleware {
             .await
     }
 
+    /// Use a bearer token from an [`OidcTokenProvider`] to complete a request to an index.
+    ///
+    /// Unless the index always requires authentication, an unauthenticated request is attempted
+    /// first and the token is only attached if the index responds with a 401. If the index rejects
+    /// the token, it's assumed to have expired, and the request is retried once with a new token.
+    async fn complete_request_with_oidc(
+        &self,
+        oidc: &OidcTokenProvider,
+        mut request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+        url: &DisplaySafeUrl,
+        auth_policy: AuthPolicy,
+    ) -> reqwest_middleware::Result<Response> {
+        if !matches!(auth_policy, AuthPolicy::Always) {
+            trace!("Attempting unauthenticated request for {url}");
+            let retry_request = clone_request(&request)?;
+            let response = next.clone().run(request, extensions).await?;
+            if response.status() != StatusCode::UNAUTHORIZED {
+                return Ok(response);
+            }
+            trace!("Request for {url} failed with 401, requesting OIDC token");
+            request = retry_request;
+        }
+
+        let token = oidc.token().await.map_err(Error::Middleware)?;
+        let retry_request = clone_request(&request)?;
+        trace!("Attempting request for {url} with OIDC token");
+        let response = next
+            .clone()
+            .run(token.authenticate(request), extensions)
+            .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        trace!("Request for {url} with OIDC token failed with 401, refreshing token");
+        let token = oidc.refresh(&token).await.map_err(Error::Middleware)?;
+        next.run(token.authenticate(retry_request), extensions)
+            .await
+    }
+
     /// Fetch credentials for a URL.
     ///
     /// Supports netrc file and keyring lookups.
@@ -571,6 +628,16 @@ impl AuthMiddleware {
     }
 }
 
+/// Clone a request so that it can be retried.
+fn clone_request(request: &Request) -> reqwest_middleware::Result<Request> {
+    // <https://github.com/TrueLayer/reqwest-middleware/blob/abdf1844c37092d323683c2396b7eefda1418d3c/reqwest-retry/src/middleware.rs#L141-L149>
+    request.try_clone().ok_or_else(|| {
+        Error::Middleware(anyhow!(
+            "Request object is not cloneable. Are you passing a streaming body?".to_string()
+        ))
+    })
+}
+
 fn tracing_url(request: &Request, credentials: Option<&Credentials>) -> DisplaySafeUrl {
     let mut url = DisplaySafeUrl::from(request.url().clone());
     if let Some(creds) = credentials {
@@ -594,7 +661,7 @@ mod tests {
     use test_log::test;
 
     use url::Url;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{basic_auth, header, method, path, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::Index;
@@ -1758,11 +1825,13 @@ mod tests {
                 url: DisplaySafeUrl::from(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                oidc: false,
             },
             Index {
                 url: DisplaySafeUrl::from(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                oidc: false,
             },
         ]);
 
@@ -1866,6 +1935,7 @@ mod tests {
             url: DisplaySafeUrl::from(index_url.clone()),
             root_url: DisplaySafeUrl::from(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            oidc: false,
         }]);
 
         let client = test_client_builder()
@@ -1925,6 +1995,20 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            oidc: false,
+        }])
+    }
+
+    /// Like [`indexes_for`], but with the index opted in to OIDC token authentication.
+    fn oidc_indexes_for(url: &Url, policy: AuthPolicy) -> Indexes {
+        let mut url = DisplaySafeUrl::from(url.clone());
+        url.set_password(None).ok();
+        url.set_username("").ok();
+        Indexes::from_indexes(vec![Index {
+            url: url.clone(),
+            root_url: url.clone(),
+            auth_policy: policy,
+            oidc: true,
         }])
     }
 
@@ -2109,6 +2193,224 @@ mod tests {
         Ok(())
     }
 
+    /// Start a server that requires a bearer token for every request.
+    async fn start_bearer_test_server(token: &'static str) -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(header("Authorization", format!("Bearer {token}").as_str()))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    fn oidc_provider_for(server: &MockServer, request_token: Option<&str>) -> OidcTokenProvider {
+        OidcTokenProvider::new(
+            DisplaySafeUrl::parse(&format!("{}/token", server.uri())).unwrap(),
+            request_token.map(ToString::to_string),
+            Client::new(),
+        )
+    }
+
+    /// With the "always" auth policy, a token from the OIDC endpoint is attached eagerly and
+    /// reused across requests.
+    #[test(tokio::test)]
+    async fn test_oidc_token_auth_policy_always() -> Result<(), Error> {
+        let server = start_bearer_test_server("token").await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let oidc_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/token"))
+            .and(header("Authorization", "Bearer request-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"value": "token"}"#))
+            .expect(1)
+            .mount(&oidc_server)
+            .await;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(oidc_indexes_for(&base_url, AuthPolicy::Always))
+                    .with_oidc(Some(oidc_provider_for(&oidc_server, Some("request-token")))),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200
+        );
+        assert_eq!(
+            client
+                .get(format!("{}/bar", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "The token should be reused"
+        );
+
+        let requests = server.received_requests().await.unwrap();
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.headers.contains_key("Authorization")),
+            "Requests should never be attempted without the token"
+        );
+
+        Ok(())
+    }
+
+    /// If the index rejects a token, a new one is requested from the OIDC endpoint.
+    #[test(tokio::test)]
+    async fn test_oidc_token_refresh() -> Result<(), Error> {
+        let server = start_bearer_test_server("fresh").await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let oidc_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"value": "stale"}"#))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&oidc_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/token"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"access_token": "fresh"}"#),
+            )
+            .expect(1)
+            .mount(&oidc_server)
+            .await;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(oidc_indexes_for(&base_url, AuthPolicy::Auto))
+                    .with_oidc(Some(oidc_provider_for(&oidc_server, None))),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200
+        );
+        assert_eq!(
+            client
+                .get(format!("{}/bar", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "The refreshed token should be reused"
+        );
+
+        Ok(())
+    }
+
+    /// With the "auto" auth policy, no token is requested unless the index responds with a 401.
+    #[test(tokio::test)]
+    async fn test_oidc_token_not_requested() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let oidc_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"value": "token"}"#))
+            .expect(0)
+            .mount(&oidc_server)
+            .await;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(oidc_indexes_for(&base_url, AuthPolicy::Auto))
+                    .with_oidc(Some(oidc_provider_for(&oidc_server, None))),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200
+        );
+
+        Ok(())
+    }
+
+    /// Indexes that didn't opt in to OIDC tokens use the usual credential lookup instead.
+    #[test(tokio::test)]
+    async fn test_oidc_token_not_opted_in() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let oidc_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"value": "token"}"#))
+            .expect(0)
+            .mount(&oidc_server)
+            .await;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r"machine {} login {username} password {password}",
+            base_url.host_str().unwrap()
+        )?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(indexes_for(&base_url, AuthPolicy::Auto))
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_oidc(Some(oidc_provider_for(&oidc_server, None))),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials from the netrc file should be used"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_tracing_url() {
         // No credentials
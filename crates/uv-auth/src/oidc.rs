@@ -0,0 +1,148 @@
+use std::sync::{Arc, LazyLock};
+
+use anyhow::{Context, anyhow};
+use reqwest::Client;
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::Credentials;
+
+/// The token slot shared by all providers created from the environment, such that every client in
+/// the process reuses the same token.
+static ENV_TOKEN: LazyLock<Arc<Mutex<Option<Arc<Credentials>>>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(None)));
+
+/// The response body of an OIDC token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// The token itself.
+    ///
+    /// GitHub Actions returns the token as `value`; other providers commonly use `token`,
+    /// `access_token`, or `id_token`.
+    #[serde(alias = "token", alias = "access_token", alias = "id_token")]
+    value: String,
+}
+
+/// A provider for short-lived bearer tokens obtained from an OpenID Connect (OIDC) token endpoint,
+/// e.g., for workload identity in CI.
+///
+/// Tokens are requested lazily, reused across requests, and refreshed when an index rejects them.
+pub struct OidcTokenProvider {
+    /// The URL of the token endpoint.
+    endpoint: DisplaySafeUrl,
+    /// A bearer token used to authenticate against the token endpoint itself, if required.
+    request_token: Option<String>,
+    client: Client,
+    /// The most recently obtained token.
+    token: Arc<Mutex<Option<Arc<Credentials>>>>,
+}
+
+impl OidcTokenProvider {
+    /// Create a new [`OidcTokenProvider`] for the given token endpoint.
+    pub fn new(endpoint: DisplaySafeUrl, request_token: Option<String>, client: Client) -> Self {
+        Self {
+            endpoint,
+            request_token,
+            client,
+            token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Create a [`OidcTokenProvider`] from the `UV_INDEX_OIDC_TOKEN_URL` and
+    /// `UV_INDEX_OIDC_REQUEST_TOKEN` environment variables, if set.
+    ///
+    /// All providers created from the environment share the same token.
+    pub fn from_env(client: Client) -> Option<Self> {
+        let endpoint = std::env::var(EnvVars::UV_INDEX_OIDC_TOKEN_URL)
+            .ok()
+            .filter(|endpoint| !endpoint.is_empty())?;
+        let endpoint = match DisplaySafeUrl::parse(&endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(err) => {
+                warn_user_once!(
+                    "Ignoring invalid `{}` value `{endpoint}`: {err}",
+                    EnvVars::UV_INDEX_OIDC_TOKEN_URL
+                );
+                return None;
+            }
+        };
+        let request_token = std::env::var(EnvVars::UV_INDEX_OIDC_REQUEST_TOKEN)
+            .ok()
+            .filter(|token| !token.is_empty());
+        debug!("Using OIDC token endpoint for index authentication: {endpoint}");
+        Some(Self {
+            token: ENV_TOKEN.clone(),
+            ..Self::new(endpoint, request_token, client)
+        })
+    }
+
+    /// Whether a named index opted in to OIDC token authentication via the environment.
+    ///
+    /// For example, given a name of `"internal"`, check whether `UV_INDEX_INTERNAL_OIDC` is set to
+    /// `1` or `true`.
+    pub fn enabled_for(name: impl AsRef<str>) -> bool {
+        std::env::var(EnvVars::index_oidc(name.as_ref()))
+            .is_ok_and(|value| matches!(value.as_str(), "1" | "true"))
+    }
+
+    /// Return a bearer token, requesting one from the endpoint if none has been obtained yet.
+    pub(crate) async fn token(&self) -> anyhow::Result<Arc<Credentials>> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            return Ok(token.clone());
+        }
+        let fresh = Arc::new(self.fetch().await?);
+        *token = Some(fresh.clone());
+        Ok(fresh)
+    }
+
+    /// Replace a rejected bearer token with a new one from the endpoint.
+    ///
+    /// If the token has already been replaced (e.g., by a concurrent request), the replacement is
+    /// returned instead of requesting another.
+    pub(crate) async fn refresh(
+        &self,
+        rejected: &Arc<Credentials>,
+    ) -> anyhow::Result<Arc<Credentials>> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            if !Arc::ptr_eq(token, rejected) {
+                return Ok(token.clone());
+            }
+        }
+        let fresh = Arc::new(self.fetch().await?);
+        *token = Some(fresh.clone());
+        Ok(fresh)
+    }
+
+    /// Request a new bearer token from the endpoint.
+    async fn fetch(&self) -> anyhow::Result<Credentials> {
+        debug!("Requesting OIDC token from {}", self.endpoint);
+        let mut request = self
+            .client
+            .get(self.endpoint.as_str())
+            .header(ACCEPT, "application/json");
+        if let Some(request_token) = &self.request_token {
+            request = request.bearer_auth(request_token);
+        }
+        let response = request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .with_context(|| format!("Failed to request OIDC token from {}", self.endpoint))?;
+        let TokenResponse { value } = response
+            .json()
+            .await
+            .with_context(|| format!("Invalid OIDC token response from {}", self.endpoint))?;
+        if value.is_empty() {
+            return Err(anyhow!("Empty OIDC token returned from {}", self.endpoint));
+        }
+        Ok(Credentials::bearer(value.into_bytes()))
+    }
+}
@@ -21,12 +21,12 @@ use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use serde::de::{self, IntoDeserializer, SeqAccess, Visitor, value};
 use serde::{Deserialize, Deserializer};
-use tempfile::TempDir;
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, warn};
 
+use uv_cache::TempDir;
 use uv_cache_key::cache_digest;
 use uv_configuration::PreviewMode;
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
@@ -211,10 +211,87 @@ impl Pep517Backend {
 }
 
 /// Uses an [`Rc`] internally, clone freely.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct SourceBuildContext {
     /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
     default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// A semaphore limiting the number of build scripts that run concurrently, across all builds.
+    concurrent_builds: Rc<Semaphore>,
+    /// The directory into which each build environment should be recorded, if any.
+    record_build_env: Option<PathBuf>,
+}
+
+impl SourceBuildContext {
+    /// Create a [`SourceBuildContext`] that runs at most `concurrent_builds` build scripts at a
+    /// time.
+    pub fn new(concurrent_builds: usize) -> Self {
+        Self {
+            default_resolution: Rc::default(),
+            concurrent_builds: Rc::new(Semaphore::new(concurrent_builds)),
+            record_build_env: None,
+        }
+    }
+
+    /// Record the installed build requirements and the invoked PEP 517 hooks of each build
+    /// environment beneath the given directory.
+    #[must_use]
+    pub fn with_record_build_env(mut self, record_build_env: Option<PathBuf>) -> Self {
+        self.record_build_env = record_build_env;
+        self
+    }
+}
+
+/// Records the contents of a build environment, along with the PEP 517 hooks invoked within it.
+///
+/// Each build is recorded in its own directory, containing a `build-requirements.txt` with the
+/// packages installed into the environment, and a `hooks.txt` with one line per hook invocation.
+/// Hooks are recorded before they're invoked, such that failing builds are captured too.
+#[derive(Debug, Clone)]
+struct BuildEnvRecorder {
+    dir: PathBuf,
+}
+
+impl BuildEnvRecorder {
+    /// Create a [`BuildEnvRecorder`] for the given distribution beneath the `root` directory.
+    fn new(
+        root: &Path,
+        package_name: Option<&PackageName>,
+        package_version: Option<&Version>,
+        source_tree: &Path,
+    ) -> io::Result<Self> {
+        let name = match (package_name, package_version) {
+            (Some(name), Some(version)) => format!("{name}-{version}"),
+            (Some(name), None) => name.to_string(),
+            (None, _) => cache_digest(&source_tree),
+        };
+        let dir = root.join(name);
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Record the packages installed into the build environment.
+    fn requirements(&self, resolution: &Resolution) -> io::Result<()> {
+        let requirements = resolution
+            .distributions()
+            .map(ToString::to_string)
+            .sorted()
+            .join("\n");
+        fs::write(
+            self.dir.join("build-requirements.txt"),
+            format!("{requirements}\n"),
+        )
+    }
+
+    /// Record the invocation of a PEP 517 hook with the given arguments.
+    fn hook(&self, backend: &Pep517Backend, hook: &str, args: &str) -> io::Result<()> {
+        use std::io::Write as _;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join("hooks.txt"))?;
+        writeln!(file, "{}.{hook}({args})", backend.backend)
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -259,6 +336,8 @@ pub struct SourceBuild {
     environment_variables: FxHashMap<OsString, OsString>,
     /// Runner for Python scripts.
     runner: PythonRunner,
+    /// Records the build environment and hook invocations, if requested.
+    recorder: Option<BuildEnvRecorder>,
 }
 
 impl SourceBuild {
@@ -285,7 +364,6 @@ impl SourceBuild {
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
         level: BuildOutput,
-        concurrent_builds: usize,
         preview: PreviewMode,
     ) -> Result<Self, Error> {
         let temp_dir = build_context.cache().venv_dir()?;
@@ -339,6 +417,20 @@ impl SourceBuild {
             )?
         };
 
+        // If requested, record the build environment for debugging.
+        let recorder = source_build_context
+            .record_build_env
+            .as_deref()
+            .map(|root| {
+                BuildEnvRecorder::new(
+                    root,
+                    package_name.as_ref(),
+                    package_version.as_ref(),
+                    &source_tree,
+                )
+            })
+            .transpose()?;
+
         // Set up the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
         if build_isolation.is_isolated(package_name.as_ref()) {
@@ -346,13 +438,17 @@ impl SourceBuild {
 
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
-                source_build_context,
+                source_build_context.clone(),
                 &default_backend,
                 &pep517_backend,
                 build_stack,
             )
             .await?;
 
+            if let Some(recorder) = &recorder {
+                recorder.requirements(&resolved_requirements)?;
+            }
+
             build_context
                 .install(&resolved_requirements, &venv, build_stack)
                 .await
@@ -394,7 +490,7 @@ impl SourceBuild {
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(concurrent_builds, level);
+        let runner = PythonRunner::new(source_build_context.concurrent_builds, level);
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
@@ -418,6 +514,7 @@ impl SourceBuild {
                 &environment_variables,
                 &modified_path,
                 &temp_dir,
+                recorder.as_ref(),
             )
             .await?;
         }
@@ -438,6 +535,7 @@ impl SourceBuild {
             environment_variables,
             modified_path,
             runner,
+            recorder,
         })
     }
 
@@ -680,6 +778,17 @@ impl SourceBuild {
             "Calling `{}.prepare_metadata_for_build_{}()`",
             self.pep517_backend.backend, self.build_kind,
         );
+        if let Some(recorder) = &self.recorder {
+            recorder.hook(
+                &self.pep517_backend,
+                &format!("prepare_metadata_for_build_{}", self.build_kind),
+                &format!(
+                    r#""{}", {}"#,
+                    escape_path_for_python(&metadata_directory),
+                    self.config_settings.escape_for_python()
+                ),
+            )?;
+        }
         let script = formatdoc! {
             r#"
             {}
@@ -774,6 +883,17 @@ impl SourceBuild {
                     output_dir.escape_for_python(),
                     self.config_settings.escape_for_python(),
                 );
+                if let Some(recorder) = &self.recorder {
+                    recorder.hook(
+                        &self.pep517_backend,
+                        &format!("build_{}", self.build_kind),
+                        &format!(
+                            r#""{}", {}"#,
+                            output_dir.escape_for_python(),
+                            self.config_settings.escape_for_python()
+                        ),
+                    )?;
+                }
                 formatdoc! {
                     r#"
                     {}
@@ -804,6 +924,18 @@ impl SourceBuild {
                     self.config_settings.escape_for_python(),
                     metadata_directory,
                 );
+                if let Some(recorder) = &self.recorder {
+                    recorder.hook(
+                        &self.pep517_backend,
+                        &format!("build_{}", self.build_kind),
+                        &format!(
+                            r#""{}", {}, {}"#,
+                            output_dir.escape_for_python(),
+                            self.config_settings.escape_for_python(),
+                            metadata_directory
+                        ),
+                    )?;
+                }
                 formatdoc! {
                     r#"
                     {}
@@ -907,6 +1039,7 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
+    recorder: Option<&BuildEnvRecorder>,
 ) -> Result<(), Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
@@ -917,6 +1050,13 @@ async fn create_pep517_build_environment(
         "Calling `{}.get_requires_for_build_{}()`",
         pep517_backend.backend, build_kind
     );
+    if let Some(recorder) = recorder {
+        recorder.hook(
+            pep517_backend,
+            &format!("get_requires_for_build_{build_kind}"),
+            &config_settings.escape_for_python(),
+        )?;
+    }
 
     let script = formatdoc! {
         r#"
@@ -1037,6 +1177,10 @@ async fn create_pep517_build_environment(
             .map_err(|err| {
                 Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
+
+        if let Some(recorder) = recorder {
+            recorder.requirements(&resolution)?;
+        }
     }
 
     Ok(())
@@ -1046,7 +1190,7 @@ async fn create_pep517_build_environment(
 /// concurrency limit.
 #[derive(Debug)]
 struct PythonRunner {
-    control: Semaphore,
+    control: Rc<Semaphore>,
     level: BuildOutput,
 }
 
@@ -1059,11 +1203,10 @@ struct PythonRunnerOutput {
 
 impl PythonRunner {
     /// Create a `PythonRunner` with the provided concurrency limit and output level.
-    fn new(concurrency: usize, level: BuildOutput) -> Self {
-        Self {
-            control: Semaphore::new(concurrency),
-            level,
-        }
+    ///
+    /// The concurrency limit is shared with any other runners that use the same semaphore.
+    fn new(control: Rc<Semaphore>, level: BuildOutput) -> Self {
+        Self { control, level }
     }
 
     /// Spawn a process that runs a python script in the provided environment.
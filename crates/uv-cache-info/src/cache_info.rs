@@ -6,6 +6,8 @@ use std::path::{Path, PathBuf};
 use serde::Deserialize;
 use tracing::{debug, warn};
 
+use uv_cache_key::hash_digest;
+
 use crate::git_info::{Commit, Tags};
 use crate::glob::cluster_globs;
 use crate::timestamp::Timestamp;
@@ -38,6 +40,10 @@ pub struct CacheInfo {
     /// The timestamp or inode of any directories that should be considered in the cache key.
     #[serde(default)]
     directories: BTreeMap<Cow<'static, str>, Option<DirectoryTimestamp>>,
+    /// A digest of the build-relevant sections of the `pyproject.toml`, used in lieu of its
+    /// timestamp for pure-Python projects with static metadata.
+    #[serde(default)]
+    metadata: Option<String>,
 }
 
 impl CacheInfo {
@@ -61,37 +67,63 @@ impl CacheInfo {
 
     /// Compute the cache info for a given directory.
     pub fn from_directory(directory: &Path) -> Result<Self, CacheInfoError> {
+        Self::from_directory_impl(directory, false)
+    }
+
+    /// Compute the cache info for a directory that's installed in editable mode.
+    ///
+    /// Unlike [`CacheInfo::from_directory`], source changes in an editable install take effect
+    /// without a rebuild, so for pure-Python projects with static metadata, the `pyproject.toml`
+    /// is tracked by the contents of its build-relevant sections rather than by timestamp.
+    pub fn from_editable(directory: &Path) -> Result<Self, CacheInfoError> {
+        Self::from_directory_impl(directory, true)
+    }
+
+    fn from_directory_impl(directory: &Path, editable: bool) -> Result<Self, CacheInfoError> {
         let mut commit = None;
         let mut tags = None;
         let mut timestamp = None;
         let mut directories = BTreeMap::new();
         let mut env = BTreeMap::new();
+        let mut metadata = None;
 
         // Read the cache keys.
-        let cache_keys =
-            if let Ok(contents) = fs_err::read_to_string(directory.join("pyproject.toml")) {
-                if let Ok(pyproject_toml) = toml::from_str::<PyProjectToml>(&contents) {
-                    pyproject_toml
-                        .tool
-                        .and_then(|tool| tool.uv)
-                        .and_then(|tool_uv| tool_uv.cache_keys)
-                } else {
-                    None
-                }
+        let contents = fs_err::read_to_string(directory.join("pyproject.toml")).ok();
+        let cache_keys = if let Some(contents) = contents.as_deref() {
+            if let Ok(pyproject_toml) = toml::from_str::<PyProjectToml>(contents) {
+                pyproject_toml
+                    .tool
+                    .and_then(|tool| tool.uv)
+                    .and_then(|tool_uv| tool_uv.cache_keys)
             } else {
                 None
-            };
+            }
+        } else {
+            None
+        };
 
         // If no cache keys were defined, use the defaults.
         let cache_keys = cache_keys.unwrap_or_else(|| {
-            vec![
-                CacheKey::Path(Cow::Borrowed("pyproject.toml")),
-                CacheKey::Path(Cow::Borrowed("setup.py")),
-                CacheKey::Path(Cow::Borrowed("setup.cfg")),
-                CacheKey::Directory {
-                    dir: Cow::Borrowed("src"),
-                },
-            ]
+            // For pure-Python editables with static metadata, only changes to the build-relevant
+            // sections of the `pyproject.toml` (e.g., the dependencies) require a rebuild, so track
+            // those sections by content rather than tracking the file by timestamp.
+            metadata = contents
+                .as_deref()
+                .filter(|_| editable && !directory.join("setup.py").is_file())
+                .and_then(metadata_digest);
+            let pyproject_toml = metadata
+                .is_none()
+                .then_some(CacheKey::Path(Cow::Borrowed("pyproject.toml")));
+            pyproject_toml
+                .into_iter()
+                .chain([
+                    CacheKey::Path(Cow::Borrowed("setup.py")),
+                    CacheKey::Path(Cow::Borrowed("setup.cfg")),
+                    CacheKey::Directory {
+                        dir: Cow::Borrowed("src"),
+                    },
+                ])
+                .collect()
         });
 
         // Incorporate timestamps from any direct filepaths.
@@ -264,7 +296,7 @@ impl CacheInfo {
         }
 
         debug!(
-            "Computed cache info: {timestamp:?}, {commit:?}, {tags:?}, {env:?}, {directories:?}"
+            "Computed cache info: {timestamp:?}, {commit:?}, {tags:?}, {env:?}, {directories:?}, {metadata:?}"
         );
 
         Ok(Self {
@@ -273,6 +305,7 @@ impl CacheInfo {
             tags,
             env,
             directories,
+            metadata,
         })
     }
 
@@ -294,7 +327,56 @@ impl CacheInfo {
             && self.tags.is_none()
             && self.env.is_empty()
             && self.directories.is_empty()
+            && self.metadata.is_none()
+    }
+}
+
+/// Build backends that don't include a compilation step, along with the `[tool]` table from
+/// which each reads its configuration.
+const PURE_PYTHON_BACKENDS: &[(&str, &str)] = &[
+    ("flit_core.buildapi", "flit"),
+    ("hatchling.build", "hatch"),
+    ("pdm.backend", "pdm"),
+    ("poetry.core.masonry.api", "poetry"),
+    ("setuptools.build_meta", "setuptools"),
+    ("uv_build", "uv"),
+];
+
+/// Compute a digest of the build-relevant sections of a `pyproject.toml`: the `[build-system]`
+/// and `[project]` tables, along with the build backend's `[tool]` table.
+///
+/// Returns `None` if the project uses a build backend that may compile sources, or declares
+/// dynamic metadata, in which case the `pyproject.toml` must be tracked by timestamp instead.
+fn metadata_digest(contents: &str) -> Option<String> {
+    let mut pyproject_toml = toml::from_str::<toml::Table>(contents).ok()?;
+
+    let build_system = pyproject_toml.remove("build-system")?;
+    let backend = build_system.get("build-backend")?.as_str()?;
+    let (_, tool_name) = PURE_PYTHON_BACKENDS
+        .iter()
+        .find(|(name, _)| *name == backend)?;
+
+    let project = pyproject_toml.remove("project")?;
+    if project
+        .get("dynamic")
+        .and_then(toml::Value::as_array)
+        .is_some_and(|dynamic| !dynamic.is_empty())
+    {
+        return None;
+    }
+
+    let tool = pyproject_toml
+        .get_mut("tool")
+        .and_then(toml::Value::as_table_mut)
+        .and_then(|tool| tool.remove(*tool_name));
+
+    let mut relevant = toml::Table::new();
+    relevant.insert("build-system".to_string(), build_system);
+    relevant.insert("project".to_string(), project);
+    if let Some(tool) = tool {
+        relevant.insert((*tool_name).to_string(), tool);
     }
+    Some(hash_digest(&relevant.to_string()))
 }
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
@@ -11,6 +11,8 @@ use tracing::{debug, warn};
 pub struct CacheArgs {
     /// Avoid reading from or writing to the cache, instead using a temporary directory for the
     /// duration of the operation.
+    ///
+    /// Takes precedence over `--cache-dir` and `UV_CACHE_DIR`.
     #[arg(
         global = true,
         long,
@@ -29,6 +31,15 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Revalidate cached index responses older than the given number of seconds.
+    ///
+    /// By default, cached index responses are reused for as long as the index's `Cache-Control`
+    /// headers allow, which can hide newly published versions. Responses older than the given age
+    /// are revalidated with the index, using a conditional request where supported. A value of `0`
+    /// revalidates every cached index response.
+    #[arg(global = true, long, env = EnvVars::UV_INDEX_CACHE_TTL, value_name = "SECONDS")]
+    pub index_cache_ttl: Option<u64>,
 }
 
 impl Cache {
@@ -42,6 +53,12 @@ impl Cache {
     /// Returns an absolute cache dir.
     pub fn from_settings(no_cache: bool, cache_dir: Option<PathBuf>) -> Result<Self, io::Error> {
         if no_cache {
+            if let Some(cache_dir) = cache_dir {
+                debug!(
+                    "Ignoring cache directory `{}` due to `--no-cache`",
+                    cache_dir.display()
+                );
+            }
             Self::temp()
         } else if let Some(cache_dir) = cache_dir {
             Ok(Self::from_path(cache_dir))
@@ -5,6 +5,7 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use rustc_hash::FxHashMap;
 use tracing::debug;
@@ -13,6 +14,7 @@ pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
 use uv_fs::{LockedFile, cachedir, directories};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -20,6 +22,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 pub use crate::cli::CacheArgs;
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::temp_dir::TempDir;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -28,6 +31,7 @@ mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
 mod removal;
+mod temp_dir;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -141,6 +145,8 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The maximum age of a cached index response before it must be revalidated, if any.
+    index_ttl: Option<Duration>,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -154,6 +160,7 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            index_ttl: None,
             temp_dir: None,
         }
     }
@@ -164,6 +171,7 @@ impl Cache {
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            index_ttl: None,
             temp_dir: Some(Arc::new(temp_dir)),
         })
     }
@@ -174,6 +182,12 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the maximum age of a cached index response before it must be revalidated.
+    #[must_use]
+    pub fn with_index_ttl(self, index_ttl: Option<Duration>) -> Self {
+        Self { index_ttl, ..self }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -210,15 +224,22 @@ impl Cache {
     }
 
     /// Create a temporary directory to be used as a Python virtual environment.
-    pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
-        fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
-        tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
+    pub fn venv_dir(&self) -> io::Result<TempDir> {
+        self.temp_dir_in(CacheBucket::Builds)
     }
 
     /// Create a temporary directory to be used for executing PEP 517 source distribution builds.
-    pub fn build_dir(&self) -> io::Result<tempfile::TempDir> {
-        fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
-        tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
+    pub fn build_dir(&self) -> io::Result<TempDir> {
+        self.temp_dir_in(CacheBucket::Builds)
+    }
+
+    /// Create a temporary directory at the top level of the given cache bucket.
+    ///
+    /// The directory is locked for as long as it's alive, such that [`Cache::prune`] won't remove
+    /// it while it's in use.
+    pub fn temp_dir_in(&self, cache_bucket: CacheBucket) -> io::Result<TempDir> {
+        fs_err::create_dir_all(self.bucket(cache_bucket))?;
+        TempDir::new_in(&self.bucket(cache_bucket))
     }
 
     /// Returns `true` if a cache entry must be revalidated given the [`Refresh`] policy.
@@ -283,6 +304,41 @@ impl Cache {
         }
     }
 
+    /// Returns the [`Freshness`] for a cached index response.
+    ///
+    /// In addition to the [`Refresh`] policy, any index response that was written longer ago than
+    /// the index TTL (if set) is considered stale.
+    pub fn index_freshness(
+        &self,
+        entry: &CacheEntry,
+        package: Option<&PackageName>,
+    ) -> io::Result<Freshness> {
+        let freshness = self.freshness(entry, package, None)?;
+        let Some(index_ttl) = self.index_ttl else {
+            return Ok(freshness);
+        };
+        if freshness != Freshness::Fresh {
+            return Ok(freshness);
+        }
+        match fs_err::metadata(entry.path()) {
+            Ok(metadata) => {
+                let age = metadata.modified()?.elapsed().unwrap_or_default();
+                if age >= index_ttl {
+                    debug!(
+                        "Cached index response is older than {}s: {}",
+                        index_ttl.as_secs(),
+                        entry.path().display()
+                    );
+                    Ok(Freshness::Stale)
+                } else {
+                    Ok(Freshness::Fresh)
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Freshness::Missing),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Persist a temporary directory to the artifact store, returning its unique ID.
     pub async fn persist(
         &self,
@@ -394,6 +450,121 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Remove the cached registry distributions for the given packages, other than those for the
+    /// given versions.
+    ///
+    /// Only registry wheels and source distributions are considered; entries for direct URL, path,
+    /// and Git dependencies are retained. Any archives that are no longer referenced are removed.
+    ///
+    /// Source distributions that are locked by another process (e.g., during a concurrent build)
+    /// are retained.
+    pub fn remove_other_versions<'a>(
+        &self,
+        packages: impl IntoIterator<Item = (&'a PackageName, &'a Version)>,
+    ) -> Result<Removal, io::Error> {
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
+
+        let mut summary = Removal::default();
+        for (name, version) in packages {
+            for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+                // For `pypi`, we expect a directory per package; for alternate indices, we expect
+                // a directory for every index, followed by a directory per package.
+                let pypi = self.bucket(bucket).join(WheelCacheKind::Pypi);
+                let index = self.bucket(bucket).join(WheelCacheKind::Index);
+                let packages = std::iter::once(pypi.join(name.to_string()))
+                    .chain(directories(index)?.map(|directory| directory.join(name.to_string())));
+                for package in packages {
+                    for entry in read_dir_paths(&package)? {
+                        if !is_other_version(&entry, version) {
+                            continue;
+                        }
+
+                        // Source distributions are built under a lock on their shard; hold it
+                        // while removing the shard, to avoid racing with a concurrent build.
+                        let _lock = if entry.is_dir() {
+                            match LockedFile::try_acquire(entry.join(".lock"), entry.display())? {
+                                Some(lock) => Some(lock),
+                                None => {
+                                    debug!("Skipping cache entry in use: {}", entry.display());
+                                    continue;
+                                }
+                            }
+                        } else {
+                            None
+                        };
+
+                        debug!("Removing unused cache entry: {}", entry.display());
+                        summary += rm_rf(entry)?;
+                    }
+                }
+            }
+        }
+
+        // Remove any archives that are no longer referenced.
+        for (target, references) in references {
+            if references.iter().all(|path| !path.exists()) {
+                debug!("Removing dangling cache entry: {}", target.display());
+                summary += rm_rf(target)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Enumerate the entries in the cache, along with their sizes.
+    ///
+    /// For the [`CacheBucket::Wheels`] and [`CacheBucket::SourceDistributions`] buckets, each
+    /// per-package directory is reported as an entry (e.g., `wheels-v5/pypi/anyio`). For the
+    /// [`CacheBucket::Git`] bucket, each repository database and checkout is reported as an entry.
+    /// For all other buckets, each top-level file or directory is reported as an entry.
+    pub fn entries(&self) -> Result<Vec<CacheEntryInfo>, io::Error> {
+        let mut entries = Vec::new();
+
+        for bucket in CacheBucket::iter() {
+            let root = self.bucket(bucket);
+
+            let mut paths = Vec::new();
+            match bucket {
+                CacheBucket::Wheels | CacheBucket::SourceDistributions => {
+                    for child in read_dir_paths(&root)? {
+                        let depth = child
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .and_then(WheelCacheKind::from_dir_name)
+                            .map_or(0, |kind| kind.depth() + 1);
+                        collect_entries(child, depth, &mut paths)?;
+                    }
+                }
+                CacheBucket::Git => {
+                    // Ex) `git-v0/db/<digest>` and `git-v0/checkouts/<digest>/<rev>`
+                    for child in read_dir_paths(&root)? {
+                        let depth = if child.file_name().is_some_and(|name| name == "checkouts") {
+                            2
+                        } else {
+                            1
+                        };
+                        collect_entries(child, depth, &mut paths)?;
+                    }
+                }
+                _ => collect_entries(root, 1, &mut paths)?,
+            }
+
+            for path in paths {
+                let mut size = 0;
+                for entry in walkdir::WalkDir::new(&path) {
+                    let entry = entry?;
+                    if !entry.file_type().is_dir() {
+                        size += entry.metadata()?.len();
+                    }
+                }
+                entries.push(CacheEntryInfo { bucket, path, size });
+            }
+        }
+
+        Ok(entries)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -441,7 +612,50 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
-        // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
+        // Third, remove any temporary directories that were left behind by an interrupted build or
+        // download (e.g., if uv was terminated before the directories could be dropped). Directories
+        // that are still locked are in use by another process, and are retained.
+        match fs_err::read_dir(self.bucket(CacheBucket::Builds)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    if entry.file_type()?.is_dir() {
+                        summary += Self::prune_temp_dir(&path, "build directory")?;
+                    } else if !path.with_extension("").is_dir() {
+                        debug!("Removing dangling build directory lock: {}", path.display());
+                        summary += rm_rf(path)?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        match fs_err::read_dir(self.bucket(CacheBucket::SourceDistributions)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if !entry.file_name().to_string_lossy().starts_with(".tmp") {
+                        continue;
+                    }
+                    let path = entry.path();
+                    if entry.file_type()?.is_dir() {
+                        summary += Self::prune_temp_dir(&path, "temporary directory")?;
+                    } else if !path.with_extension("").is_dir() {
+                        debug!(
+                            "Removing dangling temporary directory lock: {}",
+                            path.display()
+                        );
+                        summary += rm_rf(path)?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        // Fourth, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
             // Remove the entire pre-built wheel cache, since every entry is an unzipped wheel.
             match fs_err::read_dir(self.bucket(CacheBucket::Wheels)) {
@@ -498,7 +712,7 @@ impl Cache {
             }
         }
 
-        // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        // Fifth, remove any unused archives (by searching for archives that are not symlinked).
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -519,6 +733,21 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Remove a temporary directory left behind in the cache, unless it's locked by another
+    /// process.
+    fn prune_temp_dir(path: &Path, kind: &str) -> Result<Removal, io::Error> {
+        let lock_path = temp_dir::lock_path(path);
+        let Some(lock) = LockedFile::try_acquire(&lock_path, path.display())? else {
+            debug!("Skipping {kind} in use: {}", path.display());
+            return Ok(Removal::default());
+        };
+        debug!("Removing dangling {kind}: {}", path.display());
+        let summary = rm_rf(path)?;
+        fs_err::remove_file(&lock_path)?;
+        drop(lock);
+        Ok(summary)
+    }
+
     /// Find all references to entries in the archive bucket.
     ///
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
@@ -743,6 +972,50 @@ pub trait CleanReporter: Send + Sync {
     fn on_complete(&self);
 }
 
+/// An entry in the cache, as enumerated by [`Cache::entries`].
+#[derive(Debug, Clone)]
+pub struct CacheEntryInfo {
+    /// The bucket that contains the entry.
+    pub bucket: CacheBucket,
+    /// The absolute path to the entry.
+    pub path: PathBuf,
+    /// The total size of the files within the entry, in bytes.
+    pub size: u64,
+}
+
+/// Return the paths of the children of the given directory, or an empty list if the directory
+/// does not exist.
+fn read_dir_paths(path: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    match fs_err::read_dir(path) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Collect the paths that are `depth` directory levels below the given path.
+///
+/// Any files encountered above the requested depth are collected as-is.
+fn collect_entries(path: PathBuf, depth: usize, paths: &mut Vec<PathBuf>) -> Result<(), io::Error> {
+    if depth == 0 {
+        paths.push(path);
+        return Ok(());
+    }
+    match fs_err::symlink_metadata(&path) {
+        Ok(metadata) if metadata.is_dir() => {
+            for child in read_dir_paths(&path)? {
+                collect_entries(child, depth - 1, paths)?;
+            }
+        }
+        Ok(_) => paths.push(path),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    Ok(())
+}
+
 /// The different kinds of data in the cache are stored in different bucket, which in our case
 /// are subdirectories of the cache root.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -1239,13 +1512,43 @@ impl Refresh {
     }
 }
 
+/// The maximum length of the version in a wheel cache key, beyond which it's truncated.
+///
+/// Must be kept in-sync with `WheelFilename::cache_key`.
+const TRUNCATED_VERSION_LEN: usize = 47;
+
+/// Returns `true` if the cache entry was created for a different version of the package.
+///
+/// Registry wheels are keyed by `{version}-{tags}` (e.g., `2.0.0-py3-none-any.http`), and
+/// registry source distributions by `{version}`.
+fn is_other_version(path: &Path, version: &Version) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some(prefix) = file_name.split('-').next() else {
+        return false;
+    };
+    // Long wheel cache keys truncate the version, so retain any entry that matches the
+    // truncated form.
+    let version_str = version.to_string();
+    if version_str.len() > TRUNCATED_VERSION_LEN
+        && prefix == version_str[..TRUNCATED_VERSION_LEN].trim_end_matches(['.', '+'])
+    {
+        return false;
+    }
+    Version::from_str(prefix).is_ok_and(|prefix| prefix != *version)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
     use std::str::FromStr;
 
+    use uv_pep440::Version;
+
     use crate::ArchiveId;
 
-    use super::Link;
+    use super::{Link, is_other_version};
 
     #[test]
     fn test_link_round_trip() {
@@ -1264,4 +1567,36 @@ mod tests {
         assert!(Link::from_str("v1/foo").is_err());
         assert!(Link::from_str("archive-v0/").is_err());
     }
+
+    #[test]
+    fn test_is_other_version() {
+        let version = Version::from_str("1.0").unwrap();
+        assert!(!is_other_version(Path::new("1.0"), &version));
+        assert!(!is_other_version(
+            Path::new("1.0-py3-none-any.http"),
+            &version
+        ));
+        assert!(!is_other_version(
+            Path::new("1.0.0-py3-none-any.http"),
+            &version
+        ));
+        assert!(is_other_version(
+            Path::new("1"),
+            &Version::from_str("10.0").unwrap()
+        ));
+        assert!(is_other_version(
+            Path::new("10.0-py3-none-any.http"),
+            &version
+        ));
+        assert!(is_other_version(Path::new("2.0"), &version));
+
+        // Entries for long versions use a truncated version in their cache key.
+        let version = Version::from_str(&format!("1.0+{}", "a".repeat(60))).unwrap();
+        let truncated = format!("1.0+{}-0123456789abcdef.http", "a".repeat(43));
+        assert!(!is_other_version(Path::new(&truncated), &version));
+        assert!(is_other_version(
+            Path::new("1.0-py3-none-any.http"),
+            &version
+        ));
+    }
 }
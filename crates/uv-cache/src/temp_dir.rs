@@ -0,0 +1,65 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use uv_fs::LockedFile;
+
+/// A temporary directory in the cache.
+///
+/// The directory is locked (via a sibling `.lock` file) for as long as it's alive, such that
+/// `uv cache prune` can distinguish directories that are in use by another process from those that
+/// were left behind by an interrupted process.
+#[derive(Debug)]
+pub struct TempDir {
+    dir: Option<tempfile::TempDir>,
+    _lock: LockedFile,
+}
+
+impl TempDir {
+    /// Create a locked temporary directory in the given parent directory.
+    pub(crate) fn new_in(parent: &Path) -> io::Result<Self> {
+        loop {
+            let dir = tempfile::tempdir_in(parent)?;
+
+            // If `uv cache prune` acquired the lock first, it may have removed the directory, in
+            // which case we retry with a new one.
+            let Some(lock) = LockedFile::try_acquire(lock_path(dir.path()), dir.path().display())?
+            else {
+                continue;
+            };
+            if !dir.path().is_dir() {
+                continue;
+            }
+
+            return Ok(Self {
+                dir: Some(dir),
+                _lock: lock,
+            });
+        }
+    }
+
+    /// Return the path to the temporary directory.
+    pub fn path(&self) -> &Path {
+        self.dir
+            .as_ref()
+            .expect("Temporary directory to be present")
+            .path()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        // Remove the directory before its lock file, while the lock is still held.
+        if let Some(dir) = self.dir.take() {
+            let lock = lock_path(dir.path());
+            let _ = dir.close();
+            let _ = fs_err::remove_file(lock);
+        }
+    }
+}
+
+/// Return the path to the lock file for a temporary directory in the cache.
+pub(crate) fn lock_path(dir: &Path) -> PathBuf {
+    let mut path = dir.as_os_str().to_owned();
+    path.push(".lock");
+    PathBuf::from(path)
+}
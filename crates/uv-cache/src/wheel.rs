@@ -83,6 +83,29 @@ impl WheelCacheKind {
     pub(crate) fn root(self) -> PathBuf {
         Path::new(self.to_str()).to_path_buf()
     }
+
+    /// Parse a [`WheelCacheKind`] from the name of its root directory.
+    pub(crate) fn from_dir_name(name: &str) -> Option<Self> {
+        match name {
+            "pypi" => Some(Self::Pypi),
+            "index" => Some(Self::Index),
+            "url" => Some(Self::Url),
+            "path" => Some(Self::Path),
+            "editable" => Some(Self::Editable),
+            "git" => Some(Self::Git),
+            _ => None,
+        }
+    }
+
+    /// The number of directory levels between the root of the [`WheelCacheKind`] and its
+    /// per-package directories (e.g., one level for `index/<digest(index-url)>/<package>`).
+    pub(crate) fn depth(self) -> usize {
+        match self {
+            Self::Pypi => 0,
+            Self::Index | Self::Url | Self::Path | Self::Editable => 1,
+            Self::Git => 2,
+        }
+    }
 }
 
 impl AsRef<Path> for WheelCacheKind {
@@ -36,6 +36,19 @@ pub fn flag(yes: bool, no: bool, name: &str) -> Option<bool> {
     }
 }
 
+/// Resolve the value of the `--compile-bytecode` and `--no-compile-bytecode` flag pair.
+///
+/// Since `--compile-bytecode` can be enabled via `UV_COMPILE_BYTECODE`, both flags may be set at
+/// once, in which case `--no-compile-bytecode` was passed on the command line and takes precedence
+/// over the environment variable.
+fn compile_bytecode_flag(compile_bytecode: bool, no_compile_bytecode: bool) -> Option<bool> {
+    match (compile_bytecode, no_compile_bytecode) {
+        (_, true) => Some(false),
+        (true, false) => Some(true),
+        (false, false) => None,
+    }
+}
+
 impl From<RefreshArgs> for Refresh {
     fn from(value: RefreshArgs) -> Self {
         let RefreshArgs {
@@ -135,7 +148,7 @@ impl From<InstallerArgs> for PipOptions {
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             exclude_newer,
             link_mode,
-            compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+            compile_bytecode: compile_bytecode_flag(compile_bytecode, no_compile_bytecode),
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -195,7 +208,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             link_mode,
-            compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+            compile_bytecode: compile_bytecode_flag(compile_bytecode, no_compile_bytecode),
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -466,7 +479,7 @@ pub fn resolver_installer_options(
         },
         exclude_newer,
         link_mode,
-        compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+        compile_bytecode: compile_bytecode_flag(compile_bytecode, no_compile_bytecode),
         no_build: flag(no_build, build, "build"),
         no_build_package: if no_build_package.is_empty() {
             None
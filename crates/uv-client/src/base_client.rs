@@ -16,7 +16,7 @@ use http::{
     },
 };
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, IntoUrl, Proxy, Request, Response, multipart};
+use reqwest::{Client, ClientBuilder, IntoUrl, NoProxy, Proxy, Request, Response, multipart};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
@@ -27,7 +27,7 @@ use url::ParseError;
 use url::Url;
 
 use uv_auth::Credentials;
-use uv_auth::{AuthMiddleware, Indexes};
+use uv_auth::{AuthMiddleware, Indexes, OidcTokenProvider};
 use uv_configuration::{KeyringProviderType, TrustedHost};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
@@ -72,10 +72,15 @@ pub struct BaseClientBuilder<'a> {
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
+    /// Whether to read credentials from ambient sources, i.e., the `.netrc` file and any OIDC
+    /// token endpoint configured in the environment.
+    ambient_credentials: bool,
     indexes: Indexes,
     default_timeout: Duration,
+    timeout: Option<Duration>,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
+    proxy_url: Option<DisplaySafeUrl>,
     redirect_policy: RedirectPolicy,
     /// Whether credentials should be propagated during cross-origin redirects.
     ///
@@ -132,10 +137,13 @@ impl BaseClientBuilder<'_> {
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
+            ambient_credentials: true,
             indexes: Indexes::new(),
             default_timeout: Duration::from_secs(30),
+            timeout: None,
             extra_middleware: None,
             proxies: vec![],
+            proxy_url: None,
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
         }
@@ -210,6 +218,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Whether to read credentials from ambient sources, i.e., the `.netrc` file and any OIDC
+    /// token endpoint configured in the environment.
+    #[must_use]
+    pub fn ambient_credentials(mut self, ambient_credentials: bool) -> Self {
+        self.ambient_credentials = ambient_credentials;
+        self
+    }
+
     #[must_use]
     pub fn indexes(mut self, indexes: Indexes) -> Self {
         self.indexes = indexes;
@@ -222,6 +238,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the timeout for HTTP requests, taking precedence over the `UV_HTTP_TIMEOUT`
+    /// environment variable.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     #[must_use]
     pub fn extra_middleware(mut self, middleware: ExtraMiddleware) -> Self {
         self.extra_middleware = Some(middleware);
@@ -234,6 +258,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Route all requests through the given proxy, overriding any proxy configured in the
+    /// environment. Hosts in `NO_PROXY` continue to bypass the proxy.
+    #[must_use]
+    pub fn proxy_url(mut self, proxy_url: Option<DisplaySafeUrl>) -> Self {
+        self.proxy_url = proxy_url;
+        self
+    }
+
     #[must_use]
     pub fn redirect(mut self, policy: RedirectPolicy) -> Self {
         self.redirect_policy = policy;
@@ -291,19 +323,21 @@ impl<'a> BaseClientBuilder<'a> {
 
         // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
         // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
-        let timeout = env::var(EnvVars::UV_HTTP_TIMEOUT)
-            .or_else(|_| env::var(EnvVars::UV_REQUEST_TIMEOUT))
-            .or_else(|_| env::var(EnvVars::HTTP_TIMEOUT))
-            .and_then(|value| {
-                value.parse::<u64>()
-                    .map(Duration::from_secs)
-                    .or_else(|_| {
-                        // On parse error, warn and use the default timeout
-                        warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
-                        Ok(self.default_timeout)
-                    })
-            })
-            .unwrap_or(self.default_timeout);
+        let timeout = self.timeout.unwrap_or_else(|| {
+            env::var(EnvVars::UV_HTTP_TIMEOUT)
+                .or_else(|_| env::var(EnvVars::UV_REQUEST_TIMEOUT))
+                .or_else(|_| env::var(EnvVars::HTTP_TIMEOUT))
+                .and_then(|value| {
+                    value.parse::<u64>()
+                        .map(Duration::from_secs)
+                        .or_else(|_| {
+                            // On parse error, warn and use the default timeout
+                            warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
+                            Ok(self.default_timeout)
+                        })
+                })
+                .unwrap_or(self.default_timeout)
+        });
         debug!("Using request timeout of {}s", timeout.as_secs());
 
         // Create a secure client that validates certificates.
@@ -387,6 +421,7 @@ impl<'a> BaseClientBuilder<'a> {
             .http1_title_case_headers()
             .user_agent(user_agent)
             .pool_max_idle_per_host(20)
+            .connect_timeout(timeout)
             .read_timeout(timeout)
             .tls_built_in_root_certs(false)
             .redirect(redirect_policy.reqwest_policy());
@@ -421,6 +456,19 @@ impl<'a> BaseClientBuilder<'a> {
         for p in &self.proxies {
             client_builder = client_builder.proxy(p.clone());
         }
+
+        // Apply the user-provided proxy. Explicit proxies take precedence over the proxies read
+        // from the environment, which reqwest applies last.
+        if let Some(proxy_url) = &self.proxy_url {
+            match Proxy::all(proxy_url.as_str()) {
+                Ok(proxy) => {
+                    client_builder = client_builder.proxy(proxy.no_proxy(NoProxy::from_env()));
+                }
+                Err(err) => {
+                    warn_user_once!("Ignoring invalid proxy URL `{proxy_url}`: {err}");
+                }
+            }
+        }
         let client_builder = client_builder;
 
         client_builder
@@ -431,6 +479,7 @@ impl<'a> BaseClientBuilder<'a> {
     fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
+                let raw_client = client.clone();
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
@@ -446,16 +495,25 @@ impl<'a> BaseClientBuilder<'a> {
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        let auth_middleware = AuthMiddleware::new()
+                        let mut auth_middleware = AuthMiddleware::new()
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider());
+                        if self.ambient_credentials {
+                            auth_middleware =
+                                auth_middleware.with_oidc(OidcTokenProvider::from_env(raw_client));
+                        } else {
+                            auth_middleware = auth_middleware.with_netrc(None);
+                        }
                         client = client.with(auth_middleware);
                     }
                     AuthIntegration::OnlyAuthenticated => {
-                        let auth_middleware = AuthMiddleware::new()
+                        let mut auth_middleware = AuthMiddleware::new()
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider())
                             .with_only_authenticated(true);
+                        if !self.ambient_credentials {
+                            auth_middleware = auth_middleware.with_netrc(None);
+                        }
 
                         client = client.with(auth_middleware);
                     }
@@ -732,9 +790,12 @@ fn request_into_redirect(
     let mut headers = HeaderMap::new();
     std::mem::swap(req.headers_mut(), &mut headers);
 
-    let cross_host = redirect_url.host_str() != original_req_url.host_str()
+    // As in `reqwest`, a change of scheme (e.g., from `https` to `http`) is treated as a change of
+    // origin, even if the host and port are unchanged.
+    let cross_origin = redirect_url.scheme() != original_req_url.scheme()
+        || redirect_url.host_str() != original_req_url.host_str()
         || redirect_url.port_or_known_default() != original_req_url.port_or_known_default();
-    if cross_host {
+    if cross_origin {
         if cross_origin_credentials_policy == CrossOriginCredentialsPolicy::Secure {
             debug!("Received a cross-origin redirect. Removing sensitive headers.");
             headers.remove(AUTHORIZATION);
@@ -1098,6 +1159,86 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_redirect_removes_authorization_header_on_scheme_change() -> Result<()> {
+        for status in &[301, 302, 303, 307, 308] {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(*status).insert_header(
+                    "location",
+                    format!(
+                        "{}/redirect",
+                        server.uri().replacen("http://", "https://", 1)
+                    ),
+                ))
+                .mount(&server)
+                .await;
+
+            let request = Client::new()
+                .get(server.uri())
+                .basic_auth("username", Some("password"))
+                .build()
+                .unwrap();
+
+            let response = Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .unwrap()
+                .execute(request.try_clone().unwrap())
+                .await
+                .unwrap();
+
+            let redirect_request =
+                request_into_redirect(request, &response, CrossOriginCredentialsPolicy::Secure)?
+                    .unwrap();
+            assert!(!redirect_request.headers().contains_key(AUTHORIZATION));
+        }
+
+        Ok(())
+    }
+
+    /// Follow a cross-origin redirect end-to-end, and verify that the server at the redirect
+    /// location never receives the credentials.
+    #[tokio::test]
+    async fn test_redirect_cross_origin_does_not_forward_authorization() -> Result<()> {
+        let cdn = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&cdn)
+            .await;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("location", format!("{}/artifact.whl", cdn.uri())),
+            )
+            .mount(&server)
+            .await;
+
+        let client = BaseClientBuilder::new()
+            .redirect(RedirectPolicy::RetriggerMiddleware)
+            .build();
+
+        let request = Client::new()
+            .get(format!("{}/artifact.whl", server.uri()))
+            .basic_auth("username", Some("password"))
+            .build()
+            .unwrap();
+        let response = client.execute(request).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].headers.contains_key("authorization"));
+
+        let requests = cdn.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(!requests[0].headers.contains_key("authorization"));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_redirect_303_changes_post_to_get() -> Result<()> {
         let server = MockServer::start().await;
@@ -51,6 +51,21 @@ impl Error {
         matches!(err.kind(), std::io::ErrorKind::NotFound)
     }
 
+    /// Returns `true` if the request failed in a way that a mirror of the same index might not,
+    /// i.e., due to a connection error or a server error (5xx).
+    pub(crate) fn is_mirror_failure(&self) -> bool {
+        fn is_mirror_failure(kind: &ErrorKind) -> bool {
+            match kind {
+                ErrorKind::WrappedReqwestError(_, err) => {
+                    err.status().is_none_or(|status| status.is_server_error())
+                }
+                ErrorKind::RequestWithRetries { source, .. } => is_mirror_failure(source),
+                _ => false,
+            }
+        }
+        is_mirror_failure(&self.kind)
+    }
+
     /// Returns `true` if the error is due to an SSL error.
     pub fn is_ssl(&self) -> bool {
         matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_ssl())
@@ -333,6 +348,29 @@ impl WrappedReqwestError {
         false
     }
 
+    /// Check if the error chain contains a `reqwest` error that indicates a failure to connect
+    /// through a proxy, like:
+    /// * error sending request for url (...)
+    /// * client error (Connect)
+    /// * unsuccessful tunnel
+    fn is_likely_proxy(&self) -> bool {
+        if let Some(reqwest_err) = self.inner() {
+            if !reqwest_err.is_connect() {
+                return false;
+            }
+            // We have to check for the string because hyper errors are opaque.
+            let mut source = std::error::Error::source(&reqwest_err);
+            while let Some(err) = source {
+                let message = err.to_string().to_lowercase();
+                if message.contains("tunnel") || message.contains("proxy") {
+                    return true;
+                }
+                source = err.source();
+            }
+        }
+        false
+    }
+
     /// Check if the error chain contains a `reqwest` error that looks like this:
     /// * invalid peer certificate: `UnknownIssuer`
     fn is_ssl(&self) -> bool {
@@ -379,6 +417,9 @@ impl Display for WrappedReqwestError {
         if self.is_likely_offline() {
             // Insert an extra hint, we'll show the wrapped error through `source`
             f.write_str("Could not connect, are you offline?")
+        } else if self.is_likely_proxy() {
+            // Insert an extra hint, we'll show the wrapped error through `source`
+            f.write_str("Could not connect through the proxy, is it configured correctly?")
         } else {
             // Show the wrapped error
             Display::fmt(&self.0, f)
@@ -388,7 +429,7 @@ impl Display for WrappedReqwestError {
 
 impl std::error::Error for WrappedReqwestError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        if self.is_likely_offline() {
+        if self.is_likely_offline() || self.is_likely_proxy() {
             // `Display` is inserting an extra message, so we need to show the wrapped error
             Some(&self.0)
         } else {
@@ -6,8 +6,8 @@ use tracing::{Instrument, debug, info_span, warn};
 use url::Url;
 
 use uv_cache::{Cache, CacheBucket};
-use uv_cache_key::cache_digest;
-use uv_distribution_filename::DistFilename;
+use uv_cache_key::{cache_digest, hash_digest};
+use uv_distribution_filename::{DistFilename, SourceDistExtension};
 use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
 use uv_pypi_types::HashDigests;
 use uv_redacted::DisplaySafeUrl;
@@ -25,6 +25,9 @@ pub enum FlatIndexError {
     #[error("Failed to read `--find-links` directory: {0}")]
     FindLinksDirectory(PathBuf, #[source] FindLinksDirectoryError),
 
+    #[error("Failed to read `--find-links` archive: {0}")]
+    FindLinksArchive(PathBuf, #[source] FindLinksArchiveError),
+
     #[error("Failed to read `--find-links` URL: {0}")]
     FindLinksUrl(DisplaySafeUrl, #[source] Error),
 }
@@ -37,6 +40,18 @@ pub enum FindLinksDirectoryError {
     VerbatimUrl(#[from] uv_pep508::VerbatimUrlError),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum FindLinksArchiveError {
+    #[error("Expected a directory or an archive (e.g., `.tar` or `.zip`)")]
+    UnsupportedExtension,
+    #[error("Failed to extract archive")]
+    Extract(#[from] uv_extract::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Directory(#[from] FindLinksDirectoryError),
+}
+
 /// An entry in a `--find-links` index.
 #[derive(Debug, Clone)]
 pub struct FlatIndexEntry {
@@ -146,8 +161,14 @@ impl<'a> FlatIndexClient<'a> {
                 let path = url
                     .to_file_path()
                     .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                Self::read_from_directory(&path, index)
-                    .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))
+                if path.is_file() {
+                    self.read_from_archive(&path, index)
+                        .await
+                        .map_err(|err| FlatIndexError::FindLinksArchive(path.clone(), err))
+                } else {
+                    Self::read_from_directory(&path, index)
+                        .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))
+                }
             }
             IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
                 .read_from_url(url, index)
@@ -253,6 +274,59 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Read a flat remote index from a `--find-links` archive (e.g., a `.tar` or `.zip` of a
+    /// directory of wheels and source distributions).
+    ///
+    /// The archive is unpacked into the cache, keyed by its path and modification time, and its
+    /// members are then read as if the unpacked directory had been passed directly.
+    async fn read_from_archive(
+        &self,
+        path: &Path,
+        flat_index: &IndexUrl,
+    ) -> Result<FlatIndexEntries, FindLinksArchiveError> {
+        let ext = SourceDistExtension::from_path(path)
+            .map_err(|_| FindLinksArchiveError::UnsupportedExtension)?;
+
+        let modified = fs_err::metadata(path)?.modified()?;
+        let target = self
+            .cache
+            .bucket(CacheBucket::FlatIndex)
+            .join("archives")
+            .join(hash_digest(&(path, modified)));
+
+        if !target.is_dir() {
+            debug!("Unpacking `--find-links` archive: {}", path.display());
+
+            let temp_dir = tempfile::tempdir_in(self.cache.bucket(CacheBucket::FlatIndex))?;
+            let reader = fs_err::tokio::File::open(path).await?;
+            uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
+
+            // If the archive contains a single top-level directory, read from that directory.
+            let extracted = match uv_extract::strip_component(temp_dir.path()) {
+                Ok(top_level) if top_level.is_dir() => top_level,
+                Ok(_)
+                | Err(uv_extract::Error::NonSingularArchive(_) | uv_extract::Error::EmptyArchive) => {
+                    temp_dir.path().to_path_buf()
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            // Persist it to the cache.
+            fs_err::tokio::create_dir_all(target.parent().expect("Cache entry to have parent"))
+                .await?;
+            if let Err(err) = uv_fs::rename_with_retry(extracted, &target).await {
+                // If the directory already exists, accept it.
+                if err.kind() == std::io::ErrorKind::AlreadyExists {
+                    warn!("Directory already exists: {}", target.display());
+                } else {
+                    return Err(err.into());
+                }
+            }
+        }
+
+        Ok(Self::read_from_directory(&target, flat_index)?)
+    }
+
     /// Read a flat remote index from a `--find-links` directory.
     fn read_from_directory(
         path: &Path,
@@ -3,14 +3,14 @@ use std::fmt::Debug;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::{HeaderMap, StatusCode};
 use itertools::Either;
 use reqwest::{Proxy, Response};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
@@ -29,10 +29,11 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::{ResolutionMetadata, SimpleJson};
+use uv_pypi_types::{PypiJson, ResolutionMetadata, SimpleJson};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::warn_user;
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
@@ -51,6 +52,8 @@ pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchStrategy>,
+    max_artifact_size: Option<u64>,
+    warn_slow_index: Option<Duration>,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -61,6 +64,8 @@ impl RegistryClientBuilder<'_> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            max_artifact_size: None,
+            warn_slow_index: None,
             cache,
             base_client_builder: BaseClientBuilder::new(),
         }
@@ -71,6 +76,14 @@ impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_locations(mut self, index_locations: &IndexLocations) -> Self {
         self.index_urls = index_locations.index_urls();
+        let indexes = self
+            .index_urls
+            .indexes()
+            .map(|index| index.url().to_string())
+            .collect::<Vec<_>>();
+        if indexes.len() > 1 {
+            debug!("Searching indexes in order: {}", indexes.join(", "));
+        }
         self.base_client_builder = self
             .base_client_builder
             .indexes(Indexes::from(index_locations));
@@ -89,6 +102,21 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    /// Refuse to download any artifact (i.e., wheel or source distribution) larger than the given
+    /// size, in bytes.
+    #[must_use]
+    pub fn max_artifact_size(mut self, max_artifact_size: Option<u64>) -> Self {
+        self.max_artifact_size = max_artifact_size;
+        self
+    }
+
+    /// Warn if the first metadata response from an index takes longer than the given duration.
+    #[must_use]
+    pub fn warn_slow_index(mut self, warn_slow_index: Option<Duration>) -> Self {
+        self.warn_slow_index = warn_slow_index;
+        self
+    }
+
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
         self.base_client_builder = self.base_client_builder.keyring(keyring_type);
@@ -156,6 +184,18 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn proxy_url(mut self, proxy_url: Option<DisplaySafeUrl>) -> Self {
+        self.base_client_builder = self.base_client_builder.proxy_url(proxy_url);
+        self
+    }
+
+    #[must_use]
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.base_client_builder = self.base_client_builder.timeout(timeout);
+        self
+    }
+
     /// Allows credentials to be propagated on cross-origin redirects.
     ///
     /// WARNING: This should only be available for tests. In production code, propagating credentials
@@ -186,11 +226,14 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            max_artifact_size: self.max_artifact_size,
+            warn_slow_index: self.warn_slow_index,
             cache: self.cache,
             connectivity,
             client,
             timeout,
             flat_indexes: Arc::default(),
+            responded_indexes: Arc::default(),
         }
     }
 
@@ -209,11 +252,14 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            max_artifact_size: self.max_artifact_size,
+            warn_slow_index: self.warn_slow_index,
             cache: self.cache,
             connectivity,
             client,
             timeout,
             flat_indexes: Arc::default(),
+            responded_indexes: Arc::default(),
         }
     }
 }
@@ -226,6 +272,8 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            max_artifact_size: None,
+            warn_slow_index: None,
             cache: Cache::temp()?,
             base_client_builder: value,
         })
@@ -241,6 +289,10 @@ pub struct RegistryClient {
     index_strategy: IndexStrategy,
     /// The strategy to use when selecting a PyTorch backend, if any.
     torch_backend: Option<TorchStrategy>,
+    /// The maximum size of any downloaded artifact, in bytes, if any.
+    max_artifact_size: Option<u64>,
+    /// The duration after which a slow first response from an index is reported, if any.
+    warn_slow_index: Option<Duration>,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -251,6 +303,8 @@ pub struct RegistryClient {
     timeout: Duration,
     /// The flat index entries for each `--find-links`-style index URL.
     flat_indexes: Arc<Mutex<FlatIndexCache>>,
+    /// The indexes that have already returned a metadata response.
+    responded_indexes: Arc<std::sync::Mutex<FxHashSet<IndexUrl>>>,
 }
 
 /// The format of the package metadata returned by querying an index.
@@ -288,6 +342,11 @@ impl RegistryClient {
         self.timeout
     }
 
+    /// Return the maximum size of any downloaded artifact, in bytes, if any.
+    pub fn max_artifact_size(&self) -> Option<u64> {
+        self.max_artifact_size
+    }
+
     /// Return the appropriate index URLs for the given [`PackageName`].
     fn index_urls_for(&self, package_name: &PackageName) -> impl Iterator<Item = IndexMetadataRef> {
         self.torch_backend
@@ -349,13 +408,14 @@ impl RegistryClient {
                 for index in indexes {
                     let _permit = download_concurrency.acquire().await;
                     match index.format {
-                        IndexFormat::Simple => {
+                        IndexFormat::Simple | IndexFormat::JsonApi => {
                             let status_code_strategy =
                                 self.index_urls.status_code_strategy_for(index.url);
                             match self
                                 .simple_single_index(
                                     package_name,
                                     index.url,
+                                    index.format,
                                     capabilities,
                                     &status_code_strategy,
                                 )
@@ -388,13 +448,65 @@ impl RegistryClient {
                 }
             }
 
+            // If the indexes are mirrors of one another, fetch serially, moving on to the next index
+            // only if the current one can't be reached.
+            IndexStrategy::Failover => {
+                let mut indexes = indexes.peekable();
+                while let Some(index) = indexes.next() {
+                    let _permit = download_concurrency.acquire().await;
+                    let metadata = match index.format {
+                        IndexFormat::Simple | IndexFormat::JsonApi => {
+                            let status_code_strategy =
+                                self.index_urls.status_code_strategy_for(index.url);
+                            self.simple_single_index(
+                                package_name,
+                                index.url,
+                                index.format,
+                                capabilities,
+                                &status_code_strategy,
+                            )
+                            .await
+                            .map(|outcome| match outcome {
+                                SimpleMetadataSearchOutcome::Found(metadata) => {
+                                    Some(MetadataFormat::Simple(metadata))
+                                }
+                                SimpleMetadataSearchOutcome::NotFound
+                                | SimpleMetadataSearchOutcome::StatusCodeFailure(_) => None,
+                            })
+                        }
+                        IndexFormat::Flat => self
+                            .flat_single_index(package_name, index.url)
+                            .await
+                            .map(|entries| {
+                                (!entries.is_empty()).then_some(MetadataFormat::Flat(entries))
+                            }),
+                    };
+                    match metadata {
+                        Ok(Some(metadata)) => {
+                            results.push((index.url, metadata));
+                            break;
+                        }
+                        // The index is reachable, but doesn't contain the package.
+                        Ok(None) => break,
+                        // The index is unreachable, so fall back to the next mirror.
+                        Err(err) if err.is_mirror_failure() && indexes.peek().is_some() => {
+                            warn_user!(
+                                "Failed to fetch `{package_name}` from {}; falling back to the next index: {err}",
+                                index.url
+                            );
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+
             // Otherwise, fetch concurrently.
             IndexStrategy::UnsafeBestMatch | IndexStrategy::UnsafeFirstMatch => {
                 results = futures::stream::iter(indexes)
                     .map(async |index| {
                         let _permit = download_concurrency.acquire().await;
                         match index.format {
-                            IndexFormat::Simple => {
+                            IndexFormat::Simple | IndexFormat::JsonApi => {
                                 // For unsafe matches, ignore authentication failures.
                                 let status_code_strategy =
                                     IndexStatusCodeStrategy::ignore_authentication_error_codes();
@@ -402,6 +514,7 @@ impl RegistryClient {
                                     .simple_single_index(
                                         package_name,
                                         index.url,
+                                        index.format,
                                         capabilities,
                                         &status_code_strategy,
                                     )
@@ -484,24 +597,32 @@ impl RegistryClient {
 
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
-    /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
-    /// out in the same format.
+    /// The index can either be a PEP 503-compatible remote repository, a local directory laid
+    /// out in the same format, or a repository implementing PyPI's legacy JSON API.
     async fn simple_single_index(
         &self,
         package_name: &PackageName,
         index: &IndexUrl,
+        format: IndexFormat,
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
         // Format the URL for PyPI.
         let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?;
+            segments.pop_if_empty().push(package_name.as_ref());
+            if format == IndexFormat::JsonApi {
+                // The legacy JSON API serves metadata at `<url>/<project>/json`.
+                segments.push("json");
+            } else {
+                // The URL *must* end in a trailing slash for proper relative path behavior
+                // ref https://github.com/servo/rust-url/issues/333
+                segments.push("");
+            }
+        }
 
         trace!("Fetching metadata for {package_name} from {url}");
 
@@ -517,7 +638,7 @@ impl RegistryClient {
                 } else {
                     CacheControl::from(
                         self.cache
-                            .freshness(&cache_entry, Some(package_name), None)
+                            .index_freshness(&cache_entry, Some(package_name))
                             .map_err(ErrorKind::Io)?,
                     )
                 }
@@ -532,13 +653,26 @@ impl RegistryClient {
             lock_entry.lock().await.map_err(ErrorKind::CacheWrite)?
         };
 
-        let result = if matches!(index, IndexUrl::Path(_)) {
-            self.fetch_local_index(package_name, &url).await
-        } else {
-            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
-                .await
+        let start = Instant::now();
+        let result = match (index, format) {
+            (IndexUrl::Path(_), IndexFormat::JsonApi) => {
+                self.fetch_local_json_api(package_name, &url).await
+            }
+            (IndexUrl::Path(_), _) => self.fetch_local_index(package_name, &url).await,
+            (_, IndexFormat::JsonApi) => {
+                self.fetch_remote_json_api(package_name, &url, &cache_entry, cache_control)
+                    .await
+            }
+            _ => {
+                self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
+                    .await
+            }
         };
 
+        if !matches!(index, IndexUrl::Path(_)) {
+            self.warn_if_slow_index(index, start.elapsed());
+        }
+
         match result {
             Ok(metadata) => Ok(SimpleMetadataSearchOutcome::Found(metadata)),
             Err(err) => match err.into_kind() {
@@ -571,6 +705,29 @@ impl RegistryClient {
         }
     }
 
+    /// Warn if the first metadata response from the given index exceeded the configured
+    /// threshold.
+    fn warn_if_slow_index(&self, index: &IndexUrl, elapsed: Duration) {
+        let Some(threshold) = self.warn_slow_index else {
+            return;
+        };
+
+        // Only the first response from each index is considered.
+        if !self.responded_indexes.lock().unwrap().insert(index.clone()) {
+            return;
+        }
+
+        if elapsed > threshold {
+            let url = index.url();
+            warn_user!(
+                "The index at `{}` took {}ms to return its first response, which exceeds the `--warn-slow-index-ms` threshold of {}ms",
+                url.host_str().unwrap_or(url.as_str()),
+                elapsed.as_millis(),
+                threshold.as_millis(),
+            );
+        }
+    }
+
     /// Fetch the [`SimpleMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_index(
         &self,
@@ -643,6 +800,78 @@ impl RegistryClient {
         Ok(simple)
     }
 
+    /// Fetch the [`SimpleMetadata`] from a remote URL, using PyPI's legacy JSON API.
+    async fn fetch_remote_json_api(
+        &self,
+        package_name: &PackageName,
+        url: &DisplaySafeUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let json_request = self
+            .uncached_client(url)
+            .get(Url::from(url.clone()))
+            .header("Accept-Encoding", "gzip, deflate, zstd")
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let parse_json_response = |response: Response| {
+            async {
+                // Use the response URL, rather than the request URL, as the base for relative URLs.
+                let url = DisplaySafeUrl::from(response.url().clone());
+
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                let data: PypiJson = serde_json::from_slice(bytes.as_ref())
+                    .map_err(|err| Error::from_json_err(err, url.clone()))?;
+
+                let unarchived = SimpleMetadata::from_files(data.into_files(), package_name, &url);
+                OwnedArchive::from_unarchived(&unarchived)
+            }
+            .boxed_local()
+            .instrument(info_span!("parse_json_api", package = %package_name))
+        };
+        let simple = self
+            .cached_client()
+            .get_cacheable_with_retry(
+                json_request,
+                cache_entry,
+                cache_control,
+                parse_json_response,
+            )
+            .await?;
+        Ok(simple)
+    }
+
+    /// Fetch the [`SimpleMetadata`] from a local file, using the response format of PyPI's legacy
+    /// JSON API.
+    async fn fetch_local_json_api(
+        &self,
+        package_name: &PackageName,
+        url: &DisplaySafeUrl,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let path = url
+            .to_file_path()
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+        let bytes = match fs_err::tokio::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::from(ErrorKind::FileNotFound(
+                    package_name.to_string(),
+                )));
+            }
+            Err(err) => {
+                return Err(Error::from(ErrorKind::Io(err)));
+            }
+        };
+        let data: PypiJson =
+            serde_json::from_slice(&bytes).map_err(|err| Error::from_json_err(err, url.clone()))?;
+        let metadata = SimpleMetadata::from_files(data.into_files(), package_name, url);
+        OwnedArchive::from_unarchived(&metadata)
+    }
+
     /// Fetch the [`SimpleMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
     async fn fetch_local_index(
@@ -987,7 +1216,7 @@ impl RegistryClient {
             async {
                 let reader = response
                     .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
+                    .map_err(|err| self.handle_response_errors(err, url))
                     .into_async_read();
 
                 read_metadata_async_stream(filename, url.as_ref(), reader)
@@ -1004,12 +1233,12 @@ impl RegistryClient {
     }
 
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
-    fn handle_response_errors(&self, err: reqwest::Error) -> std::io::Error {
+    fn handle_response_errors(&self, err: reqwest::Error, url: &DisplaySafeUrl) -> std::io::Error {
         if err.is_timeout() {
             std::io::Error::new(
                 std::io::ErrorKind::TimedOut,
                 format!(
-                    "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",
+                    "Failed to download distribution from `{url}` due to network timeout. Try increasing `--timeout` or UV_HTTP_TIMEOUT (current value: {}s).",
                     self.timeout().as_secs()
                 ),
             )
@@ -82,6 +82,20 @@ impl BuildOptions {
         }
     }
 
+    /// Allow the given packages to be built from source, overriding `--no-build` and
+    /// `--only-binary`.
+    ///
+    /// Builds can't be re-enabled for individual packages when they're disabled for all packages,
+    /// so in that case, the packages are instead restricted to source distributions, as with
+    /// `--no-binary <package>`.
+    pub fn allow_build(&mut self, packages: &[PackageName]) {
+        match &mut self.no_build {
+            NoBuild::None => {}
+            NoBuild::All => self.no_binary.extend(NoBinary::Packages(packages.to_vec())),
+            NoBuild::Packages(no_build) => no_build.retain(|name| !packages.contains(name)),
+        }
+    }
+
     pub fn no_build_requirement(&self, package_name: Option<&PackageName>) -> bool {
         match package_name {
             Some(name) => self.no_build_package(name),
@@ -351,6 +365,14 @@ pub enum IndexStrategy {
     ///
     /// See: <https://peps.python.org/pep-0708/>
     UnsafeBestMatch,
+    /// Treat the indexes as mirrors of one another, only moving on to the next index if the
+    /// current one can't be reached.
+    ///
+    /// In this strategy, we query the indexes in order for each package, as with `first-index`.
+    /// However, we only consult the next index if the request to the current index fails due to a
+    /// connection error or a server error (e.g., a `503 Service Unavailable`); if the current
+    /// index responds that the package doesn't exist, the search ends there.
+    Failover,
 }
 
 #[cfg(test)]
@@ -416,4 +438,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn allow_build() -> Result<(), Error> {
+        let foo = PackageName::from_str("foo")?;
+        let bar = PackageName::from_str("bar")?;
+
+        // Builds are re-enabled for the package by restricting it to source distributions.
+        let mut build_options = BuildOptions::new(NoBinary::None, NoBuild::All);
+        build_options.allow_build(std::slice::from_ref(&foo));
+        assert!(!build_options.no_build_package(&foo));
+        assert!(build_options.no_binary_package(&foo));
+        assert!(build_options.no_build_package(&bar));
+        assert!(!build_options.no_binary_package(&bar));
+
+        // The package is removed from the list of packages that can't be built.
+        let mut build_options = BuildOptions::new(
+            NoBinary::None,
+            NoBuild::Packages(vec![foo.clone(), bar.clone()]),
+        );
+        build_options.allow_build(std::slice::from_ref(&foo));
+        assert!(!build_options.no_build_package(&foo));
+        assert!(!build_options.no_binary_package(&foo));
+        assert!(build_options.no_build_package(&bar));
+
+        Ok(())
+    }
 }
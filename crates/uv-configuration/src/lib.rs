@@ -10,6 +10,7 @@ pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
 pub use install_options::*;
+pub use marker_override::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
@@ -35,6 +36,7 @@ mod export_format;
 mod extras;
 mod hash;
 mod install_options;
+mod marker_override;
 mod name_specifiers;
 mod overrides;
 mod package_options;
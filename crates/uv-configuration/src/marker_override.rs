@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use uv_pep508::MarkerEnvironment;
+
+/// A marker environment variable that can be overridden via `--marker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarkerVariable {
+    ImplementationName,
+    OsName,
+    PlatformMachine,
+    PlatformPythonImplementation,
+    PlatformRelease,
+    PlatformSystem,
+    PlatformVersion,
+    SysPlatform,
+}
+
+impl MarkerVariable {
+    const ALL: [Self; 8] = [
+        Self::ImplementationName,
+        Self::OsName,
+        Self::PlatformMachine,
+        Self::PlatformPythonImplementation,
+        Self::PlatformRelease,
+        Self::PlatformSystem,
+        Self::PlatformVersion,
+        Self::SysPlatform,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::ImplementationName => "implementation_name",
+            Self::OsName => "os_name",
+            Self::PlatformMachine => "platform_machine",
+            Self::PlatformPythonImplementation => "platform_python_implementation",
+            Self::PlatformRelease => "platform_release",
+            Self::PlatformSystem => "platform_system",
+            Self::PlatformVersion => "platform_version",
+            Self::SysPlatform => "sys_platform",
+        }
+    }
+}
+
+/// An override for a single marker environment variable, e.g., `sys_platform=win32`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkerOverride {
+    /// The variable to override. For example, given `sys_platform=win32`, this would be
+    /// `sys_platform`.
+    variable: MarkerVariable,
+    /// The value of the variable. For example, given `sys_platform=win32`, this would be `win32`.
+    value: String,
+}
+
+impl MarkerOverride {
+    /// Apply the override to the given [`MarkerEnvironment`].
+    #[must_use]
+    pub fn apply(&self, markers: MarkerEnvironment) -> MarkerEnvironment {
+        let value = self.value.as_str();
+        match self.variable {
+            MarkerVariable::ImplementationName => markers.with_implementation_name(value),
+            MarkerVariable::OsName => markers.with_os_name(value),
+            MarkerVariable::PlatformMachine => markers.with_platform_machine(value),
+            MarkerVariable::PlatformPythonImplementation => {
+                markers.with_platform_python_implementation(value)
+            }
+            MarkerVariable::PlatformRelease => markers.with_platform_release(value),
+            MarkerVariable::PlatformSystem => markers.with_platform_system(value),
+            MarkerVariable::PlatformVersion => markers.with_platform_version(value),
+            MarkerVariable::SysPlatform => markers.with_sys_platform(value),
+        }
+    }
+}
+
+impl FromStr for MarkerOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid marker override: {s} (expected `KEY=VALUE`)"
+            ));
+        };
+        let key = key.trim();
+        let Some(variable) = MarkerVariable::ALL
+            .into_iter()
+            .find(|variable| variable.as_str() == key)
+        else {
+            return Err(format!(
+                "Invalid marker override: `{key}` is not a supported marker (expected one of: {})",
+                MarkerVariable::ALL
+                    .iter()
+                    .map(|variable| format!("`{}`", variable.as_str()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        };
+        Ok(Self {
+            variable,
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let marker = MarkerOverride::from_str("sys_platform=win32").unwrap();
+        assert_eq!(
+            marker,
+            MarkerOverride {
+                variable: MarkerVariable::SysPlatform,
+                value: "win32".to_string(),
+            }
+        );
+
+        let marker = MarkerOverride::from_str(" os_name = nt ").unwrap();
+        assert_eq!(
+            marker,
+            MarkerOverride {
+                variable: MarkerVariable::OsName,
+                value: "nt".to_string(),
+            }
+        );
+
+        assert!(MarkerOverride::from_str("sys_platform").is_err());
+        assert!(MarkerOverride::from_str("python_version=3.12").is_err());
+    }
+}
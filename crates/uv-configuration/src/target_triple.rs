@@ -1,6 +1,8 @@
+use std::str::FromStr;
+
 use tracing::debug;
 
-use uv_pep508::MarkerEnvironment;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_platform_tags::{Arch, Os, Platform};
 use uv_static::EnvVars;
 
@@ -770,6 +772,13 @@ impl TargetTriple {
             .with_platform_release(self.platform_release())
             .with_platform_version(self.platform_version())
     }
+
+    /// Return a [`MarkerTree`] that matches the operating system of the target, i.e., its
+    /// `sys_platform`.
+    pub fn sys_platform_marker(self) -> MarkerTree {
+        MarkerTree::from_str(&format!("sys_platform == '{}'", self.sys_platform()))
+            .expect("`sys_platform` marker should be valid")
+    }
 }
 
 /// Return the macOS deployment target as parsed from the environment.
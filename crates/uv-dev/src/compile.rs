@@ -32,13 +32,19 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         interpreter.sys_executable().to_path_buf()
     };
 
-    let files = uv_installer::compile_tree(
+    let compiled = uv_installer::compile_tree(
         &fs_err::canonicalize(args.root)?,
         &interpreter,
         &Concurrency::default(),
         cache.root(),
+        false,
+        &[],
     )
     .await?;
-    info!("Compiled {files} files");
+    info!(
+        "Compiled {} files ({} failed)",
+        compiled.files,
+        compiled.failures.len()
+    );
     Ok(())
 }
@@ -3,7 +3,7 @@
 //! implementing [`BuildContext`].
 
 use std::ffi::{OsStr, OsString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use futures::FutureExt;
@@ -125,6 +125,14 @@ impl<'a> BuildDispatch<'a> {
         concurrency: Concurrency,
         preview: PreviewMode,
     ) -> Self {
+        // Resolve Git branches to the most recent commit prior to the cutoff, like registry
+        // distributions.
+        let shared_state = SharedState {
+            git: shared_state
+                .git
+                .with_exclude_newer(exclude_newer.map(|exclude_newer| exclude_newer.timestamp())),
+            ..shared_state
+        };
         Self {
             client,
             cache,
@@ -142,7 +150,7 @@ impl<'a> BuildDispatch<'a> {
             build_options,
             hasher,
             exclude_newer,
-            source_build_context: SourceBuildContext::default(),
+            source_build_context: SourceBuildContext::new(concurrency.builds),
             build_extra_env_vars: FxHashMap::default(),
             sources,
             workspace_cache,
@@ -165,6 +173,15 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Record each isolated build environment beneath the given directory, for debugging.
+    #[must_use]
+    pub fn with_record_build_env(mut self, record_build_env: Option<PathBuf>) -> Self {
+        self.source_build_context = self
+            .source_build_context
+            .with_record_build_env(record_build_env);
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -295,6 +312,7 @@ impl BuildContext for BuildDispatch<'_> {
             remote,
             reinstalls,
             extraneous: _,
+            satisfied: _,
         } = Planner::new(resolution).build(
             site_packages,
             &Reinstall::default(),
@@ -456,7 +474,6 @@ impl BuildContext for BuildDispatch<'_> {
             build_kind,
             self.build_extra_env_vars.clone(),
             build_output,
-            self.concurrency.builds,
             self.preview,
         )
         .boxed_local()
@@ -441,6 +441,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn build_tag_ordering() {
+        let build_tag = |filename: &str| {
+            WheelFilename::from_str(filename)
+                .unwrap()
+                .build_tag()
+                .cloned()
+        };
+
+        // Wheels without a build tag sort before those with one.
+        assert!(
+            build_tag("foo-1.2.3-py3-none-any.whl") < build_tag("foo-1.2.3-1-py3-none-any.whl")
+        );
+
+        // Build tags are compared numerically, rather than lexicographically.
+        assert!(
+            build_tag("foo-1.2.3-9-py3-none-any.whl") < build_tag("foo-1.2.3-10-py3-none-any.whl")
+        );
+
+        // A suffix acts as a tie-breaker for equal numeric prefixes.
+        assert!(
+            build_tag("foo-1.2.3-1-py3-none-any.whl") < build_tag("foo-1.2.3-1a-py3-none-any.whl")
+        );
+        assert!(
+            build_tag("foo-1.2.3-1b-py3-none-any.whl") < build_tag("foo-1.2.3-2-py3-none-any.whl")
+        );
+    }
+
     #[test]
     fn from_and_to_string() {
         let wheel_names = &[
@@ -22,7 +22,8 @@ impl std::fmt::Display for SourceAnnotation {
                 RequirementOrigin::File(path) => {
                     write!(f, "-r {}", path.portable_display())
                 }
-                RequirementOrigin::Project(path, project_name) => {
+                RequirementOrigin::Project(path, project_name)
+                | RequirementOrigin::Extra(path, project_name, _) => {
                     write!(f, "{project_name} ({})", path.portable_display())
                 }
                 RequirementOrigin::Group(path, project_name, group) => {
@@ -43,7 +44,8 @@ impl std::fmt::Display for SourceAnnotation {
                 RequirementOrigin::File(path) => {
                     write!(f, "--override {}", path.portable_display())
                 }
-                RequirementOrigin::Project(path, project_name) => {
+                RequirementOrigin::Project(path, project_name)
+                | RequirementOrigin::Extra(path, project_name, _) => {
                     // Project is not used for override
                     write!(f, "--override {project_name} ({})", path.portable_display())
                 }
@@ -73,7 +75,18 @@ pub struct SourceAnnotations(BTreeMap<PackageName, BTreeSet<SourceAnnotation>>);
 
 impl SourceAnnotations {
     /// Add a source annotation to the collection for the given package.
+    ///
+    /// Requirements that were provided via a project's extra are attributed to the project itself.
     pub fn add(&mut self, package: &PackageName, annotation: SourceAnnotation) {
+        let annotation = match annotation {
+            SourceAnnotation::Requirement(RequirementOrigin::Extra(path, project_name, _)) => {
+                SourceAnnotation::Requirement(RequirementOrigin::Project(path, project_name))
+            }
+            SourceAnnotation::Override(RequirementOrigin::Extra(path, project_name, _)) => {
+                SourceAnnotation::Override(RequirementOrigin::Project(path, project_name))
+            }
+            annotation => annotation,
+        };
         self.0
             .entry(package.clone())
             .or_default()
@@ -4,7 +4,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, Credentials, OidcTokenProvider};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
@@ -152,6 +152,9 @@ pub enum IndexFormat {
     Simple,
     /// A `--find-links`-style index containing a flat list of wheels and source distributions.
     Flat,
+    /// An index implementing PyPI's legacy JSON API, in which package metadata is served at
+    /// `<url>/<project>/json` (e.g., `https://pypi.org/pypi`).
+    JsonApi,
 }
 
 impl Index {
@@ -246,6 +249,13 @@ impl Index {
         Credentials::from_url(self.url.url())
     }
 
+    /// Whether the index opted in to authentication with OIDC tokens via the environment.
+    pub fn oidc(&self) -> bool {
+        self.name
+            .as_ref()
+            .is_some_and(|name| OidcTokenProvider::enabled_for(name.to_env_var()))
+    }
+
     /// Resolve the index relative to the given root directory.
     pub fn relative_to(mut self, root_dir: &Path) -> Result<Self, IndexUrlError> {
         if let IndexUrl::Path(ref url) = self.url {
@@ -476,6 +476,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                oidc: index.oidc(),
             }
         }))
     }
@@ -541,12 +542,16 @@ impl<'a> IndexUrls {
     ///
     /// If `no_index` was enabled, then this always returns an empty
     /// iterator.
+    ///
+    /// Redundant index URLs are omitted, such that an index is only searched at its
+    /// highest-priority position. URLs are compared after normalization (e.g., ignoring a trailing
+    /// slash or an explicit default port).
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         let mut seen = FxHashSet::default();
         self.implicit_indexes()
             .chain(self.default_index())
             .filter(|index| !index.explicit)
-            .filter(move |index| seen.insert(index.raw_url())) // Filter out redundant raw URLs
+            .filter(move |index| seen.insert(normalized_url(index))) // Filter out redundant URLs
     }
 
     /// Return an iterator over all user-defined [`Index`] entries in order.
@@ -696,6 +701,15 @@ impl IndexCapabilities {
     }
 }
 
+/// Return the URL of an [`Index`], normalized for the purpose of deduplication.
+///
+/// Default ports and the case of the scheme and host are normalized during URL parsing, so we
+/// only need to account for a trailing slash.
+fn normalized_url(index: &Index) -> &str {
+    let url = index.raw_url().as_str();
+    url.strip_suffix('/').unwrap_or(url)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -792,4 +806,31 @@ mod tests {
         assert_eq!(index_urls.simple_api_cache_control_for(&url3), None);
         assert_eq!(index_urls.artifact_cache_control_for(&url3), None);
     }
+
+    #[test]
+    fn test_index_urls_deduplication() {
+        let indexes = vec![
+            Index::from_extra_index_url(IndexUrl::from_str("https://example.com/simple").unwrap()),
+            Index::from_extra_index_url(
+                IndexUrl::from_str("https://example.com:443/simple/").unwrap(),
+            ),
+            Index::from_extra_index_url(
+                IndexUrl::from_str("https://other.example.com/simple").unwrap(),
+            ),
+            Index::from_index_url(IndexUrl::from_str("https://example.com/simple/").unwrap()),
+        ];
+
+        let index_urls = IndexUrls::from_indexes(indexes);
+        let urls = index_urls
+            .indexes()
+            .map(|index| index.raw_url().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/simple",
+                "https://other.example.com/simple"
+            ]
+        );
+    }
 }
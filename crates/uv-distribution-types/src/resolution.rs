@@ -1,3 +1,6 @@
+use std::collections::VecDeque;
+
+use petgraph::Direction;
 use uv_distribution_filename::DistExtension;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pypi_types::{HashDigest, HashDigests};
@@ -92,6 +95,77 @@ impl Resolution {
         self
     }
 
+    /// Filter the resolution to exclude packages that are only required directly (i.e., by the
+    /// root), while retaining their dependencies.
+    ///
+    /// Packages that are required directly, but are also required by another package in the
+    /// resolution, are retained.
+    #[must_use]
+    pub fn without_direct(mut self) -> Self {
+        let direct = self
+            .graph
+            .node_indices()
+            .filter(|&index| {
+                let mut incoming = self
+                    .graph
+                    .neighbors_directed(index, Direction::Incoming)
+                    .filter(|&source| source != index)
+                    .peekable();
+                incoming.peek().is_some()
+                    && incoming.all(|source| matches!(self.graph[source], Node::Root))
+            })
+            .collect::<Vec<_>>();
+        for index in direct {
+            if let Node::Dist { install, .. } = &mut self.graph[index] {
+                *install = false;
+            }
+        }
+        self
+    }
+
+    /// Filter the resolution to exclude packages that are more than `max_depth` levels below the
+    /// requested packages.
+    ///
+    /// The requested packages (i.e., those required by the root) are at a depth of zero, such
+    /// that a `max_depth` of zero excludes all transitive dependencies.
+    #[must_use]
+    pub fn truncate(mut self, max_depth: usize) -> Self {
+        // Perform a breadth-first search from the root, to find the minimum depth of each node.
+        let mut depths = vec![None; self.graph.node_count()];
+        let mut queue = VecDeque::new();
+        for root in self.graph.node_indices() {
+            if !matches!(self.graph[root], Node::Root) {
+                continue;
+            }
+            for index in self.graph.neighbors_directed(root, Direction::Outgoing) {
+                if depths[index.index()].is_none() {
+                    depths[index.index()] = Some(0);
+                    queue.push_back((index, 0));
+                }
+            }
+        }
+        while let Some((index, depth)) = queue.pop_front() {
+            if depth >= max_depth {
+                continue;
+            }
+            for neighbor in self.graph.neighbors_directed(index, Direction::Outgoing) {
+                if depths[neighbor.index()].is_none() {
+                    depths[neighbor.index()] = Some(depth + 1);
+                    queue.push_back((neighbor, depth + 1));
+                }
+            }
+        }
+
+        for index in self.graph.node_indices() {
+            if depths[index.index()].is_none() {
+                if let Node::Dist { install, .. } = &mut self.graph[index] {
+                    *install = false;
+                }
+            }
+        }
+        self
+    }
+
     /// Map over the resolved distributions in this resolution.
     ///
     /// For efficiency, the map function should return `None` if the resolved distribution is
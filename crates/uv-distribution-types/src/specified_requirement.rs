@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 
 use uv_normalize::ExtraName;
 use uv_pep508::{MarkerEnvironment, UnnamedRequirement};
-use uv_pypi_types::Hashes;
+use uv_pypi_types::{Hashes, ParsedUrl};
 
 use crate::{Requirement, RequirementSource, VerbatimParsedUrl};
 
@@ -93,6 +93,30 @@ impl UnresolvedRequirement {
         }
     }
 
+    /// Convert an editable requirement into a non-editable requirement, leaving any other
+    /// requirements unchanged.
+    #[must_use]
+    pub fn into_non_editable(self) -> Self {
+        match self {
+            Self::Named(mut requirement) => {
+                if let RequirementSource::Directory { editable, .. } = &mut requirement.source {
+                    if *editable == Some(true) {
+                        *editable = Some(false);
+                    }
+                }
+                Self::Named(requirement)
+            }
+            Self::Unnamed(mut requirement) => {
+                if let ParsedUrl::Directory(directory) = &mut requirement.url.parsed_url {
+                    if directory.editable == Some(true) {
+                        directory.editable = Some(false);
+                    }
+                }
+                Self::Unnamed(requirement)
+            }
+        }
+    }
+
     /// Return the hashes of the requirement, as specified in the URL fragment.
     pub fn hashes(&self) -> Option<Hashes> {
         match self {
@@ -3,14 +3,15 @@ use std::io;
 use std::path::Path;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::task::{Context, Poll};
 
-use futures::{FutureExt, TryStreamExt};
+use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
 use tempfile::TempDir;
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
@@ -87,12 +88,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
-    fn handle_response_errors(&self, err: reqwest::Error) -> io::Error {
+    fn handle_response_errors(&self, err: reqwest::Error, url: &DisplaySafeUrl) -> io::Error {
         if err.is_timeout() {
             io::Error::new(
                 io::ErrorKind::TimedOut,
                 format!(
-                    "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",
+                    "Failed to download distribution from `{url}` due to network timeout. Try increasing `--timeout` or UV_HTTP_TIMEOUT (current value: {}s).",
                     self.client.unmanaged.timeout().as_secs()
                 ),
             )
@@ -555,19 +556,31 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
 
+        // Track whether the wheel was downloaded, or served from the cache.
+        let downloaded = AtomicBool::new(false);
+
         let download = |response: reqwest::Response| {
             async {
+                downloaded.store(true, Ordering::Relaxed);
                 let size = size.or_else(|| content_length(&response));
+                let download_url = DisplaySafeUrl::from(response.url().clone());
+
+                // Refuse to download the wheel if it's known to exceed the maximum size.
+                let max_artifact_size = self.client.unmanaged.max_artifact_size();
+                check_artifact_size(filename, size, max_artifact_size)?;
 
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                let reader = limit_artifact_size(
+                    response
+                        .bytes_stream()
+                        .map_err(|err| self.handle_response_errors(err, &download_url)),
+                    max_artifact_size,
+                )
+                .into_async_read();
 
                 // Create a hasher for each hash algorithm.
                 let algorithms = hashes.algorithms();
@@ -583,18 +596,29 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
                         uv_extract::stream::unzip(&mut reader, temp_dir.path())
                             .await
-                            .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                            .map_err(|err| {
+                                download_error(err, filename, |err| {
+                                    Error::Extract(filename.to_string(), err)
+                                })
+                            })?;
                     }
                     None => {
                         uv_extract::stream::unzip(&mut hasher, temp_dir.path())
                             .await
-                            .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                            .map_err(|err| {
+                                download_error(err, filename, |err| {
+                                    Error::Extract(filename.to_string(), err)
+                                })
+                            })?;
                     }
                 }
 
                 // If necessary, exhaust the reader to compute the hash.
                 if !hashes.is_none() {
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
+                    hasher
+                        .finish()
+                        .await
+                        .map_err(|err| download_error(err, filename, Error::HashExhaustion))?;
                 }
 
                 // Persist the temporary directory to the directory store.
@@ -609,11 +633,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                    filename.clone(),
-                ))
+                let hashes = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                log_download(dist, &download_url, hashes.as_slice());
+
+                Ok(Archive::new(id, hashes, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -685,6 +711,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .await?
         };
 
+        if !downloaded.load(Ordering::Relaxed) {
+            debug!("Using cached wheel for: {dist}");
+        }
+
         Ok(archive)
     }
 
@@ -709,19 +739,31 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
 
+        // Track whether the wheel was downloaded, or served from the cache.
+        let downloaded = AtomicBool::new(false);
+
         let download = |response: reqwest::Response| {
             async {
+                downloaded.store(true, Ordering::Relaxed);
                 let size = size.or_else(|| content_length(&response));
+                let download_url = DisplaySafeUrl::from(response.url().clone());
+
+                // Refuse to download the wheel if it's known to exceed the maximum size.
+                let max_artifact_size = self.client.unmanaged.max_artifact_size();
+                check_artifact_size(filename, size, max_artifact_size)?;
 
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                let reader = limit_artifact_size(
+                    response
+                        .bytes_stream()
+                        .map_err(|err| self.handle_response_errors(err, &download_url)),
+                    max_artifact_size,
+                )
+                .into_async_read();
 
                 // Download the wheel to a temporary file.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
@@ -738,12 +780,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
-                            .map_err(Error::CacheWrite)?;
+                            .map_err(|err| download_error(err, filename, Error::CacheWrite))?;
                     }
                     None => {
                         tokio::io::copy(&mut reader.compat(), &mut writer)
                             .await
-                            .map_err(Error::CacheWrite)?;
+                            .map_err(|err| download_error(err, filename, Error::CacheWrite))?;
                     }
                 }
 
@@ -797,6 +839,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
+                log_download(dist, &download_url, hashes.as_slice());
+
                 Ok(Archive::new(id, hashes, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
@@ -869,6 +913,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .await?
         };
 
+        if !downloaded.load(Ordering::Relaxed) {
+            debug!("Using cached wheel for: {dist}");
+        }
+
         Ok(archive)
     }
 
@@ -902,6 +950,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
+            debug!("Using cached wheel for: {dist}");
             Ok(LocalWheel {
                 dist: Dist::Built(dist.clone()),
                 archive: self
@@ -1086,7 +1135,7 @@ impl<'a> ManagedClient<'a> {
 }
 
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
-fn content_length(response: &reqwest::Response) -> Option<u64> {
+pub(crate) fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
         .headers()
         .get(reqwest::header::CONTENT_LENGTH)
@@ -1094,6 +1143,98 @@ fn content_length(response: &reqwest::Response) -> Option<u64> {
         .and_then(|val| val.parse::<u64>().ok())
 }
 
+/// An error raised when a downloaded artifact turns out to exceed the maximum artifact size
+/// while it's being streamed.
+#[derive(Debug, thiserror::Error)]
+#[error("The download exceeded the maximum artifact size of {0} bytes")]
+struct ArtifactSizeExceeded(u64);
+
+/// Returns an error if the artifact is known to exceed the maximum artifact size, if any.
+pub(crate) fn check_artifact_size(
+    artifact: &impl std::fmt::Display,
+    size: Option<u64>,
+    limit: Option<u64>,
+) -> Result<(), Error> {
+    if let (Some(size), Some(limit)) = (size, limit) {
+        if size > limit {
+            return Err(Error::ArtifactTooLarge {
+                artifact: artifact.to_string(),
+                limit,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Enforce the maximum artifact size, if any, on a response body, failing as soon as more than
+/// the allowed number of bytes have been received.
+///
+/// Unlike [`check_artifact_size`], this applies to responses that don't declare their size
+/// up-front (e.g., chunked responses).
+pub(crate) fn limit_artifact_size<T: AsRef<[u8]>>(
+    stream: impl Stream<Item = io::Result<T>> + Unpin,
+    limit: Option<u64>,
+) -> impl Stream<Item = io::Result<T>> + Unpin {
+    let mut received = 0u64;
+    stream.map(move |chunk| {
+        let chunk = chunk?;
+        received += chunk.as_ref().len() as u64;
+        match limit {
+            Some(limit) if received > limit => Err(io::Error::other(ArtifactSizeExceeded(limit))),
+            _ => Ok(chunk),
+        }
+    })
+}
+
+/// Convert an error encountered while downloading an artifact (e.g., while writing, extracting,
+/// or hashing the response body), surfacing any violation of the maximum artifact size.
+///
+/// Any other error is converted with `fallback`.
+pub(crate) fn download_error<E: std::error::Error + 'static>(
+    err: E,
+    artifact: &impl std::fmt::Display,
+    fallback: impl FnOnce(E) -> Error,
+) -> Error {
+    // The violation is raised as an I/O error by the response stream, which may in turn be
+    // wrapped by the decompression or extraction layers.
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+    while let Some(current) = source {
+        let exceeded = current.downcast_ref::<ArtifactSizeExceeded>().or_else(|| {
+            current
+                .downcast_ref::<io::Error>()
+                .and_then(io::Error::get_ref)
+                .and_then(|err| err.downcast_ref::<ArtifactSizeExceeded>())
+        });
+        if let Some(ArtifactSizeExceeded(limit)) = exceeded {
+            return Error::ArtifactTooLarge {
+                artifact: artifact.to_string(),
+                limit: *limit,
+            };
+        }
+        source = current.source();
+    }
+    fallback(err)
+}
+
+/// Log the provenance of a downloaded artifact: the URL it was ultimately fetched from (i.e.,
+/// after following any redirects), along with any hashes computed during the download.
+pub(crate) fn log_download(
+    artifact: &impl std::fmt::Display,
+    url: &DisplaySafeUrl,
+    hashes: &[HashDigest],
+) {
+    if hashes.is_empty() {
+        debug!("Downloaded `{artifact}` from: {url}");
+    } else {
+        let hashes = hashes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        debug!("Downloaded `{artifact}` from: {url} ({hashes})");
+    }
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 struct ProgressReader<'a, R> {
     reader: R,
@@ -88,6 +88,8 @@ pub enum Error {
     MissingPkgInfo,
     #[error("The source distribution `{}` has no subdirectory `{}`", _0, _1.display())]
     MissingSubdirectory(DisplaySafeUrl, PathBuf),
+    #[error("Expected a path to a wheel file, but received: `{}`", _0.user_display())]
+    MissingWheelFilename(PathBuf),
     #[error("Failed to extract static metadata from `PKG-INFO`")]
     PkgInfo(#[source] uv_pypi_types::MetadataError),
     #[error("Failed to extract metadata from `requires.txt`")]
@@ -118,6 +120,11 @@ pub enum Error {
     #[error("The task executor is broken, did some other task panic?")]
     Join(#[from] JoinError),
 
+    #[error(
+        "Refusing to download `{artifact}`, which exceeds the maximum artifact size of {limit} bytes"
+    )]
+    ArtifactTooLarge { artifact: String, limit: u64 },
+
     /// An I/O error that occurs while exhausting a reader to compute a hash.
     #[error("Failed to hash distribution")]
     HashExhaustion(#[source] std::io::Error),
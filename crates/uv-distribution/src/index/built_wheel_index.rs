@@ -140,7 +140,11 @@ impl<'a> BuiltWheelIndex<'a> {
         };
 
         // If the distribution is stale, omit it from the index.
-        let cache_info = CacheInfo::from_directory(&source_dist.install_path)?;
+        let cache_info = if source_dist.editable.unwrap_or(false) {
+            CacheInfo::from_editable(&source_dist.install_path)?
+        } else {
+            CacheInfo::from_directory(&source_dist.install_path)?
+        };
 
         if cache_info != *pointer.cache_info() {
             return Ok(None);
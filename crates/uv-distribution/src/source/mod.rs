@@ -10,7 +10,7 @@
 
 use std::borrow::Cow;
 use std::ops::Bound;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -46,7 +46,10 @@ use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, Resol
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
 use uv_workspace::pyproject::ToolUvSources;
 
-use crate::distribution_database::ManagedClient;
+use crate::distribution_database::{
+    ManagedClient, check_artifact_size, content_length, download_error, limit_artifact_size,
+    log_download,
+};
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
@@ -452,6 +455,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
@@ -746,7 +750,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
                 let algorithms = hashes.algorithms();
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        client.unmanaged.max_artifact_size(),
+                    )
                     .await?;
 
                 Ok(revision.with_hashes(HashDigests::from(hashes)))
@@ -841,6 +852,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(built_wheel);
         }
 
@@ -1145,6 +1157,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(built_wheel);
         }
 
@@ -1390,7 +1403,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Determine the last-modified time of the source distribution.
-        let cache_info = CacheInfo::from_directory(&resource.install_path)?;
+        let cache_info = if resource.editable.unwrap_or(false) {
+            CacheInfo::from_editable(&resource.install_path)?
+        } else {
+            CacheInfo::from_directory(&resource.install_path)?
+        };
 
         // Read the existing metadata from the cache.
         let entry = cache_shard.entry(LOCAL_REVISION);
@@ -1504,9 +1521,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             )
             .await?;
 
+        // If the subdirectory points at a committed wheel, install it rather than building.
+        let committed_wheel = committed_wheel(fetch.path(), resource.subdirectory);
+
         // Validate that the subdirectory exists.
         if let Some(subdirectory) = resource.subdirectory {
-            if !fetch.path().join(subdirectory).is_dir() {
+            if committed_wheel.is_none() && !fetch.path().join(subdirectory).is_dir() {
                 return Err(Error::MissingSubdirectory(
                     resource.url.to_url(),
                     subdirectory.to_path_buf(),
@@ -1538,29 +1558,38 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(built_wheel);
         }
 
-        let task = self
-            .reporter
-            .as_ref()
-            .map(|reporter| reporter.on_build_start(source));
+        let (disk_filename, filename, metadata) = if let Some(wheel) = committed_wheel {
+            debug!("Using committed wheel for: {source}");
+            self.copy_committed_wheel(source, &wheel, &cache_shard)
+                .await?
+        } else {
+            let task = self
+                .reporter
+                .as_ref()
+                .map(|reporter| reporter.on_build_start(source));
 
-        let (disk_filename, filename, metadata) = self
-            .build_distribution(
-                source,
-                fetch.path(),
-                resource.subdirectory,
-                &cache_shard,
-                self.build_context.sources(),
-            )
-            .await?;
+            let built = self
+                .build_distribution(
+                    source,
+                    fetch.path(),
+                    resource.subdirectory,
+                    &cache_shard,
+                    self.build_context.sources(),
+                )
+                .await?;
 
-        if let Some(task) = task {
-            if let Some(reporter) = self.reporter.as_ref() {
-                reporter.on_build_complete(source, task);
+            if let Some(task) = task {
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_build_complete(source, task);
+                }
             }
-        }
+
+            built
+        };
 
         // Store the metadata.
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -1694,9 +1723,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             )
             .await?;
 
+        // If the subdirectory points at a committed wheel, install it rather than building.
+        let committed_wheel = committed_wheel(fetch.path(), resource.subdirectory);
+
         // Validate that the subdirectory exists.
         if let Some(subdirectory) = resource.subdirectory {
-            if !fetch.path().join(subdirectory).is_dir() {
+            if committed_wheel.is_none() && !fetch.path().join(subdirectory).is_dir() {
                 return Err(Error::MissingSubdirectory(
                     resource.url.to_url(),
                     subdirectory.to_path_buf(),
@@ -1704,6 +1736,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If the subdirectory points at a committed wheel, read the metadata from the wheel.
+        if let Some(wheel) = committed_wheel {
+            let filename = WheelFilename::from_str(&committed_wheel_filename(&wheel)?)?;
+            let metadata = read_wheel_metadata(&filename, &wheel)?;
+            validate_metadata(source, &metadata)?;
+            validate_filename(&filename, &metadata)?;
+            return Ok(ArchiveMetadata {
+                metadata: Metadata::from_metadata23(metadata),
+                hashes: HashDigests::empty(),
+            });
+        }
+
         let git_sha = fetch.git().precise().expect("Exact commit after checkout");
         let cache_shard = self.build_context.cache().shard(
             CacheBucket::SourceDistributions,
@@ -2132,7 +2176,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        client.unmanaged.max_artifact_size(),
+                    )
                     .await?;
                 for existing in revision.hashes() {
                     if !hashes.contains(existing) {
@@ -2171,17 +2222,22 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         ext: SourceDistExtension,
         target: &Path,
         algorithms: &[HashAlgorithm],
+        max_artifact_size: Option<u64>,
     ) -> Result<Vec<HashDigest>, Error> {
-        let temp_dir = tempfile::tempdir_in(
-            self.build_context
-                .cache()
-                .bucket(CacheBucket::SourceDistributions),
+        // Refuse to download the source distribution if it's known to exceed the maximum size.
+        check_artifact_size(source, content_length(&response), max_artifact_size)?;
+
+        let temp_dir = self
+            .build_context
+            .cache()
+            .temp_dir_in(CacheBucket::SourceDistributions)
+            .map_err(Error::CacheWrite)?;
+        let download_url = DisplaySafeUrl::from(response.url().clone());
+        let reader = limit_artifact_size(
+            response.bytes_stream().map_err(std::io::Error::other),
+            max_artifact_size,
         )
-        .map_err(Error::CacheWrite)?;
-        let reader = response
-            .bytes_stream()
-            .map_err(std::io::Error::other)
-            .into_async_read();
+        .into_async_read();
 
         // Create a hasher for each hash algorithm.
         let mut hashers = algorithms
@@ -2195,20 +2251,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let span = info_span!("download_source_dist", source_dist = %source);
         uv_extract::stream::archive(&mut hasher, ext, temp_dir.path())
             .await
-            .map_err(|err| Error::Extract(source.to_string(), err))?;
+            .map_err(|err| {
+                download_error(err, source, |err| Error::Extract(source.to_string(), err))
+            })?;
         drop(span);
 
         // If necessary, exhaust the reader to compute the hash.
         if !algorithms.is_empty() {
-            hasher.finish().await.map_err(Error::HashExhaustion)?;
+            hasher
+                .finish()
+                .await
+                .map_err(|err| download_error(err, source, Error::HashExhaustion))?;
         }
 
-        let hashes = hashers.into_iter().map(HashDigest::from).collect();
+        let hashes = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+        log_download(source, &download_url, &hashes);
 
         // Extract the top-level directory.
         let extracted = match uv_extract::strip_component(temp_dir.path()) {
             Ok(top_level) => top_level,
-            Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.keep(),
+            Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
             Err(err) => {
                 return Err(Error::Extract(
                     temp_dir.path().to_string_lossy().into_owned(),
@@ -2243,12 +2308,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<Vec<HashDigest>, Error> {
         debug!("Unpacking for build: {}", path.display());
 
-        let temp_dir = tempfile::tempdir_in(
-            self.build_context
-                .cache()
-                .bucket(CacheBucket::SourceDistributions),
-        )
-        .map_err(Error::CacheWrite)?;
+        let temp_dir = self
+            .build_context
+            .cache()
+            .temp_dir_in(CacheBucket::SourceDistributions)
+            .map_err(Error::CacheWrite)?;
         let reader = fs_err::tokio::File::open(&path)
             .await
             .map_err(Error::CacheRead)?;
@@ -2301,6 +2365,36 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Copy a pre-built wheel from a Git checkout into the cache, in lieu of a build.
+    ///
+    /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
+    async fn copy_committed_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        wheel: &Path,
+        cache_shard: &CacheShard,
+    ) -> Result<(String, WheelFilename, ResolutionMetadata), Error> {
+        let disk_filename = committed_wheel_filename(wheel)?;
+
+        // Read the metadata from the wheel.
+        let filename = WheelFilename::from_str(&disk_filename)?;
+        let metadata = read_wheel_metadata(&filename, wheel)?;
+
+        // Validate the metadata.
+        validate_metadata(source, &metadata)?;
+        validate_filename(&filename, &metadata)?;
+
+        // Copy the wheel to the cache, leaving the checkout untouched.
+        fs::create_dir_all(&cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        fs::copy(wheel, cache_shard.join(&disk_filename))
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok((disk_filename, filename, metadata))
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -2749,6 +2843,29 @@ impl StaticMetadata {
     }
 }
 
+/// Returns the path to a pre-built wheel committed to a Git repository, if the subdirectory points
+/// at a wheel file (e.g., `#subdirectory=dist/foo-1.0-py3-none-any.whl`) rather than a directory.
+fn committed_wheel(fetch_root: &Path, subdirectory: Option<&Path>) -> Option<PathBuf> {
+    let path = fetch_root.join(subdirectory?);
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("whl"))
+        && path.is_file()
+    {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Returns the (un-normalized) filename of a wheel committed to a Git repository.
+fn committed_wheel_filename(wheel: &Path) -> Result<String, Error> {
+    let filename = wheel
+        .file_name()
+        .ok_or_else(|| Error::MissingWheelFilename(wheel.to_path_buf()))?;
+    Ok(filename.to_string_lossy().to_string())
+}
+
 /// Returns `true` if a `pyproject.toml` has `tool.uv.sources`.
 fn has_sources(content: &str) -> Result<bool, toml::de::Error> {
     #[derive(serde::Deserialize)]
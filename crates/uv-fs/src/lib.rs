@@ -575,6 +575,43 @@ pub fn is_temporary(path: impl AsRef<Path>) -> bool {
         .is_some_and(|name| name.starts_with(".tmp"))
 }
 
+/// The maximum length of a path on Windows, unless long path support is enabled.
+pub const WINDOWS_MAX_PATH: usize = 260;
+
+/// Returns `true` if an I/O error indicates that a path exceeded the Windows maximum path length.
+///
+/// Windows reports over-long paths as either `ERROR_FILENAME_EXCED_RANGE` or, when long path
+/// support is disabled, `ERROR_PATH_NOT_FOUND`. Since the latter is also returned for paths that
+/// are genuinely missing, it's only attributed to the path length if the longest path involved in
+/// the operation (`max_len`) exceeds [`WINDOWS_MAX_PATH`].
+///
+/// Always returns `false` on other platforms.
+#[cfg_attr(not(windows), allow(unused_variables))]
+pub fn is_path_too_long(err: &io::Error, max_len: usize) -> bool {
+    #[cfg(windows)]
+    {
+        const ERROR_PATH_NOT_FOUND: i32 = 3;
+        const ERROR_FILENAME_EXCED_RANGE: i32 = 206;
+
+        // Errors from `fs_err` wrap the underlying OS error.
+        let code = err.raw_os_error().or_else(|| {
+            err.get_ref()
+                .and_then(|err| err.source())
+                .and_then(|err| err.downcast_ref::<io::Error>())
+                .and_then(io::Error::raw_os_error)
+        });
+        match code {
+            Some(ERROR_FILENAME_EXCED_RANGE) => true,
+            Some(ERROR_PATH_NOT_FOUND) => max_len > WINDOWS_MAX_PATH,
+            _ => false,
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        false
+    }
+}
+
 /// Checks if the grandparent directory of the given executable is the base
 /// of a virtual environment.
 ///
@@ -663,6 +700,30 @@ impl LockedFile {
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Attempt to acquire a cross-process lock for a resource using a file at the provided path,
+    /// returning `None` if the lock is held by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = Self::create(path)?;
+        trace!(
+            "Checking lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                debug!("Lock for `{resource}` is held by another process");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     #[cfg(unix)]
     fn create(path: impl AsRef<Path>) -> Result<fs_err::File, std::io::Error> {
         use std::os::unix::fs::PermissionsExt;
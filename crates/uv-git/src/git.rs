@@ -9,6 +9,7 @@ use std::sync::LazyLock;
 
 use anyhow::{Context, Result};
 use cargo_util::{ProcessBuilder, paths};
+use jiff::Timestamp;
 use reqwest::StatusCode;
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::{debug, warn};
@@ -36,6 +37,16 @@ pub enum GitError {
         "Remote Git fetches are not allowed because network connectivity is disabled (i.e., with `--offline`)"
     )]
     TransportNotAllowed,
+    #[error("No commit on the branch was made at or before {0}")]
+    NoCommitBefore(Timestamp),
+}
+
+impl GitError {
+    /// Returns `true` if the error indicates that a branch exists, but has no commit at or before
+    /// the `--exclude-newer` cutoff.
+    fn is_no_commit_before(err: &anyhow::Error) -> bool {
+        matches!(err.downcast_ref::<Self>(), Some(Self::NoCommitBefore(_)))
+    }
 }
 
 /// A global cache of the result of `which git`.
@@ -65,8 +76,18 @@ enum ReferenceOrOid<'reference> {
 
 impl ReferenceOrOid<'_> {
     /// Resolves the [`ReferenceOrOid`] to an object ID with objects the `repo` currently has.
-    fn resolve(&self, repo: &GitRepository) -> Result<GitOid> {
+    ///
+    /// If `before` is provided, branches resolve to the most recent commit on the branch that was
+    /// committed at or before the given timestamp, rather than the branch head.
+    fn resolve(&self, repo: &GitRepository, before: Option<Timestamp>) -> Result<GitOid> {
         let refkind = self.kind_str();
+        let branch = |refname: &str| {
+            let oid = repo.rev_parse(&format!("{refname}^0"))?;
+            match before {
+                Some(before) => repo.rev_list_before(oid, before),
+                None => Ok(oid),
+            }
+        };
         let result = match self {
             // Resolve the commit pointed to by the tag.
             //
@@ -77,23 +98,30 @@ impl ReferenceOrOid<'_> {
             }
 
             // Resolve the commit pointed to by the branch.
-            Self::Reference(GitReference::Branch(s)) => repo.rev_parse(&format!("origin/{s}^0")),
-
-            // Attempt to resolve the branch, then the tag.
-            Self::Reference(GitReference::BranchOrTag(s)) => repo
-                .rev_parse(&format!("origin/{s}^0"))
-                .or_else(|_| repo.rev_parse(&format!("refs/remotes/origin/tags/{s}^0"))),
+            Self::Reference(GitReference::Branch(s)) => branch(&format!("origin/{s}")),
+
+            // Attempt to resolve the branch, then the tag. If the branch exists, but has no commit
+            // before the cutoff, don't fall back to a tag of the same name.
+            Self::Reference(GitReference::BranchOrTag(s)) => branch(&format!("origin/{s}"))
+                .or_else(|err| {
+                    if GitError::is_no_commit_before(&err) {
+                        return Err(err);
+                    }
+                    repo.rev_parse(&format!("refs/remotes/origin/tags/{s}^0"))
+                }),
 
             // Attempt to resolve the branch, then the tag, then the commit.
-            Self::Reference(GitReference::BranchOrTagOrCommit(s)) => repo
-                .rev_parse(&format!("origin/{s}^0"))
-                .or_else(|_| repo.rev_parse(&format!("refs/remotes/origin/tags/{s}^0")))
-                .or_else(|_| repo.rev_parse(&format!("{s}^0"))),
+            Self::Reference(GitReference::BranchOrTagOrCommit(s)) => branch(&format!("origin/{s}"))
+                .or_else(|err| {
+                    if GitError::is_no_commit_before(&err) {
+                        return Err(err);
+                    }
+                    repo.rev_parse(&format!("refs/remotes/origin/tags/{s}^0"))
+                        .or_else(|_| repo.rev_parse(&format!("{s}^0")))
+                }),
 
             // We'll be using the HEAD commit.
-            Self::Reference(GitReference::DefaultBranch) => {
-                repo.rev_parse("refs/remotes/origin/HEAD")
-            }
+            Self::Reference(GitReference::DefaultBranch) => branch("refs/remotes/origin/HEAD"),
 
             // Resolve a named reference.
             Self::Reference(GitReference::NamedRef(s)) => repo.rev_parse(&format!("{s}^0")),
@@ -204,6 +232,27 @@ impl GitRepository {
         result.truncate(result.trim_end().len());
         Ok(result.parse()?)
     }
+
+    /// Returns the most recent commit reachable from `oid` via first parents (i.e., that was on
+    /// the same branch) with a commit timestamp at or before `before`.
+    fn rev_list_before(&self, oid: GitOid, before: Timestamp) -> Result<GitOid> {
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("rev-list")
+            .arg("--max-count=1")
+            .arg("--first-parent")
+            .arg(format!("--before={}", before.as_second()))
+            .arg(oid.as_str())
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        let mut result = String::from_utf8(result.stdout)?;
+        result.truncate(result.trim_end().len());
+        if result.is_empty() {
+            return Err(GitError::NoCommitBefore(before).into());
+        }
+        debug!("Resolved `{oid}` to `{result}`, the most recent commit at or before {before}");
+        Ok(result.parse()?)
+    }
 }
 
 impl GitRemote {
@@ -223,7 +272,8 @@ impl GitRemote {
     /// This ensures that it gets the up-to-date commit when a named reference
     /// is given (tag, branch, refs/*). Thus, network connection is involved.
     ///
-    /// When `locked_rev` is provided, it takes precedence over `reference`.
+    /// When `locked_rev` is provided, it takes precedence over `reference`. Otherwise, when
+    /// `exclude_newer` is provided, branches resolve to the most recent commit at or before it.
     ///
     /// If we have a previous instance of [`GitDatabase`] then fetch into that
     /// if we can. If that can successfully load our revision then we've
@@ -235,6 +285,7 @@ impl GitRemote {
         db: Option<GitDatabase>,
         reference: &GitReference,
         locked_rev: Option<GitOid>,
+        exclude_newer: Option<Timestamp>,
         client: &ClientWithMiddleware,
         disable_ssl: bool,
         offline: bool,
@@ -257,7 +308,13 @@ impl GitRemote {
 
             let resolved_commit_hash = match locked_rev {
                 Some(rev) => db.contains(rev).then_some(rev),
-                None => reference.resolve(&db.repo).ok(),
+                None => match reference.resolve(&db.repo, exclude_newer) {
+                    Ok(rev) => Some(rev),
+                    // The branch was fetched, but has no commit before the cutoff, so a fresh
+                    // clone wouldn't help; fail without discarding the database.
+                    Err(err) if GitError::is_no_commit_before(&err) => return Err(err),
+                    Err(_) => None,
+                },
             };
 
             if let Some(rev) = resolved_commit_hash {
@@ -291,7 +348,7 @@ impl GitRemote {
         .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
-            None => reference.resolve(&repo)?,
+            None => reference.resolve(&repo, exclude_newer)?,
         };
         if enable_lfs_fetch {
             fetch_lfs(&mut repo, &self.url, &rev, disable_ssl)
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use dashmap::DashMap;
 use dashmap::mapref::one::Ref;
 use fs_err::tokio as fs;
+use jiff::Timestamp;
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::debug;
 
@@ -36,17 +37,35 @@ pub enum GitResolverError {
 
 /// A resolver for Git repositories.
 #[derive(Default, Clone)]
-pub struct GitResolver(Arc<DashMap<RepositoryReference, GitOid>>);
+pub struct GitResolver {
+    /// The precise commit for each [`RepositoryReference`] that has been resolved.
+    precise: Arc<DashMap<RepositoryReference, GitOid>>,
+    /// The checkouts that have been fetched, keyed by repository and precise commit, such that
+    /// packages in different subdirectories of the same repository share a single checkout.
+    checkouts: Arc<DashMap<(RepositoryUrl, GitOid), PathBuf>>,
+    /// Resolve branches to the most recent commit at or before this timestamp, if set.
+    exclude_newer: Option<Timestamp>,
+}
 
 impl GitResolver {
+    /// Resolve branches to the most recent commit at or before the given timestamp, rather than
+    /// the branch head (e.g., for `--exclude-newer`).
+    #[must_use]
+    pub fn with_exclude_newer(self, exclude_newer: Option<Timestamp>) -> Self {
+        Self {
+            exclude_newer,
+            ..self
+        }
+    }
+
     /// Inserts a new [`GitOid`] for the given [`RepositoryReference`].
     pub fn insert(&self, reference: RepositoryReference, sha: GitOid) {
-        self.0.insert(reference, sha);
+        self.precise.insert(reference, sha);
     }
 
     /// Returns the [`GitOid`] for the given [`RepositoryReference`], if it exists.
     fn get(&self, reference: &RepositoryReference) -> Option<Ref<RepositoryReference, GitOid>> {
-        self.0.get(reference)
+        self.precise.get(reference)
     }
 
     pub fn get_precise(&self, url: &GitUrl) -> Option<GitOid> {
@@ -82,6 +101,12 @@ impl GitResolver {
             return Ok(Some(precise));
         }
 
+        // The GitHub API resolves the current head of a branch, which may be newer than the
+        // `--exclude-newer` cutoff.
+        if self.exclude_newer.is_some() {
+            return Ok(None);
+        }
+
         // If the URL is a GitHub URL, attempt to resolve it via the GitHub API.
         let Some(GitHubRepository { owner, repo }) = GitHubRepository::parse(url.repository())
         else {
@@ -153,8 +178,20 @@ impl GitResolver {
 
         let reference = RepositoryReference::from(url);
 
+        // Avoid races between concurrent fetches of the same repository, both within and across
+        // processes.
+        let lock_dir = cache.join("locks");
+        fs::create_dir_all(&lock_dir).await?;
+        let repository_url = RepositoryUrl::new(url.repository());
+        let _lock = LockedFile::acquire(
+            lock_dir.join(cache_digest(&repository_url)),
+            &repository_url,
+        )
+        .await?;
+
         // If we know the precise commit already, reuse it, to ensure that all fetches within a
-        // single process are consistent.
+        // single process are consistent. This happens after acquiring the lock, such that a
+        // concurrent fetch of the same repository observes the commit resolved by the first.
         let url = {
             if let Some(precise) = self.get(&reference) {
                 Cow::Owned(url.clone().with_precise(*precise))
@@ -163,15 +200,16 @@ impl GitResolver {
             }
         };
 
-        // Avoid races between different processes, too.
-        let lock_dir = cache.join("locks");
-        fs::create_dir_all(&lock_dir).await?;
-        let repository_url = RepositoryUrl::new(url.repository());
-        let _lock = LockedFile::acquire(
-            lock_dir.join(cache_digest(&repository_url)),
-            &repository_url,
-        )
-        .await?;
+        // If the commit has already been checked out by this process, reuse the checkout.
+        if let Some(precise) = url.precise() {
+            if let Some(path) = self.checkouts.get(&(repository_url.clone(), precise)) {
+                debug!(
+                    "Reusing Git checkout of `{}` at `{precise}`",
+                    url.repository()
+                );
+                return Ok(Fetch::new(url.into_owned(), path.clone()));
+            }
+        }
 
         // Fetch the Git repository.
         let source = if let Some(reporter) = reporter {
@@ -186,6 +224,7 @@ impl GitResolver {
         } else {
             source
         };
+        let source = source.with_exclude_newer(self.exclude_newer);
 
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
@@ -195,6 +234,8 @@ impl GitResolver {
         // resolve to the same precise commit.
         if let Some(precise) = fetch.git().precise() {
             self.insert(reference, precise);
+            self.checkouts
+                .insert((repository_url, precise), fetch.path().to_path_buf());
         }
 
         Ok(fetch)
@@ -3,16 +3,20 @@
 //! Source: <https://github.com/rust-lang/cargo/blob/23eb492cf920ce051abfc56bbaf838514dc8365c/src/cargo/sources/git/source.rs>
 
 use std::borrow::Cow;
+use std::env;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
+use jiff::Timestamp;
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::{debug, instrument};
 
 use uv_cache_key::{RepositoryUrl, cache_digest};
 use uv_git_types::{GitOid, GitReference, GitUrl};
 use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::GIT_STORE;
 use crate::git::{GitDatabase, GitRemote};
@@ -29,6 +33,8 @@ pub struct GitSource {
     offline: bool,
     /// The path to the Git source database.
     cache: PathBuf,
+    /// Resolve branches to the most recent commit at or before this timestamp, if set.
+    exclude_newer: Option<Timestamp>,
     /// The reporter to use for this source.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -47,10 +53,20 @@ impl GitSource {
             offline,
             client: client.into(),
             cache: cache.into(),
+            exclude_newer: None,
             reporter: None,
         }
     }
 
+    /// Resolve branches to the most recent commit at or before the given timestamp.
+    #[must_use]
+    pub fn with_exclude_newer(self, exclude_newer: Option<Timestamp>) -> Self {
+        Self {
+            exclude_newer,
+            ..self
+        }
+    }
+
     /// Disable SSL verification for this [`GitSource`].
     #[must_use]
     pub fn dangerous(self) -> Self {
@@ -132,6 +148,7 @@ impl GitSource {
                 maybe_db,
                 self.git.reference(),
                 self.git.precise(),
+                self.exclude_newer,
                 &self.client,
                 self.disable_ssl,
                 self.offline,
@@ -155,6 +172,16 @@ impl GitSource {
 
         db.copy_to(actual_rev, &checkout_path)?;
 
+        // If the repository uses Git LFS, but LFS fetching is disabled, any files tracked by LFS
+        // will be checked out as pointer files.
+        if env::var(EnvVars::UV_GIT_LFS).is_err() && uses_lfs(&checkout_path) {
+            warn_user_once!(
+                "`{}` uses Git LFS, but LFS objects were not fetched; any files tracked by Git LFS will be replaced by pointer files (hint: set `{}=1` to fetch them)",
+                self.git.repository(),
+                EnvVars::UV_GIT_LFS,
+            );
+        }
+
         // Report the checkout operation to the reporter.
         if let Some(task) = maybe_task {
             if let Some(reporter) = self.reporter.as_ref() {
@@ -177,6 +204,10 @@ pub struct Fetch {
 }
 
 impl Fetch {
+    pub(crate) fn new(git: GitUrl, path: PathBuf) -> Self {
+        Self { git, path }
+    }
+
     pub fn git(&self) -> &GitUrl {
         &self.git
     }
@@ -194,6 +225,23 @@ impl Fetch {
     }
 }
 
+/// Returns `true` if the top-level `.gitattributes` file in the checkout declares any Git LFS
+/// filters.
+fn uses_lfs(checkout: &Path) -> bool {
+    let Ok(attributes) = fs_err::read_to_string(checkout.join(".gitattributes")) else {
+        return false;
+    };
+    attributes
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .any(|line| {
+            line.split_whitespace()
+                .skip(1)
+                .any(|attribute| attribute == "filter=lfs")
+        })
+}
+
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
@@ -1,19 +1,25 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use fs_err as fs;
 use fs_err::File;
-use tracing::{instrument, trace};
+use rustc_hash::FxHashSet;
+use tracing::{debug, instrument, trace, warn};
+use walkdir::WalkDir;
 
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
+use uv_fs::{Simplified, normalize_path};
+use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::{DirectUrl, Metadata10};
 
 use crate::linker::{LinkMode, Locks};
+use crate::record::RecordEntry;
 use crate::wheel::{
     LibKind, dist_info_metadata, find_dist_info, install_data, parse_scripts, parse_wheel_file,
     read_record_file, write_installer_metadata, write_script_entrypoints,
@@ -24,6 +30,9 @@ use crate::{Error, Layout};
 ///
 /// The caller must ensure that the wheel is compatible to the environment.
 ///
+/// If `sandbox_scripts` is set, the wheel's scripts (including the launchers generated for its
+/// entrypoints) are installed without executable permissions.
+///
 /// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl>
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
@@ -31,6 +40,7 @@ use crate::{Error, Layout};
 pub fn install_wheel(
     layout: &Layout,
     relocatable: bool,
+    sandbox_scripts: bool,
     wheel: impl AsRef<Path>,
     filename: &WheelFilename,
     direct_url: Option<&DirectUrl>,
@@ -75,19 +85,111 @@ pub fn install_wheel(
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks)?;
-    trace!(?name, "Extracted {num_unpacked} files");
+
+    // If we run out of disk space (or exceed the Windows path length limit) partway through,
+    // remove whatever we've written for this wheel, rather than leaving a partially installed
+    // package behind. Any files that already existed (e.g., from a previous installation, or
+    // shared with another package) are retained.
+    let existing = existing_targets(
+        layout,
+        site_packages,
+        wheel.as_ref(),
+        &dist_info_prefix,
+        &name,
+    );
+    let mut record = Vec::new();
+    match install_files(
+        layout,
+        relocatable,
+        sandbox_scripts,
+        site_packages,
+        wheel.as_ref(),
+        &dist_info_prefix,
+        &name,
+        direct_url,
+        cache_info,
+        installer,
+        installer_metadata,
+        link_mode,
+        locks,
+        &mut record,
+    ) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            if let Some(source) = err.storage_full() {
+                warn!("Ran out of disk space while installing `{name}`; rolling back");
+                remove_partial_install(site_packages, wheel.as_ref(), &record, &existing);
+                Err(Error::OutOfDiskSpace(name, source))
+            } else if let Some(source) =
+                err.path_too_long(longest_target_path(site_packages, wheel.as_ref()))
+            {
+                warn!("Exceeded the maximum path length while installing `{name}`; rolling back");
+                remove_partial_install(site_packages, wheel.as_ref(), &record, &existing);
+                Err(Error::PathTooLong(name, source))
+            } else {
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Return the length of the longest path that linking the unpacked `wheel` into `site_packages`
+/// would create.
+fn longest_target_path(site_packages: &Path, wheel: &Path) -> usize {
+    let longest = WalkDir::new(wheel)
+        .min_depth(1)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .path()
+                .strip_prefix(wheel)
+                .ok()
+                .map(|relative| relative.as_os_str().len())
+        })
+        .max()
+        .unwrap_or_default();
+    site_packages.as_os_str().len() + 1 + longest
+}
+
+/// Link the files from the unpacked wheel into `site_packages`, then write the entrypoints, data
+/// files, and metadata.
+///
+/// Every file that's installed is tracked in `record`, such that a partial installation can be
+/// rolled back.
+fn install_files(
+    layout: &Layout,
+    relocatable: bool,
+    sandbox_scripts: bool,
+    site_packages: &Path,
+    wheel: &Path,
+    dist_info_prefix: &str,
+    name: &PackageName,
+    direct_url: Option<&DirectUrl>,
+    cache_info: Option<&CacheInfo>,
+    installer: Option<&str>,
+    installer_metadata: bool,
+    link_mode: LinkMode,
+    locks: &Locks,
+    record: &mut Vec<RecordEntry>,
+) -> Result<(), Error> {
+    let linked = link_mode.link_wheel_files(site_packages, wheel, locks)?;
+    trace!(?name, "Extracted {} files", linked.count);
+    if linked.mode == link_mode {
+        debug!("Installed `{name}` using link mode `{}`", linked.mode);
+    } else {
+        debug!(
+            "Installed `{name}` using link mode `{}` (fell back from `{link_mode}`)",
+            linked.mode
+        );
+    }
 
     // Read the RECORD file.
-    let mut record_file = File::open(
-        wheel
-            .as_ref()
-            .join(format!("{dist_info_prefix}.dist-info/RECORD")),
-    )?;
-    let mut record = read_record_file(&mut record_file)?;
+    let mut record_file = File::open(wheel.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
+    *record = read_record_file(&mut record_file)?;
 
     let (console_scripts, gui_scripts) =
-        parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
+        parse_scripts(wheel, dist_info_prefix, None, layout.python_version.1)?;
 
     if console_scripts.is_empty() && gui_scripts.is_empty() {
         trace!(?name, "No entrypoints");
@@ -98,17 +200,19 @@ pub fn install_wheel(
         write_script_entrypoints(
             layout,
             relocatable,
+            sandbox_scripts,
             site_packages,
             &console_scripts,
-            &mut record,
+            record,
             false,
         )?;
         write_script_entrypoints(
             layout,
             relocatable,
+            sandbox_scripts,
             site_packages,
             &gui_scripts,
-            &mut record,
+            record,
             true,
         )?;
     }
@@ -120,12 +224,13 @@ pub fn install_wheel(
         install_data(
             layout,
             relocatable,
+            sandbox_scripts,
             site_packages,
             &data_dir,
-            &name,
+            name,
             &console_scripts,
             &gui_scripts,
-            &mut record,
+            record,
         )?;
         // 2.c If applicable, update scripts starting with #!python to point to the correct interpreter.
         // Script are unsupported through data
@@ -139,12 +244,12 @@ pub fn install_wheel(
         trace!(?name, "Writing installer metadata");
         write_installer_metadata(
             site_packages,
-            &dist_info_prefix,
+            dist_info_prefix,
             true,
             direct_url,
             cache_info,
             installer,
-            &mut record,
+            record,
         )?;
     }
 
@@ -154,9 +259,200 @@ pub fn install_wheel(
         .escape(b'"')
         .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
     record.sort();
-    for entry in record {
+    for entry in record.iter() {
         record_writer.serialize(entry)?;
     }
 
     Ok(())
 }
+
+/// Return the paths that installing the unpacked `wheel` could write to, and that already exist.
+///
+/// Only descends into directories that already exist, such that installing into a fresh
+/// environment requires a single check per top-level entry in the wheel.
+fn existing_targets(
+    layout: &Layout,
+    site_packages: &Path,
+    wheel: &Path,
+    dist_info_prefix: &str,
+    name: &PackageName,
+) -> FxHashSet<PathBuf> {
+    let mut existing = FxHashSet::default();
+    let data_dir = format!("{dist_info_prefix}.data");
+
+    // Collect the existing files and directories under `target` that correspond to `source`.
+    let mut collect = |source: &Path, target: &Path, skip: Option<&str>| {
+        let mut walker = WalkDir::new(source).min_depth(1).into_iter();
+        while let Some(entry) = walker.next() {
+            let Ok(entry) = entry else {
+                continue;
+            };
+            if entry.depth() == 1 && skip.is_some_and(|skip| entry.file_name() == skip) {
+                walker.skip_current_dir();
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(source) else {
+                continue;
+            };
+            let path = normalize_path(&target.join(relative)).into_owned();
+            if path.symlink_metadata().is_ok() {
+                existing.insert(path);
+            } else if entry.file_type().is_dir() {
+                walker.skip_current_dir();
+            }
+        }
+    };
+
+    // Files linked into `site-packages`.
+    collect(wheel, site_packages, Some(&data_dir));
+
+    // Files moved out of the `.data` directory.
+    let data_dir = wheel.join(data_dir);
+    collect(&data_dir.join("data"), &layout.scheme.data, None);
+    collect(&data_dir.join("scripts"), &layout.scheme.scripts, None);
+    collect(
+        &data_dir.join("headers"),
+        &layout.scheme.include.join(name.as_str()),
+        None,
+    );
+    collect(&data_dir.join("purelib"), &layout.scheme.purelib, None);
+    collect(&data_dir.join("platlib"), &layout.scheme.platlib, None);
+
+    // Entrypoints, which can share their names with any existing scripts.
+    if let Ok(entries) = fs::read_dir(&layout.scheme.scripts) {
+        existing.extend(
+            entries
+                .flatten()
+                .map(|entry| normalize_path(&entry.path()).into_owned()),
+        );
+    }
+
+    existing
+}
+
+/// Remove the files written by a failed installation of the unpacked wheel at `wheel`.
+///
+/// Removes every file linked from the wheel into `site_packages`, along with any files recorded
+/// prior to the failure (e.g., entrypoints and data files), and then any directories from the
+/// wheel that were left empty. Paths in `existing` predate the installation, and are retained.
+/// Failures are logged, but otherwise ignored.
+fn remove_partial_install(
+    site_packages: &Path,
+    wheel: &Path,
+    record: &[RecordEntry],
+    existing: &FxHashSet<PathBuf>,
+) {
+    let mut files = Vec::new();
+    let mut dirs = Vec::new();
+    for entry in WalkDir::new(wheel).min_depth(1).into_iter().flatten() {
+        let Ok(relative) = entry.path().strip_prefix(wheel) else {
+            continue;
+        };
+        let path = normalize_path(&site_packages.join(relative)).into_owned();
+        if entry.file_type().is_dir() {
+            dirs.push(path);
+        } else {
+            files.push(path);
+        }
+    }
+    files.extend(
+        record
+            .iter()
+            .map(|entry| normalize_path(&site_packages.join(&entry.path)).into_owned()),
+    );
+
+    for path in files {
+        if existing.contains(&path) {
+            continue;
+        }
+        match fs::remove_file(&path) {
+            Ok(()) => trace!("Removed partially installed file: {}", path.user_display()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => warn!("Failed to remove partially installed file: {err}"),
+        }
+    }
+
+    // Remove the deepest directories first, leaving any that are still in use.
+    dirs.sort_unstable_by(|a, b| b.cmp(a));
+    for dir in dirs {
+        if existing.contains(&dir) {
+            continue;
+        }
+        if fs::remove_dir(&dir).is_ok() {
+            trace!(
+                "Removed partially installed directory: {}",
+                dir.user_display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use uv_normalize::PackageName;
+    use uv_pypi_types::Scheme;
+
+    use crate::Layout;
+    use crate::record::RecordEntry;
+
+    use super::{existing_targets, remove_partial_install};
+
+    /// A rolled back installation should only remove the files it created, and retain any files
+    /// that existed before it (e.g., those shared with another package).
+    #[test]
+    fn remove_partial_install_retains_existing() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let site_packages = temp_dir.child("lib/site-packages");
+        let scripts = temp_dir.child("bin");
+
+        // An unpacked wheel that shares a namespace package with an installed package.
+        let wheel = temp_dir.child("wheel");
+        wheel.child("shared/__init__.py").write_str("")?;
+        wheel.child("shared/pkg/__init__.py").write_str("")?;
+        wheel.child("pkg-1.0.dist-info/RECORD").write_str("")?;
+
+        site_packages.child("shared/__init__.py").write_str("")?;
+        scripts.child("other").write_str("")?;
+
+        let layout = Layout {
+            sys_executable: scripts.child("python").to_path_buf(),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.to_path_buf(),
+                platlib: site_packages.to_path_buf(),
+                scripts: scripts.to_path_buf(),
+                data: temp_dir.to_path_buf(),
+                include: temp_dir.child("include").to_path_buf(),
+            },
+        };
+        let name = PackageName::from_str("pkg")?;
+        let existing = existing_targets(&layout, &site_packages, &wheel, "pkg-1.0", &name);
+
+        // Simulate an installation that failed after linking the package and writing an entrypoint.
+        site_packages.child("shared/__init__.py").write_str("")?;
+        site_packages
+            .child("shared/pkg/__init__.py")
+            .write_str("")?;
+        scripts.child("pkg").write_str("")?;
+        let record = vec![RecordEntry {
+            path: "../../bin/pkg".to_string(),
+            hash: None,
+            size: None,
+        }];
+
+        remove_partial_install(&site_packages, &wheel, &record, &existing);
+
+        assert!(site_packages.child("shared/__init__.py").path().is_file());
+        assert!(!site_packages.child("shared/pkg").path().exists());
+        assert!(scripts.child("other").path().is_file());
+        assert!(!scripts.child("pkg").path().exists());
+
+        Ok(())
+    }
+}
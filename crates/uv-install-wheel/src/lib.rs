@@ -11,14 +11,20 @@ use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
 pub use install::install_wheel;
-pub use linker::{LinkMode, Locks};
-pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
-pub use wheel::{LibKind, parse_wheel_file, read_record_file};
+pub use linker::{LinkMode, Linked, Locks};
+pub use record::RecordEntry;
+pub use strip::strip_debug;
+pub use uninstall::{
+    Uninstall, corrupt_files, modified_files, uninstall_egg, uninstall_legacy_editable,
+    uninstall_wheel,
+};
+pub use wheel::{LibKind, entrypoint_targets, parse_wheel_file, read_record_file};
 
 mod install;
 mod linker;
 mod record;
 mod script;
+mod strip;
 mod uninstall;
 mod wheel;
 
@@ -72,6 +78,8 @@ pub enum Error {
     MissingRecord(PathBuf),
     #[error("Cannot uninstall package; `top_level.txt` file not found at: {}", _0.user_display())]
     MissingTopLevel(PathBuf),
+    #[error("Failed to strip debug symbols from `{}`: {}", _0.user_display(), _1)]
+    Strip(PathBuf, String),
     #[error("Invalid package version")]
     InvalidVersion(#[from] uv_pep440::VersionParseError),
     #[error("Wheel package name does not match filename: {0} != {1}")]
@@ -84,4 +92,43 @@ pub enum Error {
     LauncherError(#[from] uv_trampoline_builder::Error),
     #[error("Scripts must not use the reserved name {0}")]
     ReservedScriptName(String),
+    #[error("Not enough disk space to install `{0}`; removed the partially installed files")]
+    OutOfDiskSpace(PackageName, #[source] io::Error),
+    #[error(
+        "Failed to install `{0}` because a path exceeds the Windows maximum path length of {max} characters; enable long path support (see https://learn.microsoft.com/en-us/windows/win32/fileio/maximum-file-path-limitation) or use a shorter path for the environment",
+        max = uv_fs::WINDOWS_MAX_PATH
+    )]
+    PathTooLong(PackageName, #[source] io::Error),
+}
+
+impl Error {
+    /// Returns the underlying I/O error, if any.
+    fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            Self::Io(err) | Self::Reflink { err, .. } => Some(err),
+            Self::WalkDir(err) => err.io_error(),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying I/O error if the device ran out of space.
+    pub(crate) fn storage_full(&self) -> Option<io::Error> {
+        let err = self.io_error()?;
+        if err.kind() == io::ErrorKind::StorageFull {
+            Some(io::Error::new(err.kind(), err.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the underlying I/O error if a path exceeded the Windows maximum path length, given
+    /// the length of the longest path involved in the operation.
+    pub(crate) fn path_too_long(&self, max_len: usize) -> Option<io::Error> {
+        let err = self.io_error()?;
+        if uv_fs::is_path_too_long(err, max_len) {
+            Some(io::Error::new(err.kind(), err.to_string()))
+        } else {
+            None
+        }
+    }
 }
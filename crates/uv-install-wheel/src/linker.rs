@@ -22,6 +22,9 @@ pub struct Locks(Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>);
 pub enum LinkMode {
     /// Clone (i.e., copy-on-write) packages from the wheel into the `site-packages` directory.
     Clone,
+    /// Clone (i.e., copy-on-write) packages from the wheel into the `site-packages` directory,
+    /// failing if the files cannot be cloned instead of falling back to a copy.
+    Reflink,
     /// Copy packages from the wheel into the `site-packages` directory.
     Copy,
     /// Hard link packages from the wheel into the `site-packages` directory.
@@ -53,10 +56,16 @@ impl LinkMode {
         site_packages: impl AsRef<Path>,
         wheel: impl AsRef<Path>,
         locks: &Locks,
-    ) -> Result<usize, Error> {
+    ) -> Result<Linked, Error> {
         match self {
-            Self::Clone => clone_wheel_files(site_packages, wheel, locks),
-            Self::Copy => copy_wheel_files(site_packages, wheel, locks),
+            Self::Clone => clone_wheel_files(site_packages, wheel, locks, self, Attempt::Initial),
+            Self::Reflink => {
+                clone_wheel_files(site_packages, wheel, locks, self, Attempt::NoFallback)
+            }
+            Self::Copy => Ok(Linked {
+                count: copy_wheel_files(site_packages, wheel, locks)?,
+                mode: Self::Copy,
+            }),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks),
             Self::Symlink => symlink_wheel_files(site_packages, wheel, locks),
         }
@@ -68,19 +77,47 @@ impl LinkMode {
     }
 }
 
+impl std::fmt::Display for LinkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clone => write!(f, "clone"),
+            Self::Reflink => write!(f, "reflink"),
+            Self::Copy => write!(f, "copy"),
+            Self::Hardlink => write!(f, "hardlink"),
+            Self::Symlink => write!(f, "symlink"),
+        }
+    }
+}
+
+/// The result of linking the files from a wheel into site packages.
+#[derive(Debug, Clone, Copy)]
+pub struct Linked {
+    /// The number of entries that were linked.
+    pub count: usize,
+    /// The link mode that was used in practice, which is [`LinkMode::Copy`] if the requested mode
+    /// wasn't supported and the installer fell back to copying.
+    pub mode: LinkMode,
+}
+
 /// Extract a wheel by cloning all of its files into site packages. The files will be cloned
 /// via copy-on-write, which is similar to a hard link, but allows the files to be modified
 /// independently (that is, the file is copied upon modification).
 ///
 /// This method uses `clonefile` on macOS, and `reflink` on Linux. See [`clone_recursive`] for
 /// details.
+///
+/// If `attempt` is [`Attempt::Initial`], a failure to clone the first file will trigger a fallback
+/// to copying; if it's [`Attempt::NoFallback`], any failure to clone is returned as an error.
+/// Unless the installer falls back to copying, the requested `mode` is reported as the effective
+/// link mode.
 fn clone_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
-) -> Result<usize, Error> {
+    mode: LinkMode,
+    mut attempt: Attempt,
+) -> Result<Linked, Error> {
     let mut count = 0usize;
-    let mut attempt = Attempt::default();
 
     for entry in fs::read_dir(wheel.as_ref())? {
         clone_recursive(
@@ -118,7 +155,10 @@ fn clone_wheel_files(
         ),
     }
 
-    Ok(count)
+    Ok(Linked {
+        count,
+        mode: attempt.mode(mode),
+    })
 }
 
 // Hard linking / reflinking might not be supported but we (afaik) can't detect this ahead of time,
@@ -131,6 +171,20 @@ enum Attempt {
     Initial,
     Subsequent,
     UseCopyFallback,
+    /// Never fall back to copying, even if the first file fails to link (e.g., for
+    /// [`LinkMode::Reflink`]).
+    NoFallback,
+}
+
+impl Attempt {
+    /// Return the [`LinkMode`] that was used in practice, given the requested [`LinkMode`].
+    fn mode(self, requested: LinkMode) -> LinkMode {
+        if self == Self::UseCopyFallback {
+            LinkMode::Copy
+        } else {
+            requested
+        }
+    }
 }
 
 /// Recursively clone the contents of `from` into `to`.
@@ -208,7 +262,7 @@ fn clone_recursive(
                 }
             }
         }
-        Attempt::Subsequent => {
+        Attempt::Subsequent | Attempt::NoFallback => {
             if let Err(err) = reflink::reflink(&from, &to) {
                 if err.kind() == std::io::ErrorKind::AlreadyExists {
                     // If cloning/copying fails and the directory exists already, it must be merged recursively.
@@ -283,7 +337,7 @@ fn hardlink_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
-) -> Result<usize, Error> {
+) -> Result<Linked, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
 
@@ -344,7 +398,7 @@ fn hardlink_wheel_files(
                     }
                 }
             }
-            Attempt::Subsequent => {
+            Attempt::Subsequent | Attempt::NoFallback => {
                 if let Err(err) = fs::hard_link(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
@@ -373,7 +427,10 @@ fn hardlink_wheel_files(
         count += 1;
     }
 
-    Ok(count)
+    Ok(Linked {
+        count,
+        mode: attempt.mode(LinkMode::Hardlink),
+    })
 }
 
 /// Extract a wheel by symbolically-linking all of its files into site packages.
@@ -381,7 +438,7 @@ fn symlink_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
-) -> Result<usize, Error> {
+) -> Result<Linked, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
 
@@ -442,7 +499,7 @@ fn symlink_wheel_files(
                     }
                 }
             }
-            Attempt::Subsequent => {
+            Attempt::Subsequent | Attempt::NoFallback => {
                 if let Err(err) = create_symlink(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
@@ -471,7 +528,10 @@ fn symlink_wheel_files(
         count += 1;
     }
 
-    Ok(count)
+    Ok(Linked {
+        count,
+        mode: attempt.mode(LinkMode::Symlink),
+    })
 }
 
 /// Copy from `from` to `to`, ensuring that the parent directory is locked. Avoids simultaneous
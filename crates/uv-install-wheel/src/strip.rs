@@ -0,0 +1,89 @@
+use std::path::Path;
+use std::process::Command;
+
+use fs_err as fs;
+use tracing::debug;
+
+use crate::Error;
+use crate::uninstall::{normalize_path, read_record};
+use crate::wheel::copy_and_hash;
+
+/// Strip debug symbols from the native extension modules (i.e., `.so` and `.pyd` files) of the
+/// wheel represented by the given `.dist-info` directory, using the given `strip` executable.
+///
+/// Each module is stripped into a temporary file, which then replaces the module, such that any
+/// hard links to the original (e.g., in the cache) are left intact. The `RECORD` is updated with
+/// the hashes and sizes of the stripped modules.
+///
+/// Returns the number of bytes saved.
+pub fn strip_debug(dist_info: &Path, strip: &Path) -> Result<u64, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    let mut record = read_record(dist_info)?;
+    let mut saved = 0;
+    let mut modified = false;
+    for entry in &mut record {
+        if Path::new(&entry.path)
+            .extension()
+            .is_none_or(|extension| extension != "so" && extension != "pyd")
+        {
+            continue;
+        }
+
+        let path = normalize_path(&site_packages.join(&entry.path));
+        let Some(parent) = path.parent() else {
+            continue;
+        };
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        let temp_dir = tempfile::tempdir_in(parent)?;
+        let stripped = temp_dir.path().join("stripped");
+        let output = Command::new(strip)
+            .arg("-S")
+            .arg("-o")
+            .arg(&stripped)
+            .arg(&path)
+            .output()?;
+        if !output.status.success() {
+            return Err(Error::Strip(
+                path,
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        fs::set_permissions(&stripped, metadata.permissions())?;
+
+        let (size, hash) = copy_and_hash(&mut fs::File::open(&stripped)?, &mut std::io::sink())?;
+        fs::rename(&stripped, &path)?;
+        debug!(
+            "Stripped debug symbols from: {} ({} to {size} bytes)",
+            path.display(),
+            metadata.len()
+        );
+
+        saved += metadata.len().saturating_sub(size);
+        entry.hash = Some(hash);
+        entry.size = Some(size);
+        modified = true;
+    }
+
+    if modified {
+        let mut record_writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .escape(b'"')
+            .from_path(dist_info.join("RECORD"))?;
+        for entry in &record {
+            record_writer.serialize(entry)?;
+        }
+        record_writer.flush()?;
+    }
+
+    Ok(saved)
+}
@@ -3,33 +3,94 @@ use std::path::{Component, Path, PathBuf};
 
 use fs_err as fs;
 use std::sync::{LazyLock, Mutex};
-use tracing::trace;
+use tracing::{debug, trace};
 use uv_fs::write_atomic_sync;
+use uv_warnings::warn_user;
 
 use crate::Error;
-use crate::wheel::read_record_file;
+use crate::record::RecordEntry;
+use crate::wheel::{copy_and_hash, read_record_file};
+
+/// Read the `RECORD` file of the given `.dist-info` directory.
+pub(crate) fn read_record(dist_info: &Path) -> Result<Vec<RecordEntry>, Error> {
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = match fs::File::open(&record_path) {
+        Ok(record_file) => record_file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    read_record_file(&mut record_file)
+}
 
-/// Uninstall the wheel represented by the given `.dist-info` directory.
-pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
+/// Return the files of the wheel represented by the given `.dist-info` directory that no longer
+/// match the hashes in its `RECORD` (e.g., because they were modified in place).
+///
+/// Entries without a SHA-256 hash (like the `RECORD` itself, or compiled bytecode) and files that
+/// no longer exist are ignored.
+pub fn modified_files(dist_info: &Path) -> Result<Vec<PathBuf>, Error> {
+    mismatched_files(dist_info, false)
+}
+
+/// Return the files of the wheel represented by the given `.dist-info` directory that are missing
+/// or no longer match the hashes in its `RECORD`.
+///
+/// Entries without a SHA-256 hash (like the `RECORD` itself, or compiled bytecode) are ignored.
+pub fn corrupt_files(dist_info: &Path) -> Result<Vec<PathBuf>, Error> {
+    mismatched_files(dist_info, true)
+}
+
+/// Return the files in the `RECORD` of the given `.dist-info` directory that don't match their
+/// recorded hashes, optionally including any files that no longer exist.
+fn mismatched_files(dist_info: &Path, include_missing: bool) -> Result<Vec<PathBuf>, Error> {
     let Some(site_packages) = dist_info.parent() else {
         return Err(Error::BrokenVenv(
             "dist-info directory is not in a site-packages directory".to_string(),
         ));
     };
 
-    // Read the RECORD file.
-    let record = {
-        let record_path = dist_info.join("RECORD");
-        let mut record_file = match fs::File::open(&record_path) {
-            Ok(record_file) => record_file,
+    let mut modified = Vec::new();
+    for entry in read_record(dist_info)? {
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .filter(|hash| hash.starts_with("sha256="))
+        else {
+            continue;
+        };
+        let path = normalize_path(&site_packages.join(&entry.path));
+        let mut file = match fs::File::open(&path) {
+            Ok(file) => file,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                return Err(Error::MissingRecord(record_path));
+                if include_missing {
+                    debug!("Installed file is missing: {}", path.display());
+                    modified.push(path);
+                }
+                continue;
             }
             Err(err) => return Err(err.into()),
         };
-        read_record_file(&mut record_file)?
+        let (_, actual) = copy_and_hash(&mut file, &mut std::io::sink())?;
+        if actual != expected {
+            debug!("Installed file does not match `RECORD`: {}", path.display());
+            modified.push(path);
+        }
+    }
+    Ok(modified)
+}
+
+/// Uninstall the wheel represented by the given `.dist-info` directory.
+pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
     };
 
+    // Read the RECORD file.
+    let record = read_record(dist_info)?;
+
     let mut file_count = 0usize;
     let mut dir_count = 0usize;
 
@@ -38,9 +99,34 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
 
     // Uninstall the files, keeping track of any directories that are left empty.
     let mut visited = BTreeSet::new();
+    let mut links = BTreeSet::new();
     for entry in &record {
         let path = site_packages.join(&entry.path);
 
+        // If the file is (or is within) a symlink that points outside of the environment (e.g.,
+        // into a read-only Nix or conda store), remove the symlink itself, but leave its target
+        // untouched.
+        if let Some(link) = external_symlink(&path, site_packages) {
+            if links.insert(link.clone()) {
+                warn_user!(
+                    "Removing symlink `{}` without removing its target, which is outside of the environment",
+                    link.display()
+                );
+                match remove_link(&link) {
+                    Ok(()) => {
+                        trace!("Removed symlink: {}", link.display());
+                        file_count += 1;
+                        if let Some(parent) = link.parent() {
+                            visited.insert(normalize_path(parent));
+                        }
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            continue;
+        }
+
         // On Windows, deleting the current executable is a special case.
         #[cfg(windows)]
         if let Some(itself) = itself.as_ref() {
@@ -49,7 +135,7 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 .is_some_and(|itself| path.file_name().is_some_and(|path| itself == path))
             {
                 if same_file::is_same_file(itself, &path).unwrap_or(false) {
-                    tracing::debug!("Detected self-delete of executable: {}", path.display());
+                    debug!("Detected self-delete of executable: {}", path.display());
                     match self_replace::self_delete_outside_path(site_packages) {
                         Ok(()) => {
                             trace!("Removed file: {}", path.display());
@@ -73,6 +159,12 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 if let Some(parent) = path.parent() {
                     visited.insert(normalize_path(parent));
                 }
+
+                // Remove any bytecode compiled from the file, which may not be listed in the
+                // RECORD (e.g., if it was compiled after installation).
+                if path.extension().is_some_and(|ext| ext == "py") {
+                    file_count += remove_bytecode(&path)?;
+                }
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => match fs::remove_dir_all(&path) {
@@ -104,32 +196,41 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 break;
             }
 
-            // If the directory contains a `__pycache__` directory, always remove it. `__pycache__`
-            // may or may not be listed in the RECORD, but installers are expected to be smart
-            // enough to remove it either way.
-            let pycache = path.join("__pycache__");
-            match fs::remove_dir_all(&pycache) {
-                Ok(()) => {
-                    trace!("Removed directory: {}", pycache.display());
-                    dir_count += 1;
-                }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-                Err(err) => return Err(err.into()),
-            }
-
             // Try to read from the directory. If it doesn't exist, assume we deleted it in a
             // previous iteration.
-            let mut read_dir = match fs::read_dir(path) {
+            let read_dir = match fs::read_dir(path) {
                 Ok(read_dir) => read_dir,
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
                 Err(err) => return Err(err.into()),
             };
 
-            // If the directory is not empty, we're done.
-            if read_dir.next().is_some() {
+            // If the directory contains anything other than `__pycache__`, we're done. The
+            // directory may be shared with another distribution (e.g., a namespace package), in
+            // which case the remaining `__pycache__` may belong to it.
+            let mut shared = false;
+            for entry in read_dir {
+                if entry?.file_name() != "__pycache__" {
+                    shared = true;
+                    break;
+                }
+            }
+            if shared {
                 break;
             }
 
+            // Otherwise, remove the `__pycache__` directory, if any. `__pycache__` may or may not
+            // be listed in the RECORD, but installers are expected to be smart enough to remove
+            // it either way.
+            let pycache = path.join("__pycache__");
+            match fs::remove_dir_all(&pycache) {
+                Ok(()) => {
+                    trace!("Removed directory: {}", pycache.display());
+                    dir_count += 1;
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+
             fs::remove_dir(path)?;
 
             trace!("Removed directory: {}", path.display());
@@ -149,6 +250,115 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
     })
 }
 
+/// Return the symlink at or above the given path (but within `site-packages`) that points outside
+/// of `site-packages`, if any.
+///
+/// Only paths within `site-packages` are considered; files installed elsewhere (e.g., scripts in
+/// `bin`) are removed as usual.
+fn external_symlink(path: &Path, site_packages: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(site_packages).ok()?;
+    let mut ancestor = site_packages.to_path_buf();
+    for component in relative.components() {
+        let Component::Normal(component) = component else {
+            return None;
+        };
+        ancestor.push(component);
+        let metadata = fs::symlink_metadata(&ancestor).ok()?;
+        if metadata.file_type().is_symlink() {
+            let target = fs::canonicalize(&ancestor).ok()?;
+            let root = fs::canonicalize(site_packages).ok()?;
+            return if target.starts_with(root) {
+                None
+            } else {
+                Some(ancestor)
+            };
+        }
+    }
+    None
+}
+
+/// Remove a symlink, without following it.
+fn remove_link(link: &Path) -> std::io::Result<()> {
+    // On Windows, symlinks (and junctions) to directories must be removed as directories.
+    #[cfg(windows)]
+    if link.is_dir() {
+        return fs::remove_dir(link);
+    }
+    fs::remove_file(link)
+}
+
+/// Remove any bytecode compiled from the given Python source file.
+///
+/// Removes both legacy `.pyc` files alongside the source file (e.g., `foo.pyc`) and PEP 3147
+/// `__pycache__` entries (e.g., `__pycache__/foo.cpython-312.pyc` or
+/// `__pycache__/foo.cpython-312.opt-1.pyc`), returning the number of files removed.
+fn remove_bytecode(source: &Path) -> Result<usize, Error> {
+    let (Some(parent), Some(stem)) = (
+        source.parent(),
+        source.file_stem().and_then(|stem| stem.to_str()),
+    ) else {
+        return Ok(0);
+    };
+
+    let mut count = 0usize;
+
+    // Remove the legacy bytecode file.
+    let legacy = source.with_extension("pyc");
+    match fs::remove_file(&legacy) {
+        Ok(()) => {
+            debug!("Removed stale bytecode: {}", legacy.display());
+            count += 1;
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    // Remove any entries in `__pycache__`, which are named `<stem>.<tag>[.opt-<level>].pyc`.
+    let pycache = parent.join("__pycache__");
+    let read_dir = match fs::read_dir(&pycache) {
+        Ok(read_dir) => read_dir,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(count),
+        Err(err) => return Err(err.into()),
+    };
+    let prefix = format!("{stem}.");
+    for entry in read_dir {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        let Some(rest) = file_name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".pyc"))
+        else {
+            continue;
+        };
+        // Avoid matching bytecode for other modules that share the prefix (e.g., `foo.bar.pyc`
+        // is not the bytecode for `foo.py`); the remainder must be a single interpreter tag,
+        // optionally followed by an optimization level.
+        let mut parts = rest.split('.');
+        let tag = parts.next().unwrap_or_default();
+        let opt = parts.next();
+        if tag.is_empty()
+            || opt.is_some_and(|opt| !opt.starts_with("opt-"))
+            || parts.next().is_some()
+        {
+            continue;
+        }
+        let path = entry.path();
+        match fs::remove_file(&path) {
+            Ok(()) => {
+                debug!("Removed stale bytecode: {}", path.display());
+                count += 1;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(count)
+}
+
 /// Uninstall the egg represented by the `.egg-info` directory.
 ///
 /// See: <https://github.com/pypa/pip/blob/41587f5e0017bcd849f42b314dc8a34a7db75621/src/pip/_internal/req/req_uninstall.py#L483>
@@ -160,65 +370,128 @@ pub fn uninstall_egg(egg_info: &Path) -> Result<Uninstall, Error> {
         .parent()
         .expect("egg-info directory is not in a site-packages directory");
 
-    // Read the `namespace_packages.txt` file.
-    let namespace_packages = {
-        let namespace_packages_path = egg_info.join("namespace_packages.txt");
-        match fs_err::read_to_string(namespace_packages_path) {
-            Ok(namespace_packages) => namespace_packages
-                .lines()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>(),
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                vec![]
+    // If the egg lists its installed files (as written by `pip install` when running
+    // `setup.py install`), remove them. Otherwise, fall back to removing the top-level modules.
+    let installed_files_path = egg_info.join("installed-files.txt");
+    match fs_err::read_to_string(&installed_files_path) {
+        Ok(installed_files) => {
+            let mut visited = BTreeSet::new();
+            for entry in installed_files.lines().map(str::trim) {
+                if entry.is_empty() {
+                    continue;
+                }
+
+                // Entries are relative to the `.egg-info` directory, which is removed below.
+                let path = normalize_path(&egg_info.join(entry));
+                if path.starts_with(egg_info) {
+                    continue;
+                }
+
+                match fs_err::remove_file(&path) {
+                    Ok(()) => {
+                        trace!("Removed file: {}", path.display());
+                        file_count += 1;
+                        if let Some(parent) = path.parent() {
+                            visited.insert(parent.to_path_buf());
+                        }
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
             }
-            Err(err) => return Err(err.into()),
-        }
-    };
 
-    // Read the `top_level.txt` file, ignoring anything in `namespace_packages.txt`.
-    let top_level = {
-        let top_level_path = egg_info.join("top_level.txt");
-        match fs_err::read_to_string(&top_level_path) {
-            Ok(top_level) => top_level
-                .lines()
-                .map(ToString::to_string)
-                .filter(|line| !namespace_packages.contains(line))
-                .collect::<Vec<_>>(),
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                return Err(Error::MissingTopLevel(top_level_path));
+            // Remove any directories within `site-packages` that were left empty, deepest first.
+            for path in visited.iter().rev() {
+                let mut path = path.as_path();
+                while path.starts_with(dist_location) && path != dist_location {
+                    let pycache = path.join("__pycache__");
+                    if fs_err::remove_dir(&pycache).is_ok() {
+                        trace!("Removed directory: {}", pycache.display());
+                        dir_count += 1;
+                    }
+                    if fs_err::remove_dir(path).is_err() {
+                        break;
+                    }
+                    trace!("Removed directory: {}", path.display());
+                    dir_count += 1;
+                    let Some(parent) = path.parent() else {
+                        break;
+                    };
+                    path = parent;
+                }
             }
-            Err(err) => return Err(err.into()),
         }
-    };
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            // Read the `namespace_packages.txt` file.
+            let namespace_packages = {
+                let namespace_packages_path = egg_info.join("namespace_packages.txt");
+                match fs_err::read_to_string(namespace_packages_path) {
+                    Ok(namespace_packages) => namespace_packages
+                        .lines()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        vec![]
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            };
 
-    // Remove everything in `top_level.txt`.
-    for entry in top_level {
-        let path = dist_location.join(&entry);
+            // Read the `top_level.txt` file, ignoring anything in `namespace_packages.txt`.
+            let top_level = {
+                let top_level_path = egg_info.join("top_level.txt");
+                match fs_err::read_to_string(&top_level_path) {
+                    Ok(top_level) => top_level
+                        .lines()
+                        .map(ToString::to_string)
+                        .filter(|line| !namespace_packages.contains(line))
+                        .collect::<Vec<_>>(),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        return Err(Error::MissingTopLevel(top_level_path));
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            };
 
-        // Remove as a directory.
-        match fs_err::remove_dir_all(&path) {
-            Ok(()) => {
-                trace!("Removed directory: {}", path.display());
-                dir_count += 1;
-                continue;
-            }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-            Err(err) => return Err(err.into()),
-        }
+            warn_user!(
+                "`{}` does not include an `installed-files.txt`; removing its top-level modules on a best-effort basis",
+                egg_info
+                    .file_name()
+                    .unwrap_or(egg_info.as_os_str())
+                    .to_string_lossy()
+            );
+
+            // Remove everything in `top_level.txt`.
+            for entry in top_level {
+                let path = dist_location.join(&entry);
+
+                // Remove as a directory.
+                match fs_err::remove_dir_all(&path) {
+                    Ok(()) => {
+                        trace!("Removed directory: {}", path.display());
+                        dir_count += 1;
+                        continue;
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
 
-        // Remove as a `.py`, `.pyc`, or `.pyo` file.
-        for extension in &["py", "pyc", "pyo"] {
-            let path = path.with_extension(extension);
-            match fs_err::remove_file(&path) {
-                Ok(()) => {
-                    trace!("Removed file: {}", path.display());
-                    file_count += 1;
-                    break;
+                // Remove as a `.py`, `.pyc`, or `.pyo` file.
+                for extension in &["py", "pyc", "pyo"] {
+                    let path = path.with_extension(extension);
+                    match fs_err::remove_file(&path) {
+                        Ok(()) => {
+                            trace!("Removed file: {}", path.display());
+                            file_count += 1;
+                            break;
+                        }
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(err) => return Err(err.into()),
+                    }
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-                Err(err) => return Err(err.into()),
             }
         }
+        Err(err) => return Err(err.into()),
     }
 
     // Remove the `.egg-info` directory.
@@ -322,7 +595,7 @@ pub struct Uninstall {
 /// Normalize a path, removing things like `.` and `..`.
 ///
 /// Source: <https://github.com/rust-lang/cargo/blob/b48c41aedbd69ee3990d62a0e2006edbb506a480/crates/cargo-util/src/paths.rs#L76C1-L109C2>
-fn normalize_path(path: &Path) -> PathBuf {
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
     let mut components = path.components().peekable();
     let mut ret = if let Some(c @ Component::Prefix(..)) = components.peek().copied() {
         components.next();
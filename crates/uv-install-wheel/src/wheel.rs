@@ -79,7 +79,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -123,6 +126,11 @@ fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool
         // Same applies for relocatable scripts (executable is relative to script dir, hence `dirname` trick)
         // (note: the Windows trampoline binaries natively support relative paths to executable)
         if shebang_length > 127 || executable.contains(' ') || relocatable {
+            if shebang_length > 127 && !relocatable {
+                debug!(
+                    "Shebang for `{executable}` exceeds the 127-byte limit ({shebang_length} bytes); wrapping in `/bin/sh`"
+                );
+            }
             let prefix = if relocatable {
                 r#""$(dirname -- "$(realpath -- "$0")")"/"#
             } else {
@@ -177,10 +185,29 @@ fn entrypoint_path(entrypoint: &Script, layout: &Layout) -> PathBuf {
     }
 }
 
+/// Return the paths of the launchers that installing the unpacked wheel at `wheel` would generate
+/// for its console and GUI scripts.
+pub fn entrypoint_targets(
+    wheel: impl AsRef<Path>,
+    dist_info_prefix: &str,
+    layout: &Layout,
+) -> Result<Vec<PathBuf>, Error> {
+    let (console_scripts, gui_scripts) =
+        parse_scripts(wheel, dist_info_prefix, None, layout.python_version.1)?;
+    Ok(console_scripts
+        .iter()
+        .chain(&gui_scripts)
+        .map(|entrypoint| entrypoint_path(entrypoint, layout))
+        .collect())
+}
+
 /// Create the wrapper scripts in the bin folder of the venv for launching console scripts.
+///
+/// If `sandbox_scripts` is set, the launchers aren't marked as executable.
 pub(crate) fn write_script_entrypoints(
     layout: &Layout,
     relocatable: bool,
+    #[allow(unused)] sandbox_scripts: bool,
     site_packages: &Path,
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
@@ -243,7 +270,7 @@ pub(crate) fn write_script_entrypoints(
 
             // Make the launcher executable.
             #[cfg(unix)]
-            {
+            if !sandbox_scripts {
                 use std::fs::Permissions;
                 use std::os::unix::fs::PermissionsExt;
 
@@ -368,9 +395,12 @@ pub(crate) fn move_folder_recorded(
 /// Installs a single script (not an entrypoint).
 ///
 /// Binary files are moved with a copy fallback, while we rewrite scripts' shebangs if applicable.
+///
+/// If `sandbox_scripts` is set, the script is installed without executable permissions.
 fn install_script(
     layout: &Layout,
     relocatable: bool,
+    #[allow(unused)] sandbox_scripts: bool,
     site_packages: &Path,
     record: &mut [RecordEntry],
     file: &DirEntry,
@@ -474,7 +504,7 @@ fn install_script(
 
         // Make the script executable. We just created the file, so we can set permissions directly.
         #[cfg(unix)]
-        {
+        if !sandbox_scripts {
             use std::fs::Permissions;
             use std::os::unix::fs::PermissionsExt;
 
@@ -500,7 +530,21 @@ fn install_script(
             use std::os::unix::fs::PermissionsExt;
 
             let permissions = fs::metadata(&path)?.permissions();
-            if permissions.mode() & 0o111 == 0o111 {
+            if sandbox_scripts {
+                if permissions.mode() & 0o111 == 0 {
+                    // If the permissions are already non-executable, we don't need to change them.
+                    rename_or_copy.rename_or_copy(&path, &script_absolute)?;
+                } else {
+                    // As below, copy the file rather than modifying the permissions of a file we
+                    // might not own (e.g., a hard link into the cache).
+                    uv_fs::copy_atomic_sync(&path, &script_absolute)?;
+
+                    fs::set_permissions(
+                        script_absolute,
+                        Permissions::from_mode(permissions.mode() & !0o111),
+                    )?;
+                }
+            } else if permissions.mode() & 0o111 == 0o111 {
                 // If the permissions are already executable, we don't need to change them.
                 // We fall back to copy when the file is on another drive.
                 rename_or_copy.rename_or_copy(&path, &script_absolute)?;
@@ -575,6 +619,7 @@ fn install_script(
 pub(crate) fn install_data(
     layout: &Layout,
     relocatable: bool,
+    sandbox_scripts: bool,
     site_packages: &Path,
     data_dir: &Path,
     dist_name: &PackageName,
@@ -632,6 +677,7 @@ pub(crate) fn install_data(
                     install_script(
                         layout,
                         relocatable,
+                        sandbox_scripts,
                         site_packages,
                         record,
                         &file,
@@ -930,8 +976,8 @@ mod test {
     use crate::wheel::format_shebang;
 
     use super::{
-        RecordEntry, Script, get_script_executable, parse_email_message_file, parse_wheel_file,
-        read_record_file, write_installer_metadata,
+        LibKind, RecordEntry, Script, copy_and_hash, get_script_executable,
+        parse_email_message_file, parse_wheel_file, read_record_file, write_installer_metadata,
     };
 
     #[test]
@@ -1009,6 +1055,28 @@ mod test {
         parse_wheel_file(&wheel_with_version("2.0")).unwrap_err();
     }
 
+    #[test]
+    fn test_parse_wheel_root_is_purelib() {
+        fn wheel_with_root_is_purelib(root_is_purelib: &str) -> String {
+            formatdoc! {"
+                Wheel-Version: 1.0
+                Generator: bdist_wheel (0.37.0)
+                Root-Is-Purelib: {}
+                Tag: cp312-cp312-manylinux_2_17_x86_64
+                ",
+                root_is_purelib
+            }
+        }
+        assert_eq!(
+            parse_wheel_file(&wheel_with_root_is_purelib("true")).unwrap(),
+            LibKind::Pure
+        );
+        assert_eq!(
+            parse_wheel_file(&wheel_with_root_is_purelib("false")).unwrap(),
+            LibKind::Plat
+        );
+    }
+
     #[test]
     fn record_with_absolute_paths() {
         let record: &str = indoc! {"
@@ -1034,6 +1102,27 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn record_with_empty_file() {
+        let record: &str = indoc! {"
+            foo/__init__.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0
+            foo-0.1.0.dist-info/RECORD,,
+        "};
+
+        let entries = read_record_file(&mut record.as_bytes()).unwrap();
+        assert_eq!(entries[0].path, "foo/__init__.py");
+        assert_eq!(
+            entries[0].hash.as_deref(),
+            Some("sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU")
+        );
+        assert_eq!(entries[0].size, Some(0));
+
+        // The digest of an empty file is the digest of the empty string.
+        let (size, hash) = copy_and_hash(&mut Cursor::new(b""), &mut Vec::new()).unwrap();
+        assert_eq!(size, 0);
+        assert_eq!(entries[0].hash.as_deref(), Some(hash.as_str()));
+    }
+
     #[test]
     fn test_script_from_value() {
         assert_eq!(
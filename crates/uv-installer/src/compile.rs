@@ -19,6 +19,8 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
 const COMPILEALL_SCRIPT: &str = include_str!("pip_compileall.py");
+/// The prefix with which the compile script reports a file that failed to compile.
+const FAILED_PREFIX: &str = "Failed: ";
 /// This is longer than any compilation should ever take.
 const DEFAULT_COMPILE_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -57,25 +59,66 @@ pub enum CompileError {
     StartupTimeout(Duration),
     #[error("Got invalid value from environment for {var}: {message}.")]
     EnvironmentError { var: &'static str, message: String },
+    #[error("Failed to compile Python file: `{0}`")]
+    CompileFailed(String),
+}
+
+/// The result of bytecode-compiling a directory tree.
+#[derive(Debug, Default)]
+pub struct CompiledTree {
+    /// The number of source files that were compiled, including any that failed.
+    pub files: usize,
+    /// The source files that failed to compile, e.g., due to syntax errors.
+    pub failures: Vec<PathBuf>,
 }
 
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
-/// a broken `python`.
+/// Unless `strict` is set, files that fail to compile (e.g., due to syntax errors) are reported in
+/// the returned [`CompiledTree`] rather than aborting the compilation. There is a 60s timeout for
+/// each file to handle a broken `python`.
+///
+/// Each file is compiled at the given `optimize` levels (e.g., `[2]` for `.opt-2.pyc` files), or at
+/// the interpreter's optimization level if `optimize` is empty.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
-) -> Result<usize, CompileError> {
+    strict: bool,
+    optimize: &[u8],
+) -> Result<CompiledTree, CompileError> {
+    compile_tree_filtered(
+        dir,
+        python_executable,
+        concurrency,
+        cache,
+        strict,
+        optimize,
+        |_| true,
+    )
+    .await
+}
+
+/// Bytecode compile the files in `dir` for which `filter` returns `true`.
+///
+/// See [`compile_tree`] for details.
+#[instrument(skip(python_executable, filter))]
+pub async fn compile_tree_filtered(
+    dir: &Path,
+    python_executable: &Path,
+    concurrency: &Concurrency,
+    cache: &Path,
+    strict: bool,
+    optimize: &[u8],
+    filter: impl Fn(&Path) -> bool,
+) -> Result<CompiledTree, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths: `{}`",
@@ -123,8 +166,10 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            optimize.to_vec(),
             receiver.clone(),
             timeout,
+            strict,
         );
 
         // Spawn each worker on a dedicated thread.
@@ -174,7 +219,10 @@ pub async fn compile_tree(
                 }
             };
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
-        if metadata.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
+        if metadata.is_file()
+            && entry.path().extension().is_some_and(|ext| ext == "py")
+            && filter(entry.path())
+        {
             source_files += 1;
             if let Err(err) = sender.send(entry.path().to_owned()).await {
                 // The workers exited.
@@ -192,15 +240,17 @@ pub async fn compile_tree(
     drop(sender);
 
     // Make sure all workers exit regularly, avoid hiding errors.
+    let mut failures = Vec::new();
     for result in futures::future::join_all(worker_handles).await {
         match result {
             // There spawning earlier errored due to a panic in a task.
             Err(_) => return Err(CompileError::Join),
             // The worker reports an error.
             Ok(Err(compile_error)) => return Err(compile_error),
-            Ok(Ok(())) => {}
+            Ok(Ok(worker_failures)) => failures.extend(worker_failures),
         }
     }
+    failures.sort();
 
     if let Some(send_error) = send_error {
         // This is suspicious: Why did the channel stop working, but all workers exited
@@ -208,16 +258,21 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
-    Ok(source_files)
+    Ok(CompiledTree {
+        files: source_files,
+        failures,
+    })
 }
 
 async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    optimize: Vec<u8>,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+    strict: bool,
+) -> Result<Vec<PathBuf>, CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
         .map_err(CompileError::TempFile)?;
@@ -230,7 +285,7 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, &optimize).await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -256,7 +311,7 @@ async fn worker(
         Ok(child_stderr_collected)
     });
 
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout, timeout).await;
+    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout, timeout, strict).await;
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
@@ -274,13 +329,13 @@ async fn worker(
     } else {
         let stderr = String::from_utf8_lossy(&child_stderr_collected);
         match result {
-            Ok(()) => {
+            Ok(failures) => {
                 debug!(
                     "Bytecode compilation `python` at {} stderr:\n{}\n---",
                     interpreter.user_display(),
                     stderr
                 );
-                Ok(())
+                Ok(failures)
             }
             Err(err) => Err(CompileError::ErrorWithStderr {
                 stderr: stderr.trim().to_string(),
@@ -299,6 +354,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    optimize: &[u8],
 ) -> Result<
     Option<(
         Child,
@@ -311,6 +367,7 @@ async fn launch_bytecode_compiler(
     // We input the paths through stdin and get the successful paths returned through stdout.
     let mut bytecode_compiler = Command::new(interpreter)
         .arg(pip_compileall_py)
+        .args(optimize.iter().map(ToString::to_string))
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -369,15 +426,19 @@ async fn launch_bytecode_compiler(
 /// We use stdin/stdout as a sort of bounded channel. We write one path to stdin, then wait until
 /// we get the same path back from stdout. This way we ensure one worker is only working on one
 /// piece of work at the same time.
+///
+/// Returns the files that failed to compile, or an error on the first failure if `strict` is set.
 async fn worker_main_loop(
     receiver: Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+    strict: bool,
+) -> Result<Vec<PathBuf>, CompileError> {
+    let mut failures = Vec::new();
     let mut out_line = String::new();
-    while let Ok(source_file) = receiver.recv().await {
-        let source_file = source_file.display().to_string();
+    while let Ok(source_path) = receiver.recv().await {
+        let source_file = source_path.display().to_string();
         if source_file.contains(['\r', '\n']) {
             warn_user!("Path contains newline, skipping: {source_file:?}");
             continue;
@@ -420,9 +481,18 @@ async fn worker_main_loop(
         // This is a sanity check, if we don't get the path back something has gone wrong, e.g.
         // we're not actually running a python interpreter.
         let actual = out_line.trim_end_matches(['\n', '\r']);
-        if actual != source_file {
+        if let Some(failed) = actual.strip_prefix(FAILED_PREFIX) {
+            if failed != source_file {
+                return Err(CompileError::WrongPath(source_file, actual.to_string()));
+            }
+            if strict {
+                return Err(CompileError::CompileFailed(source_file));
+            }
+            debug!("Failed to compile: {source_file}");
+            failures.push(source_path);
+        } else if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
     }
-    Ok(())
+    Ok(failures)
 }
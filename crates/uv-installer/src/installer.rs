@@ -21,6 +21,8 @@ pub struct Installer<'a> {
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// Whether to install scripts without executable permissions.
+    sandbox_scripts: bool,
 }
 
 impl<'a> Installer<'a> {
@@ -33,6 +35,7 @@ impl<'a> Installer<'a> {
             reporter: None,
             name: Some("uv".to_string()),
             metadata: true,
+            sandbox_scripts: false,
         }
     }
 
@@ -78,6 +81,15 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set whether to install scripts without executable permissions.
+    #[must_use]
+    pub fn with_sandbox_scripts(self, sandbox_scripts: bool) -> Self {
+        Self {
+            sandbox_scripts,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -88,6 +100,7 @@ impl<'a> Installer<'a> {
             reporter,
             name: installer_name,
             metadata: installer_metadata,
+            sandbox_scripts,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -112,6 +125,7 @@ impl<'a> Installer<'a> {
                 link_mode,
                 reporter,
                 relocatable,
+                sandbox_scripts,
                 installer_metadata,
             );
 
@@ -142,6 +156,7 @@ impl<'a> Installer<'a> {
             self.link_mode,
             self.reporter,
             self.venv.relocatable(),
+            self.sandbox_scripts,
             self.metadata,
         )
     }
@@ -156,6 +171,7 @@ fn install(
     link_mode: LinkMode,
     reporter: Option<Arc<dyn Reporter>>,
     relocatable: bool,
+    sandbox_scripts: bool,
     installer_metadata: bool,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
@@ -165,6 +181,7 @@ fn install(
         uv_install_wheel::install_wheel(
             &layout,
             relocatable,
+            sandbox_scripts,
             wheel.path(),
             wheel.filename(),
             wheel
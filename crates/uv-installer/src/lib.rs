@@ -1,9 +1,9 @@
-pub use compile::{CompileError, compile_tree};
+pub use compile::{CompileError, CompiledTree, compile_tree, compile_tree_filtered};
 pub use installer::{Installer, Reporter as InstallReporter};
-pub use plan::{Plan, Planner};
+pub use plan::{Plan, Planner, is_stale_local_wheel};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
-pub use uninstall::{UninstallError, uninstall};
+pub use uninstall::{UninstallError, corrupt_files, modified_files, uninstall};
 
 mod compile;
 mod preparer;
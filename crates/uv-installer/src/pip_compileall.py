@@ -3,7 +3,8 @@ Based on
 https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L612-L623
 
 pip silently just swallows all pyc compilation errors, but `python -m compileall` does
-not have such a flag, so we adapt the pip code. This is relevant, e.g., for
+not have such a flag, so we adapt the pip code. Unlike pip, we report the files that failed
+back to uv. This is relevant, e.g., for
 `debugpy-1.5.1-cp38-cp38-manylinux_2_5_x86_64.manylinux1_x86_64.manylinux_2_12_x86_64.manylinux2010_x86_64`,
 which contains some vendored Python 2 code which fails to compile.
 """
@@ -51,17 +52,38 @@ with warnings.catch_warnings():
         # based and has a matching mtime (unless force=True).
         force = True
 
+    # The optimization levels to compile at are passed as arguments (e.g., `2` for `.opt-2.pyc`).
+    # Without any, we use the interpreter's optimization level.
+    levels = [int(level) for level in sys.argv[1:]] or [-1]
+    if len(levels) == 1:
+        passes = levels
+    elif sys.version_info >= (3, 9):
+        # Python 3.9 and later can compile a file at multiple levels in a single pass.
+        passes = [levels]
+    else:
+        # On older versions, compile the file once per level.
+        passes = levels
+
     # In rust, we provide one line per file to compile.
     for path in sys.stdin:
         # Remove trailing newlines.
         path = path.strip()
         if not path:
             continue
-        # Unlike pip, we set quiet=2, so we don't have to capture stdout.
-        # We'd like to show those errors, but given that pip thinks that's totally fine,
-        # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+        # Unlike pip, we set quiet=2, so we don't have to capture stdout. Instead of the error
+        # itself, we report which files failed, and leave it to uv to decide whether to abort.
+        success = all(
+            compileall.compile_file(
+                path,
+                invalidation_mode=invalidation_mode,
+                force=force,
+                quiet=2,
+                optimize=optimize,
+            )
+            for optimize in passes
         )
         # We're ready for the next file.
-        print(path)
+        if success:
+            print(path)
+        else:
+            print(f"Failed: {path}")
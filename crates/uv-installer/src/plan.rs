@@ -3,14 +3,14 @@ use std::sync::Arc;
 use tracing::{debug, warn};
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
-use uv_cache_info::Timestamp;
+use uv_cache_info::{CacheInfo, Timestamp};
 use uv_configuration::{BuildOptions, ConfigSettings, PackageConfigSettings, Reinstall};
 use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
 };
 use uv_distribution_types::{
     BuiltDist, CachedDirectUrlDist, CachedDist, Dist, Error, Hashed, IndexLocations, InstalledDist,
-    Name, RequirementSource, Resolution, ResolvedDist, SourceDist,
+    Name, RegistryBuiltWheel, RequirementSource, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::Simplified;
 use uv_platform_tags::Tags;
@@ -34,7 +34,10 @@ impl<'a> Planner<'a> {
     }
 
     /// Partition a set of requirements into those that should be linked from the cache, those that
-    /// need to be downloaded, and those that should be removed.
+    /// need to be downloaded, those that should be removed, and those that are already satisfied.
+    ///
+    /// The plan is computed without modifying the environment, so it can be used to report on the
+    /// changes that would be made (e.g., for `--dry-run`) without executing them.
     ///
     /// The install plan will respect cache [`Freshness`]. Specifically, if refresh is enabled, the
     /// plan will respect cache entries created after the current time (as per the [`Refresh`]
@@ -72,6 +75,7 @@ impl<'a> Planner<'a> {
         let mut remote = vec![];
         let mut reinstalls = vec![];
         let mut extraneous = vec![];
+        let mut satisfied = vec![];
 
         // TODO(charlie): There are a few assumptions here that are hard to spot:
         //
@@ -112,6 +116,7 @@ impl<'a> Planner<'a> {
                             }
                             RequirementSatisfaction::Satisfied => {
                                 debug!("Requirement already installed: {installed}");
+                                satisfied.push(installed.clone());
                                 continue;
                             }
                             RequirementSatisfaction::OutOfDate => {
@@ -160,6 +165,11 @@ impl<'a> Planner<'a> {
                         if !entry.built && no_binary {
                             return None;
                         }
+                        if !entry.built
+                            && is_stale_local_wheel(wheel.best_wheel(), &entry.dist.cache_info)
+                        {
+                            return None;
+                        }
                         Some(&entry.dist)
                     }) {
                         debug!("Registry requirement already cached: {distribution}");
@@ -449,6 +459,7 @@ impl<'a> Planner<'a> {
             remote,
             reinstalls,
             extraneous,
+            satisfied,
         })
     }
 }
@@ -466,6 +477,23 @@ fn is_seed_package(dist_info: &InstalledDist, venv: &PythonEnvironment) -> bool
     }
 }
 
+/// Returns `true` if the given registry wheel is stored in a local directory (e.g., a
+/// `--find-links` directory), and has changed since it was unzipped (or installed) with the given
+/// [`CacheInfo`].
+pub fn is_stale_local_wheel(wheel: &RegistryBuiltWheel, cache_info: &CacheInfo) -> bool {
+    let Some(path) = wheel
+        .file
+        .url
+        .to_url()
+        .ok()
+        .and_then(|url| url.to_file_path().ok())
+    else {
+        return false;
+    };
+    !Timestamp::from_path(&path)
+        .is_ok_and(|timestamp| *cache_info == CacheInfo::from_timestamp(timestamp))
+}
+
 #[derive(Debug, Default)]
 pub struct Plan {
     /// The distributions that are not already installed in the current environment, but are
@@ -483,4 +511,96 @@ pub struct Plan {
     /// Any distributions that are already installed in the current environment, and are
     /// _not_ necessary to satisfy the requirements.
     pub extraneous: Vec<InstalledDist>,
+
+    /// Any distributions that are already installed in the current environment, and satisfy the
+    /// requirements as-is.
+    pub satisfied: Vec<InstalledDist>,
+}
+
+impl Plan {
+    /// Returns `true` if executing the plan would not modify the environment.
+    pub fn is_empty(&self) -> bool {
+        self.cached.is_empty()
+            && self.remote.is_empty()
+            && self.reinstalls.is_empty()
+            && self.extraneous.is_empty()
+    }
+
+    /// Returns the number of distributions that would be installed, whether from the cache or
+    /// after downloading them.
+    pub fn installs(&self) -> usize {
+        self.cached.len() + self.remote.len()
+    }
+
+    /// Returns the number of distributions that would be uninstalled, whether they're being
+    /// replaced or are extraneous.
+    pub fn uninstalls(&self) -> usize {
+        self.reinstalls.len() + self.extraneous.len()
+    }
+
+    /// Retain any extraneous distributions, rather than removing them (e.g., for `uv pip install`,
+    /// as opposed to `uv pip sync`).
+    #[must_use]
+    pub fn without_extraneous(self) -> Self {
+        Self {
+            extraneous: vec![],
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_distribution_types::InstalledRegistryDist;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::*;
+
+    fn installed(name: &str, version: &str) -> InstalledDist {
+        InstalledDist::Registry(InstalledRegistryDist {
+            name: PackageName::from_str(name).unwrap(),
+            version: Version::from_str(version).unwrap(),
+            path: Path::new(&format!("{name}-{version}.dist-info")).into(),
+            cache_info: None,
+        })
+    }
+
+    #[test]
+    fn empty() {
+        let plan = Plan {
+            satisfied: vec![installed("iniconfig", "2.0.0")],
+            ..Plan::default()
+        };
+        assert!(plan.is_empty());
+        assert_eq!(plan.installs(), 0);
+        assert_eq!(plan.uninstalls(), 0);
+    }
+
+    #[test]
+    fn extraneous() {
+        let plan = Plan {
+            reinstalls: vec![installed("anyio", "4.0.0")],
+            extraneous: vec![installed("sniffio", "1.3.1")],
+            satisfied: vec![installed("iniconfig", "2.0.0")],
+            ..Plan::default()
+        };
+        assert!(!plan.is_empty());
+        assert_eq!(plan.uninstalls(), 2);
+
+        let plan = plan.without_extraneous();
+        assert!(!plan.is_empty());
+        assert_eq!(plan.uninstalls(), 1);
+        assert_eq!(plan.satisfied.len(), 1);
+
+        let plan = Plan {
+            extraneous: vec![installed("sniffio", "1.3.1")],
+            ..Plan::default()
+        }
+        .without_extraneous();
+        assert!(plan.is_empty());
+    }
 }
@@ -1,7 +1,8 @@
 use std::cmp::Reverse;
+use std::pin::pin;
 use std::sync::Arc;
 
-use futures::{FutureExt, Stream, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
+use futures::{FutureExt, Stream, StreamExt, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
 use tracing::{debug, instrument};
 
 use uv_cache::Cache;
@@ -106,6 +107,45 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
 
         Ok(wheels)
     }
+
+    /// Download, build, and unzip a set of distributions, continuing past any distributions that
+    /// fail to build.
+    ///
+    /// Returns the prepared distributions alongside the build failures. Any other error (e.g., a
+    /// failed download) is returned immediately.
+    #[instrument(skip_all, fields(total = distributions.len()))]
+    pub async fn prepare_ignore_build_failures(
+        &self,
+        mut distributions: Vec<Arc<Dist>>,
+        in_flight: &InFlight,
+        resolution: &Resolution,
+    ) -> Result<(Vec<CachedDist>, Vec<Error>), Error> {
+        // Sort the distributions by size.
+        distributions
+            .sort_unstable_by_key(|distribution| Reverse(distribution.size().unwrap_or(u64::MAX)));
+
+        let mut wheels = Vec::with_capacity(distributions.len());
+        let mut failures = Vec::new();
+
+        let mut stream = pin!(self.prepare_stream(distributions, in_flight, resolution));
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(wheel) => wheels.push(wheel),
+                Err(err) if err.is_build_failure() => {
+                    debug!("Skipping distribution that failed to build: {err}");
+                    failures.push(err);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_complete();
+        }
+
+        Ok((wheels, failures))
+    }
+
     /// Download, build, and unzip a single wheel.
     #[instrument(skip_all, fields(name = % dist, size = ? dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
     pub async fn get_wheel(
@@ -230,6 +270,11 @@ pub enum Error {
 }
 
 impl Error {
+    /// Returns `true` if the error is due to a failure to build a source distribution.
+    pub fn is_build_failure(&self) -> bool {
+        matches!(self, Self::Dist(.., uv_distribution::Error::Build(_)))
+    }
+
     /// Create an [`Error`] from a distribution error.
     fn from_dist(dist: Dist, err: uv_distribution::Error, resolution: &Resolution) -> Self {
         let chain =
@@ -270,7 +270,12 @@ impl RequirementSatisfaction {
                 let Some(cache_info) = cache_info.as_ref() else {
                     return Self::OutOfDate;
                 };
-                match CacheInfo::from_path(requested_path) {
+                let read_cache_info = if requested_editable.unwrap_or(false) {
+                    CacheInfo::from_editable(requested_path)
+                } else {
+                    CacheInfo::from_path(requested_path)
+                };
+                match read_cache_info {
                     Ok(read_cache_info) => {
                         if *cache_info != read_cache_info {
                             return Self::OutOfDate;
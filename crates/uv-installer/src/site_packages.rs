@@ -189,6 +189,52 @@ impl SitePackages {
         self.distributions.iter().any(Option::is_some)
     }
 
+    /// Returns `true` if the given requirement is satisfied by a single installed distribution.
+    ///
+    /// Unlike [`SitePackages::satisfies_requirements`], the requirement's dependencies are not
+    /// considered.
+    pub fn satisfies_requirement(&self, requirement: &Requirement) -> bool {
+        match self.get_packages(&requirement.name).as_slice() {
+            [distribution] => matches!(
+                RequirementSatisfaction::check(distribution, &requirement.source),
+                RequirementSatisfaction::Satisfied
+            ),
+            _ => false,
+        }
+    }
+
+    /// Find any installed packages whose `.dist-info` directory name is inconsistent with the name
+    /// and version in their `METADATA`.
+    ///
+    /// We rely on the directory name to determine which packages are installed, so a mismatch
+    /// breaks upgrade and uninstall diffing. This is kept separate from
+    /// [`SitePackages::diagnostics`], such that only callers that opt in report it.
+    pub fn mismatched_metadata(&self) -> Vec<SitePackagesDiagnostic> {
+        self.iter()
+            .filter(|distribution| {
+                matches!(
+                    distribution,
+                    InstalledDist::Registry(_) | InstalledDist::Url(_)
+                )
+            })
+            .filter_map(|distribution| {
+                let metadata = distribution.metadata().ok()?;
+                if metadata.name == *distribution.name()
+                    && metadata.version == *distribution.version()
+                {
+                    return None;
+                }
+                Some(SitePackagesDiagnostic::MismatchedMetadata {
+                    package: distribution.name().clone(),
+                    version: distribution.version().clone(),
+                    path: distribution.install_path().to_owned(),
+                    metadata_name: metadata.name,
+                    metadata_version: metadata.version,
+                })
+            })
+            .collect()
+    }
+
     /// Validate the installed packages in the virtual environment.
     pub fn diagnostics(
         &self,
@@ -537,6 +583,18 @@ pub enum SitePackagesDiagnostic {
         /// The path to the package.
         path: PathBuf,
     },
+    MismatchedMetadata {
+        /// The package name, as inferred from the `.dist-info` directory.
+        package: PackageName,
+        /// The package version, as inferred from the `.dist-info` directory.
+        version: Version,
+        /// The path to the `.dist-info` directory.
+        path: PathBuf,
+        /// The package name, as declared in the `METADATA`.
+        metadata_name: PackageName,
+        /// The package version, as declared in the `METADATA`.
+        metadata_version: Version,
+    },
     IncompatiblePythonVersion {
         /// The package that requires a different version of Python.
         package: PackageName,
@@ -567,6 +625,16 @@ pub enum SitePackagesDiagnostic {
     },
 }
 
+impl SitePackagesDiagnostic {
+    /// Returns `true` if the diagnostic reflects a missing or incompatible dependency.
+    pub fn is_conflict(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingDependency { .. } | Self::IncompatibleDependency { .. }
+        )
+    }
+}
+
 impl Diagnostic for SitePackagesDiagnostic {
     /// Convert the diagnostic into a user-facing message.
     fn message(&self) -> String {
@@ -575,6 +643,16 @@ impl Diagnostic for SitePackagesDiagnostic {
                 "The package `{package}` is broken or incomplete (unable to read `METADATA`). Consider recreating the virtualenv, or removing the package directory at: {}.",
                 path.display(),
             ),
+            Self::MismatchedMetadata {
+                package,
+                version,
+                path,
+                metadata_name,
+                metadata_version,
+            } => format!(
+                "The package `{package}=={version}` declares `{metadata_name}=={metadata_version}` in its `METADATA`. Consider reinstalling the package, or removing the package directory at: {}.",
+                path.display(),
+            ),
             Self::IncompatiblePythonVersion {
                 package,
                 version,
@@ -611,6 +689,11 @@ impl Diagnostic for SitePackagesDiagnostic {
     fn includes(&self, name: &PackageName) -> bool {
         match self {
             Self::MetadataUnavailable { package, .. } => name == package,
+            Self::MismatchedMetadata {
+                package,
+                metadata_name,
+                ..
+            } => name == package || name == metadata_name,
             Self::IncompatiblePythonVersion { package, .. } => name == package,
             Self::MissingDependency { package, .. } => name == package,
             Self::IncompatibleDependency {
@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use uv_distribution_types::{InstalledDist, InstalledEggInfoFile};
 
 /// Uninstall a package from the specified Python environment.
@@ -24,6 +26,47 @@ pub async fn uninstall(
     Ok(uninstall)
 }
 
+/// Return the files of an installed package that were modified after installation, i.e., that no
+/// longer match the hashes in its `RECORD`.
+///
+/// Only `.dist-info` distributions record hashes; other distributions are never considered
+/// modified.
+pub async fn modified_files(dist: &InstalledDist) -> Result<Vec<PathBuf>, UninstallError> {
+    verify_record(dist, uv_install_wheel::modified_files).await
+}
+
+/// Return the files of an installed package that are missing or no longer match the hashes in its
+/// `RECORD`.
+///
+/// Only `.dist-info` distributions record hashes; other distributions are never considered
+/// corrupt.
+pub async fn corrupt_files(dist: &InstalledDist) -> Result<Vec<PathBuf>, UninstallError> {
+    verify_record(dist, uv_install_wheel::corrupt_files).await
+}
+
+/// Verify the files of an installed package against its `RECORD` with the given check.
+async fn verify_record(
+    dist: &InstalledDist,
+    check: fn(&Path) -> Result<Vec<PathBuf>, uv_install_wheel::Error>,
+) -> Result<Vec<PathBuf>, UninstallError> {
+    let modified = tokio::task::spawn_blocking({
+        let dist = dist.clone();
+        move || -> Result<Vec<PathBuf>, UninstallError> {
+            match dist {
+                InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+                    Ok(check(dist.install_path())?)
+                }
+                InstalledDist::EggInfoDirectory(_)
+                | InstalledDist::LegacyEditable(_)
+                | InstalledDist::EggInfoFile(_) => Ok(Vec::new()),
+            }
+        }
+    })
+    .await??;
+
+    Ok(modified)
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum UninstallError {
     #[error(
@@ -27,8 +27,14 @@ pub enum Error {
         "The .dist-info directory does not consist of the normalized package name and version: `{0}`"
     )]
     MissingDistInfoSegments(String),
-    #[error("The .dist-info directory {0} does not start with the normalized package name: {1}")]
-    MissingDistInfoPackageName(String, String),
+    #[error(
+        "The wheel declares the name `{declared}` (in `{dist_info_prefix}.dist-info`), but was requested as `{requested}`"
+    )]
+    MissingDistInfoPackageName {
+        dist_info_prefix: String,
+        declared: String,
+        requested: String,
+    },
     #[error("The .dist-info directory name contains invalid characters")]
     InvalidName(#[from] InvalidNameError),
     #[error("The metadata at {0} is invalid")]
@@ -85,20 +91,32 @@ pub fn find_archive_dist_info<'a, T: Copy>(
         }
     };
 
-    // Like `pip`, validate that the `.dist-info` directory is prefixed with the canonical
-    // package name.
+    validate_dist_info_name(dist_info_prefix, filename)?;
+
+    Ok((payload, dist_info_prefix))
+}
+
+/// Like `pip`, validate that the `.dist-info` directory is prefixed with the canonical package
+/// name.
+fn validate_dist_info_name(dist_info_prefix: &str, filename: &WheelFilename) -> Result<(), Error> {
     let normalized_prefix = DistInfoName::new(dist_info_prefix);
-    if !normalized_prefix
+    if normalized_prefix
         .as_ref()
         .starts_with(filename.name.as_str())
     {
-        return Err(Error::MissingDistInfoPackageName(
-            dist_info_prefix.to_string(),
-            filename.name.to_string(),
-        ));
+        return Ok(());
     }
 
-    Ok((payload, dist_info_prefix))
+    // The `.dist-info` directory is named `{name}-{version}`, so the declared name precedes the
+    // first dash.
+    let declared = dist_info_prefix
+        .split_once('-')
+        .map_or(dist_info_prefix, |(name, _)| name);
+    Err(Error::MissingDistInfoPackageName {
+        dist_info_prefix: dist_info_prefix.to_string(),
+        declared: declared.to_string(),
+        requested: filename.name.to_string(),
+    })
 }
 
 /// Returns `true` if the file is a `METADATA` file in a `.dist-info` directory that matches the
@@ -114,18 +132,7 @@ pub fn is_metadata_entry(path: &str, filename: &WheelFilename) -> Result<bool, E
         return Ok(false);
     };
 
-    // Like `pip`, validate that the `.dist-info` directory is prefixed with the canonical
-    // package name.
-    let normalized_prefix = DistInfoName::new(dist_info_prefix);
-    if !normalized_prefix
-        .as_ref()
-        .starts_with(filename.name.as_str())
-    {
-        return Err(Error::MissingDistInfoPackageName(
-            dist_info_prefix.to_string(),
-            filename.name.to_string(),
-        ));
-    }
+    validate_dist_info_name(dist_info_prefix, filename)?;
 
     Ok(true)
 }
@@ -178,18 +185,7 @@ pub fn find_flat_dist_info(
         return Err(Error::MissingDistInfo);
     };
 
-    // Like `pip`, validate that the `.dist-info` directory is prefixed with the canonical
-    // package name.
-    let normalized_prefix = DistInfoName::new(&dist_info_prefix);
-    if !normalized_prefix
-        .as_ref()
-        .starts_with(filename.name.as_str())
-    {
-        return Err(Error::MissingDistInfoPackageName(
-            dist_info_prefix.to_string(),
-            filename.name.to_string(),
-        ));
-    }
+    validate_dist_info_name(&dist_info_prefix, filename)?;
 
     Ok(dist_info_prefix)
 }
@@ -327,4 +323,20 @@ mod test {
             find_archive_dist_info(&filename, files.into_iter().map(|file| (file, file))).unwrap();
         assert_eq!(dist_info_prefix, "Mastodon.py-1.5.1");
     }
+
+    #[test]
+    fn test_mismatched_name() {
+        let files = [
+            "tomli/__init__.py",
+            "tomli-2.0.1.dist-info/METADATA",
+            "tomli-2.0.1.dist-info/RECORD",
+        ];
+        let filename = WheelFilename::from_str("foo-2.0.1-py3-none-any.whl").unwrap();
+        let err = find_archive_dist_info(&filename, files.into_iter().map(|file| (file, file)))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The wheel declares the name `tomli` (in `tomli-2.0.1.dist-info`), but was requested as `foo`"
+        );
+    }
 }
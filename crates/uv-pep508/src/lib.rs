@@ -688,6 +688,8 @@ fn parse_extras_cursor<T: Pep508Url>(
 ///   non-whitespace character (inclusive).
 /// - A semicolon (marker) or hash (comment) _followed_ by a space. We treat this as an error, since
 ///   the end of the URL is ambiguous.
+/// - A semicolon _immediately_ followed by a marker variable (e.g., `;python_version >= '3.8'`).
+///   Semicolons are valid URL characters, but a marker key is never part of a real URL.
 ///
 /// For example:
 /// - `https://pypi.org/project/requests/...`
@@ -732,6 +734,15 @@ fn parse_url<T: Pep508Url>(
                     break;
                 }
             }
+
+            // If we see a top-level semicolon followed by a marker, we're done.
+            if cursor.peek_char() == Some(';') {
+                let mut cursor = cursor.clone();
+                cursor.next();
+                if starts_with_marker_key(&cursor) {
+                    break;
+                }
+            }
         }
         (start, len)
     };
@@ -756,6 +767,18 @@ fn parse_url<T: Pep508Url>(
     Ok(url)
 }
 
+/// Returns `true` if the cursor is positioned at the start of a marker expression that begins
+/// with a marker variable (e.g., `python_version`).
+pub(crate) fn starts_with_marker_key(cursor: &Cursor) -> bool {
+    let (start, len) = cursor
+        .clone()
+        .peek_while(|c| c.is_alphanumeric() || matches!(c, '_' | '.'));
+    if len == 0 {
+        return false;
+    }
+    MarkerValue::from_str(cursor.slice(start, len)).is_ok()
+}
+
 /// Identify the extras in a relative URL (e.g., `../editable[dev]`).
 ///
 /// Pip uses `m = re.match(r'^(.+)(\[[^]]+])$', path)`. Our strategy is:
@@ -1365,6 +1388,35 @@ mod tests {
         assert_eq!(pip_url, expected);
     }
 
+    #[test]
+    fn url_marker_without_space() {
+        let pip_url = Requirement::<Url>::from_str(
+            "pip @ https://github.com/pypa/pip/archive/1.3.1.zip;python_version >= '3.8'",
+        )
+        .unwrap();
+        let expected = Requirement {
+            name: PackageName::from_str("pip").unwrap(),
+            extras: Box::new([]),
+            marker: MarkerTree::from_str("python_version >= '3.8'").unwrap(),
+            version_or_url: Some(VersionOrUrl::Url(
+                Url::parse("https://github.com/pypa/pip/archive/1.3.1.zip").unwrap(),
+            )),
+            origin: None,
+        };
+        assert_eq!(pip_url, expected);
+
+        // A semicolon that doesn't introduce a marker is part of the URL.
+        let pip_url =
+            Requirement::<Url>::from_str("pip @ https://example.com/pip;version=1.3.1.zip")
+                .unwrap();
+        assert_eq!(
+            pip_url.version_or_url,
+            Some(VersionOrUrl::Url(
+                Url::parse("https://example.com/pip;version=1.3.1.zip").unwrap()
+            ))
+        );
+    }
+
     #[test]
     fn test_marker_parsing() {
         let marker = r#"python_version == "2.7" and (sys_platform == "win32" or (os_name == "linux" and implementation_name == 'cpython'))"#;
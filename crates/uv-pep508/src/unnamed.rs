@@ -10,7 +10,7 @@ use crate::marker::parse;
 use crate::{
     Cursor, MarkerEnvironment, MarkerTree, Pep508Error, Pep508ErrorSource, Pep508Url, Reporter,
     RequirementOrigin, Scheme, TracingReporter, VerbatimUrl, VerbatimUrlError, expand_env_vars,
-    parse_extras_cursor, split_extras, split_scheme, strip_host,
+    parse_extras_cursor, split_extras, split_scheme, starts_with_marker_key, strip_host,
 };
 
 /// An extension over [`Pep508Url`] that also supports parsing unnamed requirements, namely paths.
@@ -346,6 +346,7 @@ fn preprocess_unnamed_url<Url: UnnamedRequirementUrl>(
 ///   non-whitespace character (inclusive).
 /// - A semicolon (marker) or hash (comment) _followed_ by a space. We treat this as an error, since
 ///   the end of the URL is ambiguous.
+/// - A semicolon _immediately_ followed by a marker variable (e.g., `;python_version >= '3.8'`).
 ///
 /// URLs can include extras at the end, enclosed in square brackets.
 ///
@@ -399,6 +400,15 @@ fn parse_unnamed_url<Url: UnnamedRequirementUrl>(
                     break;
                 }
             }
+
+            // If we see a top-level semicolon followed by a marker, we're done.
+            if depth == 0 && cursor.peek_char() == Some(';') {
+                let mut cursor = cursor.clone();
+                cursor.next();
+                if starts_with_marker_key(&cursor) {
+                    break;
+                }
+            }
         }
         (start, len)
     };
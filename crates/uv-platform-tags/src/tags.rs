@@ -4,7 +4,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::{cmp, num::NonZeroU32};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_small_str::SmallString;
 
@@ -222,6 +222,44 @@ impl Tags {
         Ok(Self::new(tags))
     }
 
+    /// Extend the [`Tags`] with additional platform tags.
+    ///
+    /// Each additional platform tag is considered compatible with every language and ABI tag
+    /// combination in the set, at a lower priority than any of the existing tags.
+    #[must_use]
+    pub fn with_platform_tags(&self, platform_tags: &[PlatformTag]) -> Self {
+        // Collect the existing tags, in decreasing priority.
+        let mut existing = Vec::new();
+        for (python_tag, abi_tags) in self.map.iter() {
+            for (abi_tag, platforms) in abi_tags {
+                for (platform_tag, priority) in platforms {
+                    existing.push((*priority, *python_tag, *abi_tag, platform_tag.clone()));
+                }
+            }
+        }
+        existing.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut tags = existing
+            .into_iter()
+            .map(|(_, python_tag, abi_tag, platform_tag)| (python_tag, abi_tag, platform_tag))
+            .collect::<Vec<_>>();
+
+        // Append the additional platform tags for each language and ABI tag combination.
+        let mut seen = FxHashSet::default();
+        let combinations = tags
+            .iter()
+            .filter(|(python_tag, abi_tag, _)| seen.insert((*python_tag, *abi_tag)))
+            .map(|(python_tag, abi_tag, _)| (*python_tag, *abi_tag))
+            .collect::<Vec<_>>();
+        for (python_tag, abi_tag) in combinations {
+            for platform_tag in platform_tags {
+                tags.push((python_tag, abi_tag, platform_tag.clone()));
+            }
+        }
+
+        Self::new(tags)
+    }
+
     /// Returns true when there exists at least one tag for this platform
     /// whose individual components all appear in each of the slices given.
     ///
@@ -1188,6 +1226,56 @@ mod tests {
     "###);
     }
 
+    /// Ensure that additional platform tags are appended at the lowest priority, for each
+    /// language and ABI tag combination.
+    #[test]
+    fn test_with_platform_tags() {
+        let tags = Tags::new(vec![
+            (
+                LanguageTag::CPython {
+                    python_version: (3, 12),
+                },
+                AbiTag::None,
+                PlatformTag::Linux { arch: Arch::X86_64 },
+            ),
+            (
+                LanguageTag::Python {
+                    major: 3,
+                    minor: None,
+                },
+                AbiTag::None,
+                PlatformTag::Linux { arch: Arch::X86_64 },
+            ),
+            (
+                LanguageTag::Python {
+                    major: 3,
+                    minor: None,
+                },
+                AbiTag::None,
+                PlatformTag::Any,
+            ),
+        ])
+        .with_platform_tags(&[PlatformTag::WinAmd64]);
+        assert_snapshot!(
+        tags,
+        @r###"
+    cp312-none-linux_x86_64
+    py3-none-linux_x86_64
+    py3-none-any
+    cp312-none-win_amd64
+    py3-none-win_amd64
+    "###);
+
+        assert!(tags.is_compatible(
+            &[LanguageTag::Python {
+                major: 3,
+                minor: None,
+            }],
+            &[AbiTag::None],
+            &[PlatformTag::WinAmd64],
+        ));
+    }
+
     /// Check full tag ordering.
     /// The list is displayed in decreasing priority.
     ///
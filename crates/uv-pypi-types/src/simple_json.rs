@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use jiff::Timestamp;
@@ -33,6 +34,75 @@ fn sorted_simple_json_files<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<File>
     Ok(files)
 }
 
+/// The release files for a single package from `PyPI`'s legacy JSON API (i.e.,
+/// `/pypi/<project>/json`).
+///
+/// <https://docs.pypi.org/api/json/>
+#[derive(Debug, Clone, Deserialize)]
+pub struct PypiJson {
+    /// The files available for each release of the package, keyed by version.
+    releases: BTreeMap<String, Vec<PypiJsonFile>>,
+}
+
+impl PypiJson {
+    /// Convert the release files into a list of [`File`]s, sorted by filename.
+    pub fn into_files(self) -> Vec<File> {
+        let mut files = self
+            .releases
+            .into_values()
+            .flatten()
+            .map(File::from)
+            .collect::<Vec<_>>();
+        files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
+        files
+    }
+}
+
+/// A single file in a release from `PyPI`'s legacy JSON API.
+#[derive(Debug, Clone, Deserialize)]
+struct PypiJsonFile {
+    filename: SmallString,
+    url: SmallString,
+    #[serde(default)]
+    digests: Hashes,
+    #[serde(default)]
+    requires_python: Option<String>,
+    #[serde(default)]
+    size: Option<u64>,
+    #[serde(default)]
+    upload_time_iso_8601: Option<Timestamp>,
+    #[serde(default)]
+    yanked: bool,
+    #[serde(default)]
+    yanked_reason: Option<SmallString>,
+}
+
+impl From<PypiJsonFile> for File {
+    fn from(file: PypiJsonFile) -> Self {
+        let yanked = if file.yanked {
+            Some(Box::new(
+                file.yanked_reason
+                    .map(Yanked::Reason)
+                    .unwrap_or(Yanked::Bool(true)),
+            ))
+        } else {
+            None
+        };
+        Self {
+            core_metadata: None,
+            filename: file.filename,
+            hashes: file.digests,
+            requires_python: file.requires_python.map(|requires_python| {
+                LenientVersionSpecifiers::from_str(&requires_python).map(VersionSpecifiers::from)
+            }),
+            size: file.size,
+            upload_time: file.upload_time_iso_8601,
+            url: file.url,
+            yanked,
+        }
+    }
+}
+
 /// A single (remote) file belonging to a package, either a wheel or a source distribution.
 ///
 /// <https://peps.python.org/pep-0691/#project-detail>
@@ -418,25 +418,25 @@ fn python_executables_from_installed<'a>(
                 }
             };
 
-            env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
-                .is_none()
-                .then(|| {
-                    registry_pythons()
-                        .map(|entries| {
-                            entries
-                                .into_iter()
-                                .filter(version_filter)
-                                .map(|entry| (PythonSource::Registry, entry.path))
-                                .chain(
-                                    find_microsoft_store_pythons()
-                                        .filter(version_filter)
-                                        .map(|entry| (PythonSource::MicrosoftStore, entry.path)),
-                                )
-                        })
-                        .map_err(Error::from)
-                })
-                .into_iter()
-                .flatten_ok()
+            (env::var_os(EnvVars::UV_TEST_PYTHON_PATH).is_none()
+                && env::var_os(EnvVars::UV_PYTHON_SEARCH_PATH).is_none())
+            .then(|| {
+                registry_pythons()
+                    .map(|entries| {
+                        entries
+                            .into_iter()
+                            .filter(version_filter)
+                            .map(|entry| (PythonSource::Registry, entry.path))
+                            .chain(
+                                find_microsoft_store_pythons()
+                                    .filter(version_filter)
+                                    .map(|entry| (PythonSource::MicrosoftStore, entry.path)),
+                            )
+                    })
+                    .map_err(Error::from)
+            })
+            .into_iter()
+            .flatten_ok()
         }
         #[cfg(not(windows))]
         {
@@ -545,7 +545,9 @@ fn python_executables_from_search_path<'a>(
     implementation: Option<&'a ImplementationName>,
 ) -> impl Iterator<Item = PathBuf> + 'a {
     // `UV_TEST_PYTHON_PATH` can be used to override `PATH` to limit Python executable availability in the test suite
+    // `UV_PYTHON_SEARCH_PATH` can be used to do the same for reproducible discovery, e.g., in CI
     let search_path = env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
+        .or_else(|| env::var_os(EnvVars::UV_PYTHON_SEARCH_PATH))
         .unwrap_or(env::var_os(EnvVars::PATH).unwrap_or_default());
 
     let possible_names: Vec<_> = version
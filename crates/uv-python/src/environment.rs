@@ -16,7 +16,7 @@ use crate::installation::PythonInstallation;
 use crate::virtualenv::{PyVenvConfiguration, virtualenv_python_executable};
 use crate::{
     EnvironmentPreference, Error, Interpreter, Prefix, PythonNotFound, PythonPreference,
-    PythonRequest, Target,
+    PythonRequest, Root, Target,
 };
 
 /// A Python environment, consisting of a Python [`Interpreter`] and its associated paths.
@@ -260,6 +260,15 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Create a [`PythonEnvironment`] from an existing [`Interpreter`] and `--root` directory.
+    pub fn with_root(self, root: Root) -> std::io::Result<Self> {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Ok(Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.with_root(root)?,
+            ..inner
+        })))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -319,6 +328,12 @@ impl PythonEnvironment {
         self.0.interpreter.lock().await
     }
 
+    /// Attempt to grab a file lock for the environment without waiting, returning `None` if the
+    /// lock is held by another process.
+    pub fn try_lock(&self) -> Result<Option<LockedFile>, std::io::Error> {
+        self.0.interpreter.try_lock()
+    }
+
     /// Return the [`Interpreter`] for this environment.
     ///
     /// See also [`PythonEnvironment::interpreter`].
@@ -30,7 +30,7 @@ use crate::managed::ManagedPythonInstallations;
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
 use crate::{
-    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, VersionRequest,
+    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Root, Target, VersionRequest,
     VirtualEnvironment,
 };
 
@@ -56,6 +56,7 @@ pub struct Interpreter {
     tags: OnceLock<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    root: Option<Root>,
     pointer_size: PointerSize,
     gil_disabled: bool,
     real_executable: PathBuf,
@@ -91,6 +92,7 @@ impl Interpreter {
             tags: OnceLock::new(),
             target: None,
             prefix: None,
+            root: None,
             real_executable: executable.as_ref().to_path_buf(),
         })
     }
@@ -105,6 +107,7 @@ impl Interpreter {
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
+            root: None,
             ..self
         }
     }
@@ -127,6 +130,16 @@ impl Interpreter {
         })
     }
 
+    /// Return a new [`Interpreter`] that stages its installations beneath the given `--root`
+    /// directory.
+    pub fn with_root(self, root: Root) -> io::Result<Self> {
+        root.init(&self.layout().scheme)?;
+        Ok(Self {
+            root: Some(root),
+            ..self
+        })
+    }
+
     /// Return the base Python executable; that is, the Python executable that should be
     /// considered the "base" for the virtual environment. This is typically the Python executable
     /// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
@@ -267,6 +280,11 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
+    /// Returns `true` if the environment is staged beneath a `--root` directory.
+    pub fn is_root(&self) -> bool {
+        self.root.is_some()
+    }
+
     /// Returns `true` if this interpreter is managed by uv.
     ///
     /// Returns `false` if we cannot determine the path of the uv managed Python interpreters.
@@ -305,8 +323,9 @@ impl Interpreter {
             return None;
         }
 
-        // If we're installing into a target or prefix directory, it's never externally managed.
-        if self.is_target() || self.is_prefix() {
+        // If we're installing into a target, prefix, or root directory, it's never externally
+        // managed.
+        if self.is_target() || self.is_prefix() || self.is_root() {
             return None;
         }
 
@@ -507,6 +526,11 @@ impl Interpreter {
         self.prefix.as_ref()
     }
 
+    /// Return the `--root` directory for this interpreter, if any.
+    pub fn root(&self) -> Option<&Root> {
+        self.root.as_ref()
+    }
+
     /// Returns `true` if an [`Interpreter`] may be a `python-build-standalone` interpreter.
     ///
     /// This method may return false positives, but it should not return false negatives. In other
@@ -530,7 +554,7 @@ impl Interpreter {
 
     /// Return the [`Layout`] environment used to install wheels into this interpreter.
     pub fn layout(&self) -> Layout {
-        Layout {
+        let layout = Layout {
             python_version: self.python_tuple(),
             sys_executable: self.sys_executable().to_path_buf(),
             os_name: self.markers.os_name().to_string(),
@@ -557,6 +581,17 @@ impl Interpreter {
                     },
                 }
             },
+        };
+
+        // If we're staging beneath a `--root`, relocate the physical install locations, while
+        // retaining the original `sys.executable` for use in script shebangs.
+        if let Some(root) = self.root.as_ref() {
+            Layout {
+                scheme: root.scheme(&layout.scheme),
+                ..layout
+            }
+        } else {
+            layout
         }
     }
 
@@ -594,6 +629,14 @@ impl Interpreter {
             .map(Cow::Borrowed)
             .chain(prefix.into_iter().flatten().map(Cow::Owned))
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
+            .map(|site_packages| {
+                // If we're staging beneath a `--root`, relocate the `site-packages` directories.
+                if let Some(root) = self.root() {
+                    Cow::Owned(root.join(&site_packages))
+                } else {
+                    site_packages
+                }
+            })
     }
 
     /// Check if the interpreter matches the given Python version.
@@ -625,26 +668,50 @@ impl Interpreter {
 
     /// Grab a file lock for the environment to prevent concurrent writes across processes.
     pub async fn lock(&self) -> Result<LockedFile, io::Error> {
-        if let Some(target) = self.target() {
+        let (path, resource) = self.lock_path();
+        LockedFile::acquire(path, resource).await
+    }
+
+    /// Attempt to grab a file lock for the environment without waiting, returning `None` if the
+    /// lock is held by another process.
+    pub fn try_lock(&self) -> Result<Option<LockedFile>, io::Error> {
+        let (path, resource) = self.lock_path();
+        LockedFile::try_acquire(path, resource)
+    }
+
+    /// Return the path to the lockfile for the environment, along with a description of the
+    /// locked resource.
+    fn lock_path(&self) -> (PathBuf, String) {
+        if let Some(root) = self.root() {
+            // If we're staging beneath a `--root`, use a root-specific lockfile.
+            (
+                root.root().join(".lock"),
+                root.root().user_display().to_string(),
+            )
+        } else if let Some(target) = self.target() {
             // If we're installing into a `--target`, use a target-specific lockfile.
-            LockedFile::acquire(target.root().join(".lock"), target.root().user_display()).await
+            (
+                target.root().join(".lock"),
+                target.root().user_display().to_string(),
+            )
         } else if let Some(prefix) = self.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
-            LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+            (
+                prefix.root().join(".lock"),
+                prefix.root().user_display().to_string(),
+            )
         } else if self.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
-            LockedFile::acquire(
+            (
                 self.sys_prefix.join(".lock"),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         } else {
             // Otherwise, use a global lockfile.
-            LockedFile::acquire(
+            (
                 env::temp_dir().join(format!("uv-{}.lock", cache_digest(&self.sys_executable))),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         }
     }
 }
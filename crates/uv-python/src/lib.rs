@@ -19,9 +19,11 @@ pub use crate::installation::{
 pub use crate::interpreter::{
     BrokenSymlink, Error as InterpreterError, Interpreter, canonicalize_executable,
 };
+pub use crate::libc::find_ld_path_at;
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
+pub use crate::root::Root;
 pub use crate::target::Target;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
@@ -45,6 +47,7 @@ pub mod platform;
 mod pointer_size;
 mod prefix;
 mod python_version;
+mod root;
 mod sysconfig;
 mod target;
 mod version_files;
@@ -215,6 +218,7 @@ mod tests {
             let mut run_vars = vec![
                 // Ensure `PATH` is used
                 (EnvVars::UV_TEST_PYTHON_PATH, None),
+                (EnvVars::UV_PYTHON_SEARCH_PATH, None),
                 // Ignore active virtual environments (i.e. that the dev is using)
                 (EnvVars::VIRTUAL_ENV, None),
                 (EnvVars::PATH, path.as_deref()),
@@ -813,6 +817,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_version_minor_search_path_override() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.11.2", "3.12.3"])?;
+
+        // Create a version-specific executable outside of `PATH`.
+        let pinned = context.tempdir.child("pinned");
+        pinned.create_dir_all()?;
+        TestContext::create_mock_interpreter(
+            &pinned.join(format!("python3.11{}", env::consts::EXE_SUFFIX)),
+            &PythonVersion::from_str("3.11.9").unwrap(),
+            ImplementationName::default(),
+            true,
+            false,
+        )?;
+
+        let python = context.run_with_vars(
+            &[(
+                EnvVars::UV_PYTHON_SEARCH_PATH,
+                Some(pinned.path().as_os_str()),
+            )],
+            || {
+                find_python_installation(
+                    &PythonRequest::parse("3.11"),
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    &context.cache,
+                    PreviewMode::Disabled,
+                )
+            },
+        )??;
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.11.9",
+            "We should find the `python3.11` executable in the overridden search path"
+        );
+
+        // Interpreters on `PATH` are ignored when the search path is overridden.
+        let result = context.run_with_vars(
+            &[(
+                EnvVars::UV_PYTHON_SEARCH_PATH,
+                Some(pinned.path().as_os_str()),
+            )],
+            || {
+                find_python_installation(
+                    &PythonRequest::parse("3.12"),
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    &context.cache,
+                    PreviewMode::Disabled,
+                )
+            },
+        )?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "We should not find a python; got {result:?}"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_version_patch() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -1229,6 +1294,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_from_conda_prefix_path() -> Result<()> {
+        let context = TestContext::new()?;
+
+        // Create a conda environment, i.e., with a `conda-meta` directory but without a
+        // `pyvenv.cfg`.
+        let condaenv = context.tempdir.child("condaenv");
+        TestContext::create_mock_interpreter(
+            &virtualenv_python_executable(&condaenv),
+            &PythonVersion::from_str("3.12.0").unwrap(),
+            ImplementationName::default(),
+            true,
+            false,
+        )?;
+        condaenv.child("conda-meta").create_dir_all()?;
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse(condaenv.to_str().unwrap()),
+                EnvironmentPreference::ExplicitSystem,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                PreviewMode::Disabled,
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.12.0",
+            "We should allow the conda environment to be requested by its prefix"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_from_discovered_python() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -232,7 +232,7 @@ fn find_ld_path() -> Result<PathBuf, LibcDetectionError> {
 /// Attempt to find the path to the `ld` executable by
 /// ELF parsing the given path. If this fails for any
 /// reason, then an error is returned.
-fn find_ld_path_at(path: impl AsRef<Path>) -> Option<PathBuf> {
+pub fn find_ld_path_at(path: impl AsRef<Path>) -> Option<PathBuf> {
     let path = path.as_ref();
     // Not all linux distributions have all of these paths.
     let buffer = fs::read(path).ok()?;
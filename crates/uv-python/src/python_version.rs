@@ -211,6 +211,7 @@ mod tests {
     use std::str::FromStr;
 
     use uv_pep440::{Prerelease, PrereleaseKind, Version};
+    use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder, MarkerTree};
 
     use crate::PythonVersion;
 
@@ -240,4 +241,39 @@ mod tests {
         );
         assert_eq!(version.python_full_version().to_string(), "3.11.8a1");
     }
+
+    /// A two-component Python version evaluates `python_full_version` markers as the `.0` release,
+    /// while `python_version` markers see the major and minor version.
+    #[test]
+    fn python_markers_without_patch() {
+        let base = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: "3.12.3",
+            os_name: "posix",
+            platform_machine: "x86_64",
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system: "Linux",
+            platform_version: "",
+            python_full_version: "3.12.3",
+            python_version: "3.12",
+            sys_platform: "linux",
+        })
+        .unwrap();
+
+        let version = PythonVersion::from_str("3.11").expect("valid python version");
+        let markers = version.markers(&base);
+
+        let evaluate = |marker: &str| {
+            MarkerTree::from_str(marker)
+                .unwrap()
+                .evaluate(&markers, &[])
+        };
+        assert!(evaluate("python_version == '3.11'"));
+        assert!(evaluate("python_full_version == '3.11.0'"));
+        assert!(evaluate("python_full_version >= '3.11'"));
+        assert!(evaluate("python_full_version < '3.11.1'"));
+        assert!(!evaluate("python_full_version >= '3.11.1'"));
+        assert!(evaluate("implementation_version == '3.11.0'"));
+    }
 }
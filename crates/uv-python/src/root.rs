@@ -0,0 +1,55 @@
+use std::path::{Component, Path, PathBuf};
+
+use uv_pypi_types::Scheme;
+
+/// A `--root` directory under which packages are staged, as in a `DESTDIR`-style installation.
+///
+/// Files are physically written beneath the root directory, while any recorded paths (e.g., in
+/// `RECORD` files or script shebangs) continue to refer to the final installation location.
+#[derive(Debug, Clone)]
+pub struct Root(PathBuf);
+
+impl Root {
+    /// Return the [`Scheme`] with each of its directories relocated beneath the `--root`
+    /// directory.
+    pub fn scheme(&self, scheme: &Scheme) -> Scheme {
+        Scheme {
+            purelib: self.join(&scheme.purelib),
+            platlib: self.join(&scheme.platlib),
+            scripts: self.join(&scheme.scripts),
+            data: self.join(&scheme.data),
+            include: self.join(&scheme.include),
+        }
+    }
+
+    /// Relocate the given path beneath the `--root` directory.
+    ///
+    /// Relative paths are first made absolute; any root or prefix component (e.g., `/` or `C:\`)
+    /// is then dropped, such that `/usr/lib` maps to `<root>/usr/lib`.
+    pub fn join(&self, path: &Path) -> PathBuf {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let relative = path
+            .components()
+            .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect::<PathBuf>();
+        self.0.join(relative)
+    }
+
+    /// Initialize the `--root` directory, creating the `site-packages` directories for the given
+    /// (unrelocated) [`Scheme`].
+    pub fn init(&self, scheme: &Scheme) -> std::io::Result<()> {
+        fs_err::create_dir_all(self.join(&scheme.purelib))?;
+        Ok(())
+    }
+
+    /// Return the path to the `--root` directory.
+    pub fn root(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl From<PathBuf> for Root {
+    fn from(path: PathBuf) -> Self {
+        Self(path)
+    }
+}
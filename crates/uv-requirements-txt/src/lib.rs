@@ -57,8 +57,7 @@ use uv_pypi_types::VerbatimParsedUrl;
 #[cfg(feature = "http")]
 use uv_redacted::DisplaySafeUrl;
 
-use crate::requirement::EditableError;
-pub use crate::requirement::RequirementsTxtRequirement;
+pub use crate::requirement::{EditableError, RequirementsTxtRequirement};
 use crate::shquote::unquote;
 
 mod requirement;
@@ -170,9 +169,23 @@ impl RequirementsTxt {
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self, RequirementsTxtFileError> {
-        let requirements_txt = requirements_txt.as_ref();
-        let working_dir = working_dir.as_ref();
+        Self::parse_with_ancestors(
+            requirements_txt.as_ref(),
+            working_dir.as_ref(),
+            client_builder,
+            &[],
+        )
+        .await
+    }
 
+    /// Parse a `requirements.txt` file that was included (via `-r` or `-c`) from each of the
+    /// `ancestors`, in order.
+    async fn parse_with_ancestors(
+        requirements_txt: &Path,
+        working_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        ancestors: &[PathBuf],
+    ) -> Result<Self, RequirementsTxtFileError> {
         let content =
             if requirements_txt.starts_with("http://") | requirements_txt.starts_with("https://") {
                 #[cfg(not(feature = "http"))]
@@ -214,12 +227,13 @@ impl RequirementsTxt {
             })?;
 
         let requirements_dir = requirements_txt.parent().unwrap_or(working_dir);
-        let data = Self::parse_inner(
+        let data = Self::parse_inner_with_ancestors(
             &content,
             working_dir,
             requirements_dir,
             client_builder,
             requirements_txt,
+            ancestors,
         )
         .await
         .map_err(|err| RequirementsTxtFileError {
@@ -243,6 +257,34 @@ impl RequirementsTxt {
         client_builder: &BaseClientBuilder<'_>,
         requirements_txt: &Path,
     ) -> Result<Self, RequirementsTxtParserError> {
+        Self::parse_inner_with_ancestors(
+            content,
+            working_dir,
+            requirements_dir,
+            client_builder,
+            requirements_txt,
+            &[],
+        )
+        .await
+    }
+
+    /// Parse the contents of a `requirements.txt` file that was included (via `-r` or `-c`) from
+    /// each of the `ancestors`, in order.
+    async fn parse_inner_with_ancestors(
+        content: &str,
+        working_dir: &Path,
+        requirements_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        requirements_txt: &Path,
+        ancestors: &[PathBuf],
+    ) -> Result<Self, RequirementsTxtParserError> {
+        // Track the chain of included files, to detect cycles.
+        let ancestors = ancestors
+            .iter()
+            .cloned()
+            .chain(std::iter::once(requirements_txt.to_path_buf()))
+            .collect::<Vec<_>>();
+
         let mut s = Scanner::new(content);
 
         let mut data = Self::default();
@@ -273,17 +315,32 @@ impl RequirementsTxt {
                                         end,
                                     })?,
                             )
+                        } else if let Some(sub_file) =
+                            remote_sub_file(requirements_txt, filename.as_ref())
+                        {
+                            sub_file
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_requirements =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    if let Some(cycle) = find_cycle(&ancestors, &sub_file) {
+                        return Err(RequirementsTxtParserError::Cycle {
+                            files: cycle,
+                            start,
+                            end,
+                        });
+                    }
+                    let sub_requirements = Box::pin(Self::parse_with_ancestors(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        &ancestors,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Disallow conflicting `--index-url` in nested `requirements` files.
                     if sub_requirements.index_url.is_some()
@@ -328,17 +385,32 @@ impl RequirementsTxt {
                                         end,
                                     })?,
                             )
+                        } else if let Some(sub_file) =
+                            remote_sub_file(requirements_txt, filename.as_ref())
+                        {
+                            sub_file
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_constraints =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    if let Some(cycle) = find_cycle(&ancestors, &sub_file) {
+                        return Err(RequirementsTxtParserError::Cycle {
+                            files: cycle,
+                            start,
+                            end,
+                        });
+                    }
+                    let sub_constraints = Box::pin(Self::parse_with_ancestors(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        &ancestors,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Treat any nested requirements or constraints as constraints. This differs
                     // from `pip`, which seems to treat `-r` requirements in constraints files as
@@ -937,6 +1009,19 @@ fn parse_value<'a, T>(
     Ok(value)
 }
 
+/// Resolve a nested requirements file against the URL of a remote `requirements.txt` file.
+///
+/// For example, `-r ../base.txt` within `https://example.com/requirements/dev.txt` refers to
+/// `https://example.com/base.txt`. Returns `None` if the containing file is not a remote URL.
+fn remote_sub_file(requirements_txt: &Path, filename: &str) -> Option<PathBuf> {
+    let requirements_txt = requirements_txt.to_str()?;
+    if !(requirements_txt.starts_with("http://") || requirements_txt.starts_with("https://")) {
+        return None;
+    }
+    let url = Url::parse(requirements_txt).ok()?.join(filename).ok()?;
+    Some(PathBuf::from(url.as_str()))
+}
+
 /// Fetch the contents of a URL and return them as a string.
 #[cfg(feature = "http")]
 async fn read_url_to_string(
@@ -1045,6 +1130,11 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    Cycle {
+        files: Vec<PathBuf>,
+        start: usize,
+        end: usize,
+    },
     NonUnicodeUrl {
         url: PathBuf,
     },
@@ -1110,6 +1200,13 @@ impl Display for RequirementsTxtParserError {
             Self::Subfile { start, .. } => {
                 write!(f, "Error parsing included file at position {start}")
             }
+            Self::Cycle { files, .. } => {
+                write!(
+                    f,
+                    "Detected a cycle in included requirements files: {}",
+                    display_cycle(files)
+                )
+            }
             Self::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1147,10 +1244,11 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Pep508 { source, .. } => Some(source),
             Self::ParsedUrl { source, .. } => Some(source),
             Self::Subfile { source, .. } => Some(source.as_ref()),
+            Self::Cycle { .. } => None,
             Self::Parser { .. } => None,
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
-            Self::Reqwest(_, err) => err.source(),
+            Self::Reqwest(_, err) => Some(err),
             #[cfg(feature = "http")]
             Self::InvalidUrl(_, err) => err.source(),
         }
@@ -1270,6 +1368,13 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::Cycle { files, .. } => {
+                write!(
+                    f,
+                    "Detected a cycle in included requirements files: {}",
+                    display_cycle(files)
+                )
+            }
             RequirementsTxtParserError::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1295,6 +1400,41 @@ impl std::error::Error for RequirementsTxtFileError {
     }
 }
 
+/// If including `sub_file` from the last of the `ancestors` would form a cycle, return the files
+/// that make up the cycle, starting and ending with the repeated file.
+fn find_cycle(ancestors: &[PathBuf], sub_file: &Path) -> Option<Vec<PathBuf>> {
+    /// Normalize a path such that different spellings of the same file compare equal.
+    fn normalize(path: &Path) -> PathBuf {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_path_buf();
+        }
+        std::path::absolute(path)
+            .map(uv_fs::normalize_path_buf)
+            .unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    let target = normalize(sub_file);
+    let position = ancestors
+        .iter()
+        .position(|ancestor| normalize(ancestor) == target)?;
+    Some(
+        ancestors[position..]
+            .iter()
+            .map(|ancestor| normalize(ancestor))
+            .chain(std::iter::once(target))
+            .collect(),
+    )
+}
+
+/// Format a cycle of included files for display, e.g., `a.txt` -> `b.txt` -> `a.txt`.
+fn display_cycle(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|file| format!("`{}`", file.user_display()))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 impl From<io::Error> for RequirementsTxtParserError {
     fn from(err: io::Error) -> Self {
         Self::Io(err)
@@ -1303,12 +1443,20 @@ impl From<io::Error> for RequirementsTxtParserError {
 
 #[cfg(feature = "http")]
 impl RequirementsTxtParserError {
+    /// Wrap a [`reqwest::Error`], omitting the request URL from it, as it may contain credentials.
     fn from_reqwest(url: DisplaySafeUrl, err: reqwest::Error) -> Self {
-        Self::Reqwest(url, reqwest_middleware::Error::Reqwest(err))
+        Self::Reqwest(url, reqwest_middleware::Error::Reqwest(err.without_url()))
     }
 
+    /// Wrap a [`reqwest_middleware::Error`], omitting the request URL from it, as it may contain
+    /// credentials.
     fn from_reqwest_middleware(url: DisplaySafeUrl, err: reqwest_middleware::Error) -> Self {
-        Self::Reqwest(url, err)
+        match err {
+            reqwest_middleware::Error::Reqwest(err) => Self::from_reqwest(url, err),
+            reqwest_middleware::Error::Middleware(err) => {
+                Self::Reqwest(url, reqwest_middleware::Error::Middleware(err))
+            }
+        }
     }
 }
 
@@ -2152,6 +2300,43 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn nested_cycle() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -r child.txt
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            -c ./requirements.txt
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let temp_dir = regex::escape(&temp_dir.path().user_display().to_string());
+        let filters = vec![(temp_dir.as_str(), "[TEMP_DIR]")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r"
+            Error parsing included file in `[TEMP_DIR]/requirements.txt` at position 0
+            Detected a cycle in included requirements files: `[TEMP_DIR]/requirements.txt` -> `[TEMP_DIR]/child.txt` -> `[TEMP_DIR]/requirements.txt`
+            ");
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
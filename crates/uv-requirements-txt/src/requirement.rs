@@ -61,7 +61,9 @@ impl RequirementsTxtRequirement {
     /// # Errors
     ///
     /// Returns [`EditableError`] if the requirement cannot be interpreted as editable.
-    /// Specifically, only local directory URLs are supported.
+    /// Specifically, only local directory and Git URLs are supported. Git URLs are returned
+    /// unchanged, as they must be checked out to a local directory before they can be installed
+    /// in editable mode.
     pub fn into_editable(self) -> Result<Self, EditableError> {
         match self {
             RequirementsTxtRequirement::Named(requirement) => {
@@ -82,7 +84,10 @@ impl RequirementsTxtRequirement {
                         return Err(EditableError::Https(requirement.name, url.to_string()));
                     }
                     ParsedUrl::Git(_) => {
-                        return Err(EditableError::Git(requirement.name, url.to_string()));
+                        return Ok(Self::Named(uv_pep508::Requirement {
+                            version_or_url: Some(uv_pep508::VersionOrUrl::Url(url)),
+                            ..requirement
+                        }));
                     }
                 };
 
@@ -107,7 +112,7 @@ impl RequirementsTxtRequirement {
                         return Err(EditableError::UnnamedHttps(requirement.to_string()));
                     }
                     ParsedUrl::Git(_) => {
-                        return Err(EditableError::UnnamedGit(requirement.to_string()));
+                        return Ok(Self::Unnamed(requirement));
                     }
                 };
 
@@ -127,6 +132,29 @@ impl RequirementsTxtRequirement {
 }
 
 impl RequirementsTxtRequirement {
+    /// Return an [`EditableError`] if the requirement is an editable Git requirement, for contexts
+    /// in which the repository can't be checked out to a local directory (e.g., when compiling
+    /// requirements).
+    pub fn editable_git_error(&self) -> Option<EditableError> {
+        match self {
+            Self::Named(requirement) => match &requirement.version_or_url {
+                Some(uv_pep508::VersionOrUrl::Url(url))
+                    if matches!(url.parsed_url, ParsedUrl::Git(_)) =>
+                {
+                    Some(EditableError::Git(
+                        requirement.name.clone(),
+                        url.to_string(),
+                    ))
+                }
+                _ => None,
+            },
+            Self::Unnamed(requirement) => match requirement.url.parsed_url {
+                ParsedUrl::Git(_) => Some(EditableError::UnnamedGit(requirement.to_string())),
+                _ => None,
+            },
+        }
+    }
+
     /// Parse a requirement as seen in a `requirements.txt` file.
     pub fn parse(
         input: &str,
@@ -0,0 +1,151 @@
+//! A reader for the `dependencies` of a Conda `environment.yml` file.
+//!
+//! Rather than depend on a full YAML parser, only the block-style subset that Conda itself writes
+//! (and that's used in practice) is supported: a top-level `dependencies` sequence of plain or
+//! quoted strings, optionally containing a single-key `pip` mapping with a nested sequence of
+//! strings. All other top-level keys are skipped. Anything else within `dependencies` (e.g., flow
+//! sequences like `[numpy, scipy]`, or block scalars) is rejected, rather than misread.
+
+/// The subset of a Conda `environment.yml` file that's relevant to installing packages.
+#[derive(Debug, Default)]
+pub(crate) struct EnvironmentYml {
+    /// The Conda packages (e.g., `python=3.12`).
+    pub(crate) conda: Vec<String>,
+    /// The `pip` requirements, in `requirements.txt` format.
+    pub(crate) pip: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unsupported syntax on line {line}: {reason}")]
+pub(crate) struct EnvironmentYmlError {
+    line: usize,
+    reason: &'static str,
+}
+
+impl EnvironmentYml {
+    /// Read the `dependencies` from the contents of an `environment.yml` file.
+    pub(crate) fn parse(contents: &str) -> Result<Self, EnvironmentYmlError> {
+        let mut environment_yml = Self::default();
+
+        // Whether the lines being read belong to the top-level `dependencies` key.
+        let mut in_dependencies = false;
+        // The indentation of the `-` that introduced the current `pip` section, if any.
+        let mut pip_indent = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let error = |reason| EnvironmentYmlError {
+                line: index + 1,
+                reason,
+            };
+
+            let content = strip_comment(line).trim_end();
+            if content.trim_start().is_empty() || content == "---" {
+                continue;
+            }
+            if content.starts_with('\t') {
+                return Err(error("tabs can't be used for indentation"));
+            }
+            let indent = content.len() - content.trim_start_matches(' ').len();
+            let content = &content[indent..];
+
+            // A new top-level key.
+            if indent == 0 && !content.starts_with('-') {
+                pip_indent = None;
+                in_dependencies = match content.split_once(':') {
+                    Some(("dependencies", "")) => true,
+                    Some(("dependencies", rest)) if rest.trim() == "[]" => false,
+                    Some(("dependencies", _)) => {
+                        return Err(error("`dependencies` must be a block sequence"));
+                    }
+                    _ => false,
+                };
+                continue;
+            }
+            if !in_dependencies {
+                continue;
+            }
+
+            let Some(item) = content.strip_prefix('-') else {
+                return Err(error("expected a sequence item"));
+            };
+            if !(item.is_empty() || item.starts_with(' ')) {
+                return Err(error("expected a sequence item"));
+            }
+            let item = item.trim_start();
+
+            // An item within the `pip` section.
+            if pip_indent.is_some_and(|pip_indent| indent > pip_indent) {
+                environment_yml.pip.push(
+                    parse_scalar(item)
+                        .ok_or_else(|| error("`pip` dependencies must be strings"))?,
+                );
+                continue;
+            }
+            pip_indent = None;
+
+            // The start of a `pip` section.
+            if let Some(rest) = item.strip_prefix("pip:") {
+                if !rest.trim().is_empty() {
+                    return Err(error("`pip` must be a block sequence"));
+                }
+                pip_indent = Some(indent);
+                continue;
+            }
+
+            environment_yml.conda.push(
+                parse_scalar(item)
+                    .ok_or_else(|| error("dependencies must be strings or a `pip` section"))?,
+            );
+        }
+
+        Ok(environment_yml)
+    }
+}
+
+/// Strip a trailing comment from a line, i.e., a `#` at the start of the line or preceded by
+/// whitespace, outside of any quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, ch) in line.char_indices() {
+        match quote {
+            Some(open) if ch == open => quote = None,
+            Some(_) => {}
+            None if matches!(ch, '\'' | '"') && previous == ' ' => quote = Some(ch),
+            None if ch == '#' && previous.is_whitespace() => return &line[..index],
+            None => {}
+        }
+        previous = ch;
+    }
+    line
+}
+
+/// Parse a plain or quoted scalar, returning `None` for anything else (e.g., a mapping or a flow
+/// collection).
+fn parse_scalar(item: &str) -> Option<String> {
+    if let Some(inner) = item
+        .strip_prefix('\'')
+        .and_then(|item| item.strip_suffix('\''))
+    {
+        return Some(inner.replace("''", "'"));
+    }
+    if let Some(inner) = item
+        .strip_prefix('"')
+        .and_then(|item| item.strip_suffix('"'))
+    {
+        if inner.contains('\\') {
+            return None;
+        }
+        return Some(inner.to_string());
+    }
+    if item.is_empty()
+        || item == "-"
+        || item.starts_with("- ")
+        || item.starts_with(['[', '{', '|', '>', '&', '*', '!'])
+        || item.contains(": ")
+        || item.ends_with(':')
+    {
+        return None;
+    }
+    Some(item.to_string())
+}
@@ -9,6 +9,7 @@ use uv_distribution_types::{
     Dist, DistErrorKind, GitSourceDist, Requirement, RequirementSource, SourceDist,
 };
 
+mod environment_yml;
 mod extras;
 mod lookahead;
 mod source_tree;
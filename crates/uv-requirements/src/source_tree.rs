@@ -98,14 +98,30 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         let mut requirements = Vec::new();
 
         // Flatten any transitive extras and include dependencies
-        // (unless something like --only-group was passed)
+        // (unless something like --only-group or --only-extras was passed)
         requirements.extend(
             FlatRequiresDist::from_requirements(metadata.requires_dist, &metadata.name)
                 .into_iter()
-                .map(|requirement| Requirement {
-                    origin: Some(origin.clone()),
-                    marker: requirement.marker.simplify_extras(&extras),
-                    ..requirement
+                .filter(|requirement| {
+                    // If only extras were requested, omit the project's required dependencies.
+                    self.extras.prod() || requirement.marker.top_level_extra_name().is_some()
+                })
+                .map(|requirement| {
+                    // Attribute any requirements that were enabled by a requested extra to that
+                    // extra, so that conflicts between extras can be reported as such.
+                    let origin = match requirement.marker.top_level_extra_name() {
+                        Some(extra) if extras.contains(&*extra) => RequirementOrigin::Extra(
+                            path.to_path_buf(),
+                            metadata.name.clone(),
+                            extra.into_owned(),
+                        ),
+                        _ => origin.clone(),
+                    };
+                    Requirement {
+                        origin: Some(origin),
+                        marker: requirement.marker.simplify_extras(&extras),
+                        ..requirement
+                    }
                 }),
         );
 
@@ -163,7 +179,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             HashStrategy::None => HashPolicy::None,
             HashStrategy::Generate(mode) => HashPolicy::Generate(*mode),
             HashStrategy::Verify(_) => HashPolicy::Generate(HashGeneration::All),
-            HashStrategy::Require(_) => {
+            HashStrategy::Require(..) => {
                 return Err(anyhow::anyhow!(
                     "Hash-checking is not supported for local directories: {}",
                     path.user_display()
@@ -23,8 +23,13 @@ pub enum RequirementsSource {
     SetupPy(PathBuf),
     /// Dependencies were provided via a `setup.cfg` file (e.g., `pip-compile setup.cfg`).
     SetupCfg(PathBuf),
-    /// Dependencies were provided via an unsupported Conda `environment.yml` file (e.g., `pip install -r environment.yml`).
+    /// Dependencies were provided via the `pip` section of a Conda `environment.yml` file (e.g., `pip install -r environment.yml`).
     EnvironmentYml(PathBuf),
+    /// Dependencies were provided via a Pipenv `Pipfile.lock` file (e.g., `pip sync Pipfile.lock`).
+    ///
+    /// If `develop` is set, the packages in the `develop` section are included alongside those in
+    /// the `default` section.
+    PipfileLock { path: PathBuf, develop: bool },
 }
 
 impl RequirementsSource {
@@ -39,6 +44,11 @@ impl RequirementsSource {
             Ok(Self::SetupCfg(path))
         } else if path.ends_with("environment.yml") {
             Ok(Self::EnvironmentYml(path))
+        } else if path.ends_with("Pipfile.lock") {
+            Ok(Self::PipfileLock {
+                path,
+                develop: false,
+            })
         } else if path
             .file_name()
             .is_some_and(|file_name| file_name.to_str().is_some_and(is_pylock_toml))
@@ -269,6 +279,15 @@ impl RequirementsSource {
         Ok(Self::Package(requirement))
     }
 
+    /// Include the `develop` section of a `Pipfile.lock` source, if `develop` is set.
+    #[must_use]
+    pub fn with_pipfile_develop(self, develop: bool) -> Self {
+        match self {
+            Self::PipfileLock { path, .. } => Self::PipfileLock { path, develop },
+            source => source,
+        }
+    }
+
     /// Returns `true` if the source allows extras to be specified.
     pub fn allows_extras(&self) -> bool {
         matches!(
@@ -293,7 +312,8 @@ impl std::fmt::Display for RequirementsSource {
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
             | Self::SetupCfg(path)
-            | Self::EnvironmentYml(path) => {
+            | Self::EnvironmentYml(path)
+            | Self::PipfileLock { path, .. } => {
                 write!(f, "{}", path.simplified_display())
             }
         }
@@ -29,13 +29,14 @@
 
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use rustc_hash::FxHashSet;
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 use uv_cache_key::CanonicalUrl;
-use uv_client::BaseClientBuilder;
+use uv_client::{BaseClientBuilder, Connectivity, RegistryClient};
 use uv_configuration::{DependencyGroups, NoBinary, NoBuild};
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{
@@ -43,12 +44,146 @@ use uv_distribution_types::{
     UnresolvedRequirementSpecification,
 };
 use uv_fs::{CWD, Simplified};
+use uv_git::{GIT, GitResolver};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement};
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::{
+    MarkerTree, Pep508Url, RequirementOrigin, UnnamedRequirement, VerbatimUrl, VersionOrUrl,
+};
+use uv_pypi_types::{ParsedDirectoryUrl, ParsedGitUrl, ParsedUrl, VerbatimParsedUrl};
+use uv_requirements_txt::{
+    EditableError, RequirementEntry, RequirementsTxt, RequirementsTxtRequirement,
+};
 use uv_warnings::warn_user;
 use uv_workspace::pyproject::PyProjectToml;
 
 use crate::RequirementsSource;
+use crate::environment_yml::EnvironmentYml;
+
+/// The subset of a Pipenv `Pipfile.lock` file that's relevant to installing packages.
+#[derive(Debug, serde::Deserialize)]
+struct PipfileLock {
+    #[serde(default, rename = "_meta")]
+    meta: PipfileLockMeta,
+    #[serde(default)]
+    default: BTreeMap<String, PipfileLockEntry>,
+    #[serde(default)]
+    develop: BTreeMap<String, PipfileLockEntry>,
+}
+
+/// The `_meta` table of a `Pipfile.lock` file.
+#[derive(Debug, Default, serde::Deserialize)]
+struct PipfileLockMeta {
+    #[serde(default)]
+    sources: Vec<PipfileLockSource>,
+}
+
+/// A package index declared in the `_meta` table of a `Pipfile.lock` file.
+#[derive(Debug, serde::Deserialize)]
+struct PipfileLockSource {
+    url: String,
+}
+
+/// A locked package in the `default` or `develop` section of a `Pipfile.lock` file.
+#[derive(Debug, serde::Deserialize)]
+struct PipfileLockEntry {
+    version: Option<String>,
+    git: Option<String>,
+    #[serde(rename = "ref")]
+    git_ref: Option<String>,
+    subdirectory: Option<String>,
+    markers: Option<String>,
+    #[serde(default)]
+    extras: Vec<String>,
+    #[serde(default)]
+    hashes: Vec<String>,
+}
+
+impl PipfileLockEntry {
+    /// Convert the entry to a [`RequirementEntry`], or `None` if the entry is neither pinned to a
+    /// version nor a Git dependency (e.g., a local path or a file URL).
+    fn to_requirement(&self, name: &str, path: &Path) -> Result<Option<RequirementEntry>> {
+        // Control characters (e.g., newlines) are never valid in an entry.
+        if let Some(field) = [
+            Some(name),
+            self.version.as_deref(),
+            self.git.as_deref(),
+            self.git_ref.as_deref(),
+            self.subdirectory.as_deref(),
+            self.markers.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.extras.iter().map(String::as_str))
+        .chain(self.hashes.iter().map(String::as_str))
+        .find(|field| contains_control_character(field))
+        {
+            return Err(anyhow::anyhow!(
+                "Entry `{}` contains a control character: {field:?}",
+                name.escape_debug()
+            ));
+        }
+
+        let version_or_url = if let Some(version) = &self.version {
+            VersionOrUrl::VersionSpecifier(
+                VersionSpecifiers::from_str(version)
+                    .with_context(|| format!("Invalid version for `{name}`: `{version}`"))?,
+            )
+        } else if let Some(git) = &self.git {
+            let mut url = if git.starts_with("git+") {
+                git.clone()
+            } else {
+                format!("git+{git}")
+            };
+            if let Some(git_ref) = &self.git_ref {
+                url = format!("{url}@{git_ref}");
+            }
+            if let Some(subdirectory) = &self.subdirectory {
+                url = format!("{url}#subdirectory={subdirectory}");
+            }
+            VersionOrUrl::Url(
+                VerbatimParsedUrl::parse_url(&url, None)
+                    .with_context(|| format!("Invalid Git URL for `{name}`: `{url}`"))?,
+            )
+        } else {
+            return Ok(None);
+        };
+
+        let requirement = uv_pep508::Requirement {
+            name: PackageName::from_str(name)
+                .with_context(|| format!("Invalid package name: `{name}`"))?,
+            extras: self
+                .extras
+                .iter()
+                .map(|extra| {
+                    ExtraName::from_str(extra)
+                        .with_context(|| format!("Invalid extra for `{name}`: `{extra}`"))
+                })
+                .collect::<Result<_>>()?,
+            version_or_url: Some(version_or_url),
+            marker: self
+                .markers
+                .as_deref()
+                .map(|markers| {
+                    MarkerTree::from_str(markers)
+                        .with_context(|| format!("Invalid markers for `{name}`: `{markers}`"))
+                })
+                .transpose()?
+                .unwrap_or_default(),
+            origin: Some(RequirementOrigin::File(path.to_path_buf())),
+        };
+
+        Ok(Some(RequirementEntry {
+            requirement: RequirementsTxtRequirement::Named(requirement),
+            hashes: self.hashes.clone(),
+        }))
+    }
+}
+
+/// Returns `true` if the value contains a control character (e.g., a newline).
+fn contains_control_character(value: &str) -> bool {
+    value.chars().any(char::is_control)
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct RequirementsSpecification {
@@ -56,6 +191,10 @@ pub struct RequirementsSpecification {
     pub project: Option<PackageName>,
     /// The requirements for the project.
     pub requirements: Vec<UnresolvedRequirementSpecification>,
+    /// The editable Git requirements (e.g., `-e git+https://...`), which are included in
+    /// `requirements` as non-editable Git requirements until checked out via
+    /// [`checkout_git_editables`].
+    pub git_editables: Vec<RequirementEntry>,
     /// The constraints for the project.
     pub constraints: Vec<NameRequirementSpecification>,
     /// The overrides for the project.
@@ -96,12 +235,21 @@ impl RequirementsSpecification {
                 )],
                 ..Self::default()
             },
-            RequirementsSource::Editable(requirement) => Self {
-                requirements: vec![UnresolvedRequirementSpecification::from(
-                    requirement.clone().into_editable()?,
-                )],
-                ..Self::default()
-            },
+            RequirementsSource::Editable(requirement) => {
+                let entry = RequirementEntry {
+                    requirement: requirement.clone().into_editable()?,
+                    hashes: vec![],
+                };
+                Self {
+                    requirements: vec![UnresolvedRequirementSpecification::from(entry.clone())],
+                    git_editables: if is_git(&entry.requirement) {
+                        vec![entry]
+                    } else {
+                        vec![]
+                    },
+                    ..Self::default()
+                }
+            }
             RequirementsSource::RequirementsTxt(path) => {
                 if !(path == Path::new("-")
                     || path.starts_with("http://")
@@ -124,40 +272,7 @@ impl RequirementsSpecification {
                     }
                 }
 
-                Self {
-                    requirements: requirements_txt
-                        .requirements
-                        .into_iter()
-                        .map(UnresolvedRequirementSpecification::from)
-                        .chain(
-                            requirements_txt
-                                .editables
-                                .into_iter()
-                                .map(UnresolvedRequirementSpecification::from),
-                        )
-                        .collect(),
-                    constraints: requirements_txt
-                        .constraints
-                        .into_iter()
-                        .map(Requirement::from)
-                        .map(NameRequirementSpecification::from)
-                        .collect(),
-                    index_url: requirements_txt.index_url.map(IndexUrl::from),
-                    extra_index_urls: requirements_txt
-                        .extra_index_urls
-                        .into_iter()
-                        .map(IndexUrl::from)
-                        .collect(),
-                    no_index: requirements_txt.no_index,
-                    find_links: requirements_txt
-                        .find_links
-                        .into_iter()
-                        .map(IndexUrl::from)
-                        .collect(),
-                    no_binary: requirements_txt.no_binary,
-                    no_build: requirements_txt.only_binary,
-                    ..Self::default()
-                }
+                Self::from_requirements_txt(requirements_txt)
             }
             RequirementsSource::PyprojectToml(path) => {
                 let contents = match fs_err::tokio::read_to_string(&path).await {
@@ -202,14 +317,186 @@ impl RequirementsSpecification {
                 }
             }
             RequirementsSource::EnvironmentYml(path) => {
-                return Err(anyhow::anyhow!(
-                    "Conda environment files (i.e., `{}`) are not supported",
-                    path.user_display()
-                ));
+                let contents = match fs_err::tokio::read_to_string(&path).await {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    }
+                    Err(err) => {
+                        return Err(anyhow::anyhow!(
+                            "Failed to read `{}`: {}",
+                            path.user_display(),
+                            err
+                        ));
+                    }
+                };
+                let EnvironmentYml { conda, pip } = EnvironmentYml::parse(&contents)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+                // Only the `pip` dependencies can be installed; Conda packages are skipped.
+                if !conda.is_empty() {
+                    warn_user!(
+                        "Skipping Conda dependencies in `{}`, which are not supported: {}",
+                        path.user_display(),
+                        conda.join(", ")
+                    );
+                }
+
+                // Like Conda, parse the `pip` dependencies as a `requirements.txt` file, with
+                // relative paths resolved against the directory containing the environment file.
+                let directory = path
+                    .parent()
+                    .filter(|parent| !parent.as_os_str().is_empty())
+                    .unwrap_or(&*CWD);
+                let requirements_txt = RequirementsTxt::parse_inner(
+                    &pip.join("\n"),
+                    directory,
+                    directory,
+                    client_builder,
+                    path,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to parse the `pip` dependencies in `{}`",
+                        path.user_display()
+                    )
+                })?;
+
+                if requirements_txt == RequirementsTxt::default() {
+                    warn_user!(
+                        "Conda environment file `{}` does not contain any `pip` dependencies",
+                        path.user_display()
+                    );
+                }
+
+                Self::from_requirements_txt(requirements_txt)
+            }
+            RequirementsSource::PipfileLock { path, develop } => {
+                let contents = match fs_err::tokio::read_to_string(&path).await {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    }
+                    Err(err) => {
+                        return Err(anyhow::anyhow!(
+                            "Failed to read `{}`: {}",
+                            path.user_display(),
+                            err
+                        ));
+                    }
+                };
+                let pipfile_lock = serde_json::from_str::<PipfileLock>(&contents)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+                // Use the first source as the index URL and any others as extra index URLs, as
+                // Pipenv does.
+                let mut requirements_txt = RequirementsTxt::default();
+                for (index, source) in pipfile_lock.meta.sources.iter().enumerate() {
+                    if contains_control_character(&source.url) {
+                        return Err(anyhow::anyhow!(
+                            "Source URL in `{}` contains a control character: {:?}",
+                            path.user_display(),
+                            source.url
+                        ));
+                    }
+                    let url = VerbatimUrl::parse_url(&source.url).with_context(|| {
+                        format!(
+                            "Invalid source URL in `{}`: `{}`",
+                            path.user_display(),
+                            source.url
+                        )
+                    })?;
+                    if index == 0 {
+                        requirements_txt.index_url = Some(url);
+                    } else {
+                        requirements_txt.extra_index_urls.push(url);
+                    }
+                }
+
+                let sections = if *develop {
+                    vec![pipfile_lock.default, pipfile_lock.develop]
+                } else {
+                    vec![pipfile_lock.default]
+                };
+                let mut unsupported = Vec::new();
+                for (name, entry) in sections.iter().flatten() {
+                    let requirement = entry.to_requirement(name, path).with_context(|| {
+                        format!(
+                            "Failed to parse the locked packages in `{}`",
+                            path.user_display()
+                        )
+                    })?;
+                    if let Some(requirement) = requirement {
+                        requirements_txt.requirements.push(requirement);
+                    } else {
+                        unsupported.push(name.as_str());
+                    }
+                }
+                if !unsupported.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "`{}` contains entries that are neither pinned to a version nor Git dependencies, which are not supported: {}",
+                        path.user_display(),
+                        unsupported.join(", ")
+                    ));
+                }
+
+                if requirements_txt.requirements.is_empty() {
+                    warn_user!(
+                        "Pipfile lock `{}` does not contain any packages",
+                        path.user_display()
+                    );
+                }
+
+                Self::from_requirements_txt(requirements_txt)
             }
         })
     }
 
+    /// Convert a parsed `requirements.txt` file into a [`RequirementsSpecification`].
+    fn from_requirements_txt(requirements_txt: RequirementsTxt) -> Self {
+        Self {
+            git_editables: requirements_txt
+                .editables
+                .iter()
+                .filter(|entry| is_git(&entry.requirement))
+                .cloned()
+                .collect(),
+            requirements: requirements_txt
+                .requirements
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from)
+                .chain(
+                    requirements_txt
+                        .editables
+                        .into_iter()
+                        .map(UnresolvedRequirementSpecification::from),
+                )
+                .collect(),
+            constraints: requirements_txt
+                .constraints
+                .into_iter()
+                .map(Requirement::from)
+                .map(NameRequirementSpecification::from)
+                .collect(),
+            index_url: requirements_txt.index_url.map(IndexUrl::from),
+            extra_index_urls: requirements_txt
+                .extra_index_urls
+                .into_iter()
+                .map(IndexUrl::from)
+                .collect(),
+            no_index: requirements_txt.no_index,
+            find_links: requirements_txt
+                .find_links
+                .into_iter()
+                .map(IndexUrl::from)
+                .collect(),
+            no_binary: requirements_txt.no_binary,
+            no_build: requirements_txt.only_binary,
+            ..Self::default()
+        }
+    }
+
     /// Read the combined requirements and constraints from a set of sources.
     pub async fn from_sources(
         requirements: &[RequirementsSource],
@@ -351,6 +638,7 @@ impl RequirementsSpecification {
         // a requirements file can also add constraints.
         for source in requirement_sources {
             spec.requirements.extend(source.requirements);
+            spec.git_editables.extend(source.git_editables);
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
             spec.extras.extend(source.extras);
@@ -455,6 +743,19 @@ impl RequirementsSpecification {
         Ok(spec)
     }
 
+    /// Return an error if the specification includes any editable Git requirements, for commands
+    /// that can't check them out.
+    pub fn reject_git_editables(&self) -> Result<(), EditableError> {
+        match self
+            .git_editables
+            .iter()
+            .find_map(|entry| entry.requirement.editable_git_error())
+        {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
     /// Parse an individual package requirement.
     pub fn parse_package(name: &str) -> Result<UnresolvedRequirementSpecification> {
         let requirement = RequirementsTxtRequirement::parse(name, &*CWD, false)
@@ -527,6 +828,202 @@ impl RequirementsSpecification {
     }
 }
 
+/// Check out the editable Git requirements (e.g., `-e git+https://...`) into a persistent `src`
+/// directory, and replace them in `requirements` with editable requirements on the checkouts.
+///
+/// Like pip, each repository is cloned into `src/<name>` within the working directory. The
+/// repository is fetched into the Git cache first, respecting the client's authentication, proxy,
+/// and offline settings, and the checkout is cloned from there. An existing checkout is reused
+/// as-is, such that any local changes are retained, as long as it's at the requested commit.
+pub async fn checkout_git_editables(
+    mut requirements: Vec<UnresolvedRequirementSpecification>,
+    git_editables: Vec<RequirementEntry>,
+    git: &GitResolver,
+    client: &RegistryClient,
+    cache: &Path,
+) -> Result<Vec<UnresolvedRequirementSpecification>> {
+    for entry in git_editables {
+        let original = UnresolvedRequirementSpecification::from(entry.clone());
+        let checkout = checkout_editable_git(entry, git, client, cache).await?;
+        for requirement in &mut requirements {
+            if *requirement == original {
+                *requirement = checkout.clone();
+            }
+        }
+    }
+    Ok(requirements)
+}
+
+/// Returns `true` if the requirement refers to a Git repository.
+fn is_git(requirement: &RequirementsTxtRequirement) -> bool {
+    let url = match requirement {
+        RequirementsTxtRequirement::Named(named) => match &named.version_or_url {
+            Some(VersionOrUrl::Url(url)) => url,
+            _ => return false,
+        },
+        RequirementsTxtRequirement::Unnamed(unnamed) => &unnamed.url,
+    };
+    matches!(url.parsed_url, ParsedUrl::Git(_))
+}
+
+/// Check out an editable Git requirement into `src/<name>`, and convert it into an editable
+/// requirement on the local checkout.
+async fn checkout_editable_git(
+    entry: RequirementEntry,
+    git: &GitResolver,
+    client: &RegistryClient,
+    cache: &Path,
+) -> Result<UnresolvedRequirementSpecification> {
+    let RequirementEntry {
+        requirement,
+        hashes,
+    } = entry;
+    let (name, url) = match &requirement {
+        RequirementsTxtRequirement::Named(named) => match &named.version_or_url {
+            Some(VersionOrUrl::Url(url)) => (Some(named.name.to_string()), Some(url)),
+            _ => (None, None),
+        },
+        RequirementsTxtRequirement::Unnamed(unnamed) => (None, Some(&unnamed.url)),
+    };
+    let Some((git_url, subdirectory, given)) = url.and_then(|url| match &url.parsed_url {
+        ParsedUrl::Git(ParsedGitUrl {
+            url: git_url,
+            subdirectory,
+        }) => Some((
+            git_url.clone(),
+            subdirectory.clone(),
+            url.verbatim.to_string(),
+        )),
+        _ => None,
+    }) else {
+        return Ok(UnresolvedRequirementSpecification::from(RequirementEntry {
+            requirement,
+            hashes,
+        }));
+    };
+
+    // Use the package name, if known; otherwise, use the name of the repository.
+    let Some(name) = name.or_else(|| {
+        git_url
+            .repository()
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+            .map(|segment| segment.trim_end_matches(".git").to_string())
+    }) else {
+        return Err(anyhow::anyhow!(
+            "Unable to determine a name for the editable Git requirement: `{given}`"
+        ));
+    };
+
+    // Fetch the repository into the cache, which resolves the requested revision to a commit.
+    let repository = git_url.repository().clone();
+    let fetch = git
+        .fetch(
+            &git_url,
+            client.uncached_client(&repository).clone(),
+            client.disable_ssl(&repository),
+            client.connectivity() == Connectivity::Offline,
+            cache.to_path_buf(),
+            None,
+        )
+        .await
+        .with_context(|| format!("Failed to fetch editable Git requirement: `{given}`"))?;
+    let Some(precise) = fetch.git().precise() else {
+        return Err(anyhow::anyhow!(
+            "Failed to resolve a commit for the editable Git requirement: `{given}`"
+        ));
+    };
+
+    let checkout = CWD.join("src").join(&name);
+    let source = fetch.path().to_path_buf();
+    let mut remote = repository;
+    remote.remove_credentials();
+    let checkout = tokio::task::spawn_blocking(move || -> Result<PathBuf> {
+        let git_executable = GIT.as_ref().map_err(|err| anyhow::anyhow!("{err}"))?;
+        if checkout.join(".git").exists() {
+            // Only reuse the checkout if it's at the requested commit.
+            let output = std::process::Command::new(git_executable)
+                .arg("rev-parse")
+                .arg("HEAD")
+                .current_dir(&checkout)
+                .output()?;
+            let head = String::from_utf8_lossy(&output.stdout);
+            if !output.status.success() || head.trim() != precise.as_str() {
+                return Err(anyhow::anyhow!(
+                    "The existing checkout at `{}` is not at the requested commit `{precise}`; remove it, or check out `{precise}`, to continue",
+                    checkout.user_display()
+                ));
+            }
+            debug!(
+                "Using existing checkout for editable requirement at: {}",
+                checkout.user_display()
+            );
+            return Ok(checkout);
+        }
+
+        // Clone from the cached checkout, then point the clone at the original remote.
+        debug!(
+            "Cloning `{remote}` at `{precise}` into: {}",
+            checkout.user_display()
+        );
+        fs_err::create_dir_all(CWD.join("src"))?;
+        let run = |command: &mut std::process::Command| -> Result<()> {
+            if command.status()?.success() {
+                Ok(())
+            } else {
+                Err(anyhow::anyhow!(
+                    "Failed to check out `{remote}` at `{precise}` into: {}",
+                    checkout.user_display()
+                ))
+            }
+        };
+        run(std::process::Command::new(git_executable)
+            .args(["clone", "--quiet"])
+            .arg(&source)
+            .arg(&checkout))?;
+        run(std::process::Command::new(git_executable)
+            .args(["checkout", "--quiet", precise.as_str()])
+            .current_dir(&checkout))?;
+        run(std::process::Command::new(git_executable)
+            .args(["remote", "set-url", "origin", remote.as_str()])
+            .current_dir(&checkout))?;
+        Ok(checkout)
+    })
+    .await??;
+
+    let install_path = if let Some(subdirectory) = subdirectory {
+        checkout.join(subdirectory)
+    } else {
+        checkout
+    };
+    let verbatim = VerbatimUrl::from_absolute_path(&install_path)?;
+    let url = VerbatimParsedUrl {
+        parsed_url: ParsedUrl::Directory(ParsedDirectoryUrl::from_source(
+            install_path.into_boxed_path(),
+            Some(true),
+            None,
+            verbatim.to_url(),
+        )),
+        verbatim,
+    };
+
+    let requirement = match requirement {
+        RequirementsTxtRequirement::Named(requirement) => {
+            RequirementsTxtRequirement::Named(uv_pep508::Requirement {
+                version_or_url: Some(VersionOrUrl::Url(url)),
+                ..requirement
+            })
+        }
+        RequirementsTxtRequirement::Unnamed(requirement) => {
+            RequirementsTxtRequirement::Unnamed(UnnamedRequirement { url, ..requirement })
+        }
+    };
+    Ok(UnresolvedRequirementSpecification::from(RequirementEntry {
+        requirement,
+        hashes,
+    }))
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct GroupsSpecification {
     /// The path to the project root, relative to which the default `pyproject.toml` file is
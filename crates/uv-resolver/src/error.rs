@@ -12,7 +12,8 @@ use rustc_hash::FxHashMap;
 use tracing::trace;
 
 use uv_distribution_types::{
-    DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
+    DerivationChain, DistErrorKind, IncompatibleDist, IncompatibleSource, IndexCapabilities,
+    IndexLocations, IndexUrl, RequestedDist, Requirement,
 };
 use uv_normalize::{ExtraName, InvalidNameError, PackageName};
 use uv_pep440::{LocalVersionSlice, LowerBound, Version, VersionSpecifier};
@@ -32,6 +33,7 @@ use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
     MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
+    UnavailableVersion,
 };
 use crate::{InMemoryIndex, Options};
 
@@ -121,12 +123,36 @@ pub enum ResolveError {
     )]
     UnhashedPackage(PackageName),
 
+    #[error(
+        "In `--require-hashes` mode, all requirements must have a hash, but none were provided for: `{0}`"
+    )]
+    UnhashedUrl(PackageName),
+
     #[error("found conflicting distribution in resolution: {0}")]
     ConflictingDistribution(ConflictingDistributionError),
 
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
 
+    #[error(
+        "Resolution exceeded the limit of {max_rounds} rounds{}",
+        if packages.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "; the most constrained packages were: {}",
+                packages.iter()
+                    .map(|package| format!("`{package}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    )]
+    RoundLimit {
+        max_rounds: usize,
+        packages: Vec<PackageName>,
+    },
+
     #[error("Invalid extra value in conflict marker: {reason}: {raw_extra}")]
     InvalidExtraInConflictMarker {
         reason: String,
@@ -164,6 +190,7 @@ pub struct NoSolutionError {
     error: pubgrub::NoSolutionError<UvDependencyProvider>,
     index: InMemoryIndex,
     available_versions: FxHashMap<PackageName, BTreeSet<Version>>,
+    cached_versions: Option<FxHashMap<PackageName, BTreeSet<Version>>>,
     available_indexes: FxHashMap<PackageName, BTreeSet<IndexUrl>>,
     selector: CandidateSelector,
     python_requirement: PythonRequirement,
@@ -177,6 +204,7 @@ pub struct NoSolutionError {
     current_environment: MarkerEnvironment,
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
+    requirements: Vec<Requirement>,
     options: Options,
 }
 
@@ -199,12 +227,14 @@ impl NoSolutionError {
         current_environment: MarkerEnvironment,
         tags: Option<Tags>,
         workspace_members: BTreeSet<PackageName>,
+        requirements: Vec<Requirement>,
         options: Options,
     ) -> Self {
         Self {
             error,
             index,
             available_versions,
+            cached_versions: None,
             available_indexes,
             selector,
             python_requirement,
@@ -218,10 +248,20 @@ impl NoSolutionError {
             current_environment,
             tags,
             workspace_members,
+            requirements,
             options,
         }
     }
 
+    /// Set the versions of each package with artifacts in the cache, such that only those versions
+    /// are suggested when the requested versions are unavailable (e.g., with `--offline`).
+    pub fn set_cached_versions(
+        &mut self,
+        cached_versions: FxHashMap<PackageName, BTreeSet<Version>>,
+    ) {
+        self.cached_versions = Some(cached_versions);
+    }
+
     /// Given a [`DerivationTree`], collapse any [`External::FromDependencyOf`] incompatibilities
     /// wrap an [`PubGrubPackageInner::Extra`] package.
     pub(crate) fn collapse_proxies(derivation_tree: ErrorTree) -> ErrorTree {
@@ -363,6 +403,35 @@ impl NoSolutionError {
         minimum
     }
 
+    /// Given a [`DerivationTree`], identify the packages that were unavailable because building
+    /// from source was disabled (e.g., via `--no-build` or `--only-binary`).
+    pub fn find_no_build_packages(&self) -> BTreeSet<PackageName> {
+        fn find(derivation_tree: &ErrorTree, packages: &mut BTreeSet<PackageName>) {
+            match derivation_tree {
+                DerivationTree::Derived(derived) => {
+                    find(derived.cause1.as_ref(), packages);
+                    find(derived.cause2.as_ref(), packages);
+                }
+                DerivationTree::External(External::Custom(
+                    package,
+                    _,
+                    UnavailableReason::Version(UnavailableVersion::IncompatibleDist(
+                        IncompatibleDist::Source(IncompatibleSource::NoBuild),
+                    )),
+                )) => {
+                    if let Some(name) = package.name_no_root() {
+                        packages.insert(name.clone());
+                    }
+                }
+                DerivationTree::External(_) => {}
+            }
+        }
+
+        let mut packages = BTreeSet::default();
+        find(&self.error, &mut packages);
+        packages
+    }
+
     /// Initialize a [`NoSolutionHeader`] for this error.
     pub fn header(&self) -> NoSolutionHeader {
         NoSolutionHeader::new(self.env.clone())
@@ -428,6 +497,7 @@ impl std::fmt::Debug for NoSolutionError {
             error,
             index: _,
             available_versions,
+            cached_versions,
             available_indexes,
             selector,
             python_requirement,
@@ -441,11 +511,13 @@ impl std::fmt::Debug for NoSolutionError {
             current_environment,
             tags,
             workspace_members,
+            requirements,
             options,
         } = self;
         f.debug_struct("NoSolutionError")
             .field("error", error)
             .field("available_versions", available_versions)
+            .field("cached_versions", cached_versions)
             .field("available_indexes", available_indexes)
             .field("selector", selector)
             .field("python_requirement", python_requirement)
@@ -459,6 +531,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("current_environment", current_environment)
             .field("tags", tags)
             .field("workspace_members", workspace_members)
+            .field("requirements", requirements)
             .field("options", options)
             .finish()
     }
@@ -471,8 +544,10 @@ impl std::fmt::Display for NoSolutionError {
         // Write the derivation report.
         let formatter = PubGrubReportFormatter {
             available_versions: &self.available_versions,
+            cached_versions: self.cached_versions.as_ref(),
             python_requirement: &self.python_requirement,
             workspace_members: &self.workspace_members,
+            requirements: &self.requirements,
             tags: self.tags.as_ref(),
         };
 
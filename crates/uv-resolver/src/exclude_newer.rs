@@ -13,6 +13,11 @@ impl ExcludeNewer {
     pub fn timestamp_millis(&self) -> i64 {
         self.0.as_millisecond()
     }
+
+    /// Returns the timestamp.
+    pub fn timestamp(&self) -> Timestamp {
+        self.0
+    }
 }
 
 impl From<Timestamp> for ExcludeNewer {
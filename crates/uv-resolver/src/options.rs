@@ -18,6 +18,8 @@ pub struct Options {
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub suggest_cached: bool,
+    pub max_rounds: Option<usize>,
 }
 
 /// Builder for [`Options`].
@@ -33,6 +35,8 @@ pub struct OptionsBuilder {
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    suggest_cached: bool,
+    max_rounds: Option<usize>,
 }
 
 impl OptionsBuilder {
@@ -111,6 +115,21 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to list the available versions of a package when the requested versions are
+    /// unavailable (e.g., with `--offline` or `--no-index`).
+    #[must_use]
+    pub fn suggest_cached(mut self, suggest_cached: bool) -> Self {
+        self.suggest_cached = suggest_cached;
+        self
+    }
+
+    /// Sets the maximum number of decisions the resolver may make before giving up.
+    #[must_use]
+    pub fn max_rounds(mut self, max_rounds: Option<usize>) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -124,6 +143,8 @@ impl OptionsBuilder {
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            suggest_cached: self.suggest_cached,
+            max_rounds: self.max_rounds,
         }
     }
 }
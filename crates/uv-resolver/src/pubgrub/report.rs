@@ -11,10 +11,11 @@ use rustc_hash::FxHashMap;
 use uv_configuration::{IndexStrategy, NoBinary, NoBuild};
 use uv_distribution_types::{
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index, IndexCapabilities,
-    IndexLocations, IndexMetadata, IndexUrl, RequiresPython,
+    IndexLocations, IndexMetadata, IndexUrl, Requirement, RequirementSource, RequiresPython,
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::RequirementOrigin;
 use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, Tags};
 
 use crate::candidate_selector::CandidateSelector;
@@ -34,12 +35,18 @@ pub(crate) struct PubGrubReportFormatter<'a> {
     /// The versions that were available for each package.
     pub(crate) available_versions: &'a FxHashMap<PackageName, BTreeSet<Version>>,
 
+    /// The versions with artifacts in the cache for each package, if known.
+    pub(crate) cached_versions: Option<&'a FxHashMap<PackageName, BTreeSet<Version>>>,
+
     /// The versions that were available for each package.
     pub(crate) python_requirement: &'a PythonRequirement,
 
     /// The members of the workspace.
     pub(crate) workspace_members: &'a BTreeSet<PackageName>,
 
+    /// The requirements of the root package.
+    pub(crate) requirements: &'a [Requirement],
+
     /// The compatible tags for the resolution.
     pub(crate) tags: Option<&'a Tags>,
 }
@@ -150,6 +157,12 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                     }
                 }
 
+                if let Some(origin) = self.format_root_origin(package, dependency, dependency_set) {
+                    return format!(
+                        "{origin} depends on {}",
+                        self.dependency_range(dependency, dependency_set)
+                    );
+                }
                 if let Some(root) = self.format_root_requires(package) {
                     return format!(
                         "{root} {}",
@@ -365,6 +378,44 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Return the extra or dependency group that introduced a requirement of the root package
+    /// (e.g., `project[extra]` or `project:group`), if the given package is the root package and
+    /// the requirement can be attributed to a single extra or group.
+    fn format_root_origin(
+        &self,
+        package: &PubGrubPackage,
+        dependency: &PubGrubPackage,
+        dependency_set: &Range<Version>,
+    ) -> Option<String> {
+        if !Self::is_root(package) || self.is_workspace() {
+            return None;
+        }
+        let name = dependency.name_no_root()?;
+        let mut origins = self
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.name == *name)
+            .filter(|requirement| match &requirement.source {
+                RequirementSource::Registry { specifier, .. } => {
+                    Range::from(specifier.clone()) == *dependency_set
+                }
+                _ => *dependency_set == Range::full(),
+            })
+            .map(|requirement| requirement.origin.as_ref());
+        let origin = origins.next()??;
+        if !origins.all(|other| other == Some(origin)) {
+            return None;
+        }
+        match origin {
+            RequirementOrigin::Extra(_, project, extra) => Some(format!("{project}[{extra}]")),
+            RequirementOrigin::Group(_, Some(project), group) => Some(format!("{project}:{group}")),
+            RequirementOrigin::File(_)
+            | RequirementOrigin::Project(..)
+            | RequirementOrigin::Group(_, None, _)
+            | RequirementOrigin::Workspace => None,
+        }
+    }
+
     /// Return the formatting for "the root package", if the given
     /// package is the root package.
     ///
@@ -473,7 +524,16 @@ impl PubGrubReportFormatter<'_> {
             (
                 External::FromDependencyOf(package1, package_set1, dependency1, dependency_set1),
                 External::FromDependencyOf(package2, _, dependency2, dependency_set2),
-            ) if package1 == package2 => {
+            ) if package1 == package2
+                // If either requirement was introduced by an extra or group of the root
+                // package, describe each requirement separately.
+                && self
+                    .format_root_origin(package1, dependency1, dependency_set1)
+                    .is_none()
+                && self
+                    .format_root_origin(package2, dependency2, dependency_set2)
+                    .is_none() =>
+            {
                 let dependency1 = self.dependency_range(dependency1, dependency_set1);
                 let dependency2 = self.dependency_range(dependency2, dependency_set2);
 
@@ -609,6 +669,11 @@ impl PubGrubReportFormatter<'_> {
                         }
                     }
 
+                    // List the versions that are available instead, if requested.
+                    if options.suggest_cached {
+                        self.available_versions_hint(name, set, output_hints);
+                    }
+
                     // Check for no versions due to no `--find-links` flat index.
                     Self::index_hints(
                         name,
@@ -892,7 +957,10 @@ impl PubGrubReportFormatter<'_> {
 
         // Add hints due to the package being available on an index, but not at the correct version,
         // with subsequent indexes that were _not_ queried.
-        if matches!(selector.index_strategy(), IndexStrategy::FirstIndex) {
+        if matches!(
+            selector.index_strategy(),
+            IndexStrategy::FirstIndex | IndexStrategy::Failover
+        ) {
             // Do not include the hint if the set is "all versions". This is an unusual but valid
             // case in which a package returns a 200 response, but without any versions or
             // distributions for the package.
@@ -914,6 +982,7 @@ impl PubGrubReportFormatter<'_> {
                             range: set.clone(),
                             found_index: found_index.clone(),
                             next_index: next_index.clone(),
+                            failover: matches!(selector.index_strategy(), IndexStrategy::Failover),
                         });
                     }
                 }
@@ -935,6 +1004,38 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Generate a hint listing the available versions of a package, if none of them are in the
+    /// requested range.
+    fn available_versions_hint(
+        &self,
+        name: &PackageName,
+        set: &Range<Version>,
+        hints: &mut IndexSet<PubGrubHint>,
+    ) {
+        let Some(versions) = self.available_versions.get(name) else {
+            return;
+        };
+        // If known, only list the versions with artifacts in the cache.
+        let versions = versions
+            .iter()
+            .filter(|version| {
+                self.cached_versions.is_none_or(|cached_versions| {
+                    cached_versions
+                        .get(name)
+                        .is_some_and(|cached| cached.contains(*version))
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        if versions.is_empty() || versions.iter().any(|version| set.contains(version)) {
+            return;
+        }
+        hints.insert(PubGrubHint::AvailableVersions {
+            package: name.clone(),
+            versions,
+        });
+    }
+
     fn prerelease_available_hint(
         &self,
         name: &PackageName,
@@ -1018,6 +1119,13 @@ pub(crate) enum PubGrubHint {
     NoIndex,
     /// A package was not found in the registry, but network access was disabled.
     Offline,
+    /// None of the requested versions of a package were available, but other versions were (e.g.,
+    /// in the cache, with `--offline`).
+    AvailableVersions {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        versions: Vec<Version>,
+    },
     /// Metadata for a package could not be parsed.
     InvalidPackageMetadata {
         package: PackageName,
@@ -1100,6 +1208,8 @@ pub(crate) enum PubGrubHint {
         found_index: IndexUrl,
         // excluded from `PartialEq` and `Hash`
         next_index: IndexUrl,
+        // excluded from `PartialEq` and `Hash`
+        failover: bool,
     },
     /// No wheels are available for a package, and using source distributions was disabled.
     NoBuild {
@@ -1163,6 +1273,9 @@ enum PubGrubHintCore {
     },
     NoIndex,
     Offline,
+    AvailableVersions {
+        package: PackageName,
+    },
     InvalidPackageMetadata {
         package: PackageName,
     },
@@ -1232,6 +1345,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             }
             PubGrubHint::NoIndex => Self::NoIndex,
             PubGrubHint::Offline => Self::Offline,
+            PubGrubHint::AvailableVersions { package, .. } => Self::AvailableVersions { package },
             PubGrubHint::InvalidPackageMetadata { package, .. } => {
                 Self::InvalidPackageMetadata { package }
             }
@@ -1341,6 +1455,16 @@ impl std::fmt::Display for PubGrubHint {
                     ":".bold(),
                 )
             }
+            Self::AvailableVersions { package, versions } => {
+                write!(
+                    f,
+                    "{}{} The requested versions of `{}` are unavailable, but the following versions are available: {}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    versions.iter().map(|version| version.cyan()).join(", "),
+                )
+            }
             Self::InvalidPackageMetadata { package, reason } => {
                 write!(
                     f,
@@ -1522,6 +1646,27 @@ impl std::fmt::Display for PubGrubHint {
                 range,
                 found_index,
                 next_index,
+                failover: true,
+            } => {
+                write!(
+                    f,
+                    "{}{} `{}` was found on {}, but not at the requested version ({}). A compatible version may be available on a subsequent index (e.g., {}). With `{}`, subsequent indexes are only queried if an index can't be reached. If the indexes aren't mirrors of one another, use `{}` to consider all versions from all indexes, regardless of the order in which they were defined.",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    name.cyan(),
+                    found_index.without_credentials().cyan(),
+                    PackageRange::compatibility(&PubGrubPackage::base(name), range, None).cyan(),
+                    next_index.cyan(),
+                    "--index-strategy failover".green(),
+                    "--index-strategy unsafe-best-match".green(),
+                )
+            }
+            Self::UncheckedIndex {
+                name,
+                range,
+                found_index,
+                next_index,
+                failover: false,
             } => {
                 write!(
                     f,
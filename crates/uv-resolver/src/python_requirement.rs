@@ -184,3 +184,13 @@ pub enum PythonRequirementSource {
     /// The discovered Python interpreter.
     Interpreter,
 }
+
+impl std::fmt::Display for PythonRequirementSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PythonVersion => f.write_str("`--python-version`"),
+            Self::RequiresPython => f.write_str("`requires-python`"),
+            Self::Interpreter => f.write_str("the Python interpreter"),
+        }
+    }
+}
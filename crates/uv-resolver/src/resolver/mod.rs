@@ -318,8 +318,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.python_requirement.exact()
         );
         debug!(
-            "Solving with target Python version: {}",
-            self.python_requirement.target()
+            "Solving with target Python version: {} (from {})",
+            self.python_requirement.target(),
+            self.python_requirement.source()
         );
 
         let mut visited = FxHashSet::default();
@@ -340,6 +341,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let mut rounds = 0;
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -348,6 +350,17 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                // Bail out if the resolver has exceeded its budget, across all forks.
+                rounds += 1;
+                if let Some(max_rounds) = self.options.max_rounds {
+                    if rounds > max_rounds {
+                        return Err(ResolveError::RoundLimit {
+                            max_rounds,
+                            packages: state.most_constrained_packages(),
+                        });
+                    }
+                }
+
                 let highest_priority_pkg =
                     if let Some(initial) = state.initial_id.take() {
                         // If we just forked based on `requires-python`, we can skip unit
@@ -973,8 +986,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
         if let Some(url) = url {
             // Verify that the package is allowed under the hash-checking policy.
-            if !self.hasher.allows_url(&url.verbatim) {
-                return Err(ResolveError::UnhashedPackage(name.clone()));
+            if !self.hasher.allows_url(name, &url.verbatim) {
+                return Err(ResolveError::UnhashedUrl(name.clone()));
             }
 
             // Emit a request to fetch the metadata for this distribution.
@@ -2106,17 +2119,21 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 if !requirement
                     .evaluate_markers(env.marker_environment(), slice::from_ref(source_extra))
                 {
+                    Self::explain_markers(requirement, env, false);
                     return false;
                 }
                 if !env.included_by_group(ConflictItemRef::from((&requirement.name, source_extra)))
                 {
                     return false;
                 }
+                Self::explain_markers(requirement, env, true);
             }
             None => {
                 if !requirement.evaluate_markers(env.marker_environment(), &[]) {
+                    Self::explain_markers(requirement, env, false);
                     return false;
                 }
+                Self::explain_markers(requirement, env, true);
             }
         }
 
@@ -2140,6 +2157,30 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         true
     }
 
+    /// Log the result of evaluating a requirement's markers against the target environment.
+    ///
+    /// Only applies when resolving for a specific environment (e.g., `uv pip sync`), as universal
+    /// resolutions don't evaluate markers against a concrete environment.
+    fn explain_markers(requirement: &Requirement, env: &ResolverEnvironment, included: bool) {
+        if env.marker_environment().is_none() {
+            return;
+        }
+        let Some(marker) = requirement.marker.contents() else {
+            return;
+        };
+        if included {
+            debug!(
+                "Including {} because `{marker}` evaluates to true for the target environment",
+                requirement.name
+            );
+        } else {
+            debug!(
+                "Excluding {} because `{marker}` evaluates to false for the target environment",
+                requirement.name
+            );
+        }
+    }
+
     /// The constraints applicable to the requirement, filtered by Python version, the markers of
     /// this fork and the requested extra.
     fn constraints_for_requirement<'data, 'parameters>(
@@ -2324,11 +2365,16 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
+                let start = Instant::now();
                 let package_versions = provider
                     .get_package_versions(&package_name, index.as_ref())
                     .boxed_local()
                     .await
                     .map_err(ResolveError::Client)?;
+                debug!(
+                    "Fetched index metadata for `{package_name}` in {:.3}s",
+                    start.elapsed().as_secs_f32()
+                );
 
                 Ok(Some(Response::Package(
                     package_name,
@@ -2626,6 +2672,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             current_environment,
             self.tags.clone(),
             self.workspace_members.clone(),
+            self.requirements.clone(),
             self.options.clone(),
         )))
     }
@@ -2920,6 +2967,29 @@ impl ForkState {
         }
     }
 
+    /// Return the packages involved in the most conflicts so far, in descending order.
+    fn most_constrained_packages(&self) -> Vec<PackageName> {
+        let mut conflicts = FxHashMap::<&PackageName, usize>::default();
+        for (id, count) in self
+            .conflict_tracker
+            .affected
+            .iter()
+            .chain(self.conflict_tracker.culprit.iter())
+        {
+            if let Some(name) = self.pubgrub.package_store[*id].name_no_root() {
+                *conflicts.entry(name).or_default() += count;
+            }
+        }
+        conflicts
+            .into_iter()
+            .sorted_unstable_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+            })
+            .take(5)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
     fn add_unavailable_version(&mut self, version: Version, reason: UnavailableVersion) {
         // Incompatible requires-python versions are special in that we track
         // them as incompatible dependencies instead of marking the package version
@@ -1,6 +1,8 @@
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
+use uv_configuration::PackageNameSpecifier;
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_fs::Simplified;
 use uv_static::EnvVars;
@@ -548,10 +550,11 @@ pub enum Error {
 ///
 /// This is currently a subset of all respected environment variables, most are parsed via Clap at
 /// the CLI level, however there are limited semantics in that context.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct EnvironmentOptions {
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
 }
 
 impl EnvironmentOptions {
@@ -562,10 +565,47 @@ impl EnvironmentOptions {
             python_install_registry: parse_boolish_environment_variable(
                 EnvVars::UV_PYTHON_INSTALL_REGISTRY,
             )?,
+            only_binary: parse_package_name_specifiers_environment_variable(
+                EnvVars::UV_ONLY_BINARY,
+            )?,
         })
     }
 }
 
+/// Parse a space-delimited list of package names (or `:all:` and `:none:`) from an environment
+/// variable.
+fn parse_package_name_specifiers_environment_variable(
+    name: &'static str,
+) -> Result<Option<Vec<PackageNameSpecifier>>, Error> {
+    let Some(value) = std::env::var_os(name) else {
+        return Ok(None);
+    };
+
+    let Some(value) = value.to_str() else {
+        return Err(Error::InvalidEnvironmentVariable {
+            name: name.to_string(),
+            value: value.to_string_lossy().to_string(),
+            err: "expected a valid UTF-8 string".to_string(),
+        });
+    };
+
+    let specifiers = value
+        .split_whitespace()
+        .map(PackageNameSpecifier::from_str)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| Error::InvalidEnvironmentVariable {
+            name: name.to_string(),
+            value: value.to_string(),
+            err: err.to_string(),
+        })?;
+
+    if specifiers.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(specifiers))
+}
+
 /// Parse a boolean environment variable.
 ///
 /// Adapted from Clap's `BoolishValueParser` which is dual licensed under the MIT and Apache-2.0.
@@ -472,6 +472,9 @@ pub struct ResolverInstallerOptions {
     /// If a path, the target must be a directory that contains packages as wheel files (`.whl`) or
     /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level.
     ///
+    /// Alternatively, the target may be an archive of such a directory (e.g., `.tar` or `.zip`),
+    /// which will be unpacked into the cache.
+    ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
     #[option(
@@ -961,6 +964,19 @@ pub struct PipOptions {
         "#
     )]
     pub prefix: Option<PathBuf>,
+    /// Stage the installation beneath the specified directory, as in a `DESTDIR`-style install.
+    ///
+    /// Files are written beneath the root directory, but any recorded paths (e.g., in `RECORD`
+    /// files and script shebangs) refer to the final installation location, as if the root were
+    /// absent. May be combined with `prefix`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            root = "./stage"
+        "#
+    )]
+    pub root: Option<PathBuf>,
     #[serde(skip)]
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub index: Option<Vec<Index>>,
@@ -1013,6 +1029,9 @@ pub struct PipOptions {
     /// If a path, the target must be a directory that contains packages as wheel files (`.whl`) or
     /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level.
     ///
+    /// Alternatively, the target may be an archive of such a directory (e.g., `.tar` or `.zip`),
+    /// which will be unpacked into the cache.
+    ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
     #[option(
@@ -1130,6 +1149,19 @@ pub struct PipOptions {
         "#
     )]
     pub strict: Option<bool>,
+    /// Warn about installed packages with missing or incompatible dependencies when validating
+    /// the environment with `strict`.
+    ///
+    /// Other environment diagnostics (e.g., packages with broken metadata) are reported
+    /// regardless.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            warn-conflicts = false
+        "#
+    )]
+    pub warn_conflicts: Option<bool>,
     /// Include optional dependencies from the specified extra; may be provided more than once.
     ///
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
@@ -1162,6 +1194,11 @@ pub struct PipOptions {
         "#
     )]
     pub no_extra: Option<Vec<ExtraName>>,
+    /// Only include optional dependencies from the specified extras. Only set by
+    /// `uv pip sync --only-extras`, and not configurable in a settings file.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub only_extra: Option<Vec<ExtraName>>,
     /// Ignore package dependencies, instead only add those packages explicitly listed
     /// on the command line to the resulting requirements file.
     #[option(
@@ -49,6 +49,10 @@ impl EnvVars {
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// Equivalent to the `--index-cache-ttl` command-line argument. If set, uv will revalidate
+    /// cached index responses older than this many seconds.
+    pub const UV_INDEX_CACHE_TTL: &'static str = "UV_INDEX_CACHE_TTL";
+
     /// Equivalent to the `--resolution` command-line argument. For example, if set to
     /// `lowest-direct`, uv will install the lowest compatible versions of all direct dependencies.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
@@ -143,6 +147,12 @@ impl EnvVars {
     /// Whether uv should prefer system or managed Python versions.
     pub const UV_PYTHON_PREFERENCE: &'static str = "UV_PYTHON_PREFERENCE";
 
+    /// Override the directories that uv searches for Python executables, in place of `PATH`.
+    ///
+    /// Uses the same format as `PATH`. When set, the Windows registry is not searched either, such
+    /// that only the given directories (and managed Python installations) are considered.
+    pub const UV_PYTHON_SEARCH_PATH: &'static str = "UV_PYTHON_SEARCH_PATH";
+
     /// Require use of uv-managed Python versions.
     pub const UV_MANAGED_PYTHON: &'static str = "UV_MANAGED_PYTHON";
 
@@ -183,6 +193,12 @@ impl EnvVars {
     /// source distributions.
     pub const UV_NO_BUILD: &'static str = "UV_NO_BUILD";
 
+    /// Equivalent to the `--only-binary` command-line argument for `uv pip` commands. If set, uv
+    /// will not build source distributions for the given space-delimited list of packages, or for
+    /// any package with `:all:`. Takes precedence over the `only-binary` setting, but is
+    /// ignored when `--only-binary` is provided.
+    pub const UV_ONLY_BINARY: &'static str = "UV_ONLY_BINARY";
+
     /// Equivalent to the `--no-build-package` command line argument. If set, uv will
     /// not build source distributions for the given space-delimited list of packages.
     pub const UV_NO_BUILD_PACKAGE: &'static str = "UV_NO_BUILD_PACKAGE";
@@ -309,6 +325,10 @@ impl EnvVars {
     /// existing files or directories at the target path.
     pub const UV_VENV_CLEAR: &'static str = "UV_VENV_CLEAR";
 
+    /// Equivalent to the `--venv-dir` command-line argument. Specifies the directory where uv
+    /// stores named virtual environments, as selected with `uv pip sync --venv`.
+    pub const UV_VENV_DIR: &'static str = "UV_VENV_DIR";
+
     /// Install seed packages (one or more of: `pip`, `setuptools`, and `wheel`) into the virtual environment
     /// created by `uv venv`.
     ///
@@ -348,6 +368,16 @@ impl EnvVars {
         format!("UV_INDEX_{name}_PASSWORD")
     }
 
+    /// Opts a named index in to authentication with tokens from `UV_INDEX_OIDC_TOKEN_URL` when set
+    /// to `1` or `true`.
+    ///
+    /// The `name` parameter is the name of the index. For example, given an index named `foo`,
+    /// the environment variable key would be `UV_INDEX_FOO_OIDC`.
+    #[attr_env_var_pattern("UV_INDEX_{name}_OIDC")]
+    pub fn index_oidc(name: &str) -> String {
+        format!("UV_INDEX_{name}_OIDC")
+    }
+
     /// Used to set the uv commit hash at build time via `build.rs`.
     #[attr_hidden]
     pub const UV_COMMIT_HASH: &'static str = "UV_COMMIT_HASH";
@@ -424,6 +454,10 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Comma-separated list of hosts (e.g., `example.com`), domain suffixes (e.g., `.example.com`),
+    /// and IP ranges (e.g., `192.168.1.0/24`) for which proxies should be bypassed.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
@@ -766,6 +800,16 @@ impl EnvVars {
     /// Disable GitHub-specific requests that allow uv to skip `git fetch` in some circumstances.
     pub const UV_NO_GITHUB_FAST_PATH: &'static str = "UV_NO_GITHUB_FAST_PATH";
 
+    /// The URL of an OpenID Connect (OIDC) token endpoint. When set, uv will request a short-lived
+    /// token from this endpoint and send it as a bearer token to named indexes that opt in via
+    /// `UV_INDEX_{name}_OIDC`, either when they respond with a 401, or eagerly if they set
+    /// `authenticate = "always"`. If the index rejects the token, uv will request a new one.
+    pub const UV_INDEX_OIDC_TOKEN_URL: &'static str = "UV_INDEX_OIDC_TOKEN_URL";
+
+    /// A bearer token used to authenticate requests to the `UV_INDEX_OIDC_TOKEN_URL` endpoint
+    /// (e.g., `ACTIONS_ID_TOKEN_REQUEST_TOKEN` on GitHub Actions).
+    pub const UV_INDEX_OIDC_REQUEST_TOKEN: &'static str = "UV_INDEX_OIDC_REQUEST_TOKEN";
+
     /// Authentication token for Hugging Face requests. When set, uv will use this token
     /// when making requests to `https://huggingface.co/` and any subdomains.
     pub const HF_TOKEN: &'static str = "HF_TOKEN";
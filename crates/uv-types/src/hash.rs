@@ -1,7 +1,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_configuration::HashCheckingMode;
 use uv_distribution_types::{
@@ -26,8 +26,12 @@ pub enum HashStrategy {
     Verify(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
     /// Hashes should be validated against a pre-defined list of hashes.
     ///
-    /// If necessary, hashes should be generated to ensure that the archive is valid.
-    Require(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
+    /// If necessary, hashes should be generated to ensure that the archive is valid. Packages in
+    /// the exemption set are not required to provide a hash.
+    Require(
+        Arc<FxHashMap<VersionId, Vec<HashDigest>>>,
+        Arc<FxHashSet<PackageName>>,
+    ),
 }
 
 impl HashStrategy {
@@ -43,7 +47,8 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
+            Self::Require(_, exempt) if exempt.contains(distribution.name()) => HashPolicy::None,
+            Self::Require(hashes, _) => HashPolicy::Validate(
                 hashes
                     .get(&distribution.version_id())
                     .map(Vec::as_slice)
@@ -66,7 +71,8 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
+            Self::Require(_, exempt) if exempt.contains(name) => HashPolicy::None,
+            Self::Require(hashes, _) => HashPolicy::Validate(
                 hashes
                     .get(&VersionId::from_registry(name.clone(), version.clone()))
                     .map(Vec::as_slice)
@@ -87,7 +93,7 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
+            Self::Require(hashes, _) => HashPolicy::Validate(
                 hashes
                     .get(&VersionId::from_url(url))
                     .map(Vec::as_slice)
@@ -102,19 +108,34 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate(_) => true,
             Self::Verify(_) => true,
-            Self::Require(hashes) => {
-                hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
+            Self::Require(hashes, exempt) => {
+                exempt.contains(name)
+                    || hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
             }
         }
     }
 
     /// Returns `true` if the given direct URL package is allowed.
-    pub fn allows_url(&self, url: &DisplaySafeUrl) -> bool {
+    pub fn allows_url(&self, name: &PackageName, url: &DisplaySafeUrl) -> bool {
         match self {
             Self::None => true,
             Self::Generate(_) => true,
             Self::Verify(_) => true,
-            Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
+            Self::Require(hashes, exempt) => {
+                exempt.contains(name) || hashes.contains_key(&VersionId::from_url(url))
+            }
+        }
+    }
+
+    /// Exempt the given packages from the hash requirement, such that they can be installed
+    /// without a hash under `--require-hashes`.
+    #[must_use]
+    pub fn with_exemptions(self, exempt: impl IntoIterator<Item = PackageName>) -> Self {
+        match self {
+            Self::Require(hashes, _) => {
+                Self::Require(hashes, Arc::new(exempt.into_iter().collect()))
+            }
+            strategy => strategy,
         }
     }
 
@@ -265,7 +286,7 @@ impl HashStrategy {
             .collect();
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes), Arc::default())),
         }
     }
 
@@ -292,7 +313,7 @@ impl HashStrategy {
 
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes), Arc::default())),
         }
     }
 
@@ -44,7 +44,7 @@ use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterMode};
 use crate::settings::{NetworkSettings, ResolverSettings};
 
 #[derive(Debug, Error)]
@@ -211,7 +211,9 @@ async fn build_impl(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Determine the source to build.
     let src = if let Some(src) = src {
@@ -612,15 +614,15 @@ async fn build_package(
     let subdirectory = None;
     let version_id = source.path().file_name().and_then(|name| name.to_str());
 
-    let build_output = match printer {
-        Printer::Default | Printer::NoProgress | Printer::Verbose => {
+    let build_output = match printer.mode() {
+        PrinterMode::Default | PrinterMode::NoProgress | PrinterMode::Verbose => {
             if build_logs {
                 BuildOutput::Stderr
             } else {
                 BuildOutput::Quiet
             }
         }
-        Printer::Quiet | Printer::Silent => BuildOutput::Quiet,
+        PrinterMode::Quiet | PrinterMode::Silent => BuildOutput::Quiet,
     };
 
     let mut build_results = Vec::new();
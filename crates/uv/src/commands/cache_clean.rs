@@ -2,8 +2,10 @@ use std::fmt::Write;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::{Cache, Removal};
+use uv_cli::CacheCleanFormat;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
@@ -11,9 +13,31 @@ use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageRepor
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
+/// A summary of the cache entries removed for a package, as reported by `--output-format json`.
+#[derive(Debug, Serialize)]
+struct CleanReport<'a> {
+    /// The package whose entries were removed, or `None` if the entire cache was cleared.
+    package: Option<&'a PackageName>,
+    /// The number of files removed.
+    removed_files: u64,
+    /// The total number of bytes freed.
+    freed_bytes: u64,
+}
+
+impl<'a> CleanReport<'a> {
+    fn new(package: Option<&'a PackageName>, removal: &Removal) -> Self {
+        Self {
+            package,
+            removed_files: removal.num_files,
+            freed_bytes: removal.total_bytes,
+        }
+    }
+}
+
 /// Clear the cache, removing all entries or those linked to specific packages.
 pub(crate) fn cache_clean(
     packages: &[PackageName],
+    output_format: CacheCleanFormat,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -23,9 +47,14 @@ pub(crate) fn cache_clean(
             "No cache found at: {}",
             cache.root().user_display().cyan()
         )?;
+        if matches!(output_format, CacheCleanFormat::Json) {
+            writeln!(printer.stdout(), "[]")?;
+        }
         return Ok(ExitStatus::Success);
     }
 
+    let mut reports = Vec::new();
+
     let summary = if packages.is_empty() {
         writeln!(
             printer.stderr(),
@@ -36,15 +65,19 @@ pub(crate) fn cache_clean(
         let num_paths = walkdir::WalkDir::new(cache.root()).into_iter().count();
         let reporter = CleaningDirectoryReporter::new(printer, num_paths);
 
-        cache
-            .clear(Box::new(reporter))
-            .with_context(|| format!("Failed to clear cache at: {}", cache.root().user_display()))?
+        let summary = cache.clear(Box::new(reporter)).with_context(|| {
+            format!("Failed to clear cache at: {}", cache.root().user_display())
+        })?;
+        reports.push(CleanReport::new(None, &summary));
+
+        summary
     } else {
         let reporter = CleaningPackageReporter::new(printer, packages.len());
         let mut summary = Removal::default();
 
         for package in packages {
             let removed = cache.remove(package)?;
+            reports.push(CleanReport::new(Some(package), &removed));
             summary += removed;
             reporter.on_clean(package.as_str(), &summary);
         }
@@ -53,6 +86,11 @@ pub(crate) fn cache_clean(
         summary
     };
 
+    if matches!(output_format, CacheCleanFormat::Json) {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&reports)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -0,0 +1,203 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cli::CacheInfoFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// The number of entries to include in the list of largest cache entries.
+const LARGEST_ENTRIES: usize = 10;
+
+/// A summary of the cache contents, as reported by `--output-format json`.
+#[derive(Debug, Serialize)]
+struct CacheInfo {
+    /// The root of the cache.
+    root: String,
+    /// The total size of the cache, in bytes.
+    total_bytes: u64,
+    /// The number of entries and their combined size in each cache bucket.
+    buckets: Vec<BucketInfo>,
+    /// The largest entries in the cache, in descending order of size.
+    largest: Vec<EntryInfo>,
+}
+
+#[derive(Debug, Serialize)]
+struct BucketInfo {
+    /// The name of the bucket's directory within the cache (e.g., `wheels-v5`).
+    bucket: String,
+    /// A human-readable description of the bucket's contents.
+    description: &'static str,
+    /// The number of entries in the bucket.
+    entries: usize,
+    /// The combined size of the entries in the bucket, in bytes.
+    bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct EntryInfo {
+    /// The name of the bucket's directory within the cache (e.g., `wheels-v5`).
+    bucket: String,
+    /// The path to the entry, relative to the cache root.
+    path: String,
+    /// The size of the entry, in bytes.
+    bytes: u64,
+}
+
+/// Show a summary of the cache contents.
+pub(crate) fn cache_info(
+    output_format: CacheInfoFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        match output_format {
+            CacheInfoFormat::Text => {
+                writeln!(
+                    printer.stderr(),
+                    "No cache found at: {}",
+                    cache.root().user_display().cyan()
+                )?;
+            }
+            CacheInfoFormat::Json => {
+                let info = CacheInfo {
+                    root: cache.root().simplified_display().to_string(),
+                    total_bytes: 0,
+                    buckets: Vec::new(),
+                    largest: Vec::new(),
+                };
+                writeln!(printer.stdout(), "{}", serde_json::to_string(&info)?)?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut entries = cache.entries().with_context(|| {
+        format!(
+            "Failed to read cache entries at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    let buckets = CacheBucket::iter()
+        .map(|bucket| {
+            let (count, bytes) = entries
+                .iter()
+                .filter(|entry| entry.bucket == bucket)
+                .fold((0, 0), |(count, bytes), entry| {
+                    (count + 1, bytes + entry.size)
+                });
+            BucketInfo {
+                bucket: bucket.to_string(),
+                description: description(bucket),
+                entries: count,
+                bytes,
+            }
+        })
+        .collect::<Vec<_>>();
+    let total_bytes = buckets.iter().map(|bucket| bucket.bytes).sum();
+
+    entries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+    let largest = entries
+        .iter()
+        .take(LARGEST_ENTRIES)
+        .map(|entry| EntryInfo {
+            bucket: entry.bucket.to_string(),
+            path: entry
+                .path
+                .strip_prefix(cache.root())
+                .unwrap_or(&entry.path)
+                .portable_display()
+                .to_string(),
+            bytes: entry.size,
+        })
+        .collect::<Vec<_>>();
+
+    let info = CacheInfo {
+        root: cache.root().simplified_display().to_string(),
+        total_bytes,
+        buckets,
+        largest,
+    };
+
+    if matches!(output_format, CacheInfoFormat::Json) {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&info)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stdout(),
+        "Total size: {}",
+        format_bytes(info.total_bytes).green()
+    )?;
+
+    if info.buckets.iter().any(|bucket| bucket.entries > 0) {
+        writeln!(printer.stdout())?;
+        writeln!(printer.stdout(), "{}", "Buckets:".bold())?;
+        for bucket in info.buckets.iter().filter(|bucket| bucket.entries > 0) {
+            writeln!(
+                printer.stdout(),
+                "  {} ({}): {} {} ({})",
+                bucket.description,
+                bucket.bucket.dimmed(),
+                bucket.entries,
+                if bucket.entries == 1 {
+                    "entry"
+                } else {
+                    "entries"
+                },
+                format_bytes(bucket.bytes).green()
+            )?;
+        }
+    }
+
+    if !info.largest.is_empty() {
+        writeln!(printer.stdout())?;
+        writeln!(printer.stdout(), "{}", "Largest entries:".bold())?;
+        for entry in &info.largest {
+            writeln!(
+                printer.stdout(),
+                "  {} ({})",
+                entry.path.cyan(),
+                format_bytes(entry.bytes).green()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return a human-readable description of the contents of a [`CacheBucket`].
+fn description(bucket: CacheBucket) -> &'static str {
+    match bucket {
+        CacheBucket::Wheels => "Wheels",
+        CacheBucket::SourceDistributions => "Source distributions and built wheels",
+        CacheBucket::FlatIndex => "Flat indexes",
+        CacheBucket::Git => "Git repositories",
+        CacheBucket::Interpreter => "Interpreter metadata",
+        CacheBucket::Simple => "Index metadata",
+        CacheBucket::Archive => "Unzipped wheels",
+        CacheBucket::Builds => "Build environments",
+        CacheBucket::Environments => "Ephemeral environments",
+    }
+}
+
+/// Format a byte count for display.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
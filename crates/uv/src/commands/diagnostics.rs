@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
@@ -13,6 +14,7 @@ use uv_pep440::Version;
 use uv_resolver::SentinelRange;
 
 use crate::commands::pip;
+use crate::printer::{Printer, Stderr};
 
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
     let suggestions: Vec<(String, String)> =
@@ -38,6 +40,8 @@ pub(crate) struct OperationDiagnostic {
     pub(crate) native_tls: bool,
     /// The context to display to the user upon resolution failure.
     pub(crate) context: Option<&'static str>,
+    /// The stream to which diagnostics are written.
+    pub(crate) stderr: Stderr,
 }
 
 impl OperationDiagnostic {
@@ -68,6 +72,16 @@ impl OperationDiagnostic {
         }
     }
 
+    /// Write diagnostics through the given printer, such that they're included in any captured
+    /// output (e.g., for `--summary-file`).
+    #[must_use]
+    pub(crate) fn with_printer(self, printer: Printer) -> Self {
+        Self {
+            stderr: printer.diagnostics(),
+            ..self
+        }
+    }
+
     /// Attempt to report an error with rich diagnostic context.
     ///
     /// Returns `Some` if the error was not handled.
@@ -75,11 +89,11 @@ impl OperationDiagnostic {
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
-                    no_solution_context(&err, context);
+                    no_solution_context(&err, context, self.stderr);
                 } else if let Some(hint) = self.hint {
-                    no_solution_hint(err, hint);
+                    no_solution_hint(err, hint, self.stderr);
                 } else {
-                    no_solution(&err);
+                    no_solution(&err, self.stderr);
                 }
                 None
             }
@@ -89,7 +103,7 @@ impl OperationDiagnostic {
                 chain,
                 err,
             )) => {
-                requested_dist_error(kind, dist, &chain, err, self.hint);
+                requested_dist_error(kind, dist, &chain, err, self.hint, self.stderr);
                 None
             }
             pip::operations::Error::Requirements(uv_requirements::Error::Dist(kind, dist, err)) => {
@@ -99,6 +113,7 @@ impl OperationDiagnostic {
                     &DerivationChain::default(),
                     Arc::new(err),
                     self.hint,
+                    self.stderr,
                 );
                 None
             }
@@ -108,14 +123,15 @@ impl OperationDiagnostic {
                 chain,
                 err,
             )) => {
-                dist_error(kind, dist, &chain, Arc::new(err), self.hint);
+                dist_error(kind, dist, &chain, Arc::new(err), self.hint, self.stderr);
                 None
             }
             pip::operations::Error::Requirements(err) => {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
                         .context(format!("Failed to resolve {context} requirement"));
-                    anstream::eprint!("{err:?}");
+                    let mut stderr = self.stderr;
+                    let _ = write!(stderr, "{err:?}");
                     None
                 } else {
                     Some(pip::operations::Error::Requirements(err))
@@ -124,11 +140,12 @@ impl OperationDiagnostic {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Client(err))
                 if !self.native_tls && err.is_ssl() =>
             {
-                native_tls_hint(err);
+                native_tls_hint(err, self.stderr);
                 None
             }
             pip::operations::Error::OutdatedEnvironment => {
-                anstream::eprintln!("{}", err);
+                let mut stderr = self.stderr;
+                let _ = writeln!(stderr, "{err}");
                 None
             }
             err => Some(err),
@@ -143,6 +160,7 @@ pub(crate) fn dist_error(
     chain: &DerivationChain,
     cause: Arc<uv_distribution::Error>,
     help: Option<String>,
+    mut stderr: Stderr,
 ) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("{kind} `{dist}`")]
@@ -181,7 +199,7 @@ pub(crate) fn dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Render a requested distribution failure (read, download or build) with a help message.
@@ -191,6 +209,7 @@ pub(crate) fn requested_dist_error(
     chain: &DerivationChain,
     cause: Arc<uv_distribution::Error>,
     help: Option<String>,
+    mut stderr: Stderr,
 ) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("{kind} `{dist}`")]
@@ -229,23 +248,31 @@ pub(crate) fn requested_dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`].
-pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
+pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError, mut stderr: Stderr) {
     let report = miette::Report::msg(format!("{err}")).context(err.header());
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
-pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
+pub(crate) fn no_solution_context(
+    err: &uv_resolver::NoSolutionError,
+    context: &'static str,
+    mut stderr: Stderr,
+) {
     let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
-pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: String) {
+pub(crate) fn no_solution_hint(
+    err: Box<uv_resolver::NoSolutionError>,
+    help: String,
+    mut stderr: Stderr,
+) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("{header}")]
     #[diagnostic()]
@@ -264,11 +291,11 @@ pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: Str
 
     let header = err.header();
     let report = miette::Report::new(Error { header, err, help });
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
-pub(crate) fn native_tls_hint(err: uv_client::Error) {
+pub(crate) fn native_tls_hint(err: uv_client::Error, mut stderr: Stderr) {
     #[derive(Debug, miette::Diagnostic)]
     #[diagnostic()]
     struct Error {
@@ -299,7 +326,7 @@ pub(crate) fn native_tls_hint(err: uv_client::Error) {
             "--native-tls".green()
         ),
     });
-    anstream::eprint!("{report:?}");
+    let _ = write!(stderr, "{report:?}");
 }
 
 /// Format a [`DerivationChain`] as a human-readable error message.
@@ -1,15 +1,18 @@
 use anstream::AutoStream;
 use anyhow::Context;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
+use tracing::debug;
 
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_info::cache_info;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
@@ -51,12 +54,14 @@ pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
 use uv_configuration::Concurrency;
-use uv_distribution_types::InstalledMetadata;
-use uv_fs::{CWD, Simplified};
-use uv_installer::compile_tree;
+use uv_distribution_types::{InstalledMetadata, Name};
+use uv_fs::{CWD, Simplified, normalize_path};
+use uv_install_wheel::read_record_file;
+use uv_installer::{SitePackages, compile_tree_filtered};
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
+use uv_warnings::warn_user;
 pub(crate) use venv::venv;
 
 use crate::printer::Printer;
@@ -65,6 +70,7 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
 mod diagnostics;
 mod help;
@@ -91,6 +97,9 @@ pub(crate) enum ExitStatus {
 
     /// The command's exit status is propagated from an external command.
     External(u8),
+
+    /// The command failed because a required lock is held by another process.
+    Locked,
 }
 
 impl From<ExitStatus> for ExitCode {
@@ -100,6 +109,7 @@ impl From<ExitStatus> for ExitCode {
             ExitStatus::Failure => Self::from(1),
             ExitStatus::Error => Self::from(2),
             ExitStatus::External(code) => Self::from(code),
+            ExitStatus::Locked => Self::from(3),
         }
     }
 }
@@ -146,22 +156,51 @@ pub(super) struct DryRunEvent<T: Display> {
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
-/// See the `--compile` option on `pip sync` and `pip install`.
+/// If `packages` is non-empty, only the source files installed by those packages are compiled.
+///
+/// Files that fail to compile are reported in a warning, unless `strict` is set, in which case the
+/// first failure is returned as an error. `strict` is `None` for commands that don't support
+/// `--compile-strict`.
+///
+/// If `optimize` is non-empty, the files are compiled at the given optimization levels, rather than
+/// at the interpreter's default level.
+///
+/// See the `--compile`, `--compile-package`, and `--compile-strict` options on `pip sync` and
+/// `pip install`, and the `--optimize` option on `pip sync`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    packages: &[PackageName],
+    strict: bool,
+    optimize: &[u8],
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
+
+    // If compilation is limited to specific packages, collect their source files up-front.
+    let sources = if packages.is_empty() {
+        None
+    } else {
+        Some(package_sources(venv, packages)?)
+    };
+
     let mut files = 0;
+    let mut failures = Vec::new();
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
-        files += compile_tree(
+        let compiled = compile_tree_filtered(
             &site_packages,
             venv.python_executable(),
             concurrency,
             cache.root(),
+            strict,
+            optimize,
+            |path| {
+                sources
+                    .as_ref()
+                    .is_none_or(|sources| sources.contains(path))
+            },
         )
         .await
         .with_context(|| {
@@ -170,6 +209,8 @@ pub(super) async fn compile_bytecode(
                 site_packages.user_display()
             )
         })?;
+        files += compiled.files - compiled.failures.len();
+        failures.extend(compiled.failures);
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -182,9 +223,57 @@ pub(super) async fn compile_bytecode(
         )
         .dimmed()
     )?;
+    if !failures.is_empty() {
+        let s = if failures.len() == 1 { "" } else { "s" };
+        warn_user!(
+            "Failed to bytecode-compile {} file{s}:{}",
+            failures.len(),
+            failures
+                .iter()
+                .map(|failure| format!("\n  - {}", failure.user_display()))
+                .collect::<String>()
+        );
+    }
     Ok(())
 }
 
+/// Collect the Python source files installed by the given packages, as listed in their `RECORD`
+/// files.
+fn package_sources(
+    venv: &PythonEnvironment,
+    packages: &[PackageName],
+) -> anyhow::Result<FxHashSet<PathBuf>> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    let mut sources = FxHashSet::default();
+    for package in packages {
+        let distributions = site_packages.get_packages(package);
+        if distributions.is_empty() {
+            warn_user!("Skipping bytecode compilation for `{package}`, which is not installed");
+            continue;
+        }
+        for distribution in distributions {
+            let Some(root) = distribution.install_path().parent() else {
+                continue;
+            };
+            let record = distribution.install_path().join("RECORD");
+            let Ok(mut record) = fs_err::File::open(&record) else {
+                debug!(
+                    "Skipping bytecode compilation for `{}`, which has no `RECORD` file",
+                    distribution.name()
+                );
+                continue;
+            };
+            for entry in read_record_file(&mut record)? {
+                let path = CWD.join(normalize_path(&root.join(&entry.path)));
+                if path.extension().is_some_and(|ext| ext == "py") {
+                    sources.insert(path);
+                }
+            }
+        }
+    }
+    Ok(sources)
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
@@ -292,6 +381,61 @@ pub(super) fn capitalize(s: &str) -> String {
     }
 }
 
+/// Load environment variables from the given `.env` files into the process environment.
+///
+/// Subsequent files take precedence over previous files. Variables that are already set in the
+/// environment take precedence over all files, unless `override_existing` is set.
+pub(super) fn read_env_files(env_files: &[PathBuf], override_existing: bool) -> anyhow::Result<()> {
+    // Without overriding, the first file to define a variable wins, so read the files in reverse.
+    let env_files: Vec<&Path> = if override_existing {
+        env_files.iter().map(PathBuf::as_path).collect()
+    } else {
+        env_files.iter().rev().map(PathBuf::as_path).collect()
+    };
+
+    for env_file_path in env_files {
+        let result = if override_existing {
+            dotenvy::from_path_override(env_file_path)
+        } else {
+            dotenvy::from_path(env_file_path)
+        };
+        match result {
+            Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                anyhow::bail!(
+                    "No environment file found at: `{}`",
+                    env_file_path.simplified_display()
+                );
+            }
+            Err(dotenvy::Error::Io(err)) => {
+                anyhow::bail!(
+                    "Failed to read environment file `{}`: {err}",
+                    env_file_path.simplified_display()
+                );
+            }
+            Err(dotenvy::Error::LineParse(content, position)) => {
+                warn_user!(
+                    "Failed to parse environment file `{}` at position {position}: {content}",
+                    env_file_path.simplified_display(),
+                );
+            }
+            Err(err) => {
+                warn_user!(
+                    "Failed to parse environment file `{}`: {err}",
+                    env_file_path.simplified_display(),
+                );
+            }
+            Ok(()) => {
+                debug!(
+                    "Read environment file at: `{}`",
+                    env_file_path.simplified_display()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// A Python file that may or may not include an existing PEP 723 script tag.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
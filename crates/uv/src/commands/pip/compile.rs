@@ -64,6 +64,8 @@ pub(crate) async fn pip_compile(
     overrides_from_workspace: Vec<Requirement>,
     build_constraints_from_workspace: Vec<Requirement>,
     environments: SupportedEnvironments,
+    required_environments: SupportedEnvironments,
+    report_unused_constraints: bool,
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
@@ -186,12 +188,27 @@ pub(crate) async fn pip_compile(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Read all requirements from the provided sources.
+    let spec = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        Some(&groups),
+        &client_builder,
+    )
+    .await?;
+
+    // Editable Git requirements are only checked out when installing.
+    spec.reject_git_editables()?;
+
     let RequirementsSpecification {
         project,
         requirements,
+        git_editables: _,
         constraints,
         overrides,
         pylock,
@@ -204,14 +221,7 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
-    } = RequirementsSpecification::from_sources(
-        requirements,
-        constraints,
-        overrides,
-        Some(&groups),
-        &client_builder,
-    )
-    .await?;
+    } = spec;
 
     // Reject `pylock.toml` files, which are valid outputs but not inputs.
     if pylock.is_some() {
@@ -220,7 +230,7 @@ pub(crate) async fn pip_compile(
         ));
     }
 
-    let constraints = constraints
+    let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
         .chain(
@@ -230,6 +240,10 @@ pub(crate) async fn pip_compile(
         )
         .collect();
 
+    // If requested, retain the constraints, to report any that go unused in the resolution.
+    let reported_constraints =
+        operations::reported_constraints(&constraints, report_unused_constraints);
+
     let overrides: Vec<UnresolvedRequirementSpecification> = overrides
         .iter()
         .cloned()
@@ -501,6 +515,7 @@ pub(crate) async fn pip_compile(
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
+        .required_environments(required_environments)
         .build();
 
     // Resolve the requirements.
@@ -542,6 +557,13 @@ pub(crate) async fn pip_compile(
         }
     };
 
+    // Notify the user of any constraints that don't apply to the resolution.
+    operations::report_unused_constraints(
+        &reported_constraints,
+        |name| resolution.contains(name),
+        printer,
+    )?;
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
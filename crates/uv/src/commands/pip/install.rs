@@ -1,12 +1,12 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 
 use anyhow::Context;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::{Level, debug, enabled, warn};
+use tracing::{Level, debug, enabled};
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
@@ -16,20 +16,24 @@ use uv_configuration::{
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, Origin, Requirement,
-    Resolution, UnresolvedRequirementSpecification,
+    DependencyMetadata, Index, IndexLocations, Name, NameRequirementSpecification, Origin,
+    Requirement, Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_pep440::VersionSpecifiers;
 use uv_pep508::PackageName;
+use uv_platform_tags::PlatformTag;
 use uv_pypi_types::Conflicts;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    PythonRequest, PythonVersion, Root, Target,
+};
+use uv_requirements::{
+    GroupsSpecification, RequirementsSource, RequirementsSpecification, checkout_git_editables,
 };
-use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
     PythonRequirement, ResolutionMode, ResolverEnvironment,
@@ -40,9 +44,14 @@ use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{
+    EnvironmentLock, FileConflicts, InstallFlags, Modifications,
+};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    check_python_version, operations, resolution_markers, resolution_tags,
+    warn_platform_tag_overrides, with_platform_tags,
+};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -54,6 +63,7 @@ pub(crate) async fn pip_install(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    build_requirements: &[RequirementsSource],
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
     build_constraints_from_workspace: Vec<Requirement>,
@@ -72,24 +82,39 @@ pub(crate) async fn pip_install(
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    compile_package: Vec<PackageName>,
+    compile_strict: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
+    installer_name: Option<String>,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
     modifications: Modifications,
+    only_deps: bool,
+    max_depth: Option<usize>,
+    list_extraneous: bool,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     strict: bool,
+    warn_conflicts: bool,
+    warn_shadowing: bool,
+    warn_import_conflicts: bool,
+    verify_metadata_consistency: bool,
+    no_wait: bool,
+    report_unused_constraints: bool,
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
     python: Option<String>,
+    verify_python_version: Option<VersionSpecifiers>,
+    platform_tag: Vec<PlatformTag>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    root: Option<Root>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     cache: Cache,
@@ -104,12 +129,15 @@ pub(crate) async fn pip_install(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
         requirements,
+        git_editables,
         constraints,
         overrides,
         pylock,
@@ -150,6 +178,10 @@ pub(crate) async fn pip_install(
         )
         .collect();
 
+    // If requested, retain the constraints, to report any that go unused in the resolution.
+    let reported_constraints =
+        operations::reported_constraints(&constraints, report_unused_constraints);
+
     let overrides: Vec<UnresolvedRequirementSpecification> = overrides
         .iter()
         .cloned()
@@ -173,8 +205,17 @@ pub(crate) async fn pip_install(
             )
             .collect();
 
+    // Read any build requirements, to install ahead of the build.
+    let build_requirements = if build_requirements.is_empty() {
+        Vec::new()
+    } else {
+        RequirementsSpecification::from_sources(build_requirements, &[], &[], None, &client_builder)
+            .await?
+            .requirements
+    };
+
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if target.is_some() || prefix.is_some() || root.is_some() {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
@@ -201,6 +242,11 @@ pub(crate) async fn pip_install(
         environment
     };
 
+    // Verify the interpreter's version before resolving or installing anything.
+    if let Some(specifiers) = verify_python_version.as_ref() {
+        check_python_version(environment.interpreter(), specifiers)?;
+    }
+
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
         debug!(
@@ -218,6 +264,14 @@ pub(crate) async fn pip_install(
         environment
     };
 
+    // Apply any `--root` directory, beneath which the installation is staged.
+    let environment = if let Some(root) = root {
+        debug!("Using `--root` directory at {}", root.root().user_display());
+        environment.with_root(root)?
+    } else {
+        environment
+    };
+
     // If the environment is externally managed, abort.
     if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
         if break_system_packages {
@@ -238,13 +292,10 @@ pub(crate) async fn pip_install(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = match operations::lock_environment(&environment, no_wait, printer).await? {
+        EnvironmentLock::Acquired(lock) => lock,
+        EnvironmentLock::Held => return Ok(ExitStatus::Locked),
+    };
 
     // Determine the markers to use for the resolution.
     let interpreter = environment.interpreter();
@@ -266,6 +317,7 @@ pub(crate) async fn pip_install(
         && groups.is_empty()
         && pylock.is_none()
         && matches!(modifications, Modifications::Sufficient)
+        && !list_extraneous
     {
         match site_packages.satisfies_spec(&requirements, &constraints, &overrides, &marker_env)? {
             // If the requirements are already satisfied, we're done.
@@ -281,7 +333,7 @@ pub(crate) async fn pip_install(
                         debug!("Requirement satisfied: {requirement}");
                     }
                 }
-                DefaultInstallLogger.on_audit(requirements.len(), start, printer)?;
+                DefaultInstallLogger::default().on_audit(requirements.len(), start, printer)?;
                 if dry_run.enabled() {
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
@@ -302,12 +354,15 @@ pub(crate) async fn pip_install(
     };
 
     // Determine the tags to use for the resolution.
-    let tags = resolution_tags(
+    let base_tags = resolution_tags(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
     )?;
 
+    // Extend the tags with any additional platform tags, if requested.
+    let tags = with_platform_tags(base_tags.clone(), &platform_tag);
+
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
@@ -411,6 +466,20 @@ pub(crate) async fn pip_install(
     // Initialize any shared state.
     let state = SharedState::default();
 
+    // Check out any editable Git requirements, unless this is a dry run.
+    let requirements = if dry_run.enabled() {
+        requirements
+    } else {
+        checkout_git_editables(
+            requirements,
+            git_editables,
+            state.git(),
+            &client,
+            &cache.bucket(CacheBucket::Git),
+        )
+        .await?
+    };
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -435,6 +504,103 @@ pub(crate) async fn pip_install(
         preview,
     );
 
+    // Install the build requirements into the environment up-front, such that they're available
+    // to any builds that run without build isolation.
+    let site_packages = if build_requirements.is_empty() {
+        site_packages
+    } else if dry_run.enabled() {
+        debug!("Skipping installation of build requirements in dry-run mode");
+        site_packages
+    } else {
+        let options = OptionsBuilder::new()
+            .resolution_mode(resolution_mode)
+            .prerelease_mode(prerelease_mode)
+            .exclude_newer(exclude_newer)
+            .index_strategy(index_strategy)
+            .torch_backend(torch_backend.clone())
+            .build_options(build_options.clone())
+            .build();
+
+        let resolution = match operations::resolve(
+            build_requirements,
+            constraints.clone(),
+            Vec::new(),
+            Vec::new(),
+            None,
+            BTreeSet::default(),
+            &ExtrasSpecification::default(),
+            &BTreeMap::default(),
+            Vec::default(),
+            site_packages.clone(),
+            &build_hasher,
+            &reinstall,
+            &upgrade,
+            Some(&tags),
+            ResolverEnvironment::specific(marker_env.clone()),
+            python_requirement.clone(),
+            interpreter.markers(),
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            state.index(),
+            &build_dispatch,
+            concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(graph) => Resolution::from(graph),
+            Err(err) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+        };
+
+        match operations::install(
+            &resolution,
+            site_packages,
+            Modifications::Sufficient,
+            &reinstall,
+            &build_options,
+            link_mode,
+            false,
+            InstallFlags {
+                installer_name: installer_name.as_deref(),
+                ..InstallFlags::default()
+            },
+            &index_locations,
+            config_settings,
+            config_settings_package,
+            &build_hasher,
+            &tags,
+            &client,
+            state.in_flight(),
+            concurrency,
+            &build_dispatch,
+            &cache,
+            &environment,
+            Box::new(DefaultInstallLogger::default()),
+            installer_metadata,
+            dry_run,
+            printer,
+        )
+        .await
+        {
+            Ok(..) => {}
+            Err(err) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+        }
+
+        // Refresh the set of installed packages, to include the build requirements.
+        SitePackages::from_environment(&environment)?
+    };
+
     let (resolution, hasher) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
         let install_path = std::path::absolute(&pylock)?;
@@ -539,6 +705,54 @@ pub(crate) async fn pip_install(
         (resolution, hasher)
     };
 
+    // Notify the user of any constraints that don't apply to the resolution.
+    operations::report_unused_constraints(
+        &reported_constraints,
+        |name| resolution.distributions().any(|dist| dist.name() == name),
+        printer,
+    )?;
+
+    // If we're only installing dependencies, omit the requested packages themselves.
+    let resolution = if only_deps {
+        resolution.without_direct()
+    } else {
+        resolution
+    };
+
+    // If a maximum depth was requested, omit any packages beyond it.
+    let resolution = if let Some(max_depth) = max_depth {
+        let total = resolution.len();
+        let resolution = resolution.truncate(max_depth);
+        let omitted = total - resolution.len();
+        if omitted > 0 {
+            let s = if omitted == 1 { "" } else { "s" };
+            warn_user!(
+                "The dependency graph was truncated at a depth of {max_depth}, omitting {omitted} package{s}; the environment may be non-functional"
+            );
+        }
+        resolution
+    } else {
+        resolution
+    };
+
+    // Warn about any wheels that are only installable due to `--platform-tag`.
+    if !platform_tag.is_empty() {
+        warn_platform_tag_overrides(&resolution, &base_tags);
+    }
+
+    // Warn about any packages whose wheel and source distribution declare different dependencies.
+    if verify_metadata_consistency {
+        operations::diagnose_metadata_consistency(
+            &resolution,
+            &hasher,
+            &client,
+            &build_dispatch,
+            concurrency,
+            printer,
+        )
+        .await?;
+    }
+
     // Sync the environment.
     match operations::install(
         &resolution,
@@ -548,6 +762,19 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        InstallFlags {
+            compile_packages: &compile_package,
+            compile_strict,
+            // Warn about any files that would be overwritten by another package, if requested.
+            file_conflicts: if strict {
+                FileConflicts::Warn
+            } else {
+                FileConflicts::Ignore
+            },
+            installer_name: installer_name.as_deref(),
+            list_extraneous,
+            ..InstallFlags::default()
+        },
         &index_locations,
         config_settings,
         config_settings_package,
@@ -559,7 +786,7 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        Box::new(DefaultInstallLogger::default()),
         installer_metadata,
         dry_run,
         printer,
@@ -579,7 +806,23 @@ pub(crate) async fn pip_install(
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run.enabled() {
-        operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+        operations::diagnose_environment(
+            &resolution,
+            &environment,
+            &marker_env,
+            warn_conflicts,
+            printer,
+        )?;
+    }
+
+    // Notify the user of any installed modules that shadow the standard library.
+    if warn_shadowing && !dry_run.enabled() {
+        operations::diagnose_shadowing(&resolution, &environment, printer)?;
+    }
+
+    // Notify the user of any installed packages that provide the same top-level module.
+    if warn_import_conflicts && !dry_run.enabled() {
+        operations::diagnose_import_conflicts(&resolution, &environment, printer)?;
     }
 
     Ok(ExitStatus::Success)
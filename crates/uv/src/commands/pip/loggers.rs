@@ -6,13 +6,15 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use uv_distribution_types::{InstalledMetadata, Name};
+use uv_distribution_types::{
+    Dist, InstalledMetadata, LocalDist, Name, Resolution, ResolvedDist, SourceDist,
+};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
 use crate::commands::pip::operations::Changelog;
 use crate::commands::{ChangeEvent, ChangeEventKind, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterMode};
 
 /// A trait to handle logging during install operations.
 pub(crate) trait InstallLogger {
@@ -38,8 +40,61 @@ pub(crate) trait InstallLogger {
 }
 
 /// The default logger for install operations.
-#[derive(Debug, Default, Clone, Copy)]
-pub(crate) struct DefaultInstallLogger;
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DefaultInstallLogger {
+    /// How each package in the resolution was obtained, if installed packages should be annotated
+    /// with their source (as with `--emit-build-tags`).
+    build_sources: Option<FxHashMap<PackageName, BuildSource>>,
+}
+
+impl DefaultInstallLogger {
+    /// Create a logger that annotates each installed package with how it was obtained (e.g., from
+    /// a wheel, or built from a source distribution), along with the tag of the installed wheel in
+    /// verbose mode.
+    pub(crate) fn with_build_tags(resolution: &Resolution) -> Self {
+        let build_sources = resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable { dist, .. } => {
+                    Some((dist.name().clone(), BuildSource::from_dist(dist)))
+                }
+                ResolvedDist::Installed { .. } => None,
+            })
+            .collect();
+        Self {
+            build_sources: Some(build_sources),
+        }
+    }
+
+    /// Return the annotation for an installed distribution, or an empty string if build tags
+    /// weren't requested or its source is unknown.
+    fn annotation(&self, dist: &LocalDist, printer: Printer) -> String {
+        let Some(source) = self
+            .build_sources
+            .as_ref()
+            .and_then(|build_sources| build_sources.get(dist.name()))
+        else {
+            return String::new();
+        };
+        match dist {
+            LocalDist::Cached(dist, _) if matches!(printer.mode(), PrinterMode::Verbose) => {
+                let filename = dist.filename();
+                let tag = format!(
+                    "{}-{}-{}",
+                    filename.python_tags().iter().join("."),
+                    filename.abi_tags().iter().join("."),
+                    filename.platform_tags().iter().join(".")
+                );
+                if *source == BuildSource::Wheel {
+                    format!(" ({source} {tag})")
+                } else {
+                    format!(" ({source} as {tag})")
+                }
+            }
+            _ => format!(" ({source})"),
+        }
+    }
+}
 
 impl InstallLogger for DefaultInstallLogger {
     fn on_audit(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
@@ -144,10 +199,11 @@ impl InstallLogger for DefaultInstallLogger {
                 ChangeEventKind::Added => {
                     writeln!(
                         printer.stderr(),
-                        " {} {}{}",
+                        " {} {}{}{}",
                         "+".green(),
                         event.dist.name().bold(),
-                        event.dist.installed_version().dimmed()
+                        event.dist.installed_version().dimmed(),
+                        self.annotation(event.dist, printer).dimmed()
                     )?;
                 }
                 ChangeEventKind::Removed => {
@@ -162,10 +218,11 @@ impl InstallLogger for DefaultInstallLogger {
                 ChangeEventKind::Reinstalled => {
                     writeln!(
                         printer.stderr(),
-                        " {} {}{}",
+                        " {} {}{}{}",
                         "~".yellow(),
                         event.dist.name().bold(),
-                        event.dist.installed_version().dimmed()
+                        event.dist.installed_version().dimmed(),
+                        self.annotation(event.dist, printer).dimmed()
                     )?;
                 }
             }
@@ -388,12 +445,50 @@ impl InstallLogger for UpgradeInstallLogger {
         }
 
         // Follow-up with a detailed summary of all changes.
-        DefaultInstallLogger.on_complete(changelog, printer)?;
+        DefaultInstallLogger::default().on_complete(changelog, printer)?;
 
         Ok(())
     }
 }
 
+/// How an installed distribution was obtained, as reported by `--emit-build-tags`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BuildSource {
+    /// The distribution was installed from a pre-built wheel.
+    Wheel,
+    /// The distribution was built from a source distribution archive.
+    Sdist,
+    /// The distribution was built from a Git repository.
+    Git,
+    /// The distribution was built from a local source tree.
+    Directory,
+}
+
+impl BuildSource {
+    /// Determine how the given distribution is obtained.
+    fn from_dist(dist: &Dist) -> Self {
+        match dist {
+            Dist::Built(_) => Self::Wheel,
+            Dist::Source(
+                SourceDist::Registry(_) | SourceDist::DirectUrl(_) | SourceDist::Path(_),
+            ) => Self::Sdist,
+            Dist::Source(SourceDist::Git(_)) => Self::Git,
+            Dist::Source(SourceDist::Directory(_)) => Self::Directory,
+        }
+    }
+}
+
+impl fmt::Display for BuildSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wheel => write!(f, "wheel"),
+            Self::Sdist => write!(f, "built from sdist"),
+            Self::Git => write!(f, "built from git"),
+            Self::Directory => write!(f, "built from path"),
+        }
+    }
+}
+
 /// A trait to handle logging during resolve operations.
 pub(crate) trait ResolveLogger {
     /// Log the completion of the operation.
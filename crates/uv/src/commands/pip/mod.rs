@@ -1,9 +1,14 @@
 use std::borrow::Cow;
 
-use uv_configuration::TargetTriple;
-use uv_platform_tags::{Tags, TagsError};
+use anyhow::Result;
+
+use uv_configuration::{MarkerOverride, TargetTriple};
+use uv_distribution_types::{BuiltDist, Dist, Resolution, ResolvedDist};
+use uv_pep440::VersionSpecifiers;
+use uv_platform_tags::{PlatformTag, Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
+use uv_warnings::warn_user;
 
 pub(crate) mod check;
 pub(crate) mod compile;
@@ -121,3 +126,69 @@ pub(crate) fn resolution_environment(
 
     Ok((tags, markers))
 }
+
+/// Verify that the [`Interpreter`]'s Python version satisfies the `--verify-python-version`
+/// specifiers.
+pub(crate) fn check_python_version(
+    interpreter: &Interpreter,
+    specifiers: &VersionSpecifiers,
+) -> Result<()> {
+    let version = interpreter.python_version();
+    if specifiers.contains(version) {
+        return Ok(());
+    }
+    Err(anyhow::anyhow!(
+        "The Python interpreter's version ({version}) does not satisfy `--verify-python-version {specifiers}`"
+    ))
+}
+
+/// Extend the [`Tags`] with any additional platform tags provided via `--platform-tag`.
+pub(crate) fn with_platform_tags<'env>(
+    tags: Cow<'env, Tags>,
+    platform_tags: &[PlatformTag],
+) -> Cow<'env, Tags> {
+    if platform_tags.is_empty() {
+        tags
+    } else {
+        Cow::Owned(tags.with_platform_tags(platform_tags))
+    }
+}
+
+/// Apply any `--marker` overrides to the [`ResolverMarkerEnvironment`].
+pub(crate) fn with_marker_overrides(
+    marker_env: ResolverMarkerEnvironment,
+    overrides: &[MarkerOverride],
+) -> ResolverMarkerEnvironment {
+    if overrides.is_empty() {
+        marker_env
+    } else {
+        ResolverMarkerEnvironment::from(
+            overrides
+                .iter()
+                .fold(marker_env.markers().clone(), |markers, marker| {
+                    marker.apply(markers)
+                }),
+        )
+    }
+}
+
+/// Warn about any wheels in the [`Resolution`] that are incompatible with the given [`Tags`],
+/// i.e., that are only being installed due to a `--platform-tag` override.
+pub(crate) fn warn_platform_tag_overrides(resolution: &Resolution, tags: &Tags) {
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        let filename = match dist.as_ref() {
+            Dist::Built(BuiltDist::Registry(wheels)) => &wheels.best_wheel().filename,
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => &wheel.filename,
+            Dist::Built(BuiltDist::Path(wheel)) => &wheel.filename,
+            Dist::Source(_) => continue,
+        };
+        if !filename.is_compatible(tags) {
+            warn_user!(
+                "Installing `{filename}`, which is incompatible with the current platform, due to `--platform-tag`; the installed package may not work"
+            );
+        }
+    }
+}
@@ -3,13 +3,17 @@
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use uv_cache::Cache;
+use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DependencyGroups, DryRun,
@@ -18,20 +22,24 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    BuiltDist, CachedDist, Diagnostic, Dist, InstalledDist, LocalDist,
+    NameRequirementSpecification, Requirement, RequirementSource, ResolutionDiagnostic,
+    ResolvedDist, SourceDist, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution, VersionOrUrlRef,
+};
+use uv_fs::{LockedFile, Simplified, normalize_path_buf, relative_to};
+use uv_install_wheel::{
+    Layout, LibKind, LinkMode, RecordEntry, parse_wheel_file, read_record_file,
 };
-use uv_fs::Simplified;
-use uv_install_wheel::LinkMode;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
-use uv_python::{PythonEnvironment, PythonInstallation};
+use uv_python::{Prefix, PythonEnvironment, PythonInstallation, Target};
 use uv_requirements::{
     GroupsSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
     RequirementsSpecification, SourceTreeResolver,
@@ -46,7 +54,9 @@ use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
-use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode};
+use crate::commands::{
+    ChangeEventKind, DryRunEvent, compile_bytecode, diagnostics, human_readable_bytes,
+};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
@@ -376,6 +386,48 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// How to handle files that would be overwritten by a package other than the one that owns them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileConflicts {
+    /// Don't check for conflicting files.
+    #[default]
+    Ignore,
+    /// Warn about conflicting files, but install the packages regardless.
+    Warn,
+    /// Abort the installation if any files conflict.
+    Error,
+}
+
+/// Flags for [`install`] that only some commands expose.
+///
+/// The defaults match `pip install` without any of the corresponding flags.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct InstallFlags<'a> {
+    /// When compiling bytecode, only compile the given packages (or all packages, if empty).
+    pub(crate) compile_packages: &'a [PackageName],
+    /// Whether to fail on any file that can't be compiled to bytecode.
+    pub(crate) compile_strict: bool,
+    /// The optimization levels at which to compile bytecode.
+    pub(crate) compile_optimize: &'a [u8],
+    /// How to handle files that would be overwritten by another package.
+    pub(crate) file_conflicts: FileConflicts,
+    /// Whether to verify files against the `RECORD` before uninstalling a package.
+    pub(crate) verify_records: bool,
+    /// Whether to keep locally modified files when uninstalling a package.
+    pub(crate) preserve_modified: bool,
+    /// Whether to sandbox the installed scripts.
+    pub(crate) sandbox_scripts: bool,
+    /// The name to record in the `INSTALLER` file, instead of `uv`.
+    pub(crate) installer_name: Option<&'a str>,
+    /// Whether to skip any packages that fail to build, rather than aborting the installation.
+    pub(crate) ignore_build_failures: bool,
+    /// Whether to list any extraneous packages in the environment.
+    pub(crate) list_extraneous: bool,
+    /// The format in which to report the changes in dry-run mode.
+    pub(crate) dry_run_format: SyncFormat,
+}
+
 /// A summary of the changes made to the environment during an installation.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Changelog {
@@ -385,6 +437,8 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<LocalDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<LocalDist>,
+    /// The distributions that were skipped, as they failed to build.
+    pub(crate) skipped: Vec<Box<Dist>>,
 }
 
 impl Changelog {
@@ -403,6 +457,7 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            skipped: Vec::new(),
         }
     }
 
@@ -412,6 +467,7 @@ impl Changelog {
             installed: installed.into_iter().map(LocalDist::from).collect(),
             uninstalled: HashSet::default(),
             reinstalled: HashSet::default(),
+            skipped: Vec::new(),
         }
     }
 
@@ -439,6 +495,7 @@ pub(crate) async fn install(
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: bool,
+    options: InstallFlags<'_>,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
@@ -455,6 +512,20 @@ pub(crate) async fn install(
     dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
+    let InstallFlags {
+        compile_packages,
+        compile_strict,
+        compile_optimize,
+        file_conflicts,
+        verify_records,
+        preserve_modified,
+        sandbox_scripts,
+        installer_name,
+        ignore_build_failures,
+        list_extraneous,
+        dry_run_format,
+    } = options;
+
     let start = std::time::Instant::now();
 
     // Partition into those that should be linked from the cache (`local`), those that need to be
@@ -474,36 +545,41 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
-    if dry_run.enabled() {
-        report_dry_run(dry_run, resolution, plan, modifications, start, printer)?;
-        return Ok(Changelog::default());
+    // Report any extraneous distributions, regardless of whether they'll be removed.
+    if list_extraneous {
+        report_extraneous(&plan.extraneous, printer)?;
     }
 
-    let Plan {
-        cached,
-        remote,
-        reinstalls,
-        extraneous,
-    } = plan;
-
     // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
+    let plan = match modifications {
+        Modifications::Sufficient => plan.without_extraneous(),
+        Modifications::Exact => plan,
     };
 
+    if dry_run.enabled() {
+        match dry_run_format {
+            SyncFormat::Text => report_dry_run(dry_run, resolution, plan, start, printer)?,
+            SyncFormat::Json => report_dry_run_json(dry_run, resolution, plan, printer)?,
+        }
+        return Ok(Changelog::default());
+    }
+
     // Nothing to do.
-    if remote.is_empty()
-        && cached.is_empty()
-        && reinstalls.is_empty()
-        && extraneous.is_empty()
-        && !compile
-    {
+    if plan.is_empty() && !compile && compile_packages.is_empty() {
         logger.on_audit(resolution.len(), start, printer)?;
         return Ok(Changelog::default());
     }
 
+    let Plan {
+        cached,
+        remote,
+        mut reinstalls,
+        extraneous,
+        satisfied: _,
+    } = plan;
+
     // Download, build, and unzip any missing distributions.
+    let mut skipped = Vec::new();
     let wheels = if remote.is_empty() {
         vec![]
     } else {
@@ -520,17 +596,117 @@ pub(crate) async fn install(
             PrepareReporter::from(printer).with_length(remote.len() as u64),
         ));
 
-        let wheels = preparer
-            .prepare(remote.clone(), in_flight, resolution)
-            .await?;
+        let wheels = if ignore_build_failures {
+            let (wheels, failures) = preparer
+                .prepare_ignore_build_failures(remote.clone(), in_flight, resolution)
+                .await?;
+
+            // Report any distributions that failed to build, and skip them.
+            for failure in failures {
+                if let uv_installer::PrepareError::Dist(kind, dist, chain, err) = failure {
+                    diagnostics::dist_error(
+                        kind,
+                        dist.clone(),
+                        &chain,
+                        Arc::new(err),
+                        None,
+                        printer.diagnostics(),
+                    );
+                    skipped.push(dist);
+                }
+            }
+
+            wheels
+        } else {
+            preparer
+                .prepare(remote.clone(), in_flight, resolution)
+                .await?
+        };
 
         logger.on_prepare(wheels.len(), start, printer)?;
 
         wheels
     };
 
+    // Retain any existing installations of distributions that failed to build.
+    if !skipped.is_empty() {
+        reinstalls.retain(|dist_info| !skipped.iter().any(|dist| dist.name() == dist_info.name()));
+    }
+
     // Remove any upgraded or extraneous installations.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
+
+    // Before modifying the environment, check whether any of the new distributions would overwrite
+    // files owned by another package.
+    if file_conflicts != FileConflicts::Ignore {
+        let installs = wheels.iter().chain(&cached).collect::<Vec<_>>();
+        for conflict in find_file_conflicts(venv, &installs, &uninstalls)? {
+            if file_conflicts == FileConflicts::Error {
+                return Err(anyhow!("{conflict}").into());
+            }
+            warn_user!("{conflict}");
+        }
+    }
+
+    // Before removing any files, verify that they haven't been modified since they were installed,
+    // to avoid discarding any local changes.
+    if verify_records {
+        let mut modified = Vec::new();
+        for dist_info in &uninstalls {
+            match uv_installer::modified_files(dist_info).await {
+                Ok(files) => {
+                    modified.extend(files.into_iter().map(|path| (dist_info.name(), path)));
+                }
+                // A missing `RECORD` is reported when uninstalling.
+                Err(uv_installer::UninstallError::Uninstall(
+                    uv_install_wheel::Error::MissingRecord(_),
+                )) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        if !modified.is_empty() {
+            let mut message = format!(
+                "The following installed {} modified after installation and would be removed, but `--verify-records-on-uninstall` was provided:",
+                if modified.len() == 1 {
+                    "file was"
+                } else {
+                    "files were"
+                }
+            );
+            for (name, path) in modified {
+                write!(message, "\n  {} (from `{name}`)", path.user_display())?;
+            }
+            return Err(anyhow!(message).into());
+        }
+    }
+
+    // Before reinstalling any packages, back up any files that were modified since they were
+    // installed, so that local changes survive the reinstall.
+    let mut backups = Vec::new();
+    if preserve_modified {
+        let installs = wheels.iter().chain(&cached).collect::<Vec<_>>();
+        for dist_info in &uninstalls {
+            if !installs.iter().any(|dist| dist.name() == dist_info.name()) {
+                continue;
+            }
+            match uv_installer::modified_files(dist_info).await {
+                Ok(files) => {
+                    for path in files {
+                        let backup = backup_path(&path);
+                        fs_err::copy(&path, &backup)?;
+                        debug!("Backed up modified file to: {}", backup.user_display());
+                        backups.push(backup);
+                    }
+                }
+                // A missing `RECORD` is reported when uninstalling.
+                Err(uv_installer::UninstallError::Uninstall(
+                    uv_install_wheel::Error::MissingRecord(_),
+                )) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
     if !uninstalls.is_empty() {
         let start = std::time::Instant::now();
 
@@ -573,10 +749,15 @@ pub(crate) async fn install(
     let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !installs.is_empty() {
         let start = std::time::Instant::now();
-        installs = uv_installer::Installer::new(venv)
+        let mut installer = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            .with_sandbox_scripts(sandbox_scripts);
+        if let Some(installer_name) = installer_name {
+            installer = installer.with_installer_name(Some(installer_name.to_string()));
+        }
+        installs = installer
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
@@ -588,19 +769,542 @@ pub(crate) async fn install(
         logger.on_install(installs.len(), start, printer)?;
     }
 
-    if compile {
-        compile_bytecode(venv, &concurrency, cache, printer).await?;
+    if compile || !compile_packages.is_empty() {
+        // `--compile` covers the entire environment, including any `--compile-package` packages.
+        compile_bytecode(
+            venv,
+            if compile { &[] } else { compile_packages },
+            compile_strict,
+            compile_optimize,
+            &concurrency,
+            cache,
+            printer,
+        )
+        .await?;
     }
 
     // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::new(installs, uninstalls);
+    let mut changelog = Changelog::new(installs, uninstalls);
+    changelog.skipped = skipped;
 
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer)?;
 
+    // Report any modified files that were backed up before reinstalling.
+    if !backups.is_empty() {
+        let s = if backups.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Backed up {} before reinstalling:",
+                format!("{} modified file{s}", backups.len()).bold(),
+            )
+            .dimmed()
+        )?;
+        for backup in &backups {
+            writeln!(printer.stderr(), "  {}", backup.user_display())?;
+        }
+    }
+
     Ok(changelog)
 }
 
+/// Return the path to which a modified file should be backed up before it's overwritten, i.e.,
+/// the same path with a `.uv-backup` suffix.
+///
+/// If that path already exists (e.g., from a previous reinstall), a numeric suffix is appended
+/// (e.g., `.uv-backup.1`), such that existing backups are never overwritten.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".uv-backup");
+    let mut candidate = PathBuf::from(&backup);
+    let mut index = 1;
+    while candidate.symlink_metadata().is_ok() {
+        let mut numbered = backup.clone();
+        numbered.push(format!(".{index}"));
+        candidate = PathBuf::from(numbered);
+        index += 1;
+    }
+    candidate
+}
+
+/// Download and build the distributions in the [`Resolution`], writing a wheel for each to the
+/// `dest` directory rather than installing them.
+pub(crate) async fn download(
+    resolution: &Resolution,
+    build_options: &BuildOptions,
+    hasher: &HashStrategy,
+    tags: &Tags,
+    client: &RegistryClient,
+    in_flight: &InFlight,
+    concurrency: Concurrency,
+    build_dispatch: &BuildDispatch<'_>,
+    cache: &Cache,
+    dest: &Path,
+    printer: Printer,
+) -> Result<(), Error> {
+    let remote = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.clone()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Download, build, and unzip the distributions into the cache.
+    let start = std::time::Instant::now();
+    let preparer = Preparer::new(
+        cache,
+        tags,
+        hasher,
+        build_options,
+        DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+    )
+    .with_reporter(Arc::new(
+        PrepareReporter::from(printer).with_length(remote.len() as u64),
+    ));
+    let wheels = preparer.prepare(remote, in_flight, resolution).await?;
+    DefaultInstallLogger::default().on_prepare(wheels.len(), start, printer)?;
+
+    // Re-pack each unzipped wheel into the destination directory.
+    fs_err::create_dir_all(dest)?;
+    for wheel in &wheels {
+        let target = dest.join(wheel.filename().to_string());
+        write_wheel(wheel.path(), &target)?;
+        debug!("Wrote `{wheel}` to: {}", target.user_display());
+    }
+
+    let s = if wheels.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Wrote {} to: {}",
+        format!("{} wheel{s}", wheels.len()).bold(),
+        dest.user_display().cyan()
+    )?;
+
+    Ok(())
+}
+
+/// Write the unzipped wheel at `source` to a wheel archive at `target`.
+///
+/// Files are written in sorted order, with the `.dist-info` directory last, as recommended by the
+/// binary distribution format.
+fn write_wheel(source: &Path, target: &Path) -> Result<(), std::io::Error> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(source).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .expect("walkdir starts with the root");
+        files.push((relative.to_path_buf(), entry.into_path()));
+    }
+    files.sort_by_key(|(relative, _)| {
+        relative
+            .components()
+            .next()
+            .and_then(|component| component.as_os_str().to_str())
+            .is_some_and(|name| name.ends_with(".dist-info"))
+    });
+
+    let mut writer = zip::ZipWriter::new(fs_err::File::create(target)?);
+    for (relative, path) in files {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        #[cfg(unix)]
+        let options = {
+            use std::os::unix::fs::PermissionsExt;
+            options.unix_permissions(fs_err::metadata(&path)?.permissions().mode())
+        };
+        writer
+            .start_file(relative.portable_display().to_string(), options)
+            .map_err(std::io::Error::other)?;
+        std::io::copy(&mut fs_err::File::open(&path)?, &mut writer)?;
+    }
+    writer.finish().map_err(std::io::Error::other)?;
+
+    Ok(())
+}
+
+/// Retain the constraints to check with [`report_unused_constraints`], if `report` is set.
+pub(crate) fn reported_constraints(
+    constraints: &[NameRequirementSpecification],
+    report: bool,
+) -> Vec<Requirement> {
+    if report {
+        constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone())
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Report any constraints that don't bound a package in the resolution: those that don't apply
+/// to any resolved package, as determined by `is_resolved`, and those that don't restrict the
+/// versions of the package at all.
+#[allow(clippy::result_large_err)]
+pub(crate) fn report_unused_constraints(
+    constraints: &[Requirement],
+    is_resolved: impl Fn(&PackageName) -> bool,
+    printer: Printer,
+) -> Result<(), Error> {
+    for constraint in constraints {
+        let message = if !is_resolved(&constraint.name) {
+            format!("The constraint `{constraint}` doesn't apply to any package in the resolution")
+        } else if matches!(
+            &constraint.source,
+            RequirementSource::Registry { specifier, .. } if specifier.is_empty()
+        ) {
+            format!(
+                "The constraint `{constraint}` doesn't bound any versions of `{}`",
+                constraint.name
+            )
+        } else {
+            continue;
+        };
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            message.bold()
+        )?;
+    }
+    Ok(())
+}
+
+/// A file that installing a distribution would overwrite, despite being owned by another package.
+#[derive(Debug)]
+struct FileConflict {
+    /// The path to the conflicting file.
+    path: PathBuf,
+    /// The package that would overwrite the file.
+    package: PackageName,
+    /// The package that owns the file.
+    owner: PackageName,
+}
+
+impl std::fmt::Display for FileConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Installing `{}` would overwrite `{}`, which is owned by `{}`",
+            self.package,
+            self.path.user_display(),
+            self.owner
+        )
+    }
+}
+
+/// Find any files that installing the given distributions would overwrite, despite being owned by
+/// another package, as determined by the `RECORD` files of the installed packages that aren't
+/// about to be removed and of the distributions themselves.
+#[allow(clippy::result_large_err)]
+fn find_file_conflicts(
+    venv: &PythonEnvironment,
+    installs: &[&CachedDist],
+    uninstalls: &[InstalledDist],
+) -> Result<Vec<FileConflict>, Error> {
+    let site_packages = SitePackages::from_environment(venv)?;
+
+    let mut owners = FxHashMap::default();
+    for dist in site_packages.iter() {
+        if uninstalls
+            .iter()
+            .any(|uninstall| uninstall.install_path() == dist.install_path())
+        {
+            continue;
+        }
+        let Some(root) = dist.install_path().parent() else {
+            continue;
+        };
+        let Ok(mut record) = fs_err::File::open(dist.install_path().join("RECORD")) else {
+            continue;
+        };
+        for entry in read_record_file(&mut record).map_err(anyhow::Error::from)? {
+            owners.insert(
+                normalize_path_buf(root.join(&entry.path)),
+                dist.name().clone(),
+            );
+        }
+    }
+
+    let layout = venv.interpreter().layout();
+    let mut conflicts = Vec::new();
+    for dist in installs.iter().sorted_by(|a, b| a.name().cmp(b.name())) {
+        for path in wheel_targets(dist.path(), &layout)? {
+            match owners.entry(path) {
+                Entry::Occupied(entry) => {
+                    if entry.get() != dist.name() {
+                        conflicts.push(FileConflict {
+                            path: entry.key().clone(),
+                            package: dist.name().clone(),
+                            owner: entry.get().clone(),
+                        });
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(dist.name().clone());
+                }
+            }
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Return the paths that installing the unpacked wheel at `wheel` would create, as listed in its
+/// `RECORD` file, along with the launchers generated for its entry points.
+///
+/// Files at the root of the wheel are installed into `purelib` or `platlib`, per the
+/// `Root-Is-Purelib` field in its `WHEEL` file. Files in the wheel's `.data` directory are mapped
+/// to their destination in the [`Layout`]; headers are omitted, since their destination depends on
+/// the package name.
+#[allow(clippy::result_large_err)]
+fn wheel_targets(wheel: &Path, layout: &Layout) -> Result<Vec<PathBuf>, Error> {
+    let Some(dist_info) = fs_err::read_dir(wheel)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "dist-info"))
+    else {
+        return Ok(Vec::new());
+    };
+    let wheel_text = fs_err::read_to_string(dist_info.join("WHEEL"))?;
+    let root = match parse_wheel_file(&wheel_text).map_err(anyhow::Error::from)? {
+        LibKind::Pure => &layout.scheme.purelib,
+        LibKind::Plat => &layout.scheme.platlib,
+    };
+    let mut record = fs_err::File::open(dist_info.join("RECORD"))?;
+
+    let mut targets = Vec::new();
+    for entry in read_record_file(&mut record).map_err(anyhow::Error::from)? {
+        let target = match entry.path.split_once('/') {
+            Some((data_dir, rest)) if data_dir.ends_with(".data") => {
+                let Some((key, rest)) = rest.split_once('/') else {
+                    continue;
+                };
+                match key {
+                    "purelib" => layout.scheme.purelib.join(rest),
+                    "platlib" => layout.scheme.platlib.join(rest),
+                    "scripts" => layout.scheme.scripts.join(rest),
+                    "data" => layout.scheme.data.join(rest),
+                    _ => continue,
+                }
+            }
+            _ => root.join(&entry.path),
+        };
+        targets.push(normalize_path_buf(target));
+    }
+
+    if let Some(dist_info_prefix) = dist_info.file_stem().and_then(|stem| stem.to_str()) {
+        targets.extend(
+            uv_install_wheel::entrypoint_targets(wheel, dist_info_prefix, layout)
+                .map_err(anyhow::Error::from)?
+                .into_iter()
+                .map(normalize_path_buf),
+        );
+    }
+
+    Ok(targets)
+}
+
+/// Write the paths of all files installed during an operation to a single record file, as in
+/// `pip install --record`.
+///
+/// The paths are read from the `RECORD` file of each installed (or reinstalled) distribution in
+/// the [`Changelog`]. If `relative` is set, the paths are written relative to the installation
+/// prefix; otherwise, they're written as absolute paths.
+#[allow(clippy::result_large_err)]
+pub(crate) fn write_record(
+    changelog: &Changelog,
+    environment: &PythonEnvironment,
+    record: &Path,
+    relative: bool,
+) -> Result<(), Error> {
+    let interpreter = environment.interpreter();
+    let prefix = interpreter
+        .target()
+        .map(Target::root)
+        .or_else(|| interpreter.prefix().map(Prefix::root))
+        .unwrap_or_else(|| environment.root());
+
+    let site_packages = SitePackages::from_environment(environment)?;
+
+    let mut paths = BTreeSet::new();
+    for dist in changelog.installed.iter().chain(&changelog.reinstalled) {
+        for installed in site_packages.get_packages(dist.name()) {
+            let Some(root) = installed.install_path().parent() else {
+                continue;
+            };
+            let mut file = fs_err::File::open(installed.install_path().join("RECORD"))?;
+            for entry in read_record_file(&mut file).map_err(anyhow::Error::from)? {
+                let path = normalize_path_buf(root.join(&entry.path));
+                if relative {
+                    paths.insert(relative_to(&path, prefix)?);
+                } else {
+                    paths.insert(path);
+                }
+            }
+        }
+    }
+
+    let mut contents = String::new();
+    for path in &paths {
+        writeln!(contents, "{}", path.simplified_display())?;
+    }
+    fs_err::write(record, contents)?;
+
+    debug!("Wrote {} paths to: {}", paths.len(), record.user_display());
+
+    Ok(())
+}
+
+/// Strip debug symbols from the native extension modules of any distributions that were installed
+/// or reinstalled.
+///
+/// If `strip` is unavailable, the extension modules are left as-is.
+#[allow(clippy::result_large_err)]
+pub(crate) fn strip_debug(
+    changelog: &Changelog,
+    environment: &PythonEnvironment,
+) -> Result<(), Error> {
+    let Ok(strip) = which::which("strip") else {
+        warn_user!(
+            "`strip` was not found; debug symbols will not be removed from native extension modules"
+        );
+        return Ok(());
+    };
+
+    let site_packages = SitePackages::from_environment(environment)?;
+
+    let mut saved = 0;
+    for dist in changelog.installed.iter().chain(&changelog.reinstalled) {
+        for installed in site_packages.get_packages(dist.name()) {
+            if installed
+                .install_path()
+                .extension()
+                .is_none_or(|extension| extension != "dist-info")
+            {
+                continue;
+            }
+            saved += uv_install_wheel::strip_debug(installed.install_path(), &strip)
+                .map_err(anyhow::Error::from)?;
+        }
+    }
+
+    debug!("Saved {saved} bytes by stripping debug symbols");
+
+    Ok(())
+}
+
+/// The outcome of attempting to lock a [`PythonEnvironment`].
+pub(crate) enum EnvironmentLock {
+    /// The lock was acquired, or could not be created, in which case the environment is left
+    /// unlocked.
+    Acquired(Option<LockedFile>),
+    /// The lock is held by another process, and the caller opted not to wait.
+    Held,
+}
+
+/// Remove the cached distributions for any versions of the packages in the [`Resolution`] other
+/// than the resolved ones, reporting the space that was reclaimed.
+///
+/// Packages that aren't part of the resolution are left untouched. Since pruning runs after the
+/// environment has been synced, any failure is reported as a warning.
+#[allow(clippy::result_large_err)]
+pub(crate) fn prune_cache(
+    resolution: &Resolution,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<(), Error> {
+    let packages = resolution
+        .distributions()
+        .filter_map(|dist| Some((dist.name(), dist.version()?)));
+    let summary = match cache.remove_other_versions(packages) {
+        Ok(summary) => summary,
+        Err(err) => {
+            warn_user!("Failed to prune the cache: {err}");
+            return Ok(());
+        }
+    };
+
+    if summary.num_files == 0 && summary.num_dirs == 0 {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No unused cache entries found".dimmed()
+        )?;
+        return Ok(());
+    }
+
+    let s = if summary.num_files == 1 { "" } else { "s" };
+    let bytes = if summary.total_bytes < 1024 {
+        format!("{}B", summary.total_bytes)
+    } else {
+        let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+        format!("{bytes:.1}{unit}")
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Pruned {} from the cache ({})",
+            format!("{} file{s}", summary.num_files).bold(),
+            bytes.green()
+        )
+        .dimmed()
+    )?;
+    Ok(())
+}
+
+/// Lock the [`PythonEnvironment`] to prevent concurrent modifications across processes.
+///
+/// If the lock is held by another process, wait for it to be released, unless `no_wait` is set.
+pub(crate) async fn lock_environment(
+    venv: &PythonEnvironment,
+    no_wait: bool,
+    printer: Printer,
+) -> Result<EnvironmentLock, Error> {
+    match venv.try_lock() {
+        Ok(Some(lock)) => return Ok(EnvironmentLock::Acquired(Some(lock))),
+        Ok(None) => {}
+        Err(err) => {
+            warn!("Failed to acquire environment lock: {err}");
+            return Ok(EnvironmentLock::Acquired(None));
+        }
+    }
+
+    if no_wait {
+        writeln!(
+            printer.stderr(),
+            "{}{} The environment at {} is locked by another process",
+            "error".red().bold(),
+            ":".bold(),
+            venv.root().user_display().cyan()
+        )?;
+        return Ok(EnvironmentLock::Held);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Waiting for another process to release the lock on: {}",
+        venv.root().user_display().cyan()
+    )?;
+    let lock = venv
+        .lock()
+        .await
+        .inspect_err(|err| {
+            warn!("Failed to acquire environment lock: {err}");
+        })
+        .ok();
+    Ok(EnvironmentLock::Acquired(lock))
+}
+
 /// Display a message about the interpreter that was selected for the operation.
 #[allow(clippy::result_large_err)]
 pub(crate) fn report_interpreter(
@@ -702,36 +1406,70 @@ pub(crate) fn report_target_environment(
     Ok(writeln!(printer.stderr(), "{}", message.dimmed())?)
 }
 
+/// Report on any installed distributions that aren't included in the resolution.
+#[allow(clippy::result_large_err)]
+fn report_extraneous(extraneous: &[InstalledDist], printer: Printer) -> Result<(), Error> {
+    if extraneous.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "Found no extraneous packages".dimmed()
+        )?;
+        return Ok(());
+    }
+
+    let s = if extraneous.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {}",
+            format!("{} extraneous package{s}", extraneous.len()).bold(),
+        )
+        .dimmed()
+    )?;
+    for dist in extraneous
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            "?".yellow(),
+            dist.name().bold(),
+            dist.installed_version().to_string().dimmed()
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Report on the results of a dry-run installation.
 #[allow(clippy::result_large_err)]
 fn report_dry_run(
     dry_run: DryRun,
     resolution: &Resolution,
     plan: Plan,
-    modifications: Modifications,
     start: std::time::Instant,
     printer: Printer,
 ) -> Result<(), Error> {
+    // Nothing to do.
+    if plan.is_empty() {
+        DefaultInstallLogger::default().on_audit(resolution.len(), start, printer)?;
+        writeln!(printer.stderr(), "Would make no changes")?;
+        return Ok(());
+    }
+
+    let uninstalls = plan.uninstalls();
+    let installs = plan.installs();
     let Plan {
         cached,
         remote,
         reinstalls,
         extraneous,
+        satisfied: _,
     } = plan;
 
-    // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
-    };
-
-    // Nothing to do.
-    if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
-        DefaultInstallLogger.on_audit(resolution.len(), start, printer)?;
-        writeln!(printer.stderr(), "Would make no changes")?;
-        return Ok(());
-    }
-
     // Download, build, and unzip any missing distributions.
     let wheels = if remote.is_empty() {
         vec![]
@@ -750,8 +1488,6 @@ fn report_dry_run(
     };
 
     // Remove any upgraded or extraneous installations.
-    let uninstalls = extraneous.len() + reinstalls.len();
-
     if uninstalls > 0 {
         let s = if uninstalls == 1 { "" } else { "s" };
         writeln!(
@@ -766,8 +1502,6 @@ fn report_dry_run(
     }
 
     // Install the resolved distributions.
-    let installs = wheels.len() + cached.len();
-
     if installs > 0 {
         let s = if installs == 1 { "" } else { "s" };
         writeln!(
@@ -836,6 +1570,117 @@ fn report_dry_run(
     Ok(())
 }
 
+/// A package that would be installed, in the JSON report of a dry-run installation.
+#[derive(Debug, Serialize)]
+struct DryRunInstall<'a> {
+    name: &'a PackageName,
+    version: Option<&'a Version>,
+    /// The index or URL from which the package would be installed.
+    source: Option<String>,
+    /// Whether the package would need to be built from a source distribution.
+    requires_build: bool,
+}
+
+/// A package that is already installed, in the JSON report of a dry-run installation.
+#[derive(Debug, Serialize)]
+struct DryRunInstalled<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+}
+
+/// The JSON report of a dry-run installation.
+#[derive(Debug, Serialize)]
+struct DryRunReport<'a> {
+    /// The packages that would be installed (including reinstalls and upgrades).
+    install: Vec<DryRunInstall<'a>>,
+    /// The packages that would be removed (including reinstalls and upgrades).
+    remove: Vec<DryRunInstalled<'a>>,
+    /// The packages that are already installed, and would be left as-is.
+    audit: Vec<DryRunInstalled<'a>>,
+}
+
+/// Report on the results of a dry-run installation in JSON format.
+#[allow(clippy::result_large_err)]
+fn report_dry_run_json(
+    dry_run: DryRun,
+    resolution: &Resolution,
+    plan: Plan,
+    printer: Printer,
+) -> Result<(), Error> {
+    let is_empty = plan.is_empty();
+    let Plan {
+        cached,
+        remote,
+        reinstalls,
+        extraneous,
+        satisfied,
+    } = plan;
+
+    // Distributions in the cache have already been built; remote source distributions have not.
+    let installs = cached
+        .iter()
+        .map(|dist| (dist.name(), false))
+        .chain(
+            remote
+                .iter()
+                .map(|dist| (dist.name(), matches!(dist.as_ref(), Dist::Source(_)))),
+        )
+        .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut install = Vec::new();
+    for (name, requires_build) in installs {
+        let Some(dist) = resolution.distributions().find(|dist| dist.name() == name) else {
+            continue;
+        };
+        let source = match dist.version_or_url() {
+            VersionOrUrlRef::Url(url) => Some(url.to_string()),
+            VersionOrUrlRef::Version(_) => dist.index().map(ToString::to_string),
+        };
+        install.push(DryRunInstall {
+            name: dist.name(),
+            version: dist.version(),
+            source,
+            requires_build,
+        });
+    }
+
+    let remove = reinstalls
+        .iter()
+        .chain(extraneous.iter())
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+        .map(|dist| DryRunInstalled {
+            name: dist.name(),
+            version: dist.version(),
+        })
+        .collect();
+
+    let audit = satisfied
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+        .map(|dist| DryRunInstalled {
+            name: dist.name(),
+            version: dist.version(),
+        })
+        .collect();
+
+    let report = DryRunReport {
+        install,
+        remove,
+        audit,
+    };
+    writeln!(
+        printer.stdout(),
+        "{}",
+        serde_json::to_string(&report).context("Failed to serialize dry-run report")?
+    )?;
+
+    if matches!(dry_run, DryRun::Check) && !is_empty {
+        return Err(Error::OutdatedEnvironment);
+    }
+
+    Ok(())
+}
+
 /// Report any diagnostics on resolved distributions.
 #[allow(clippy::result_large_err)]
 pub(crate) fn diagnose_resolution(
@@ -860,10 +1705,20 @@ pub(crate) fn diagnose_environment(
     resolution: &Resolution,
     venv: &PythonEnvironment,
     markers: &ResolverMarkerEnvironment,
+    warn_conflicts: bool,
     printer: Printer,
 ) -> Result<(), Error> {
     let site_packages = SitePackages::from_environment(venv)?;
-    for diagnostic in site_packages.diagnostics(markers)? {
+    let diagnostics = site_packages.diagnostics(markers)?;
+    for diagnostic in diagnostics
+        .into_iter()
+        .chain(site_packages.mismatched_metadata())
+    {
+        // Skip dependency conflicts, if the user opted out of them.
+        if !warn_conflicts && diagnostic.is_conflict() {
+            continue;
+        }
+
         // Only surface diagnostics that are "relevant" to the current resolution.
         if resolution
             .distributions()
@@ -881,6 +1736,473 @@ pub(crate) fn diagnose_environment(
     Ok(())
 }
 
+/// Report any registry distributions whose wheel and source distribution declare different
+/// dependencies.
+#[allow(clippy::result_large_err)]
+pub(crate) async fn diagnose_metadata_consistency(
+    resolution: &Resolution,
+    hasher: &HashStrategy,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    concurrency: Concurrency,
+    printer: Printer,
+) -> Result<(), Error> {
+    let database = DistributionDatabase::new(client, build_dispatch, concurrency.downloads);
+
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        let Dist::Built(BuiltDist::Registry(wheels)) = dist.as_ref() else {
+            continue;
+        };
+        let Some(sdist) = wheels.sdist.as_ref() else {
+            continue;
+        };
+
+        let wheel_metadata = match database
+            .get_or_build_wheel_metadata(dist, hasher.get(dist.as_ref()))
+            .await
+        {
+            Ok(metadata) => metadata.metadata,
+            Err(err) => {
+                debug!("Skipping metadata consistency check for `{dist}`: {err}");
+                continue;
+            }
+        };
+
+        let sdist = Dist::Source(SourceDist::Registry(sdist.clone()));
+        let sdist_metadata = match database
+            .get_or_build_wheel_metadata(&sdist, hasher.get(&sdist))
+            .await
+        {
+            Ok(metadata) => metadata.metadata,
+            Err(err) => {
+                debug!("Skipping metadata consistency check for `{dist}`: {err}");
+                continue;
+            }
+        };
+
+        let wheel_requires = wheel_metadata
+            .requires_dist
+            .iter()
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>();
+        let sdist_requires = sdist_metadata
+            .requires_dist
+            .iter()
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>();
+        if wheel_requires == sdist_requires {
+            continue;
+        }
+
+        let mut differences = Vec::new();
+        let wheel_only = wheel_requires
+            .difference(&sdist_requires)
+            .collect::<Vec<_>>();
+        if !wheel_only.is_empty() {
+            differences.push(format!(
+                "only the wheel requires {}",
+                wheel_only
+                    .iter()
+                    .map(|requirement| format!("`{requirement}`"))
+                    .join(", ")
+            ));
+        }
+        let sdist_only = sdist_requires
+            .difference(&wheel_requires)
+            .collect::<Vec<_>>();
+        if !sdist_only.is_empty() {
+            differences.push(format!(
+                "only the source distribution requires {}",
+                sdist_only
+                    .iter()
+                    .map(|requirement| format!("`{requirement}`"))
+                    .join(", ")
+            ));
+        }
+
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            format!(
+                "The wheel and source distribution for `{dist}` declare different dependencies ({})",
+                differences.join("; ")
+            )
+            .bold()
+        )?;
+    }
+    Ok(())
+}
+
+/// Report any installed packages that provide a top-level module that shadows a module in the
+/// Python standard library.
+#[allow(clippy::result_large_err)]
+pub(crate) fn diagnose_shadowing(
+    resolution: &Resolution,
+    venv: &PythonEnvironment,
+    printer: Printer,
+) -> Result<(), Error> {
+    let stdlib = stdlib_modules(venv.interpreter().stdlib());
+    if stdlib.is_empty() {
+        debug!(
+            "No standard library modules found at: {}",
+            venv.interpreter().stdlib().user_display()
+        );
+        return Ok(());
+    }
+
+    let site_packages = SitePackages::from_environment(venv)?;
+    for dist in resolution.distributions() {
+        for installed in site_packages.get_packages(dist.name()) {
+            for module in top_level_modules(installed.install_path()) {
+                if !stdlib.contains(&module) {
+                    continue;
+                }
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    format!(
+                        "The package `{}` provides a top-level module `{module}`, which shadows a module in the Python standard library",
+                        installed.name()
+                    )
+                    .bold()
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Report any top-level modules that are provided by more than one installed package, where at
+/// least one of the packages is part of the [`Resolution`].
+#[allow(clippy::result_large_err)]
+pub(crate) fn diagnose_import_conflicts(
+    resolution: &Resolution,
+    venv: &PythonEnvironment,
+    printer: Printer,
+) -> Result<(), Error> {
+    let site_packages = SitePackages::from_environment(venv)?;
+
+    // Map each top-level module to the packages that provide it.
+    let mut providers: BTreeMap<String, BTreeSet<&PackageName>> = BTreeMap::new();
+    for installed in site_packages.iter() {
+        let Some(root) = installed.install_path().parent() else {
+            continue;
+        };
+        for module in top_level_modules(installed.install_path()) {
+            // Namespace packages are designed to be shared across distributions.
+            let path = root.join(&module);
+            if path.is_dir() && !path.join("__init__.py").is_file() {
+                continue;
+            }
+            providers
+                .entry(module)
+                .or_default()
+                .insert(installed.name());
+        }
+    }
+
+    for (module, packages) in providers {
+        if packages.len() < 2 {
+            continue;
+        }
+        if !resolution
+            .distributions()
+            .any(|dist| packages.contains(dist.name()))
+        {
+            continue;
+        }
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            format!(
+                "Multiple packages provide the top-level module `{module}`: {}",
+                packages
+                    .iter()
+                    .map(|package| format!("`{package}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .bold()
+        )?;
+    }
+    Ok(())
+}
+
+/// Report any shared libraries that the extension modules of the packages in the [`Resolution`]
+/// depend on, but that can't be located by the dynamic loader.
+///
+/// On Linux, the dependencies are resolved by invoking the interpreter's dynamic loader with
+/// `--list`, which (unlike `ldd`) never executes the module or a loader that it requests. On
+/// macOS, they're read with `otool -L`. On other platforms, the check is skipped.
+#[allow(clippy::result_large_err)]
+pub(crate) fn diagnose_native_dependencies(
+    resolution: &Resolution,
+    venv: &PythonEnvironment,
+    printer: Printer,
+) -> Result<(), Error> {
+    let inspector = if cfg!(target_os = "macos") {
+        NativeInspector::Otool
+    } else if cfg!(target_os = "linux") {
+        let Some(loader) = uv_python::find_ld_path_at(venv.interpreter().sys_executable()) else {
+            debug!(
+                "Skipping native dependency check, as the dynamic loader for the interpreter could not be determined"
+            );
+            return Ok(());
+        };
+        NativeInspector::Loader(loader)
+    } else {
+        debug!("Skipping native dependency check, which is only supported on Linux and macOS");
+        return Ok(());
+    };
+
+    let site_packages = SitePackages::from_environment(venv)?;
+    for dist in resolution.distributions() {
+        for installed in site_packages.get_packages(dist.name()) {
+            for (relative, module) in extension_modules(installed.install_path()) {
+                let missing = match inspector.missing_shared_libraries(&module) {
+                    Ok(missing) => missing,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        debug!(
+                            "Skipping native dependency check, as the inspection tool is not available: {err}"
+                        );
+                        return Ok(());
+                    }
+                    Err(err) => {
+                        debug!(
+                            "Failed to inspect extension module {}: {err}",
+                            module.user_display()
+                        );
+                        continue;
+                    }
+                };
+                if missing.is_empty() {
+                    continue;
+                }
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    format!(
+                        "The extension module `{relative}` in `{}` depends on shared libraries that could not be found: {}",
+                        installed.name(),
+                        missing.iter().map(|library| format!("`{library}`")).join(", ")
+                    )
+                    .bold()
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Return the extension modules listed in the `RECORD` of an installed distribution, as pairs of
+/// the `RECORD` entry and the absolute path to the module.
+fn extension_modules(install_path: &Path) -> Vec<(String, PathBuf)> {
+    let Some(root) = install_path.parent() else {
+        return Vec::new();
+    };
+    installed_record(install_path)
+        .into_iter()
+        .filter(|entry| {
+            Path::new(&entry.path)
+                .extension()
+                .is_some_and(|extension| extension == "so")
+        })
+        .map(|entry| {
+            let path = root.join(&entry.path);
+            (entry.path, path)
+        })
+        .collect()
+}
+
+/// Read the `RECORD` of an installed distribution, returning an empty list (and logging the
+/// failure) if it's missing or can't be parsed.
+fn installed_record(install_path: &Path) -> Vec<RecordEntry> {
+    let Ok(mut record) = fs_err::File::open(install_path.join("RECORD")) else {
+        return Vec::new();
+    };
+    match read_record_file(&mut record) {
+        Ok(record) => record,
+        Err(err) => {
+            debug!(
+                "Failed to read `RECORD` at {}: {err}",
+                install_path.user_display()
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// The tool used to inspect the shared library dependencies of an extension module.
+enum NativeInspector {
+    /// The dynamic loader at the given path, invoked with `--list` (Linux).
+    Loader(PathBuf),
+    /// `otool -L` (macOS).
+    Otool,
+}
+
+impl NativeInspector {
+    /// Return the shared libraries that an extension module links against, but that can't be
+    /// located.
+    fn missing_shared_libraries(&self, module: &Path) -> Result<Vec<String>, std::io::Error> {
+        match self {
+            Self::Otool => {
+                // Ex) `\t@rpath/libffi.8.dylib (compatibility version 10.0.0, current version 10.2.0)`
+                let output = std::process::Command::new("otool")
+                    .arg("-L")
+                    .arg(module)
+                    .output()?;
+                if !output.status.success() {
+                    return Err(std::io::Error::other(
+                        String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                    ));
+                }
+                let parent = module.parent().unwrap_or(module);
+                Ok(String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .skip(1)
+                    .filter_map(|line| {
+                        let library = line.trim().split(" (").next()?;
+                        let path = if let Some(relative) = library.strip_prefix("@loader_path/") {
+                            parent.join(relative)
+                        } else if library.starts_with('@') {
+                            // Resolving `@rpath` requires the module's load commands; assume it's
+                            // present.
+                            return None;
+                        } else if library.starts_with("/usr/lib/")
+                            || library.starts_with("/System/")
+                        {
+                            // System libraries live in the dyld shared cache, rather than on disk.
+                            return None;
+                        } else {
+                            PathBuf::from(library)
+                        };
+                        (!path.exists()).then(|| library.to_string())
+                    })
+                    .collect())
+            }
+            Self::Loader(loader) => {
+                let output = std::process::Command::new(loader)
+                    .arg("--list")
+                    .arg(module)
+                    .output()?;
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+
+                // glibc reports missing libraries on stdout, and musl on stderr:
+                // Ex) `\tlibffi.so.7 => not found`
+                // Ex) `Error loading shared library libffi.so.7: No such file or directory (needed by ...)`
+                let missing = stdout
+                    .lines()
+                    .filter_map(|line| line.trim().strip_suffix("=> not found"))
+                    .chain(stderr.lines().filter_map(|line| {
+                        line.strip_prefix("Error loading shared library ")?
+                            .split_once(": No such file or directory")
+                            .map(|(library, _)| library)
+                    }))
+                    .map(|library| library.trim().to_string())
+                    .collect::<Vec<_>>();
+                if missing.is_empty() && !output.status.success() {
+                    return Err(std::io::Error::other(stderr.trim().to_string()));
+                }
+                Ok(missing)
+            }
+        }
+    }
+}
+
+/// Return the names of the top-level modules in the standard library directory.
+fn stdlib_modules(stdlib: &Path) -> HashSet<String> {
+    let mut modules = HashSet::new();
+    let Ok(entries) = fs_err::read_dir(stdlib) else {
+        return modules;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_dir() {
+            if name == "lib-dynload" {
+                // Ex) `lib-dynload/_ssl.cpython-312-x86_64-linux-gnu.so`
+                let Ok(extensions) = fs_err::read_dir(&path) else {
+                    continue;
+                };
+                for extension in extensions.flatten() {
+                    if let Some(module) = extension.file_name().to_str().and_then(module_name) {
+                        modules.insert(module.to_string());
+                    }
+                }
+            } else if path.join("__init__.py").is_file() {
+                // Ex) `json/__init__.py`
+                modules.insert(name.to_string());
+            }
+        } else if let Some(module) = module_name(name) {
+            // Ex) `colorsys.py`
+            modules.insert(module.to_string());
+        }
+    }
+    modules
+}
+
+/// Return the top-level modules provided by an installed distribution, as declared in its
+/// `top_level.txt` or, if absent, as inferred from its `RECORD`.
+fn top_level_modules(install_path: &Path) -> BTreeSet<String> {
+    if let Ok(top_level) = fs_err::read_to_string(install_path.join("top_level.txt")) {
+        return top_level
+            .lines()
+            .filter_map(|line| line.trim().split(['/', '\\']).next())
+            .filter(|module| !module.is_empty())
+            .map(ToString::to_string)
+            .collect();
+    }
+
+    installed_record(install_path)
+        .iter()
+        .filter_map(|entry| {
+            let mut components = entry.path.split(['/', '\\']);
+            let first = components.next()?;
+            if components.next().is_some() {
+                // Ex) `foo/__init__.py`
+                if first.is_empty()
+                    || first == ".."
+                    || first == "__pycache__"
+                    || first.ends_with(".dist-info")
+                    || first.ends_with(".data")
+                {
+                    return None;
+                }
+                Some(first)
+            } else {
+                // Ex) `foo.py` or `foo.cpython-312-x86_64-linux-gnu.so`
+                module_name(first)
+            }
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Return the module name for a Python source file or extension module (e.g., `json` for
+/// `json.py`).
+fn module_name(filename: &str) -> Option<&str> {
+    let (_, extension) = filename.rsplit_once('.')?;
+    if !matches!(extension, "py" | "so" | "pyd") {
+        return None;
+    }
+    let (module, _) = filename.split_once('.')?;
+    (!module.is_empty()).then_some(module)
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum Error {
     #[error("Failed to prepare distributions")]
@@ -1,45 +1,81 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
+use console::Term;
+use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::{debug, warn};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use tokio::sync::Semaphore;
+use tracing::debug;
 
-use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_cache::{Cache, CacheBucket, Refresh};
+use uv_cli::{OnConflict, UpgradesFormat};
+use uv_client::{
+    BaseClientBuilder, FlatIndexClient, FlatIndexEntries, MetadataFormat, RegistryClient,
+    RegistryClientBuilder, VersionFiles,
+};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
     HashCheckingMode, IndexStrategy, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
     Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, MarkerOverride, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, Origin, Resolution};
+use uv_distribution::{DistributionDatabase, RegistryWheelIndex};
+use uv_distribution_types::{
+    BuiltDist, DependencyMetadata, Dist, Index, IndexCapabilities, IndexLocations,
+    IndexMetadataRef, InstalledDist, Name, NameRequirementSpecification, Origin, Requirement,
+    RequirementSource, RequiresPython, Resolution, ResolvedDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
+};
 use uv_fs::Simplified;
+use uv_git::GitResolver;
+use uv_git_types::{GitOid, GitReference, GitUrl};
 use uv_install_wheel::LinkMode;
-use uv_installer::SitePackages;
+use uv_installer::{SitePackages, is_stale_local_wheel};
 use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_pep440::{Operator, Version, VersionSpecifiers};
 use uv_pep508::PackageName;
-use uv_pypi_types::Conflicts;
+use uv_platform_tags::{PlatformTag, Tags};
+use uv_pypi_types::{
+    Conflicts, ParsedGitUrl, ParsedUrl, ResolutionMetadata, ResolverMarkerEnvironment, Yanked,
+};
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, Interpreter, Prefix, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, Root, Target,
+};
+use uv_requirements::{
+    GroupsSpecification, RequirementsSource, RequirementsSpecification, checkout_git_editables,
 };
-use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
+use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
     PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
 use uv_torch::{TorchMode, TorchStrategy};
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
+use crate::commands::pip::operations::{EnvironmentLock, InstallFlags, Modifications};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::pip::tree::DisplayDependencyGraph;
+use crate::commands::pip::{
+    check_python_version, operations, resolution_markers, resolution_tags,
+    warn_platform_tag_overrides, with_marker_overrides, with_platform_tags,
+};
+use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::{ExitStatus, diagnostics, human_readable_bytes, read_env_files};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
 
@@ -54,9 +90,14 @@ pub(crate) async fn pip_sync(
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    install_flags: InstallFlags<'_>,
+    exclude_dependency: Vec<PackageName>,
+    exclude_hashes_for: Vec<PackageName>,
+    fail_on_missing_wheel_for: Vec<PackageName>,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    resolution_mode: ResolutionMode,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
@@ -71,31 +112,85 @@ pub(crate) async fn pip_sync(
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     strict: bool,
+    warn_conflicts: bool,
+    warn_shadowing: bool,
+    warn_import_conflicts: bool,
+    check_native_deps: bool,
+    verify_metadata_consistency: bool,
+    show_upgrades: bool,
+    upgrades_format: UpgradesFormat,
+    explain: Vec<PackageName>,
+    no_wait: bool,
+    report_unused_constraints: bool,
+    on_conflict: OnConflict,
     exclude_newer: Option<ExcludeNewer>,
     python: Option<String>,
+    verify_python_version: Option<VersionSpecifiers>,
+    venv: Option<String>,
+    venv_dir: Option<PathBuf>,
+    create: bool,
+    platform_tag: Vec<PlatformTag>,
+    marker: Vec<MarkerOverride>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    root: Option<Root>,
     sources: SourceStrategy,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    download_only: Option<PathBuf>,
+    locked: bool,
+    no_resolve: bool,
+    verify_no_downgrade: bool,
+    verify_no_yanked_in_lock: bool,
+    verify_interpreter_abi: bool,
+    warn_on_unpinned: bool,
+    require_pinned: bool,
+    allow_sdist_fallback: bool,
+    emit_build_tags: bool,
+    max_artifact_size: Option<u64>,
+    warn_slow_index: Option<Duration>,
+    warn_install_size: Option<u64>,
+    warn_install_count: Option<usize>,
+    error_on_large_install: bool,
+    verify_clean: Option<PathBuf>,
+    record: Option<PathBuf>,
+    record_relative: bool,
+    record_build_env: Option<PathBuf>,
+    suggest_cached: bool,
+    max_resolution_rounds: Option<usize>,
+    prune_unused_wheels_from_cache: bool,
+    strip_debug: bool,
+    exclude_installed: bool,
+    reinstall_editables: bool,
+    reinstall_foreign: bool,
+    reinstall_if_corrupt: bool,
+    no_editable: bool,
+    env_file: Vec<PathBuf>,
+    env_file_override: bool,
+    isolated: bool,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
+    // Read from the `.env` files, if provided, before any requirements or markers are evaluated.
+    read_env_files(&env_file, env_file_override)?;
+
     let client_builder = BaseClientBuilder::new()
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout)
+        .ambient_credentials(!isolated);
 
     // Initialize a few defaults.
     let overrides = &[];
     let upgrade = Upgrade::default();
-    let resolution_mode = ResolutionMode::default();
     let prerelease_mode = PrereleaseMode::default();
     let dependency_mode = DependencyMode::Direct;
 
@@ -103,6 +198,7 @@ pub(crate) async fn pip_sync(
     let RequirementsSpecification {
         project,
         requirements,
+        git_editables,
         constraints,
         overrides,
         pylock,
@@ -133,10 +229,122 @@ pub(crate) async fn pip_sync(
         }
     }
 
+    // If the environment must already be in sync, validate that every requirement is pinned.
+    if locked {
+        if let Some(source_tree) = source_trees.first() {
+            return Err(anyhow::anyhow!(
+                "`--locked` requires pinned requirements, but found a source tree: `{}`",
+                source_tree.user_display()
+            ));
+        }
+        if let Some(requirement) = requirements
+            .iter()
+            .find(|requirement| !is_pinned(&requirement.requirement))
+        {
+            return Err(anyhow::anyhow!(
+                "`--locked` requires all requirements to be pinned to an exact version, but found: `{}`",
+                requirement.requirement
+            ));
+        }
+    }
+
+    // Report any requirements that aren't pinned to an exact version, if requested.
+    if warn_on_unpinned || require_pinned {
+        let unpinned = requirements
+            .iter()
+            .filter(|requirement| !is_pinned(&requirement.requirement))
+            .collect::<Vec<_>>();
+        if require_pinned && !unpinned.is_empty() {
+            let mut message = format!(
+                "The following {} not pinned to an exact version, but `--require-pinned` was provided:",
+                if unpinned.len() == 1 {
+                    "requirement is"
+                } else {
+                    "requirements are"
+                }
+            );
+            for requirement in unpinned {
+                write!(message, "\n  {}", requirement.requirement)?;
+            }
+            return Err(anyhow::anyhow!(message));
+        }
+        for requirement in unpinned {
+            warn_user!(
+                "Requirement `{}` is not pinned to an exact version (e.g., with `==`)",
+                requirement.requirement
+            );
+        }
+    }
+
+    // If the requirements must be installed as recorded, validate that none of them require an
+    // index lookup.
+    if no_resolve {
+        if let Some(source_tree) = source_trees.first() {
+            return Err(anyhow::anyhow!(
+                "`--no-resolve` requires pinned requirements, but found a source tree: `{}`",
+                source_tree.user_display()
+            ));
+        }
+        if let Some(requirement) = requirements
+            .iter()
+            .find(|requirement| !has_direct_source(&requirement.requirement))
+        {
+            return Err(anyhow::anyhow!(
+                "`--no-resolve` requires all requirements to be pinned to a direct URL or path, but found: `{}`",
+                requirement.requirement
+            ));
+        }
+    }
+
+    // If `--no-editable` is set, install any editable requirements as regular builds.
+    let requirements = if no_editable {
+        requirements
+            .into_iter()
+            .map(|entry| UnresolvedRequirementSpecification {
+                requirement: entry.requirement.into_non_editable(),
+                ..entry
+            })
+            .collect()
+    } else {
+        requirements
+    };
+
+    // If requested, retain the constraints, to report any that go unused in the resolution.
+    let reported_constraints =
+        operations::reported_constraints(&constraints, report_unused_constraints);
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
 
+    // If `--reinstall-editables` is set, rebuild and reinstall every editable requirement, leaving
+    // all other packages untouched.
+    let (reinstall, cache) = if reinstall_editables {
+        let editables = requirements
+            .iter()
+            .filter(|entry| entry.requirement.is_editable())
+            .filter_map(|entry| match entry.requirement.source().as_ref() {
+                RequirementSource::Directory { install_path, .. } => Some(install_path.clone()),
+                _ => None,
+            })
+            .fold(Reinstall::None, Reinstall::with_path);
+        // Editable Git requirements are only checked out later, so select them by name.
+        let editables = git_editables
+            .iter()
+            .filter_map(|entry| match &entry.requirement {
+                RequirementsTxtRequirement::Named(requirement) => Some(requirement.name.clone()),
+                RequirementsTxtRequirement::Unnamed(_) => None,
+            })
+            .fold(editables, Reinstall::with_package);
+        let refresh = cache
+            .refresh()
+            .clone()
+            .combine(Refresh::from(editables.clone()));
+        (reinstall.combine(editables), cache.with_refresh(refresh))
+    } else {
+        (reinstall, cache)
+    };
+
     // Validate that the requirements are non-empty.
     if !allow_empty_requirements {
         let num_requirements =
@@ -151,7 +359,7 @@ pub(crate) async fn pip_sync(
     }
 
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if target.is_some() || prefix.is_some() || root.is_some() {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
@@ -164,6 +372,18 @@ pub(crate) async fn pip_sync(
         )?;
         report_interpreter(&installation, true, printer)?;
         PythonEnvironment::from_installation(installation)
+    } else if let Some(name) = venv.as_deref() {
+        let environment = find_named_environment(
+            name,
+            venv_dir.as_deref(),
+            create,
+            python.as_deref(),
+            python_preference,
+            &cache,
+            preview,
+        )?;
+        report_target_environment(&environment, &cache, printer)?;
+        environment
     } else {
         let environment = PythonEnvironment::find(
             &python
@@ -178,6 +398,11 @@ pub(crate) async fn pip_sync(
         environment
     };
 
+    // Verify the interpreter's version before resolving or installing anything.
+    if let Some(specifiers) = verify_python_version.as_ref() {
+        check_python_version(environment.interpreter(), specifiers)?;
+    }
+
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
         debug!(
@@ -195,6 +420,14 @@ pub(crate) async fn pip_sync(
         environment
     };
 
+    // Apply any `--root` directory, beneath which the installation is staged.
+    let environment = if let Some(root) = root {
+        debug!("Using `--root` directory at {}", root.root().user_display());
+        environment.with_root(root)?
+    } else {
+        environment
+    };
+
     // If the environment is externally managed, abort.
     if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
         if break_system_packages {
@@ -215,13 +448,10 @@ pub(crate) async fn pip_sync(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = match operations::lock_environment(&environment, no_wait, printer).await? {
+        EnvironmentLock::Acquired(lock) => lock,
+        EnvironmentLock::Held => return Ok(ExitStatus::Locked),
+    };
 
     let interpreter = environment.interpreter();
 
@@ -238,24 +468,44 @@ pub(crate) async fn pip_sync(
         python_platform.as_ref(),
         interpreter,
     );
-    let tags = resolution_tags(
+    let base_tags = resolution_tags(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
     )?;
 
+    // Extend the tags with any additional platform tags, if requested.
+    let tags = with_platform_tags(base_tags.clone(), &platform_tag);
+
+    // Override any marker variables, if requested.
+    let marker_env = with_marker_overrides(marker_env, &marker);
+
+    // If requested, keep only one of any requirements that pin the same package to different
+    // versions.
+    let requirements = resolve_conflicting_pins(requirements, &marker_env, on_conflict);
+
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
+        // Skip any packages that are exempt from the hash requirement. Unnamed requirements are
+        // never exempt, since their names aren't known until they're built.
         HashStrategy::from_requirements(
             requirements
                 .iter()
+                .filter(|entry| match &entry.requirement {
+                    UnresolvedRequirement::Named(requirement) => {
+                        !exclude_hashes_for.contains(&requirement.name)
+                    }
+                    UnresolvedRequirement::Unnamed(_) => true,
+                })
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             constraints
                 .iter()
+                .filter(|entry| !exclude_hashes_for.contains(&entry.requirement.name))
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             hash_checking,
         )?
+        .with_exemptions(exclude_hashes_for)
     } else {
         HashStrategy::None
     };
@@ -298,21 +548,43 @@ pub(crate) async fn pip_sync(
         .index_locations(&index_locations)
         .index_strategy(index_strategy)
         .torch_backend(torch_backend.clone())
+        .max_artifact_size(max_artifact_size)
+        .warn_slow_index(warn_slow_index)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
 
+    // Check out any editable Git requirements, unless this is a dry run or editables are installed
+    // as regular builds.
+    let requirements = if dry_run.enabled() || no_editable {
+        requirements
+    } else {
+        checkout_git_editables(
+            requirements,
+            git_editables,
+            &GitResolver::default(),
+            &client,
+            &cache.bucket(CacheBucket::Git),
+        )
+        .await?
+    };
+
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
-    let build_options = build_options.combine(no_binary, no_build);
+    let mut build_options = build_options.combine(no_binary, no_build);
 
     // Resolve the flat indexes from `--find-links`.
-    let flat_index = {
+    let flat_index_entries = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
-        let entries = client
+        client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
-            .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+            .await?
     };
+    let mut flat_index = FlatIndex::from_entries(
+        flat_index_entries.clone(),
+        Some(&tags),
+        &hasher,
+        &build_options,
+    );
 
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
@@ -343,37 +615,81 @@ pub(crate) async fn pip_sync(
             .map(|constraint| constraint.requirement.clone()),
     );
 
-    // Initialize any shared state.
-    let state = SharedState::default();
-
-    // Create a build dispatch.
-    let build_dispatch = BuildDispatch::new(
-        &client,
-        &cache,
-        build_constraints,
-        interpreter,
-        &index_locations,
-        &flat_index,
-        &dependency_metadata,
-        state.clone(),
-        index_strategy,
-        config_settings,
-        config_settings_package,
-        build_isolation,
-        link_mode,
-        &build_options,
-        &build_hasher,
-        exclude_newer,
-        sources,
-        WorkspaceCache::default(),
-        concurrency,
-        preview,
-    );
-
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
-    let (resolution, hasher) = if let Some(pylock) = pylock {
+    // If requested, verify that the environment matches the reference manifest before making any
+    // changes.
+    if let Some(manifest) = verify_clean.as_deref() {
+        let source = RequirementsSource::from_requirements_file(manifest.to_path_buf())?;
+        let specification =
+            RequirementsSpecification::from_source(&source, &client_builder).await?;
+        let drift = find_drift(&site_packages, &specification.requirements, &marker_env);
+        if !drift.is_empty() {
+            let mut message = format!(
+                "The environment does not match `{}`, but `--verify-clean` was provided:",
+                manifest.user_display()
+            );
+            for entry in drift {
+                write!(message, "\n  - {entry}")?;
+            }
+            return Err(anyhow::anyhow!(message));
+        }
+    }
+
+    // Reinstall any packages whose wheel in a `--find-links` directory has been rebuilt since it
+    // was installed.
+    let reinstall = with_stale_find_links(reinstall, &site_packages, &flat_index);
+
+    // If `--reinstall-foreign` is set, reinstall any packages that were installed by another tool.
+    let reinstall = if reinstall_foreign {
+        with_foreign_installer(
+            reinstall,
+            &site_packages,
+            install_flags.installer_name.unwrap_or("uv"),
+        )
+    } else {
+        reinstall
+    };
+
+    // If `--reinstall-if-corrupt` is set, reinstall any packages whose files don't match their
+    // `RECORD`.
+    let reinstall = if reinstall_if_corrupt {
+        with_corrupt_files(
+            reinstall,
+            &requirements,
+            &site_packages,
+            &marker_env,
+            concurrency,
+        )
+        .await?
+    } else {
+        reinstall
+    };
+
+    // If `--exclude-installed` is set, leave any requirement that's already satisfied by an
+    // installed distribution untouched, even if it would otherwise be reinstalled.
+    let reinstall = if exclude_installed {
+        without_installed(
+            reinstall,
+            &requirements,
+            &constraints,
+            &site_packages,
+            &marker_env,
+        )
+    } else {
+        reinstall
+    };
+
+    // If `--download-only` is set, ignore any installed distributions, since they can't be written
+    // to the destination directory.
+    let reinstall = if download_only.is_some() {
+        Reinstall::All
+    } else {
+        reinstall
+    };
+
+    let (resolution, hasher, state) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
         let install_path = std::path::absolute(&pylock)?;
         let install_path = install_path.parent().unwrap();
@@ -420,64 +736,275 @@ pub(crate) async fn pip_sync(
         )?;
         let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
 
-        (resolution, hasher)
+        (resolution, hasher, SharedState::default())
     } else {
-        // When resolving, don't take any external preferences into account.
-        let preferences = Vec::default();
-
-        let options = OptionsBuilder::new()
-            .resolution_mode(resolution_mode)
-            .prerelease_mode(prerelease_mode)
-            .dependency_mode(dependency_mode)
-            .exclude_newer(exclude_newer)
-            .index_strategy(index_strategy)
-            .torch_backend(torch_backend)
-            .build_options(build_options.clone())
-            .build();
-
-        let resolution = match operations::resolve(
-            requirements,
-            constraints,
-            overrides,
-            source_trees,
-            project,
-            BTreeSet::default(),
-            extras,
-            &groups,
-            preferences,
-            site_packages.clone(),
+        // Resolve the requirements with the given build options, using a fresh build dispatch for
+        // each attempt.
+        let resolve = async |build_options: &BuildOptions,
+                             flat_index: &FlatIndex|
+               -> Result<(Resolution, SharedState), operations::Error> {
+            // Initialize any shared state.
+            let state = SharedState::default();
+
+            // Create a build dispatch.
+            let build_dispatch = BuildDispatch::new(
+                &client,
+                &cache,
+                build_constraints.clone(),
+                interpreter,
+                &index_locations,
+                flat_index,
+                &dependency_metadata,
+                state.clone(),
+                index_strategy,
+                config_settings,
+                config_settings_package,
+                build_isolation,
+                link_mode,
+                build_options,
+                &build_hasher,
+                exclude_newer,
+                sources,
+                WorkspaceCache::default(),
+                concurrency,
+                preview,
+            )
+            .with_record_build_env(record_build_env.clone());
+
+            // When resolving, don't take any external preferences into account.
+            let preferences = Vec::default();
+
+            let options = OptionsBuilder::new()
+                .resolution_mode(resolution_mode)
+                .prerelease_mode(prerelease_mode)
+                .dependency_mode(dependency_mode)
+                .exclude_newer(exclude_newer)
+                .index_strategy(index_strategy)
+                .torch_backend(torch_backend.clone())
+                .build_options(build_options.clone())
+                .suggest_cached(suggest_cached)
+                .max_rounds(max_resolution_rounds)
+                .build();
+
+            let resolution = operations::resolve(
+                requirements.clone(),
+                constraints.clone(),
+                overrides.clone(),
+                source_trees.clone(),
+                project.clone(),
+                BTreeSet::default(),
+                extras,
+                &groups,
+                preferences,
+                site_packages.clone(),
+                &hasher,
+                &reinstall,
+                &upgrade,
+                Some(&tags),
+                ResolverEnvironment::specific(marker_env.clone()),
+                python_requirement.clone(),
+                interpreter.markers(),
+                Conflicts::empty(),
+                &client,
+                flat_index,
+                state.index(),
+                &build_dispatch,
+                concurrency,
+                options,
+                Box::new(DefaultResolveLogger),
+                printer,
+            )
+            .await?;
+
+            Ok((Resolution::from(resolution), state))
+        };
+
+        let (resolution, state) = match resolve_with_sdist_fallback(
+            resolve,
+            allow_sdist_fallback,
+            &mut build_options,
+            &mut flat_index,
+            &flat_index_entries,
+            &tags,
             &hasher,
-            &reinstall,
-            &upgrade,
-            Some(&tags),
-            ResolverEnvironment::specific(marker_env.clone()),
-            python_requirement,
-            interpreter.markers(),
-            Conflicts::empty(),
-            &client,
-            &flat_index,
-            state.index(),
-            &build_dispatch,
-            concurrency,
-            options,
-            Box::new(DefaultResolveLogger),
-            printer,
         )
         .await
         {
-            Ok(resolution) => Resolution::from(resolution),
+            Ok(resolution) => resolution,
             Err(err) => {
+                // Without network access, only suggest the versions with artifacts in the cache.
+                let err = match err {
+                    operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(
+                        mut no_solution,
+                    )) if suggest_cached && client.connectivity().is_offline() => {
+                        let cached_versions = cached_versions(
+                            no_solution.packages(),
+                            &flat_index,
+                            &cache,
+                            &tags,
+                            &index_locations,
+                            &hasher,
+                            config_settings,
+                        );
+                        no_solution.set_cached_versions(cached_versions);
+                        operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(
+                            no_solution,
+                        ))
+                    }
+                    err => err,
+                };
+
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .with_printer(printer)
                     .report(err)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
         };
 
-        (resolution, hasher)
+        (resolution, hasher, state)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        build_constraints,
+        interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        build_isolation,
+        link_mode,
+        &build_options,
+        &build_hasher,
+        exclude_newer,
+        sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    )
+    .with_record_build_env(record_build_env.clone());
+
+    // Notify the user of any constraints that don't apply to the resolution.
+    operations::report_unused_constraints(
+        &reported_constraints,
+        |name| resolution.distributions().any(|dist| dist.name() == name),
+        printer,
+    )?;
+
+    // Drop any excluded dependencies, along with anything only they require.
+    let resolution = if exclude_dependency.is_empty() {
+        resolution
+    } else {
+        let packages = resolution_metadata(
+            &resolution,
+            &hasher,
+            &client,
+            &build_dispatch,
+            concurrency,
+            true,
+        )
+        .await;
+        let excluded = excluded_dependencies(&exclude_dependency, &packages, &marker_env);
+        resolution.filter(|dist| !excluded.contains(dist.name()))
+    };
+
+    // Warn about any wheels that are only installable due to `--platform-tag`.
+    if !platform_tag.is_empty() {
+        warn_platform_tag_overrides(&resolution, &base_tags);
+    }
+
+    // Warn about any packages whose wheel and source distribution declare different dependencies.
+    if verify_metadata_consistency {
+        operations::diagnose_metadata_consistency(
+            &resolution,
+            &hasher,
+            &client,
+            &build_dispatch,
+            concurrency,
+            printer,
+        )
+        .await?;
+    }
+
+    // Refuse to downgrade any installed packages, if requested.
+    if verify_no_downgrade {
+        verify_no_downgrades(&resolution, &site_packages)?;
+    }
+
+    // Refuse to install any wheels that aren't built for the interpreter's ABI, if requested.
+    if verify_interpreter_abi {
+        verify_interpreter_abis(&resolution, interpreter)?;
+    }
+
+    // If `--download-only` is set, write the distributions to the destination directory, rather
+    // than installing them.
+    if let Some(dest) = download_only.as_deref() {
+        if let Err(err) = operations::download(
+            &resolution,
+            &build_options,
+            &hasher,
+            &tags,
+            &client,
+            state.in_flight(),
+            concurrency,
+            &build_dispatch,
+            &cache,
+            dest,
+            printer,
+        )
+        .await
+        {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .with_printer(printer)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Refuse to build any of the given packages from source, if requested.
+    if !fail_on_missing_wheel_for.is_empty() {
+        verify_wheels_for(&resolution, &fail_on_missing_wheel_for)?;
+    }
+
+    // Warn (or fail) if the environment exceeds the configured size or count thresholds.
+    if warn_install_size.is_some() || warn_install_count.is_some() {
+        check_install_size(
+            &resolution,
+            warn_install_size,
+            warn_install_count,
+            error_on_large_install,
+        )?;
+    }
+
+    // Refuse to install any versions that have since been yanked, if requested.
+    if verify_no_yanked_in_lock {
+        if client.connectivity().is_offline() {
+            debug!("Skipping yank check due to `--offline`");
+        } else {
+            verify_no_yanked(&resolution, &client, concurrency).await?;
+        }
+    }
+
+    // Annotate each installed package with how it was obtained, if requested.
+    let logger: Box<dyn InstallLogger> = if emit_build_tags {
+        Box::new(DefaultInstallLogger::with_build_tags(&resolution))
+    } else {
+        Box::new(DefaultInstallLogger::default())
     };
 
+    // On Windows, scripts are launched via `.exe` wrappers, which can't be sandboxed.
+    if install_flags.sandbox_scripts && cfg!(windows) {
+        warn_user!("`--sandbox-scripts` is not supported on Windows; scripts will be left as-is");
+    }
+
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         Modifications::Exact,
@@ -485,6 +1012,7 @@ pub(crate) async fn pip_sync(
         &build_options,
         link_mode,
         compile,
+        install_flags,
         &index_locations,
         config_settings,
         config_settings_package,
@@ -496,19 +1024,63 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        logger,
         installer_metadata,
         dry_run,
         printer,
     )
     .await
     {
-        Ok(_) => {}
+        Ok(changelog) => changelog,
+        Err(operations::Error::OutdatedEnvironment) if locked => {
+            return Err(anyhow::anyhow!(
+                "The environment is not in sync with the requirements, but `--locked` was provided"
+            ));
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .with_printer(printer)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
+    };
+
+    // Strip debug symbols from any installed extension modules, if requested.
+    if strip_debug && !dry_run.enabled() {
+        operations::strip_debug(&changelog, &environment)?;
+    }
+
+    // Write the combined record of installed files, if requested.
+    if let Some(record) = record.as_deref() {
+        if !dry_run.enabled() {
+            operations::write_record(&changelog, &environment, record, record_relative)?;
+        }
+    }
+
+    // Remove the cached distributions for any other versions of the synced packages, if requested.
+    if prune_unused_wheels_from_cache && !dry_run.enabled() {
+        operations::prune_cache(&resolution, &cache, printer)?;
+    }
+
+    // Notify the user of any distributions that were skipped due to build failures.
+    if !changelog.skipped.is_empty() {
+        let s = if changelog.skipped.len() == 1 {
+            ""
+        } else {
+            "s"
+        };
+        writeln!(
+            printer.stderr(),
+            "{}{} Skipped {} that failed to build: {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            format!("{} package{s}", changelog.skipped.len()).bold(),
+            changelog
+                .skipped
+                .iter()
+                .map(|dist| format!("`{}`", dist.cyan()))
+                .join(", ")
+        )?;
     }
 
     // Notify the user of any resolution diagnostics.
@@ -516,8 +1088,1220 @@ pub(crate) async fn pip_sync(
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run.enabled() {
-        operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
+        operations::diagnose_environment(
+            &resolution,
+            &environment,
+            &marker_env,
+            warn_conflicts,
+            printer,
+        )?;
+    }
+
+    // Notify the user of any installed modules that shadow the standard library.
+    if warn_shadowing && !dry_run.enabled() {
+        operations::diagnose_shadowing(&resolution, &environment, printer)?;
+    }
+
+    // Notify the user of any installed packages that provide the same top-level module.
+    if warn_import_conflicts && !dry_run.enabled() {
+        operations::diagnose_import_conflicts(&resolution, &environment, printer)?;
+    }
+
+    // Notify the user of any extension modules with unresolved shared library dependencies.
+    if check_native_deps && !dry_run.enabled() {
+        if strict {
+            operations::diagnose_native_dependencies(&resolution, &environment, printer)?;
+        } else {
+            warn_user!("`--check-native-deps` has no effect without `--strict`");
+        }
+    }
+
+    // Explain why each of the requested packages is installed.
+    if !explain.is_empty() {
+        report_explanations(
+            &explain,
+            &resolution,
+            &hasher,
+            &marker_env,
+            &client,
+            &build_dispatch,
+            concurrency,
+            dry_run,
+            printer,
+        )
+        .await?;
+    }
+
+    // Notify the user of any newer versions of the installed packages.
+    if show_upgrades {
+        if client.connectivity().is_offline() {
+            debug!("Skipping upgrade check due to `--offline`");
+        } else {
+            report_upgrades(
+                &resolution,
+                &client,
+                &tags,
+                &marker_env,
+                exclude_newer,
+                concurrency,
+                upgrades_format,
+                printer,
+            )
+            .await?;
+        }
+    }
+
+    if !changelog.skipped.is_empty() {
+        return Ok(ExitStatus::Failure);
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the metadata for each package in the resolution.
+///
+/// The metadata for each package is read from the environment if the package was already
+/// installed, and fetched otherwise, such that it's available before anything is installed.
+///
+/// If `build` is `false`, source distributions are omitted, rather than built to read their
+/// metadata.
+async fn resolution_metadata<'a>(
+    resolution: &'a Resolution,
+    hasher: &HashStrategy,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    concurrency: Concurrency,
+    build: bool,
+) -> FxHashMap<&'a PackageName, Vec<ResolutionMetadata>> {
+    let database = DistributionDatabase::new(client, build_dispatch, concurrency.downloads);
+
+    let distributions = resolution.distributions().filter(|dist| {
+        if build {
+            return true;
+        }
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            return true;
+        };
+        if matches!(dist.as_ref(), Dist::Source(_)) {
+            warn_user!(
+                "Skipping the metadata for `{}`, since reading it would require a build",
+                dist.name()
+            );
+            return false;
+        }
+        true
+    });
+
+    let mut fetches = futures::stream::iter(distributions)
+        .map(async |dist| {
+            let metadata = match dist {
+                ResolvedDist::Installed { dist } => dist.metadata().map_err(anyhow::Error::from),
+                ResolvedDist::Installable { dist, .. } => database
+                    .get_or_build_wheel_metadata(dist, hasher.get(dist.as_ref()))
+                    .await
+                    .map(|archive| ResolutionMetadata {
+                        name: archive.metadata.name,
+                        version: archive.metadata.version,
+                        requires_dist: archive
+                            .metadata
+                            .requires_dist
+                            .into_iter()
+                            .map(Into::into)
+                            .collect(),
+                        requires_python: archive.metadata.requires_python,
+                        provides_extras: archive.metadata.provides_extras,
+                        dynamic: archive.metadata.dynamic,
+                    })
+                    .map_err(anyhow::Error::from),
+            };
+            (dist, metadata)
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut packages: FxHashMap<_, Vec<_>> = FxHashMap::default();
+    while let Some((dist, metadata)) = fetches.next().await {
+        // A package without metadata is omitted, rather than failing the command.
+        match metadata {
+            Ok(metadata) => packages.entry(dist.name()).or_default().push(metadata),
+            Err(err) => {
+                warn_user!("Failed to read the metadata for `{}`: {err}", dist.name());
+            }
+        }
+    }
+    packages
+}
+
+/// Determine the packages to drop from the resolution for `--exclude-dependency`.
+///
+/// Each excluded package is dropped if another package in the resolution depends on it, along with
+/// any packages that are only required by dropped packages. An excluded package that no other
+/// package depends on was requested directly, and is retained.
+fn excluded_dependencies(
+    exclude: &[PackageName],
+    packages: &FxHashMap<&PackageName, Vec<ResolutionMetadata>>,
+    marker_env: &ResolverMarkerEnvironment,
+) -> FxHashSet<PackageName> {
+    // Determine the packages that require each package in the resolution.
+    let mut dependents: FxHashMap<&PackageName, FxHashSet<&PackageName>> = FxHashMap::default();
+    for (name, metadata) in packages {
+        for requirement in metadata.iter().flat_map(|metadata| &metadata.requires_dist) {
+            if requirement.name != **name
+                && packages.contains_key(&requirement.name)
+                && requirement.evaluate_markers(marker_env, &[])
+            {
+                dependents
+                    .entry(&requirement.name)
+                    .or_default()
+                    .insert(*name);
+            }
+        }
+    }
+
+    let mut excluded = FxHashSet::default();
+    for name in exclude {
+        if dependents.contains_key(name) {
+            excluded.insert(name);
+        } else if packages.contains_key(name) {
+            warn_user!("Not excluding `{name}`, since no other package depends on it");
+        }
+    }
+
+    // Drop any packages whose dependents have all been dropped.
+    loop {
+        let orphaned = dependents
+            .iter()
+            .filter(|(name, dependents)| {
+                !excluded.contains(*name)
+                    && dependents
+                        .iter()
+                        .all(|dependent| excluded.contains(dependent))
+            })
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        if orphaned.is_empty() {
+            break;
+        }
+        excluded.extend(orphaned);
+    }
+
+    excluded.into_iter().cloned().collect()
+}
+
+/// Report the chains of requirements that lead to each of the given packages in the resolution.
+///
+/// In `--dry-run` mode, source distributions aren't built, so any requirements they declare are
+/// omitted from the report.
+async fn report_explanations(
+    explain: &[PackageName],
+    resolution: &Resolution,
+    hasher: &HashStrategy,
+    marker_env: &ResolverMarkerEnvironment,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    concurrency: Concurrency,
+    dry_run: DryRun,
+    printer: Printer,
+) -> Result<()> {
+    let packages = resolution_metadata(
+        resolution,
+        hasher,
+        client,
+        build_dispatch,
+        concurrency,
+        !dry_run.enabled(),
+    )
+    .await;
+
+    for name in explain {
+        if !resolution.distributions().any(|dist| dist.name() == name) {
+            warn_user!("Cannot explain `{name}`, as it is not part of the resolution");
+        }
+    }
+
+    // Render the inverted tree for the requested packages, including the version specifiers.
+    let rendered_tree = DisplayDependencyGraph::new(
+        usize::MAX,
+        &[],
+        explain,
+        false,
+        true,
+        true,
+        marker_env,
+        &packages,
+        &FxHashMap::default(),
+    )
+    .render()
+    .join("\n");
+
+    if !rendered_tree.is_empty() {
+        writeln!(printer.stdout(), "{rendered_tree}")?;
+    }
+
+    Ok(())
+}
+
+/// A newer version of an installed package, as reported by `--show-upgrades`.
+#[derive(Debug, Serialize)]
+struct AvailableUpgrade<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    latest_version: Version,
+}
+
+/// Report any packages in the [`Resolution`] for which a newer version is available.
+///
+/// Only packages that were installed from a registry are considered.
+async fn report_upgrades(
+    resolution: &Resolution,
+    client: &RegistryClient,
+    tags: &Tags,
+    marker_env: &ResolverMarkerEnvironment,
+    exclude_newer: Option<ExcludeNewer>,
+    concurrency: Concurrency,
+    format: UpgradesFormat,
+    printer: Printer,
+) -> Result<()> {
+    let packages = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => {
+                let index = dist.index()?;
+                Some((dist.name(), dist.version()?, Some(index)))
+            }
+            ResolvedDist::Installed { dist } => match dist.as_ref() {
+                InstalledDist::Registry(dist) => Some((&dist.name, &dist.version, None)),
+                _ => None,
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let capabilities = IndexCapabilities::default();
+    let requires_python =
+        RequiresPython::greater_than_equal_version(&marker_env.python_full_version().version);
+
+    // Initialize the client to fetch the latest version of each package.
+    let latest_client = LatestClient {
+        client,
+        capabilities: &capabilities,
+        prerelease: PrereleaseMode::default(),
+        exclude_newer,
+        tags: Some(tags),
+        requires_python: &requires_python,
+    };
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    let reporter = LatestVersionReporter::from(printer).with_length(packages.len() as u64);
+
+    // Fetch the latest version for each package.
+    let mut fetches = futures::stream::iter(&packages)
+        .map(async |&(name, version, index)| {
+            let latest = latest_client
+                .find_latest(name, index, &download_concurrency)
+                .await?;
+            Ok::<_, uv_client::Error>((name, version, latest))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut upgrades = Vec::new();
+    while let Some(result) = fetches.next().await {
+        // The installation has already succeeded, so a failure to check for upgrades shouldn't
+        // fail the command.
+        let (name, version, latest) = match result {
+            Ok(fetch) => fetch,
+            Err(err) => {
+                reporter.on_fetch_complete();
+                warn_user!("Failed to check for available upgrades: {err}");
+                return Ok(());
+            }
+        };
+        let Some(latest) = latest else {
+            reporter.on_fetch_progress();
+            continue;
+        };
+        reporter.on_fetch_version(name, latest.version());
+        if latest.version() > version {
+            upgrades.push(AvailableUpgrade {
+                name,
+                version,
+                latest_version: latest.version().clone(),
+            });
+        }
+    }
+    reporter.on_fetch_complete();
+
+    upgrades.sort_unstable_by(|a, b| a.name.cmp(b.name));
+
+    match format {
+        UpgradesFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&upgrades)?)?;
+        }
+        UpgradesFormat::Text if upgrades.is_empty() => {
+            writeln!(printer.stderr(), "No upgrades available")?;
+        }
+        UpgradesFormat::Text => {
+            let s = if upgrades.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!("Upgrades available for {} package{s}:", upgrades.len()).dimmed()
+            )?;
+
+            // Align the versions in each row.
+            let name_width = upgrades
+                .iter()
+                .map(|upgrade| upgrade.name.as_str().len())
+                .max()
+                .unwrap_or_default();
+            let version_width = upgrades
+                .iter()
+                .map(|upgrade| upgrade.version.to_string().len())
+                .max()
+                .unwrap_or_default();
+            for upgrade in &upgrades {
+                writeln!(
+                    printer.stderr(),
+                    " {} {} -> {} available",
+                    format!("{:name_width$}", upgrade.name.as_str()).bold(),
+                    format!("{:version_width$}", upgrade.version.to_string()).dimmed(),
+                    upgrade.latest_version.to_string().green(),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare the installed packages against the requirements in a reference manifest, returning a
+/// description of every package that is missing, modified, or not listed in the manifest.
+fn find_drift(
+    site_packages: &SitePackages,
+    manifest: &[UnresolvedRequirementSpecification],
+    marker_env: &ResolverMarkerEnvironment,
+) -> Vec<String> {
+    let mut drift = Vec::new();
+    let mut expected = FxHashSet::default();
+
+    for entry in manifest {
+        if !entry.requirement.evaluate_markers(Some(marker_env), &[]) {
+            continue;
+        }
+        match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                expected.insert(requirement.name.clone());
+                let installed = site_packages.get_packages(&requirement.name);
+                if installed.is_empty() {
+                    drift.push(format!("`{requirement}` is not installed"));
+                } else if !site_packages.satisfies_requirement(requirement) {
+                    for dist in installed {
+                        drift.push(format!(
+                            "`{}=={}` is installed, but `{requirement}` was expected",
+                            dist.name(),
+                            dist.version()
+                        ));
+                    }
+                }
+            }
+            UnresolvedRequirement::Unnamed(requirement) => {
+                let installed = site_packages.get_urls(&requirement.url.verbatim.to_url());
+                if installed.is_empty() {
+                    drift.push(format!("`{requirement}` is not installed"));
+                }
+                expected.extend(installed.into_iter().map(|dist| dist.name().clone()));
+            }
+        }
+    }
+
+    for dist in site_packages
+        .iter()
+        .filter(|dist| !expected.contains(dist.name()))
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        drift.push(format!(
+            "`{}=={}` is installed, but not listed in the manifest",
+            dist.name(),
+            dist.version()
+        ));
+    }
+
+    drift
+}
+
+/// Drop all but one of any requirements that pin the same package to different versions,
+/// according to the [`OnConflict`] policy, warning for each conflict.
+///
+/// Requirements whose markers don't apply to the target environment are ignored.
+fn resolve_conflicting_pins(
+    requirements: Vec<UnresolvedRequirementSpecification>,
+    marker_env: &ResolverMarkerEnvironment,
+    on_conflict: OnConflict,
+) -> Vec<UnresolvedRequirementSpecification> {
+    // Group the pinned versions by package name, alongside the index of each requirement.
+    let mut pins: BTreeMap<&PackageName, Vec<(usize, &Version)>> = BTreeMap::new();
+    for (index, entry) in requirements.iter().enumerate() {
+        if !entry.requirement.evaluate_markers(Some(marker_env), &[]) {
+            continue;
+        }
+        let UnresolvedRequirement::Named(requirement) = &entry.requirement else {
+            continue;
+        };
+        let Some(version) = pinned_version(requirement) else {
+            continue;
+        };
+        pins.entry(&requirement.name)
+            .or_default()
+            .push((index, version));
+    }
+
+    let mut dropped = FxHashSet::default();
+    for (name, pins) in pins {
+        if pins.iter().map(|(_, version)| version).all_equal() {
+            continue;
+        }
+        let selected = match on_conflict {
+            // Leave the conflicting requirements in place, for the resolver to reject.
+            OnConflict::Error => None,
+            OnConflict::Warn => pins.last(),
+            OnConflict::Newest => pins.iter().max_by_key(|(_, version)| *version),
+        };
+        let Some((_, selected)) = selected.copied() else {
+            continue;
+        };
+        warn_user!(
+            "Found conflicting pins for `{name}` ({}); using `{name}=={selected}`",
+            pins.iter()
+                .map(|(_, version)| version)
+                .unique()
+                .map(|version| format!("`{name}=={version}`"))
+                .join(", ")
+        );
+        dropped.extend(
+            pins.iter()
+                .filter(|(_, version)| *version != selected)
+                .map(|(index, _)| *index),
+        );
+    }
+
+    requirements
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !dropped.contains(index))
+        .map(|(_, entry)| entry)
+        .collect()
+}
+
+/// Returns the version to which a requirement is pinned, if it's pinned to an exact version.
+fn pinned_version(requirement: &Requirement) -> Option<&Version> {
+    let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+        return None;
+    };
+    let [specifier] = &**specifier else {
+        return None;
+    };
+    matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+        .then(|| specifier.version())
+}
+
+/// Returns `true` if the requirement is pinned to an exact version, a full Git commit, or a
+/// direct URL.
+fn is_pinned(requirement: &UnresolvedRequirement) -> bool {
+    match requirement {
+        UnresolvedRequirement::Named(requirement) => match &requirement.source {
+            RequirementSource::Registry { specifier, .. } => {
+                specifier.len() == 1
+                    && specifier.iter().all(|specifier| {
+                        matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+                    })
+            }
+            RequirementSource::Git { git, .. } => is_pinned_git(git),
+            RequirementSource::Url { .. }
+            | RequirementSource::Path { .. }
+            | RequirementSource::Directory { .. } => true,
+        },
+        UnresolvedRequirement::Unnamed(requirement) => match &requirement.url.parsed_url {
+            ParsedUrl::Git(ParsedGitUrl { url, .. }) => is_pinned_git(url),
+            ParsedUrl::Path(_) | ParsedUrl::Directory(_) | ParsedUrl::Archive(_) => true,
+        },
+    }
+}
+
+/// Returns `true` if the Git URL is pinned to a full commit SHA, rather than a branch or tag,
+/// either of which can move.
+fn is_pinned_git(git: &GitUrl) -> bool {
+    git.precise().is_some()
+        || matches!(
+            git.reference(),
+            GitReference::BranchOrTagOrCommit(rev) if GitOid::from_str(rev).is_ok()
+        )
+}
+
+/// Returns `true` if the requirement can be resolved without querying an index, i.e., it points
+/// to a direct URL, Git repository, or local path.
+fn has_direct_source(requirement: &UnresolvedRequirement) -> bool {
+    match requirement {
+        UnresolvedRequirement::Named(requirement) => {
+            !matches!(requirement.source, RequirementSource::Registry { .. })
+        }
+        UnresolvedRequirement::Unnamed(_) => true,
+    }
+}
+
+/// Remove any requirements that are already satisfied by an installed distribution from the
+/// [`Reinstall`] strategy.
+///
+/// Since [`Reinstall::All`] can't exclude individual packages, it's narrowed to the remaining
+/// requirements. Unnamed requirements can't be matched against the environment, so they're
+/// never excluded; however, only those that point to a local path are retained in the narrowed
+/// strategy.
+fn without_installed(
+    reinstall: Reinstall,
+    requirements: &[UnresolvedRequirementSpecification],
+    constraints: &[NameRequirementSpecification],
+    site_packages: &SitePackages,
+    markers: &ResolverMarkerEnvironment,
+) -> Reinstall {
+    let installed = requirements
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(requirement),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .filter(|requirement| {
+            site_packages.satisfies_requirement(requirement)
+                && constraints
+                    .iter()
+                    .map(|constraint| &constraint.requirement)
+                    .filter(|constraint| constraint.name == requirement.name)
+                    .filter(|constraint| constraint.evaluate_markers(Some(markers), &[]))
+                    .all(|constraint| site_packages.satisfies_requirement(constraint))
+        })
+        .map(|requirement| &requirement.name)
+        .collect::<FxHashSet<_>>();
+
+    if installed.is_empty() {
+        return reinstall;
+    }
+
+    for name in &installed {
+        debug!("Leaving installed package untouched: {name}");
+    }
+
+    match reinstall {
+        Reinstall::None => Reinstall::None,
+        Reinstall::All => {
+            requirements
+                .iter()
+                .fold(Reinstall::None, |reinstall, entry| {
+                    match &entry.requirement {
+                        UnresolvedRequirement::Named(requirement) => {
+                            if installed.contains(&requirement.name) {
+                                reinstall
+                            } else {
+                                reinstall.with_package(requirement.name.clone())
+                            }
+                        }
+                        UnresolvedRequirement::Unnamed(_) => {
+                            match entry.requirement.source().as_ref() {
+                                RequirementSource::Path { install_path, .. }
+                                | RequirementSource::Directory { install_path, .. } => {
+                                    reinstall.with_path(install_path.clone())
+                                }
+                                RequirementSource::Registry { .. }
+                                | RequirementSource::Url { .. }
+                                | RequirementSource::Git { .. } => reinstall,
+                            }
+                        }
+                    }
+                })
+        }
+        Reinstall::Packages(packages, paths) => Reinstall::Packages(
+            packages
+                .into_iter()
+                .filter(|package| !installed.contains(package))
+                .collect(),
+            paths,
+        ),
+    }
+}
+
+/// Return an error if syncing the [`Resolution`] would downgrade any package that's currently
+/// installed in the environment.
+fn verify_no_downgrades(resolution: &Resolution, site_packages: &SitePackages) -> Result<()> {
+    let downgrades = resolution
+        .distributions()
+        .filter_map(|dist| {
+            let version = dist.version()?;
+            let installed = site_packages
+                .get_packages(dist.name())
+                .into_iter()
+                .map(InstalledDist::version)
+                .max()?;
+            (version < installed).then(|| (dist.name(), installed, version))
+        })
+        .collect::<Vec<_>>();
+
+    if downgrades.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "The following {} would be downgraded, but `--verify-no-downgrade` was provided:",
+        if downgrades.len() == 1 {
+            "package"
+        } else {
+            "packages"
+        }
+    );
+    for (name, installed, version) in downgrades {
+        write!(message, "\n  {name} ({installed} -> {version})")?;
+    }
+    Err(anyhow::anyhow!(message))
+}
+
+/// Return an error if any of the given packages resolved to a source distribution in the
+/// [`Resolution`], i.e., if it would need to be built from source.
+fn verify_wheels_for(resolution: &Resolution, packages: &[PackageName]) -> Result<()> {
+    let sources = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => match dist.as_ref() {
+                Dist::Source(source) if packages.contains(source.name()) => Some(source),
+                _ => None,
+            },
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    if sources.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "The following {} no compatible wheel, but `--fail-on-missing-wheel-for` was provided:",
+        if sources.len() == 1 {
+            "package has"
+        } else {
+            "packages have"
+        }
+    );
+    for source in sources {
+        write!(message, "\n  {source}")?;
+    }
+    Err(anyhow::anyhow!(message))
+}
+
+/// Warn if the distributions to be installed from the [`Resolution`] exceed the given total
+/// artifact size or package count, or return an error if `error` is set.
+///
+/// Distributions that are already installed don't contribute to either threshold, and only
+/// artifacts with a size reported by the index contribute to the total size.
+fn check_install_size(
+    resolution: &Resolution,
+    max_size: Option<u64>,
+    max_count: Option<usize>,
+    error: bool,
+) -> Result<()> {
+    let installable = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut violations = Vec::new();
+
+    if let Some(max_count) = max_count {
+        let count = installable.len();
+        if count > max_count {
+            violations.push(format!(
+                "The sync would install {count} packages, which exceeds the `--warn-install-count` threshold of {max_count}"
+            ));
+        }
+    }
+
+    if let Some(max_size) = max_size {
+        let size = installable
+            .iter()
+            .filter_map(|dist| dist.file()?.size)
+            .sum::<u64>();
+        if size > max_size {
+            let (size, size_unit) = human_readable_bytes(size);
+            let (max_size, max_size_unit) = human_readable_bytes(max_size);
+            violations.push(format!(
+                "The distributions to be installed total {size:.1}{size_unit}, which exceeds the `--warn-install-size` threshold of {max_size:.1}{max_size_unit}"
+            ));
+        }
+    }
+
+    if error {
+        if let Some(violation) = violations.into_iter().next() {
+            return Err(anyhow::anyhow!(
+                "{violation}, and `--error-on-large-install` was provided"
+            ));
+        }
+    } else {
+        for violation in violations {
+            warn_user!("{violation}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Return an error if any registry distribution in the [`Resolution`] is pinned to a version that
+/// is currently yanked on its index.
+///
+/// Unlike the yank diagnostics emitted by the resolver, this queries the index directly, so it
+/// also applies to distributions that were read from a lockfile (e.g., a `pylock.toml`).
+async fn verify_no_yanked(
+    resolution: &Resolution,
+    client: &RegistryClient,
+    concurrency: Concurrency,
+) -> Result<()> {
+    let packages = resolution
+        .distributions()
+        .filter_map(|dist| {
+            let index = dist.index()?;
+            Some((dist.name(), dist.version()?, index))
+        })
+        .collect::<Vec<_>>();
+
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    let mut fetches = futures::stream::iter(&packages)
+        .map(async |&(name, version, index)| {
+            let archives = match client
+                .package_metadata(
+                    name,
+                    Some(IndexMetadataRef::from(index)),
+                    &capabilities,
+                    &download_concurrency,
+                )
+                .await
+            {
+                Ok(archives) => archives,
+                Err(err) => {
+                    return match err.into_kind() {
+                        uv_client::ErrorKind::PackageNotFound(_)
+                        | uv_client::ErrorKind::NoIndex(_)
+                        | uv_client::ErrorKind::Offline(_) => Ok((name, version, None)),
+                        kind => Err(uv_client::Error::from(kind)),
+                    };
+                }
+            };
+
+            for (_, archive) in archives {
+                let MetadataFormat::Simple(archive) = archive else {
+                    continue;
+                };
+                for datum in archive.iter() {
+                    let datum_version =
+                        rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                            .expect("archived version always deserializes");
+                    if datum_version != *version {
+                        continue;
+                    }
+                    let files =
+                        rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                            .expect("archived version files always deserializes");
+                    let yanked = files
+                        .all()
+                        .filter_map(|(_, file)| file.yanked)
+                        .find(|yanked| yanked.is_yanked());
+                    if let Some(yanked) = yanked {
+                        return Ok((name, version, Some(*yanked)));
+                    }
+                }
+            }
+            Ok((name, version, None))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut yanked = Vec::new();
+    while let Some((name, version, status)) = fetches.next().await.transpose()? {
+        if let Some(status) = status {
+            yanked.push((name, version, status));
+        }
+    }
+
+    if yanked.is_empty() {
+        return Ok(());
+    }
+    yanked.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+    let mut message = format!(
+        "The following {} yanked, but `--verify-no-yanked-in-lock` was provided:",
+        if yanked.len() == 1 {
+            "version is"
+        } else {
+            "versions are"
+        }
+    );
+    for (name, version, status) in yanked {
+        match status {
+            Yanked::Reason(reason) if !reason.trim().is_empty() => {
+                write!(
+                    message,
+                    "\n  {name}=={version} (reason: \"{}\")",
+                    reason.trim()
+                )?;
+            }
+            _ => {
+                write!(message, "\n  {name}=={version}")?;
+            }
+        }
+    }
+    Err(anyhow::anyhow!(message))
+}
+
+/// Verify that every wheel in the [`Resolution`] is built for the ABI of the given [`Interpreter`].
+///
+/// Unlike the tags used for resolution, which may be derived from `--python-version` or extended
+/// by `--platform-tag`, the tags used here always reflect the interpreter itself (e.g., including
+/// the `t` ABI flag for free-threaded builds).
+fn verify_interpreter_abis(resolution: &Resolution, interpreter: &Interpreter) -> Result<()> {
+    let tags = interpreter.tags()?;
+    let incompatible = resolution
+        .distributions()
+        .filter_map(|dist| {
+            let ResolvedDist::Installable { dist, .. } = dist else {
+                return None;
+            };
+            let filename = match dist.as_ref() {
+                Dist::Built(BuiltDist::Registry(wheels)) => &wheels.best_wheel().filename,
+                Dist::Built(BuiltDist::DirectUrl(wheel)) => &wheel.filename,
+                Dist::Built(BuiltDist::Path(wheel)) => &wheel.filename,
+                Dist::Source(_) => return None,
+            };
+            let compatible = filename.python_tags().iter().any(|python_tag| {
+                filename
+                    .abi_tags()
+                    .iter()
+                    .any(|abi_tag| tags.is_compatible_abi(*python_tag, *abi_tag))
+            });
+            (!compatible).then_some(filename)
+        })
+        .collect::<Vec<_>>();
+
+    if incompatible.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "The following {} not built for the interpreter's ABI ({}), but `--verify-interpreter-abi` was provided:",
+        if incompatible.len() == 1 {
+            "wheel is"
+        } else {
+            "wheels are"
+        },
+        tags.abi_tag()
+            .map(|abi_tag| abi_tag.to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+    );
+    for filename in incompatible {
+        write!(message, "\n  {filename}")?;
+    }
+    Err(anyhow::anyhow!(message))
+}
+
+/// Prompt the user to build the given packages from source, as no compatible wheels are available.
+///
+/// Returns `false` without prompting if `stderr` is not a terminal.
+fn confirm_sdist_fallback(packages: &BTreeSet<PackageName>) -> Result<bool> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Ok(false);
+    }
+    let prompt = format!(
+        "No compatible wheels are available for {}. Build from source?",
+        packages
+            .iter()
+            .map(|name| format!("`{}`", name.cyan()))
+            .join(", ")
+    );
+    Ok(uv_console::confirm(&prompt, &term, false)?)
+}
+
+/// Resolve the requirements via `resolve` with the given build options.
+///
+/// If `allow_sdist_fallback` is set and the resolution fails because some packages can only be
+/// built from source, prompt to build them anyway and resolve again, updating `build_options` and
+/// `flat_index` to match.
+async fn resolve_with_sdist_fallback(
+    resolve: impl AsyncFn(
+        &BuildOptions,
+        &FlatIndex,
+    ) -> Result<(Resolution, SharedState), operations::Error>,
+    allow_sdist_fallback: bool,
+    build_options: &mut BuildOptions,
+    flat_index: &mut FlatIndex,
+    flat_index_entries: &FlatIndexEntries,
+    tags: &Tags,
+    hasher: &HashStrategy,
+) -> Result<(Resolution, SharedState), operations::Error> {
+    loop {
+        let err = match resolve(build_options, flat_index).await {
+            Ok(resolution) => return Ok(resolution),
+            Err(err) => err,
+        };
+        if !allow_sdist_fallback {
+            return Err(err);
+        }
+        let operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(ref no_solution)) =
+            err
+        else {
+            return Err(err);
+        };
+        let packages = no_solution.find_no_build_packages();
+        if packages.is_empty() || !confirm_sdist_fallback(&packages)? {
+            return Err(err);
+        }
+        build_options.allow_build(&packages.into_iter().collect::<Vec<_>>());
+        *flat_index = FlatIndex::from_entries(
+            flat_index_entries.clone(),
+            Some(tags),
+            hasher,
+            build_options,
+        );
+    }
+}
+
+/// Collect the versions of the given packages that are available without network access, i.e.,
+/// with a wheel in the cache or an entry in the `--find-links` locations.
+fn cached_versions<'a>(
+    packages: impl Iterator<Item = &'a PackageName>,
+    flat_index: &FlatIndex,
+    cache: &'a Cache,
+    tags: &'a Tags,
+    index_locations: &'a IndexLocations,
+    hasher: &'a HashStrategy,
+    config_settings: &'a ConfigSettings,
+) -> FxHashMap<PackageName, BTreeSet<Version>> {
+    let mut registry_index =
+        RegistryWheelIndex::new(cache, tags, index_locations, hasher, config_settings);
+    packages
+        .map(|name| {
+            let mut versions = registry_index
+                .get(name)
+                .map(|entry| entry.dist.filename.version.clone())
+                .collect::<BTreeSet<_>>();
+            if let Some(distributions) = flat_index.get(name) {
+                versions.extend(distributions.iter().map(|(version, _)| version.clone()));
+            }
+            (name.clone(), versions)
+        })
+        .collect()
+}
+
+/// Add any installed packages whose wheel in a local `--find-links` directory has changed since
+/// it was installed (e.g., because it was rebuilt in place) to the [`Reinstall`] strategy.
+///
+/// Wheels installed from a local path record the wheel's modification time in `uv_cache.json`,
+/// which is compared against the wheel that would be selected from the flat index, as in the
+/// install plan.
+fn with_stale_find_links(
+    reinstall: Reinstall,
+    site_packages: &SitePackages,
+    flat_index: &FlatIndex,
+) -> Reinstall {
+    site_packages
+        .iter()
+        .filter_map(|dist| {
+            let InstalledDist::Registry(dist) = dist else {
+                return None;
+            };
+            let cache_info = dist.cache_info.as_ref()?;
+            let (_, prioritized) = flat_index
+                .get(&dist.name)?
+                .iter()
+                .find(|(version, _)| **version == dist.version)?;
+            let (wheel, _) = prioritized.best_wheel()?;
+            if !is_stale_local_wheel(wheel, cache_info) {
+                return None;
+            }
+            debug!(
+                "Wheel for installed package `{}` has changed: {}",
+                dist.name, wheel.filename
+            );
+            Some(dist.name.clone())
+        })
+        .fold(reinstall, Reinstall::with_package)
+}
+
+/// Add any installed packages whose `INSTALLER` doesn't match the given installer name (e.g.,
+/// because they were installed by `pip`) to the [`Reinstall`] strategy.
+fn with_foreign_installer(
+    reinstall: Reinstall,
+    site_packages: &SitePackages,
+    installer_name: &str,
+) -> Reinstall {
+    site_packages
+        .iter()
+        .filter_map(|dist| {
+            let installer = match dist.installer() {
+                Ok(installer) => installer,
+                Err(err) => {
+                    debug!("Failed to read `INSTALLER` for `{}`: {err}", dist.name());
+                    None
+                }
+            };
+            if installer.as_deref() == Some(installer_name) {
+                return None;
+            }
+            if let Some(installer) = installer {
+                debug!(
+                    "Installed package `{}` was installed by `{installer}`",
+                    dist.name()
+                );
+            } else {
+                debug!("Installed package `{}` has no `INSTALLER`", dist.name());
+            }
+            Some(dist.name().clone())
+        })
+        .fold(reinstall, Reinstall::with_package)
+}
+
+/// Add any installed packages with files that are missing or don't match the hashes in their
+/// `RECORD` (e.g., because they were truncated) to the [`Reinstall`] strategy.
+///
+/// Only packages that would otherwise be left untouched (i.e., that satisfy a requirement and
+/// aren't already being reinstalled) are verified.
+async fn with_corrupt_files(
+    mut reinstall: Reinstall,
+    requirements: &[UnresolvedRequirementSpecification],
+    site_packages: &SitePackages,
+    markers: &ResolverMarkerEnvironment,
+    concurrency: Concurrency,
+) -> Result<Reinstall> {
+    if reinstall.is_all() {
+        return Ok(reinstall);
+    }
+
+    let dists = requirements
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(requirement),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .filter(|requirement| requirement.evaluate_markers(Some(markers), &[]))
+        .filter(|requirement| !reinstall.contains_package(&requirement.name))
+        .filter(|requirement| site_packages.satisfies_requirement(requirement))
+        .flat_map(|requirement| site_packages.get_packages(&requirement.name))
+        .collect::<Vec<_>>();
+
+    let mut verifications = futures::stream::iter(dists)
+        .map(async |dist| (dist, uv_installer::corrupt_files(dist).await))
+        .buffer_unordered(concurrency.installs);
+
+    let mut corrupt = Vec::new();
+    while let Some((dist, result)) = verifications.next().await {
+        let files = match result {
+            Ok(files) => files,
+            // Without a `RECORD`, the files can't be verified.
+            Err(uv_installer::UninstallError::Uninstall(
+                uv_install_wheel::Error::MissingRecord(_),
+            )) => continue,
+            Err(err) => return Err(err.into()),
+        };
+        if !files.is_empty() {
+            corrupt.push(dist);
+        }
+    }
+
+    // Report the packages in a deterministic order.
+    corrupt.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+    corrupt.dedup_by(|a, b| a.name() == b.name());
+
+    for dist in corrupt {
+        warn_user!(
+            "The installed package `{}` has files that are missing or don't match its `RECORD`; reinstalling",
+            dist.name()
+        );
+        reinstall = reinstall.with_package(dist.name().clone());
+    }
+    Ok(reinstall)
+}
+
+/// Find the named virtual environment in the managed virtual environment directory, creating it if
+/// requested.
+fn find_named_environment(
+    name: &str,
+    venv_dir: Option<&Path>,
+    create: bool,
+    python: Option<&str>,
+    python_preference: PythonPreference,
+    cache: &Cache,
+    preview: PreviewMode,
+) -> Result<PythonEnvironment> {
+    // Reject names that would escape the managed directory, e.g., `../foo` or `/foo`.
+    let mut components = Path::new(name).components();
+    if !matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    ) {
+        return Err(anyhow::anyhow!(
+            "Invalid virtual environment name `{name}`: expected a single directory name"
+        ));
+    }
+
+    let venv_dir = if let Some(venv_dir) = venv_dir {
+        std::path::absolute(venv_dir)?
+    } else {
+        StateStore::from_settings(None)?.bucket(StateBucket::Venvs)
+    };
+    let path = venv_dir.join(name);
+
+    if path.is_dir() {
+        debug!(
+            "Using named virtual environment at: {}",
+            path.user_display()
+        );
+        let environment = PythonEnvironment::from_root(&path, cache)?;
+
+        // Refuse to use an existing environment that doesn't match the requested interpreter.
+        if let Some(python) = python {
+            if !PythonRequest::parse(python).satisfied(environment.interpreter(), cache) {
+                return Err(anyhow::anyhow!(
+                    "The virtual environment named `{name}` uses Python {}, which does not satisfy the requested interpreter: `{python}`",
+                    environment.interpreter().python_version(),
+                ));
+            }
+        }
+
+        return Ok(environment);
+    }
+
+    if !create {
+        return Err(anyhow::anyhow!(
+            "No virtual environment named `{name}` found at: {}\n\n{}{} Use `--create` to create it",
+            path.user_display().cyan(),
+            "hint".bold().cyan(),
+            ":".bold(),
+        ));
+    }
+
+    let installation = PythonInstallation::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        cache,
+        preview,
+    )?;
+    debug!(
+        "Creating named virtual environment at: {}",
+        path.user_display()
+    );
+    Ok(uv_virtualenv::create_venv(
+        &path,
+        installation.into_interpreter(),
+        uv_virtualenv::Prompt::Static(name.to_string()),
+        false,
+        uv_virtualenv::OnExisting::Fail,
+        false,
+        false,
+        false,
+        preview,
+    )?)
+}
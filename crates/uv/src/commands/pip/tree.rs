@@ -90,7 +90,9 @@ pub(crate) async fn pip_tree(
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .keyring(keyring_provider)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout);
 
         // Initialize the registry client.
         let client = RegistryClientBuilder::try_from(client_builder)?
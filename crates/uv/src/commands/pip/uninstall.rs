@@ -1,6 +1,7 @@
 use std::fmt::Write;
 
 use anyhow::Result;
+use console::Term;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use tracing::{debug, warn};
@@ -9,13 +10,14 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DryRun, KeyringProviderType, PreviewMode};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
+use uv_distribution_types::{InstalledDist, InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
 use uv_pep508::UnnamedRequirement;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::EnvironmentPreference;
 use uv_python::PythonRequest;
-use uv_python::{Prefix, PythonEnvironment, Target};
+use uv_python::{Prefix, PythonEnvironment, Root, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
 use crate::commands::pip::operations::report_target_environment;
@@ -27,11 +29,15 @@ use crate::settings::NetworkSettings;
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
+    all: bool,
+    include_base: bool,
+    yes: bool,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    root: Option<Root>,
     cache: Cache,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
@@ -46,7 +52,9 @@ pub(crate) async fn pip_uninstall(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Read all requirements from the provided sources.
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
@@ -81,6 +89,14 @@ pub(crate) async fn pip_uninstall(
         environment
     };
 
+    // Apply any `--root` directory, beneath which the packages were staged.
+    let environment = if let Some(root) = root {
+        debug!("Using `--root` directory at {}", root.root().user_display());
+        environment.with_root(root)?
+    } else {
+        environment
+    };
+
     // If the environment is externally managed, abort.
     if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
         if break_system_packages {
@@ -166,6 +182,16 @@ pub(crate) async fn pip_uninstall(
             }
         }
 
+        // With `--all`, identify every installed package, retaining the base packages unless
+        // `--include-base` was provided.
+        if all {
+            distributions.extend(
+                site_packages
+                    .iter()
+                    .filter(|dist| include_base || !is_base_package(dist.name())),
+            );
+        }
+
         // Identify all unnamed distributions that are installed.
         for url in &urls {
             let installed = site_packages.get_urls(url);
@@ -204,6 +230,12 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // Confirm before uninstalling every package in the environment.
+    if all && !yes && !dry_run.enabled() && !confirm_uninstall_all(&environment, &distributions)? {
+        writeln!(printer.stderr(), "No packages were uninstalled")?;
+        return Ok(ExitStatus::Failure);
+    }
+
     // Uninstall each package.
     if !dry_run.enabled() {
         for distribution in &distributions {
@@ -256,3 +288,29 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Returns `true` if the package is one of the base packages (`pip`, `setuptools`, or `wheel`),
+/// which are retained by `--all` unless `--include-base` is provided.
+fn is_base_package(name: &PackageName) -> bool {
+    matches!(name.as_ref(), "pip" | "setuptools" | "wheel")
+}
+
+/// Prompt the user to confirm the removal of every package in the environment.
+///
+/// Returns `true` without prompting if `stderr` is not a terminal.
+fn confirm_uninstall_all(
+    environment: &PythonEnvironment,
+    distributions: &[&InstalledDist],
+) -> Result<bool> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Ok(true);
+    }
+    let s = if distributions.len() == 1 { "" } else { "s" };
+    let prompt = format!(
+        "Uninstall {} package{s} from {}?",
+        distributions.len(),
+        environment.root().user_display().cyan()
+    );
+    Ok(uv_console::confirm(&prompt, &term, false)?)
+}
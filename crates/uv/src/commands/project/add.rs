@@ -115,10 +115,18 @@ pub(crate) async fn add(
             RequirementsSource::PylockToml(_) => {
                 bail!("Adding requirements from a `pylock.toml` is not supported in `uv add`");
             }
+            RequirementsSource::EnvironmentYml(path) => {
+                bail!(
+                    "Conda environment files (i.e., `{}`) are not supported",
+                    path.user_display()
+                );
+            }
+            RequirementsSource::PipfileLock { .. } => {
+                bail!("Adding requirements from a `Pipfile.lock` is not supported in `uv add`");
+            }
             RequirementsSource::Package(_)
             | RequirementsSource::Editable(_)
-            | RequirementsSource::RequirementsTxt(_)
-            | RequirementsSource::EnvironmentYml(_) => {}
+            | RequirementsSource::RequirementsTxt(_) => {}
         }
     }
 
@@ -179,7 +187,9 @@ pub(crate) async fn add(
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout);
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
         // metadata tag.
@@ -334,14 +344,12 @@ pub(crate) async fn add(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(settings.resolver.keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Read the requirements.
-    let RequirementsSpecification {
-        requirements,
-        constraints,
-        ..
-    } = RequirementsSpecification::from_sources(
+    let spec = RequirementsSpecification::from_sources(
         &requirements,
         &constraints,
         &[],
@@ -349,6 +357,12 @@ pub(crate) async fn add(
         &client_builder,
     )
     .await?;
+    spec.reject_git_editables()?;
+    let RequirementsSpecification {
+        requirements,
+        constraints,
+        ..
+    } = spec;
 
     // Initialize any shared state.
     let state = PlatformState::default();
@@ -1123,7 +1137,7 @@ async fn lock_and_sync(
         settings.into(),
         network_settings,
         &sync_state,
-        Box::new(DefaultInstallLogger),
+        Box::new(DefaultInstallLogger::default()),
         installer_metadata,
         concurrency,
         cache,
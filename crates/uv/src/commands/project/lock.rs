@@ -102,7 +102,9 @@ pub(crate) async fn lock(
                 .retries_from_env()?
                 .connectivity(network_settings.connectivity)
                 .native_tls(network_settings.native_tls)
-                .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                .proxy_url(network_settings.proxy.clone())
+                .timeout(network_settings.timeout);
             let reporter = PythonDownloadReporter::single(printer);
             let requires_python = init_script_python_requirement(
                 python.as_deref(),
@@ -602,7 +604,9 @@ async fn do_lock(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     index_locations.cache_index_credentials();
 
@@ -49,7 +49,7 @@ use uv_workspace::pyproject::PyProjectToml;
 use uv_workspace::{RequiresPythonSources, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
-use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::operations::{Changelog, InstallFlags, Modifications};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
@@ -691,7 +691,9 @@ impl ScriptInterpreter {
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout);
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -969,7 +971,9 @@ impl ProjectInterpreter {
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout);
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -1235,14 +1239,14 @@ enum ProjectEnvironment {
     WouldReplace(
         PathBuf,
         PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
+        #[allow(unused)] uv_cache::TempDir,
     ),
     /// A new [`PythonEnvironment`] would've been created, but `--dry-run` mode is enabled; as such,
     /// a temporary environment was created instead.
     WouldCreate(
         PathBuf,
         PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
+        #[allow(unused)] uv_cache::TempDir,
     ),
 }
 
@@ -1467,14 +1471,14 @@ enum ScriptEnvironment {
     WouldReplace(
         PathBuf,
         PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
+        #[allow(unused)] uv_cache::TempDir,
     ),
     /// A new [`PythonEnvironment`] would've been created, but `--dry-run` mode is enabled; as such,
     /// a temporary environment was created instead.
     WouldCreate(
         PathBuf,
         PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
+        #[allow(unused)] uv_cache::TempDir,
     ),
 }
 
@@ -1699,7 +1703,9 @@ pub(crate) async fn resolve_names(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     index_locations.cache_index_credentials();
 
@@ -1859,7 +1865,9 @@ pub(crate) async fn resolve_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Determine the tags, markers, and interpreter to use for resolution.
     let tags = interpreter.tags()?;
@@ -2033,7 +2041,9 @@ pub(crate) async fn sync_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     let site_packages = SitePackages::from_environment(&venv)?;
 
@@ -2110,6 +2120,7 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        InstallFlags::default(),
         index_locations,
         config_setting,
         config_settings_package,
@@ -2200,7 +2211,9 @@ pub(crate) async fn update_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Respect all requirements from the provided sources.
     let RequirementsSpecification {
@@ -2369,6 +2382,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
+        InstallFlags::default(),
         index_locations,
         config_setting,
         config_settings_package,
@@ -361,7 +361,7 @@ pub(crate) async fn remove(
         (&settings).into(),
         &network_settings,
         &state,
-        Box::new(DefaultInstallLogger),
+        Box::new(DefaultInstallLogger::default()),
         installer_metadata,
         concurrency,
         cache,
@@ -56,7 +56,7 @@ use crate::commands::project::{
     validate_project_requires_python,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::{ExitStatus, diagnostics, project};
+use crate::commands::{ExitStatus, diagnostics, project, read_env_files};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
@@ -150,40 +150,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
     // Read from the `.env` file, if necessary.
     if !no_env_file {
-        for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
-            match dotenvy::from_path(env_file_path) {
-                Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                    bail!(
-                        "No environment file found at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::Io(err)) => {
-                    bail!(
-                        "Failed to read environment file `{}`: {err}",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::LineParse(content, position)) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}` at position {position}: {content}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Err(err) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}`: {err}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Ok(()) => {
-                    debug!(
-                        "Read environment file at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-            }
-        }
+        read_env_files(&env_file, false)?;
     }
 
     // Initialize any output reporters.
@@ -310,7 +277,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 &network_settings,
                 &sync_state,
                 if show_resolution {
-                    Box::new(DefaultInstallLogger)
+                    Box::new(DefaultInstallLogger::default())
                 } else {
                     Box::new(SummaryInstallLogger)
                 },
@@ -415,7 +382,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryResolveLogger)
                     },
                     if show_resolution {
-                        Box::new(DefaultInstallLogger)
+                        Box::new(DefaultInstallLogger::default())
                     } else {
                         Box::new(SummaryInstallLogger)
                     },
@@ -621,7 +588,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .retries_from_env()?
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_url(network_settings.proxy.clone())
+                    .timeout(network_settings.timeout);
 
                 // Resolve the Python request and requirement for the workspace.
                 let WorkspacePython {
@@ -823,7 +792,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     &network_settings,
                     &sync_state,
                     if show_resolution {
-                        Box::new(DefaultInstallLogger)
+                        Box::new(DefaultInstallLogger::default())
                     } else {
                         Box::new(SummaryInstallLogger)
                     },
@@ -863,7 +832,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .retries_from_env()?
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_url(network_settings.proxy.clone())
+                    .timeout(network_settings.timeout);
 
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
@@ -934,10 +905,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout);
 
         let spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
+        spec.reject_git_editables()?;
 
         Some(spec)
     };
@@ -988,7 +962,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryResolveLogger)
                 },
                 if show_resolution {
-                    Box::new(DefaultInstallLogger)
+                    Box::new(DefaultInstallLogger::default())
                 } else {
                     Box::new(SummaryInstallLogger)
                 },
@@ -1577,6 +1551,8 @@ impl RunCommand {
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_url(network_settings.proxy.clone())
+                    .timeout(network_settings.timeout)
                     .build();
                 let response = client
                     .for_host(&url)
@@ -35,7 +35,7 @@ use uv_workspace::pyproject::Source;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{InstallFlags, Modifications};
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
@@ -250,7 +250,7 @@ pub(crate) async fn sync(
                 &network_settings,
                 &PlatformState::default(),
                 Box::new(DefaultResolveLogger),
-                Box::new(DefaultInstallLogger),
+                Box::new(DefaultInstallLogger::default()),
                 installer_metadata,
                 concurrency,
                 cache,
@@ -385,7 +385,7 @@ pub(crate) async fn sync(
         (&settings).into(),
         &network_settings,
         &state,
-        Box::new(DefaultInstallLogger),
+        Box::new(DefaultInstallLogger::default()),
         installer_metadata,
         concurrency,
         cache,
@@ -589,7 +589,9 @@ pub(super) async fn do_sync(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Validate that the Python version is supported by the lockfile.
     if !target
@@ -733,6 +735,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        InstallFlags::default(),
         index_locations,
         config_setting,
         config_settings_package,
@@ -622,7 +622,7 @@ async fn lock_and_sync(
         settings.into(),
         &network_settings,
         &state,
-        Box::new(DefaultInstallLogger),
+        Box::new(DefaultInstallLogger::default()),
         installer_metadata,
         concurrency,
         cache,
@@ -21,7 +21,7 @@ use uv_warnings::warn_user_once;
 
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterMode};
 use crate::settings::NetworkSettings;
 
 pub(crate) async fn publish(
@@ -62,6 +62,8 @@ pub(crate) async fn publish(
         .keyring(keyring_provider)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout)
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
         // Set a very high timeout for uploads, connections are often 10x slower on upload than
@@ -99,6 +101,8 @@ pub(crate) async fn publish(
             .native_tls(network_settings.native_tls)
             .connectivity(network_settings.connectivity)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout)
             .index_locations(&index_locations)
             .keyring(keyring_provider);
         Some(CheckUrlClient {
@@ -361,7 +365,7 @@ mod tests {
             &client,
             None,
             Prompt::Disabled,
-            Printer::Quiet,
+            Printer::from(PrinterMode::Quiet),
         )
         .await
     }
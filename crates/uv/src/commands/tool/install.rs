@@ -68,7 +68,9 @@ pub(crate) async fn install(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -100,7 +102,9 @@ pub(crate) async fn install(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     // Parse the input requirement.
     let request = ToolRequest::parse(&package, from.as_deref())?;
@@ -124,9 +128,9 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::from_package(from)?
             };
-            let requirement = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let spec = RequirementsSpecification::from_source(&source, &client_builder).await?;
+            spec.reject_git_editables()?;
+            let requirement = spec.requirements;
 
             // If the user provided an executable name, verify that it matches the `--from` requirement.
             let executable = if let Some(executable) = executable {
@@ -264,6 +268,7 @@ pub(crate) async fn install(
         &client_builder,
     )
     .await?;
+    spec.reject_git_editables()?;
 
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
@@ -443,7 +448,7 @@ pub(crate) async fn install(
             &network_settings,
             &state,
             Box::new(DefaultResolveLogger),
-            Box::new(DefaultInstallLogger),
+            Box::new(DefaultInstallLogger::default()),
             installer_metadata,
             concurrency,
             &cache,
@@ -576,7 +581,7 @@ pub(crate) async fn install(
             (&settings).into(),
             &network_settings,
             &state,
-            Box::new(DefaultInstallLogger),
+            Box::new(DefaultInstallLogger::default()),
             installer_metadata,
             concurrency,
             &cache,
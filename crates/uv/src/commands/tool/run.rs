@@ -692,7 +692,9 @@ async fn get_or_create_environment(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -874,6 +876,7 @@ async fn get_or_create_environment(
         &client_builder,
     )
     .await?;
+    spec.reject_git_editables()?;
 
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
@@ -1005,7 +1008,7 @@ async fn get_or_create_environment(
             Box::new(SummaryResolveLogger)
         },
         if show_resolution {
-            Box::new(DefaultInstallLogger)
+            Box::new(DefaultInstallLogger::default())
         } else {
             Box::new(SummaryInstallLogger)
         },
@@ -1064,7 +1067,7 @@ async fn get_or_create_environment(
                         Box::new(SummaryResolveLogger)
                     },
                     if show_resolution {
-                        Box::new(DefaultInstallLogger)
+                        Box::new(DefaultInstallLogger::default())
                     } else {
                         Box::new(SummaryInstallLogger)
                     },
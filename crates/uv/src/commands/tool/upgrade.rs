@@ -83,7 +83,9 @@ pub(crate) async fn upgrade(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     let python_request = python.as_deref().map(PythonRequest::parse);
 
@@ -321,7 +323,7 @@ async fn upgrade_tool(
             (&settings).into(),
             network_settings,
             &state,
-            Box::new(DefaultInstallLogger),
+            Box::new(DefaultInstallLogger::default()),
             installer_metadata,
             concurrency,
             cache,
@@ -130,7 +130,9 @@ pub(crate) async fn venv(
     let client_builder = BaseClientBuilder::default()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_url(network_settings.proxy.clone())
+        .timeout(network_settings.timeout);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -241,6 +243,8 @@ pub(crate) async fn venv(
             .index_strategy(index_strategy)
             .keyring(keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_url(network_settings.proxy.clone())
+            .timeout(network_settings.timeout)
             .markers(interpreter.markers())
             .platform(interpreter.platform())
             .build();
@@ -329,7 +333,7 @@ pub(crate) async fn venv(
             .map_err(|err| VenvError::Seed(err.into()))?;
 
         let changelog = Changelog::from_installed(installed);
-        DefaultInstallLogger.on_complete(&changelog, printer)?;
+        DefaultInstallLogger::default().on_complete(&changelog, printer)?;
     }
 
     // Determine the appropriate activation command.
@@ -11,8 +11,9 @@ use std::sync::atomic::Ordering;
 
 use anstream::eprintln;
 use anyhow::{Context, Result, bail};
+use clap::builder::Resettable;
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches, Parser};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -24,9 +25,9 @@ use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
-    BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands, PipCommand, PipNamespace,
-    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
-    ToolNamespace, TopLevelArgs, compat::CompatArgs,
+    BuildBackendCommand, CacheCommand, CacheNamespace, Cli, ColorChoice, Commands, PipCommand,
+    PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, compat::CompatArgs,
 };
 use uv_configuration::min_stack_size;
 use uv_fs::{CWD, Simplified};
@@ -43,8 +44,9 @@ use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
+use crate::commands::pip::operations::{FileConflicts, InstallFlags};
 use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterMode};
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
@@ -94,6 +96,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Supports `--isolated` as its own argument, so we can't warn either way.
             Commands::Project(command) if matches!(**command, ProjectCommand::Run(_)) => false,
 
+            // Supports `--isolated` as its own argument, which also ignores configuration files.
+            Commands::Pip(PipNamespace {
+                command: PipCommand::Sync(args),
+            }) if args.isolated => false,
+
             // `--isolated` moved to `--no-workspace`.
             Commands::Project(command) if matches!(**command, ProjectCommand::Init(_)) => {
                 warn_user!(
@@ -139,7 +146,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             );
         }
         Some(FilesystemOptions::from_file(config_file)?)
-    } else if deprecated_isolated || cli.top_level.no_config {
+    } else if deprecated_isolated || cli.top_level.no_config || is_isolated_pip_sync(&cli) {
         None
     } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
         // For commands that operate at the user-level, ignore local configuration.
@@ -304,7 +311,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .combine(filesystem);
 
     // Load environment variables not handled by Clap
-    let environment = EnvironmentOptions::new()?;
+    let environment = if is_isolated_pip_sync(&cli) {
+        EnvironmentOptions::default()
+    } else {
+        EnvironmentOptions::new()?
+    };
 
     // Resolve the global settings.
     let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
@@ -368,17 +379,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
-    let printer = if globals.quiet == 1 {
-        Printer::Quiet
+    let printer = Printer::from(if globals.quiet == 1 {
+        PrinterMode::Quiet
     } else if globals.quiet > 1 {
-        Printer::Silent
+        PrinterMode::Silent
     } else if globals.verbose > 0 {
-        Printer::Verbose
+        PrinterMode::Verbose
     } else if globals.no_progress {
-        Printer::NoProgress
+        PrinterMode::NoProgress
     } else {
-        Printer::Default
-    };
+        PrinterMode::Default
+    });
 
     // Configure the `warn!` macros, which control user-facing warnings in the CLI.
     if globals.quiet > 0 {
@@ -389,6 +400,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     anstream::ColorChoice::write_global(globals.color.into());
 
+    // Apply the same choice to `console`, which styles progress bars and prompts, and otherwise
+    // only consults `CLICOLOR` and `CLICOLOR_FORCE`.
+    match globals.color {
+        ColorChoice::Auto => {}
+        ColorChoice::Always => {
+            console::set_colors_enabled(true);
+            console::set_colors_enabled_stderr(true);
+        }
+        ColorChoice::Never => {
+            console::set_colors_enabled(false);
+            console::set_colors_enabled_stderr(false);
+        }
+    }
+
     miette::set_hook(Box::new(|_| {
         Box::new(
             miette::MietteHandlerOpts::new()
@@ -427,7 +452,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_index_ttl(cache_settings.index_cache_ttl);
 
     match *cli.command {
         Commands::Help(args) => commands::help(
@@ -440,8 +466,26 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }) => {
             args.compat_args.validate()?;
 
+            // Multiple platforms are distinguished by `sys_platform` in the output, so each must
+            // target a different operating system.
+            let duplicate = args
+                .python_platform
+                .iter()
+                .enumerate()
+                .find(|(index, platform)| {
+                    args.python_platform[..*index].iter().any(|other| {
+                        other != *platform && other.sys_platform() == platform.sys_platform()
+                    })
+                });
+            if let Some((_, platform)) = duplicate {
+                let sys_platform = platform.sys_platform();
+                bail!(
+                    "Multiple `--python-platform` values target the same operating system (`sys_platform == '{sys_platform}'`); provide at most one platform per operating system"
+                );
+            }
+
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let args = PipCompileSettings::resolve(args, filesystem);
+            let args = PipCompileSettings::resolve(args, filesystem, environment);
             show_settings!(args);
 
             // Initialize the cache.
@@ -485,6 +529,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.overrides_from_workspace,
                 args.build_constraints_from_workspace,
                 args.environments,
+                args.required_environments,
+                args.report_unused_constraints,
                 args.settings.extras,
                 groups,
                 args.settings.output_file.as_deref(),
@@ -541,7 +587,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.compat_args.validate()?;
 
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let args = PipSyncSettings::resolve(args, filesystem);
+            let args = PipSyncSettings::resolve(args, filesystem, environment);
             show_settings!(args);
 
             // Initialize the cache.
@@ -554,8 +600,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let requirements = args
                 .src_file
                 .into_iter()
-                .map(RequirementsSource::from_requirements_file)
+                .map(|path| {
+                    RequirementsSource::from_requirements_file(path)
+                        .map(|source| source.with_pipfile_develop(args.dev))
+                })
                 .collect::<Result<Vec<_>, _>>()?;
+            if args.dev
+                && !requirements
+                    .iter()
+                    .any(|source| matches!(source, RequirementsSource::PipfileLock { .. }))
+            {
+                bail!("`--dev` can only be used with a `Pipfile.lock`");
+            }
             let constraints = args
                 .constraints
                 .into_iter()
@@ -571,7 +627,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 groups: args.settings.groups,
             };
 
-            commands::pip_sync(
+            // Capture a copy of the summary, if requested.
+            let (printer, capture) = match args.summary_file {
+                Some(path) => {
+                    let (printer, capture) = printer.with_capture(path);
+                    (printer, Some(capture))
+                }
+                None => (printer, None),
+            };
+
+            let result = commands::pip_sync(
                 &requirements,
                 &constraints,
                 &build_constraints,
@@ -580,9 +645,33 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                InstallFlags {
+                    compile_packages: &args.compile_package,
+                    compile_strict: args.compile_strict,
+                    compile_optimize: &args.optimize,
+                    // Check for files that would be overwritten by another package, if requested.
+                    file_conflicts: if args.strict_file_conflicts {
+                        FileConflicts::Error
+                    } else if args.settings.strict {
+                        FileConflicts::Warn
+                    } else {
+                        FileConflicts::Ignore
+                    },
+                    verify_records: args.verify_records_on_uninstall,
+                    preserve_modified: args.preserve_modified,
+                    sandbox_scripts: args.sandbox_scripts,
+                    installer_name: args.installer_name.as_deref(),
+                    ignore_build_failures: args.ignore_build_failures,
+                    list_extraneous: args.list_extraneous,
+                    dry_run_format: args.output_format,
+                },
+                args.exclude_dependency,
+                args.exclude_hashes_for,
+                args.fail_on_missing_wheel_for,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.settings.resolution,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
@@ -597,21 +686,89 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python_version,
                 args.settings.python_platform,
                 args.settings.strict,
+                args.settings.warn_conflicts,
+                args.warn_shadowing,
+                args.warn_import_conflicts,
+                args.check_native_deps,
+                args.verify_metadata_consistency,
+                args.show_upgrades,
+                args.upgrades_format,
+                args.explain,
+                args.no_wait,
+                args.report_unused_constraints,
+                args.on_conflict,
                 args.settings.exclude_newer,
                 args.settings.python,
+                args.verify_python_version,
+                args.venv,
+                args.venv_dir,
+                args.create,
+                args.platform_tag,
+                args.marker,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.settings.root,
                 args.settings.sources,
                 globals.python_preference,
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.download_only,
+                args.locked,
+                args.no_resolve,
+                args.verify_no_downgrade,
+                args.verify_no_yanked_in_lock,
+                args.verify_interpreter_abi,
+                args.warn_on_unpinned,
+                args.require_pinned,
+                args.allow_sdist_fallback,
+                args.emit_build_tags,
+                args.max_artifact_size,
+                args.warn_slow_index,
+                args.warn_install_size,
+                args.warn_install_count,
+                args.error_on_large_install,
+                args.verify_clean,
+                args.record,
+                args.record_relative,
+                args.record_build_env,
+                args.suggest_cached,
+                args.max_resolution_rounds,
+                args.prune_unused_wheels_from_cache,
+                args.strip_debug,
+                args.exclude_installed,
+                args.reinstall_editables,
+                args.reinstall_foreign,
+                args.reinstall_if_corrupt,
+                args.no_editable,
+                args.env_file,
+                args.env_file_override,
+                args.isolated,
                 printer,
                 globals.preview,
             )
-            .await
+            .await;
+
+            // Persist the summary, including the final error, if any. A failure to write the summary
+            // shouldn't override the outcome of the sync itself.
+            if let Some(capture) = capture {
+                if let Err(err) = &result {
+                    let mut causes = err.chain();
+                    if let Some(first) = causes.next() {
+                        capture.push_str(&format!("error: {}\n", first.to_string().trim()));
+                    }
+                    for cause in causes {
+                        capture.push_str(&format!("  Caused by: {}\n", cause.to_string().trim()));
+                    }
+                }
+                if let Err(err) = capture.finish() {
+                    warn_user!("Failed to write the summary file: {err}");
+                }
+            }
+
+            result
         }
         Commands::Pip(PipNamespace {
             command: PipCommand::Install(args),
@@ -619,7 +776,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.compat_args.validate()?;
 
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let mut args = PipInstallSettings::resolve(args, filesystem);
+            let mut args = PipInstallSettings::resolve(args, filesystem, environment);
             show_settings!(args);
 
             let mut requirements = Vec::with_capacity(
@@ -652,6 +809,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
                 .collect::<Result<Vec<_>, _>>()?;
+            let build_requirements = args
+                .build_requirements
+                .into_iter()
+                .map(RequirementsSource::from_requirements_txt)
+                .collect::<Result<Vec<_>, _>>()?;
             let groups = GroupsSpecification {
                 root: project_dir.to_path_buf(),
                 groups: args.settings.groups,
@@ -711,6 +873,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &constraints,
                 &overrides,
                 &build_constraints,
+                &build_requirements,
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
                 args.build_constraints_from_workspace,
@@ -729,24 +892,39 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                args.compile_package,
+                args.compile_strict,
                 args.settings.hash_checking,
                 globals.installer_metadata,
+                args.installer_name,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
                 args.modifications,
+                args.only_deps,
+                args.max_depth,
+                args.list_extraneous,
                 args.settings.python_version,
                 args.settings.python_platform,
                 args.settings.strict,
+                args.settings.warn_conflicts,
+                args.warn_shadowing,
+                args.warn_import_conflicts,
+                args.verify_metadata_consistency,
+                args.no_wait,
+                args.report_unused_constraints,
                 args.settings.exclude_newer,
                 args.settings.sources,
                 args.settings.python,
+                args.verify_python_version,
+                args.platform_tag,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.settings.root,
                 globals.python_preference,
                 globals.concurrency,
                 cache,
@@ -778,11 +956,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             );
             commands::pip_uninstall(
                 &sources,
+                args.all,
+                args.include_base,
+                args.yes,
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.settings.root,
                 cache,
                 args.settings.keyring_provider,
                 &globals.network_settings,
@@ -923,7 +1105,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, &cache, printer)
+            commands::cache_clean(&args.package, args.output_format, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -931,6 +1113,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, &cache, printer)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_info(args.output_format, &cache, printer)
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
@@ -2165,6 +2353,52 @@ async fn run_project(
     }
 }
 
+/// Returns `true` if the command is `uv pip sync --isolated`.
+fn is_isolated_pip_sync(cli: &Cli) -> bool {
+    matches!(
+        &*cli.command,
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Sync(args),
+        }) if args.isolated
+    )
+}
+
+/// Parse the command-line arguments.
+///
+/// Under `uv pip sync --isolated`, the arguments are parsed without reading any defaults from the
+/// environment (e.g., `UV_INDEX_URL`), such that only the options provided on the command-line
+/// apply, and invalid values in the environment are ignored.
+fn parse_args(args: Vec<OsString>) -> Result<Cli, clap::Error> {
+    let result = Cli::try_parse_from(&args);
+    if result.as_ref().is_ok_and(|cli| !is_isolated_pip_sync(cli)) {
+        return result;
+    }
+
+    // The arguments either requested isolation, or failed to parse, possibly due to a value in
+    // the environment that isolation would ignore. Global arguments are defined on the top-level
+    // command, and propagated to the subcommands.
+    without_env(Cli::command())
+        .mut_subcommand("pip", |pip| pip.mut_subcommand("sync", without_env))
+        .try_get_matches_from(&args)
+        .and_then(|matches| Cli::from_arg_matches(&matches))
+        .ok()
+        .filter(is_isolated_pip_sync)
+        .map_or(result, Ok)
+}
+
+/// Disable reading the arguments of the given command from environment variables.
+fn without_env(mut command: clap::Command) -> clap::Command {
+    let ids = command
+        .get_arguments()
+        .filter(|arg| arg.get_env().is_some())
+        .map(|arg| arg.get_id().clone())
+        .collect::<Vec<_>>();
+    for id in ids {
+        command = command.mut_arg(id, |arg| arg.env(Resettable::Reset));
+    }
+    command
+}
+
 /// The main entry point for a uv invocation.
 ///
 /// # Usage
@@ -2201,7 +2435,7 @@ where
 
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
+    let cli = match parse_args(args.into_iter().map(Into::into).collect()) {
         Ok(cli) => cli,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
@@ -1,8 +1,31 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
 
+/// The buffer to which a capturing [`Stderr`] copies its output.
+static CAPTURED: Mutex<String> = Mutex::new(String::new());
+
+/// A printer for user-facing output, which optionally captures a copy of the output written to
+/// [`Stderr`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Printer {
+    mode: PrinterMode,
+    capture: bool,
+}
+
+impl From<PrinterMode> for Printer {
+    fn from(mode: PrinterMode) -> Self {
+        Self {
+            mode,
+            capture: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Printer {
+pub(crate) enum PrinterMode {
     /// A printer that suppresses all output.
     Silent,
     /// A printer that suppresses most output, but preserves "important" stdout.
@@ -16,52 +39,84 @@ pub(crate) enum Printer {
 }
 
 impl Printer {
+    /// Return the [`PrinterMode`] for this printer.
+    pub(crate) fn mode(self) -> PrinterMode {
+        self.mode
+    }
+
     /// Return the [`ProgressDrawTarget`] for this printer.
     pub(crate) fn target(self) -> ProgressDrawTarget {
-        match self {
-            Self::Silent => ProgressDrawTarget::hidden(),
-            Self::Quiet => ProgressDrawTarget::hidden(),
-            Self::Default => ProgressDrawTarget::stderr(),
+        match self.mode {
+            PrinterMode::Silent => ProgressDrawTarget::hidden(),
+            PrinterMode::Quiet => ProgressDrawTarget::hidden(),
+            PrinterMode::Default => ProgressDrawTarget::stderr(),
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
-            Self::Verbose => ProgressDrawTarget::hidden(),
-            Self::NoProgress => ProgressDrawTarget::hidden(),
+            PrinterMode::Verbose => ProgressDrawTarget::hidden(),
+            PrinterMode::NoProgress => ProgressDrawTarget::hidden(),
         }
     }
 
     /// Return the [`Stdout`] for this printer.
     #[allow(dead_code, reason = "to be adopted incrementally")]
     pub(crate) fn stdout_important(self) -> Stdout {
-        match self {
-            Self::Silent => Stdout::Disabled,
-            Self::Quiet => Stdout::Enabled,
-            Self::Default => Stdout::Enabled,
-            Self::Verbose => Stdout::Enabled,
-            Self::NoProgress => Stdout::Enabled,
+        match self.mode {
+            PrinterMode::Silent => Stdout::Disabled,
+            PrinterMode::Quiet => Stdout::Enabled,
+            PrinterMode::Default => Stdout::Enabled,
+            PrinterMode::Verbose => Stdout::Enabled,
+            PrinterMode::NoProgress => Stdout::Enabled,
         }
     }
 
     /// Return the [`Stdout`] for this printer.
     pub(crate) fn stdout(self) -> Stdout {
-        match self {
-            Self::Silent => Stdout::Disabled,
-            Self::Quiet => Stdout::Disabled,
-            Self::Default => Stdout::Enabled,
-            Self::Verbose => Stdout::Enabled,
-            Self::NoProgress => Stdout::Enabled,
+        match self.mode {
+            PrinterMode::Silent => Stdout::Disabled,
+            PrinterMode::Quiet => Stdout::Disabled,
+            PrinterMode::Default => Stdout::Enabled,
+            PrinterMode::Verbose => Stdout::Enabled,
+            PrinterMode::NoProgress => Stdout::Enabled,
         }
     }
 
     /// Return the [`Stderr`] for this printer.
     pub(crate) fn stderr(self) -> Stderr {
-        match self {
-            Self::Silent => Stderr::Disabled,
-            Self::Quiet => Stderr::Disabled,
-            Self::Default => Stderr::Enabled,
-            Self::Verbose => Stderr::Enabled,
-            Self::NoProgress => Stderr::Enabled,
+        let enabled = match self.mode {
+            PrinterMode::Silent => false,
+            PrinterMode::Quiet => false,
+            PrinterMode::Default => true,
+            PrinterMode::Verbose => true,
+            PrinterMode::NoProgress => true,
+        };
+        Stderr {
+            enabled,
+            capture: self.capture,
+        }
+    }
+
+    /// Return the [`Stderr`] for rich diagnostics (e.g., resolution failures), which are shown
+    /// regardless of the printer's verbosity.
+    pub(crate) fn diagnostics(self) -> Stderr {
+        Stderr {
+            enabled: true,
+            capture: self.capture,
         }
     }
+
+    /// Capture a copy of the output written to [`Stderr`] by the returned printer, to be persisted
+    /// to a file (e.g., for `--summary-file`).
+    ///
+    /// Output is captured regardless of whether the [`Stderr`] is enabled.
+    pub(crate) fn with_capture(self, path: PathBuf) -> (Self, StderrCapture) {
+        (
+            Self {
+                capture: true,
+                ..self
+            },
+            StderrCapture { path },
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -86,24 +141,57 @@ impl std::fmt::Write for Stdout {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Stderr {
-    Enabled,
-    Disabled,
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stderr {
+    enabled: bool,
+    capture: bool,
+}
+
+impl Default for Stderr {
+    /// A [`Stderr`] that writes to stderr unconditionally, without capturing its output.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            capture: false,
+        }
+    }
 }
 
 impl std::fmt::Write for Stderr {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
-        match self {
-            Self::Enabled => {
-                #[allow(clippy::print_stderr, clippy::ignored_unit_patterns)]
-                {
-                    eprint!("{s}");
-                }
+        if self.capture {
+            CAPTURED.lock().unwrap().push_str(s);
+        }
+
+        if self.enabled {
+            #[allow(clippy::print_stderr, clippy::ignored_unit_patterns)]
+            {
+                eprint!("{s}");
             }
-            Self::Disabled => {}
         }
 
         Ok(())
     }
 }
+
+/// A copy of the output written to [`Stderr`] by a capturing [`Printer`].
+#[derive(Debug)]
+pub(crate) struct StderrCapture {
+    path: PathBuf,
+}
+
+impl StderrCapture {
+    /// Append the given text (e.g., a final error message) to the captured output.
+    pub(crate) fn push_str(&self, s: &str) {
+        CAPTURED.lock().unwrap().push_str(s);
+    }
+
+    /// Write the captured output to the file, without any ANSI escape codes.
+    pub(crate) fn finish(self) -> std::io::Result<()> {
+        let captured = std::mem::take(&mut *CAPTURED.lock().unwrap());
+        fs_err::write(
+            &self.path,
+            anstream::adapter::strip_str(&captured).to_string(),
+        )
+    }
+}
@@ -3,16 +3,18 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
+    OnConflict, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs,
+    ToolRunArgs, ToolUninstallArgs, TreeArgs, UpgradesFormat, VenvArgs, VersionArgs, VersionBump,
+    VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
@@ -23,16 +25,18 @@ use uv_client::Connectivity;
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
     ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PackageConfigSettings, PreviewMode,
+    KeyringProviderType, MarkerOverride, NoBinary, NoBuild, PackageConfigSettings, PreviewMode,
     ProjectBuildBackend, Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost,
     TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl, Requirement};
 use uv_install_wheel::LinkMode;
 use uv_normalize::{PackageName, PipGroupName};
+use uv_pep440::VersionSpecifiers;
 use uv_pep508::{ExtraName, MarkerTree, RequirementOrigin};
+use uv_platform_tags::PlatformTag;
 use uv_pypi_types::SupportedEnvironments;
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Root, Target};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode,
@@ -83,6 +87,9 @@ impl GlobalSettings {
             color: if let Some(color_choice) = args.color {
                 // If `--color` is passed explicitly, use its value.
                 color_choice
+            } else if args.no_color {
+                // If `--no-color` is passed explicitly, disable color output.
+                ColorChoice::Never
             } else if std::env::var_os(EnvVars::NO_COLOR)
                 .filter(|v| !v.is_empty())
                 .is_some()
@@ -107,7 +114,9 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
-                builds: env(env::CONCURRENT_BUILDS)
+                builds: args
+                    .max_parallel_builds
+                    .combine(env(env::CONCURRENT_BUILDS))
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
@@ -161,6 +170,8 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) proxy: Option<DisplaySafeUrl>,
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl NetworkSettings {
@@ -197,6 +208,8 @@ impl NetworkSettings {
             connectivity,
             native_tls,
             allow_insecure_host,
+            proxy: args.proxy.clone(),
+            timeout: args.timeout.map(Duration::from_secs),
         }
     }
 }
@@ -206,6 +219,7 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) index_cache_ttl: Option<Duration>,
 }
 
 impl CacheSettings {
@@ -219,6 +233,7 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            index_cache_ttl: args.index_cache_ttl.map(Duration::from_secs),
         }
     }
 }
@@ -1863,16 +1878,23 @@ pub(crate) struct PipCompileSettings {
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) required_environments: SupportedEnvironments,
+    pub(crate) report_unused_constraints: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
 
 impl PipCompileSettings {
-    /// Resolve the [`PipCompileSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: PipCompileArgs, filesystem: Option<FilesystemOptions>) -> Self {
+    /// Resolve the [`PipCompileSettings`] from the CLI, environment, and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipCompileArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
         let PipCompileArgs {
             src_file,
             constraints,
+            report_unused_constraints,
             overrides,
             extra,
             all_extras,
@@ -1971,6 +1993,43 @@ impl PipCompileSettings {
             SupportedEnvironments::default()
         };
 
+        // If multiple platforms were requested, perform a universal resolution that's limited to,
+        // and requires wheel coverage for, the operating system of each platform.
+        let universal = flag(universal, no_universal, "universal");
+        if python_platform.len() > 1 {
+            if universal == Some(false) {
+                warn_user_once!(
+                    "`--no-universal` has no effect when multiple `--python-platform` values are provided"
+                );
+            }
+            if !environments.as_markers().is_empty() {
+                warn_user_once!(
+                    "The `environments` setting has no effect when multiple `--python-platform` values are provided"
+                );
+            }
+        }
+        let (python_platform, universal, environments, required_environments) =
+            if python_platform.len() > 1 {
+                let mut markers = Vec::with_capacity(python_platform.len());
+                for marker in python_platform
+                    .into_iter()
+                    .map(TargetTriple::sys_platform_marker)
+                {
+                    if !markers.contains(&marker) {
+                        markers.push(marker);
+                    }
+                }
+                let markers = SupportedEnvironments::from_markers(markers);
+                (None, Some(true), markers.clone(), markers)
+            } else {
+                (
+                    python_platform.into_iter().next(),
+                    universal,
+                    environments,
+                    SupportedEnvironments::default(),
+                )
+            };
+
         Self {
             format,
             src_file,
@@ -1990,6 +2049,8 @@ impl PipCompileSettings {
             overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            required_environments,
+            report_unused_constraints,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1997,7 +2058,7 @@ impl PipCompileSettings {
                     system: flag(system, no_system, "system"),
                     no_build: flag(no_build, build, "build"),
                     no_binary,
-                    only_binary,
+                    only_binary: only_binary.or(environment.only_binary),
                     extra,
                     all_extras: flag(all_extras, no_all_extras, "all-extras"),
                     no_deps: flag(no_deps, deps, "deps"),
@@ -2011,7 +2072,7 @@ impl PipCompileSettings {
                     generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
                     python_version,
                     python_platform,
-                    universal: flag(universal, no_universal, "universal"),
+                    universal,
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url"),
                     emit_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links"),
@@ -2046,14 +2107,81 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) dev: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) output_format: SyncFormat,
+    pub(crate) download_only: Option<PathBuf>,
+    pub(crate) ignore_build_failures: bool,
+    pub(crate) locked: bool,
+    pub(crate) no_resolve: bool,
+    pub(crate) verify_no_downgrade: bool,
+    pub(crate) verify_no_yanked_in_lock: bool,
+    pub(crate) verify_interpreter_abi: bool,
+    pub(crate) warn_on_unpinned: bool,
+    pub(crate) require_pinned: bool,
+    pub(crate) allow_sdist_fallback: bool,
+    pub(crate) strict_file_conflicts: bool,
+    pub(crate) verify_records_on_uninstall: bool,
+    pub(crate) preserve_modified: bool,
+    pub(crate) emit_build_tags: bool,
+    pub(crate) max_artifact_size: Option<u64>,
+    pub(crate) warn_slow_index: Option<Duration>,
+    pub(crate) warn_install_size: Option<u64>,
+    pub(crate) warn_install_count: Option<usize>,
+    pub(crate) error_on_large_install: bool,
+    pub(crate) list_extraneous: bool,
+    pub(crate) verify_clean: Option<PathBuf>,
+    pub(crate) record: Option<PathBuf>,
+    pub(crate) record_relative: bool,
+    pub(crate) record_build_env: Option<PathBuf>,
+    pub(crate) summary_file: Option<PathBuf>,
+    pub(crate) suggest_cached: bool,
+    pub(crate) max_resolution_rounds: Option<usize>,
+    pub(crate) prune_unused_wheels_from_cache: bool,
+    pub(crate) strip_debug: bool,
+    pub(crate) sandbox_scripts: bool,
+    pub(crate) exclude_installed: bool,
+    pub(crate) reinstall_editables: bool,
+    pub(crate) reinstall_foreign: bool,
+    pub(crate) reinstall_if_corrupt: bool,
+    pub(crate) no_editable: bool,
+    pub(crate) env_file: Vec<PathBuf>,
+    pub(crate) env_file_override: bool,
+    pub(crate) isolated: bool,
+    pub(crate) compile_package: Vec<PackageName>,
+    pub(crate) compile_strict: bool,
+    pub(crate) optimize: Vec<u8>,
+    pub(crate) exclude_dependency: Vec<PackageName>,
+    pub(crate) exclude_hashes_for: Vec<PackageName>,
+    pub(crate) fail_on_missing_wheel_for: Vec<PackageName>,
+    pub(crate) installer_name: Option<String>,
+    pub(crate) verify_python_version: Option<VersionSpecifiers>,
+    pub(crate) venv: Option<String>,
+    pub(crate) venv_dir: Option<PathBuf>,
+    pub(crate) create: bool,
+    pub(crate) platform_tag: Vec<PlatformTag>,
+    pub(crate) marker: Vec<MarkerOverride>,
+    pub(crate) warn_shadowing: bool,
+    pub(crate) warn_import_conflicts: bool,
+    pub(crate) check_native_deps: bool,
+    pub(crate) verify_metadata_consistency: bool,
+    pub(crate) show_upgrades: bool,
+    pub(crate) upgrades_format: UpgradesFormat,
+    pub(crate) explain: Vec<PackageName>,
+    pub(crate) no_wait: bool,
+    pub(crate) report_unused_constraints: bool,
+    pub(crate) on_conflict: OnConflict,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
 
 impl PipSyncSettings {
-    /// Resolve the [`PipSyncSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: Box<PipSyncArgs>, filesystem: Option<FilesystemOptions>) -> Self {
+    /// Resolve the [`PipSyncSettings`] from the CLI, environment, and filesystem configuration.
+    pub(crate) fn resolve(
+        args: Box<PipSyncArgs>,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
         let PipSyncArgs {
             src_file,
             constraints,
@@ -2061,7 +2189,9 @@ impl PipSyncSettings {
             extra,
             all_extras,
             no_all_extras,
+            only_extras,
             group,
+            dev,
             installer,
             refresh,
             require_hashes,
@@ -2071,12 +2201,17 @@ impl PipSyncSettings {
             python,
             system,
             no_system,
+            venv,
+            venv_dir,
+            create,
             break_system_packages,
             no_break_system_packages,
             target,
             prefix,
+            root,
             allow_empty_requirements,
             no_allow_empty_requirements,
+            resolution,
             no_build,
             build,
             no_binary,
@@ -2085,11 +2220,79 @@ impl PipSyncSettings {
             python_platform,
             strict,
             no_strict,
+            no_warn_conflicts,
+            warn_conflicts,
+            warn_shadowing,
+            warn_import_conflicts,
+            check_native_deps,
+            verify_metadata_consistency,
+            show_upgrades,
+            upgrades_format,
+            explain,
+            no_wait,
+            report_unused_constraints,
+            on_conflict,
             dry_run,
+            output_format,
+            download_only,
+            dest,
+            ignore_build_failures,
+            locked,
+            no_resolve,
+            verify_no_downgrade,
+            verify_no_yanked_in_lock,
+            verify_interpreter_abi,
+            warn_on_unpinned,
+            require_pinned,
+            allow_sdist_fallback,
+            strict_file_conflicts,
+            verify_records_on_uninstall,
+            preserve_modified,
+            emit_build_tags,
+            max_artifact_size,
+            warn_slow_index_ms,
+            warn_install_size,
+            warn_install_count,
+            error_on_large_install,
+            list_extraneous,
+            verify_clean,
+            record,
+            record_relative,
+            record_build_env,
+            summary_file,
+            suggest_cached,
+            max_resolution_rounds,
+            prune_unused_wheels_from_cache,
+            strip_debug,
+            sandbox_scripts,
+            exclude_installed,
+            reinstall_editables,
+            reinstall_foreign,
+            reinstall_if_corrupt,
+            no_editable,
+            env_file,
+            env_file_override,
+            isolated,
+            compile_package,
+            compile_strict,
+            optimize,
+            exclude_dependency,
+            exclude_hashes_for,
+            fail_on_missing_wheel_for,
+            installer_name,
+            verify_python_version,
+            platform_tag,
+            marker,
             torch_backend,
             compat_args: _,
         } = *args;
 
+        let dry_run = if locked {
+            DryRun::Check
+        } else {
+            DryRun::from_args(dry_run)
+        };
+
         Self {
             src_file,
             constraints: constraints
@@ -2100,7 +2303,70 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
-            dry_run: DryRun::from_args(dry_run),
+            dev,
+            dry_run,
+            output_format,
+            download_only: if download_only { dest } else { None },
+            ignore_build_failures,
+            locked,
+            no_resolve,
+            verify_no_downgrade,
+            verify_no_yanked_in_lock,
+            verify_interpreter_abi,
+            warn_on_unpinned,
+            require_pinned,
+            allow_sdist_fallback,
+            strict_file_conflicts,
+            verify_records_on_uninstall,
+            preserve_modified,
+            emit_build_tags,
+            max_artifact_size,
+            warn_slow_index: warn_slow_index_ms.map(Duration::from_millis),
+            warn_install_size,
+            warn_install_count,
+            error_on_large_install,
+            list_extraneous,
+            verify_clean,
+            record,
+            record_relative,
+            record_build_env,
+            summary_file,
+            suggest_cached,
+            max_resolution_rounds,
+            prune_unused_wheels_from_cache,
+            strip_debug,
+            sandbox_scripts,
+            exclude_installed,
+            reinstall_editables,
+            reinstall_foreign,
+            reinstall_if_corrupt,
+            no_editable,
+            env_file,
+            env_file_override,
+            isolated,
+            compile_package,
+            compile_strict,
+            optimize,
+            exclude_dependency,
+            exclude_hashes_for,
+            fail_on_missing_wheel_for,
+            installer_name,
+            verify_python_version,
+            venv,
+            venv_dir,
+            create,
+            platform_tag,
+            marker,
+            warn_shadowing,
+            warn_import_conflicts,
+            check_native_deps,
+            verify_metadata_consistency,
+            show_upgrades,
+            upgrades_format,
+            explain,
+            no_wait,
+            report_unused_constraints,
+            on_conflict,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2113,21 +2379,25 @@ impl PipSyncSettings {
                     ),
                     target,
                     prefix,
+                    root,
                     require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
                     verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
                     no_build: flag(no_build, build, "build"),
                     no_binary,
-                    only_binary,
+                    only_binary: only_binary.or(environment.only_binary),
                     allow_empty_requirements: flag(
                         allow_empty_requirements,
                         no_allow_empty_requirements,
                         "allow-empty-requirements",
                     ),
+                    resolution,
                     python_version,
                     python_platform,
                     strict: flag(strict, no_strict, "strict"),
+                    warn_conflicts: flag(warn_conflicts, no_warn_conflicts, "warn-conflicts"),
                     extra,
                     all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                    only_extra: only_extras,
                     group: Some(group),
                     torch_backend,
                     ..PipOptions::from(installer)
@@ -2147,18 +2417,36 @@ pub(crate) struct PipInstallSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) build_requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) only_deps: bool,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) list_extraneous: bool,
+    pub(crate) compile_package: Vec<PackageName>,
+    pub(crate) compile_strict: bool,
+    pub(crate) installer_name: Option<String>,
+    pub(crate) verify_python_version: Option<VersionSpecifiers>,
+    pub(crate) platform_tag: Vec<PlatformTag>,
+    pub(crate) warn_shadowing: bool,
+    pub(crate) warn_import_conflicts: bool,
+    pub(crate) verify_metadata_consistency: bool,
+    pub(crate) no_wait: bool,
+    pub(crate) report_unused_constraints: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
 
 impl PipInstallSettings {
-    /// Resolve the [`PipInstallSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: PipInstallArgs, filesystem: Option<FilesystemOptions>) -> Self {
+    /// Resolve the [`PipInstallSettings`] from the CLI, environment, and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipInstallArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
         let PipInstallArgs {
             package,
             requirements,
@@ -2166,6 +2454,7 @@ impl PipInstallSettings {
             constraints,
             overrides,
             build_constraints,
+            build_requirements,
             extra,
             all_extras,
             no_all_extras,
@@ -2173,6 +2462,8 @@ impl PipInstallSettings {
             refresh,
             no_deps,
             deps,
+            only_deps,
+            max_depth,
             group,
             require_hashes,
             no_require_hashes,
@@ -2185,6 +2476,7 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            root,
             no_build,
             build,
             no_binary,
@@ -2195,7 +2487,20 @@ impl PipInstallSettings {
             exact,
             strict,
             no_strict,
+            no_warn_conflicts,
+            warn_conflicts,
+            warn_shadowing,
+            warn_import_conflicts,
+            verify_metadata_consistency,
+            no_wait,
+            report_unused_constraints,
             dry_run,
+            list_extraneous,
+            compile_package,
+            compile_strict,
+            installer_name,
+            verify_python_version,
+            platform_tag,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2258,6 +2563,10 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            build_requirements: build_requirements
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             dry_run: DryRun::from_args(dry_run),
             constraints_from_workspace,
             overrides_from_workspace,
@@ -2267,6 +2576,19 @@ impl PipInstallSettings {
             } else {
                 Modifications::Sufficient
             },
+            only_deps,
+            max_depth,
+            list_extraneous,
+            compile_package,
+            compile_strict,
+            installer_name,
+            verify_python_version,
+            platform_tag,
+            warn_shadowing,
+            warn_import_conflicts,
+            verify_metadata_consistency,
+            no_wait,
+            report_unused_constraints,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2279,10 +2601,12 @@ impl PipInstallSettings {
                     ),
                     target,
                     prefix,
+                    root,
                     no_build: flag(no_build, build, "build"),
                     no_binary,
-                    only_binary,
+                    only_binary: only_binary.or(environment.only_binary),
                     strict: flag(strict, no_strict, "strict"),
+                    warn_conflicts: flag(warn_conflicts, no_warn_conflicts, "warn-conflicts"),
                     extra,
                     all_extras: flag(all_extras, no_all_extras, "all-extras"),
                     group: Some(group),
@@ -2305,6 +2629,9 @@ impl PipInstallSettings {
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) all: bool,
+    pub(crate) include_base: bool,
+    pub(crate) yes: bool,
     pub(crate) dry_run: DryRun,
     pub(crate) settings: PipSettings,
 }
@@ -2315,6 +2642,9 @@ impl PipUninstallSettings {
         let PipUninstallArgs {
             package,
             requirements,
+            all,
+            include_base,
+            yes,
             python,
             keyring_provider,
             system,
@@ -2323,6 +2653,7 @@ impl PipUninstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            root,
             dry_run,
             compat_args: _,
         } = args;
@@ -2330,6 +2661,9 @@ impl PipUninstallSettings {
         Self {
             package,
             requirements,
+            all,
+            include_base,
+            yes,
             dry_run: DryRun::from_args(dry_run),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2342,6 +2676,7 @@ impl PipUninstallSettings {
                     ),
                     target,
                     prefix,
+                    root,
                     keyring_provider,
                     ..PipOptions::default()
                 },
@@ -2910,6 +3245,7 @@ pub(crate) struct PipSettings {
     pub(crate) break_system_packages: bool,
     pub(crate) target: Option<Target>,
     pub(crate) prefix: Option<Prefix>,
+    pub(crate) root: Option<Root>,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) torch_backend: Option<TorchMode>,
@@ -2918,6 +3254,7 @@ pub(crate) struct PipSettings {
     pub(crate) build_options: BuildOptions,
     pub(crate) allow_empty_requirements: bool,
     pub(crate) strict: bool,
+    pub(crate) warn_conflicts: bool,
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
@@ -2969,6 +3306,7 @@ impl PipSettings {
             break_system_packages,
             target,
             prefix,
+            root,
             index,
             index_url,
             extra_index_url,
@@ -2983,9 +3321,11 @@ impl PipSettings {
             no_build_isolation,
             no_build_isolation_package,
             strict,
+            warn_conflicts,
             extra,
             all_extras,
             no_extra,
+            only_extra,
             group,
             no_deps,
             allow_empty_requirements,
@@ -3108,8 +3448,7 @@ impl PipSettings {
                 args.no_extra.combine(no_extra).unwrap_or_default(),
                 // TODO(blueraft): support no_default_extras
                 false,
-                // TODO(blueraft): support only_extra
-                vec![],
+                args.only_extra.combine(only_extra).unwrap_or_default(),
                 args.all_extras.combine(all_extras).unwrap_or_default(),
             ),
 
@@ -3220,6 +3559,7 @@ impl PipSettings {
                 .unwrap_or_default(),
             target: args.target.combine(target).map(Target::from),
             prefix: args.prefix.combine(prefix).map(Prefix::from),
+            root: args.root.combine(root).map(Root::from),
             compile_bytecode: args
                 .compile_bytecode
                 .combine(compile_bytecode)
@@ -3228,6 +3568,7 @@ impl PipSettings {
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
             strict: args.strict.combine(strict).unwrap_or_default(),
+            warn_conflicts: args.warn_conflicts.combine(warn_conflicts).unwrap_or(true),
             upgrade: Upgrade::from_args(
                 args.upgrade.combine(upgrade),
                 args.upgrade_package
@@ -167,3 +167,56 @@ fn clean_package_index() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache clean --output-format json` should report the removed entries for each package.
+#[test]
+fn clean_package_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The file count and size vary by operating system, so we filter them out.
+            (r#""removed_files":\d+"#, r#""removed_files":[N]"#),
+            (r#""freed_bytes":\d+"#, r#""freed_bytes":[SIZE]"#),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean()
+        .arg("iniconfig")
+        .arg("--output-format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"package":"iniconfig","removed_files":[N],"freed_bytes":[SIZE]}]
+
+    ----- stderr -----
+    "###);
+
+    // Cleaning a package without any cache entries should report zero removals.
+    uv_snapshot!(context.filters(), context.clean()
+        .arg("iniconfig")
+        .arg("--output-format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"package":"iniconfig","removed_files":0,"freed_bytes":0}]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache info` should report a missing cache.
+#[test]
+fn info_empty() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    fs_err::remove_dir_all(&context.cache_dir)?;
+
+    uv_snapshot!(context.filters(), context.cache_info(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache found at: [CACHE_DIR]/
+    "###);
+
+    Ok(())
+}
+
+/// `cache info --output-format json` should summarize the entries in each bucket.
+#[test]
+fn info_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let output = context
+        .cache_info()
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(info["total_bytes"].as_u64().is_some_and(|bytes| bytes > 0));
+
+    // The `iniconfig` wheel should be the only entry in the wheels bucket.
+    let wheels = info["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| bucket["bucket"] == "wheels-v5")
+        .unwrap();
+    assert_eq!(wheels["entries"], 1);
+
+    let largest = info["largest"].as_array().unwrap();
+    assert!(!largest.is_empty());
+    assert!(
+        largest
+            .iter()
+            .any(|entry| entry["path"] == "wheels-v5/pypi/iniconfig")
+    );
+
+    Ok(())
+}
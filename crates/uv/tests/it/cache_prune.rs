@@ -2,7 +2,9 @@ use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::indoc;
+use predicates::prelude::predicate;
 
+use uv_fs::LockedFile;
 use uv_static::EnvVars;
 
 use crate::common::TestContext;
@@ -83,6 +85,83 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune` should remove any temporary directories left behind by an interrupted build.
+#[test]
+fn prune_interrupted_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Simulate the temporary directories of a build that was interrupted before they could be
+    // dropped.
+    let build_dir = context.cache_dir.child("builds-v0").child(".tmpAbC123");
+    build_dir.create_dir_all()?;
+    let unpack_dir = context.cache_dir.child("sdists-v9").child(".tmpDeF456");
+    unpack_dir.create_dir_all()?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((r"Removed \d+ files", "Removed [N] files")))
+        .collect();
+
+    uv_snapshot!(&filters, context.prune().arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/builds-v0/.tmpAbC123`
+    DEBUG Removing dangling build directory: [CACHE_DIR]/builds-v0/.tmpAbC123
+    DEBUG Acquired lock for `[CACHE_DIR]/sdists-v9/.tmpDeF456`
+    DEBUG Removing dangling temporary directory: [CACHE_DIR]/sdists-v9/.tmpDeF456
+    Removed 2 directories
+    "###);
+
+    build_dir.assert(predicate::path::missing());
+    unpack_dir.assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// `cache prune` should retain any temporary directories that are in use by another process.
+#[test]
+fn prune_in_use_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Simulate the temporary directory of a build that's still running, which holds its lock.
+    let build_dir = context.cache_dir.child("builds-v0").child(".tmpGhI789");
+    build_dir.create_dir_all()?;
+    let _lock = LockedFile::acquire_blocking(build_dir.path().with_extension("lock"), "build")?;
+
+    uv_snapshot!(context.filters(), context.prune().arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Lock for `[CACHE_DIR]/builds-v0/.tmpGhI789` is held by another process
+    DEBUG Skipping build directory in use: [CACHE_DIR]/builds-v0/.tmpGhI789
+    No unused entries found
+    "###);
+
+    build_dir.assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
 /// `cache prune` should remove all cached environments from the cache.
 #[test]
 fn prune_cached_env() {
@@ -14,7 +14,7 @@ use assert_fs::assert::PathAssert;
 use assert_fs::fixture::{ChildPath, PathChild, PathCopy, PathCreateDir, SymlinkToFile};
 use base64::{Engine, prelude::BASE64_STANDARD as base64};
 use futures::StreamExt;
-use indoc::formatdoc;
+use indoc::{formatdoc, indoc};
 use itertools::Itertools;
 use predicates::prelude::predicate;
 use regex::Regex;
@@ -1139,6 +1139,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache info` command.
+    pub fn cache_info(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("info");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1689,6 +1697,52 @@ pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Create a package `name` in `dir` with an in-tree PEP 517 build backend.
+///
+/// The `backend` source is appended to a `backend.py` module that provides a
+/// `write_wheel(wheel_directory, name, files)` helper, which writes a `{name}-0.1.0` wheel
+/// containing the given files (a mapping from path to contents), along with default `METADATA`,
+/// `WHEEL`, and `RECORD` files. The `backend` source must define (at least) `build_wheel`.
+pub fn make_backend_project(dir: &Path, name: &str, backend: &str) -> anyhow::Result<()> {
+    let pyproject_toml = formatdoc! {r#"
+        [project]
+        name = "{name}"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+        "#
+    };
+    let prelude = indoc! {r#"
+        import os
+        import zipfile
+
+        def write_wheel(wheel_directory, name, files):
+            dist_info = f"{name}-0.1.0.dist-info"
+            files = {
+                f"{dist_info}/METADATA": f"Metadata-Version: 2.1\nName: {name}\nVersion: 0.1.0\n",
+                f"{dist_info}/WHEEL": (
+                    "Wheel-Version: 1.0\nGenerator: backend\nRoot-Is-Purelib: true\nTag: py3-none-any\n"
+                ),
+                **files,
+            }
+            record = "".join(f"{path},,\n" for path in [*files, f"{dist_info}/RECORD"])
+            filename = f"{name}-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                for path, content in files.items():
+                    wheel.writestr(path, content.encode("utf-8"))
+                wheel.writestr(f"{dist_info}/RECORD", record.encode("utf-8"))
+            return filename
+
+    "#};
+    fs_err::create_dir_all(dir)?;
+    fs_err::write(dir.join("pyproject.toml"), pyproject_toml)?;
+    fs_err::write(dir.join("backend.py"), format!("{prelude}\n{backend}"))?;
+    Ok(())
+}
+
 // This is a fine-grained token that only has read-only access to the `uv-private-pypackage` repository
 pub const READ_ONLY_GITHUB_TOKEN: &[&str] = &[
     "Z2l0aHViX3BhdA==",
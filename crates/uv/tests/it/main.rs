@@ -14,6 +14,9 @@ mod build_backend;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_info;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
@@ -1,5 +1,7 @@
+use std::time::Duration;
 use std::{env, io};
 
+use assert_cmd::prelude::*;
 use assert_fs::fixture::{ChildPath, FileWriteStr, PathChild};
 use http::StatusCode;
 use serde_json::json;
@@ -300,3 +302,135 @@ async fn python_install_io_error() {
       Caused by: connection closed before message completed
     ");
 }
+
+/// Check that a stalled index request times out after `--timeout` seconds, and is retried.
+#[tokio::test]
+async fn simple_timeout() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_delay(Duration::from_secs(10)))
+        .mount(&server)
+        .await;
+    let mock_server_uri = server.uri();
+
+    // `--timeout` takes precedence over `UV_HTTP_TIMEOUT`.
+    let filters = vec![(mock_server_uri.as_str(), "[SERVER]")];
+    uv_snapshot!(filters, context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--timeout")
+        .arg("1")
+        .env(EnvVars::UV_HTTP_TIMEOUT, "60")
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to fetch: `[SERVER]/tqdm/`
+      Caused by: Request failed after 3 retries
+      Caused by: error sending request for url ([SERVER]/tqdm/)
+      Caused by: operation timed out
+    ");
+
+    // The initial request and each of the retries should have reached the server.
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+}
+
+/// Check that a `--timeout` of zero is rejected, rather than failing every request.
+#[test]
+fn timeout_zero() {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--timeout")
+        .arg("0")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid value '0' for '--timeout <SECONDS>'"),
+        "{stderr}"
+    );
+}
+
+/// Check that requests are routed through the proxy provided via `--proxy`.
+#[tokio::test]
+async fn proxy() {
+    let context = TestContext::new("3.12");
+
+    let (index_server, index_server_uri) = http_error_server().await;
+    let (proxy_server, proxy_server_uri) = http_error_server().await;
+
+    context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&index_server_uri)
+        .arg("--proxy")
+        .arg(&proxy_server_uri)
+        .env_remove(EnvVars::NO_PROXY)
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .assert()
+        .failure();
+
+    // The request should've been sent to the proxy, rather than to the index.
+    assert!(!proxy_server.received_requests().await.unwrap().is_empty());
+    assert!(index_server.received_requests().await.unwrap().is_empty());
+}
+
+/// Check that hosts listed in `NO_PROXY` bypass the proxy provided via `--proxy`.
+#[tokio::test]
+async fn proxy_no_proxy() {
+    let context = TestContext::new("3.12");
+
+    let (index_server, index_server_uri) = http_error_server().await;
+    let (proxy_server, proxy_server_uri) = http_error_server().await;
+
+    context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&index_server_uri)
+        .arg("--proxy")
+        .arg(&proxy_server_uri)
+        .env(EnvVars::NO_PROXY, "example.com,127.0.0.1")
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .assert()
+        .failure();
+
+    // The request should've been sent directly to the index, bypassing the proxy.
+    assert!(proxy_server.received_requests().await.unwrap().is_empty());
+    assert!(!index_server.received_requests().await.unwrap().is_empty());
+}
+
+/// Check that an unsupported proxy scheme is rejected.
+#[test]
+fn proxy_invalid_scheme() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--proxy")
+        .arg("ftp://proxy.example.com"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'ftp://proxy.example.com' for '--proxy <PROXY>': unsupported proxy scheme `ftp` (expected `http`, `https`, `socks5`, or `socks5h`)
+
+    For more information, try '--help'.
+    ");
+}
@@ -364,6 +364,52 @@ fn compile_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Report any constraints that don't apply to the resolution, or that don't bound the versions of
+/// a package, with `--report-unused-constraints`.
+#[test]
+fn compile_report_unused_constraints() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna<3.4
+        flask<3
+        sniffio
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--report-unused-constraints"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --report-unused-constraints
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.3
+        # via
+        #   -c constraints.txt
+        #   anyio
+    sniffio==1.3.1
+        # via
+        #   -c constraints.txt
+        #   anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: The constraint `flask<3` doesn't apply to any package in the resolution
+    warning: The constraint `sniffio` doesn't bound any versions of `sniffio`
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with an inline constraint.
 #[test]
 fn compile_constraints_inline() -> Result<()> {
@@ -3316,6 +3362,46 @@ dependencies = ["anyio==3.7.0", "anyio==4.0.0"]
     Ok(())
 }
 
+/// Compile requirements that cannot be solved due to a conflict between two extras in a
+/// `pyproject.toml` file. The error should name the extras that introduced each requirement.
+#[test]
+fn compile_unsolvable_requirements_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools>=42"]
+
+[project]
+name = "my-project"
+version = "0.1.0"
+dependencies = []
+
+[project.optional-dependencies]
+cpu = ["anyio==3.7.0"]
+gpu = ["anyio==4.0.0"]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("cpu")
+            .arg("--extra")
+            .arg("gpu"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because my-project[cpu] depends on anyio==3.7.0 and my-project[gpu] depends on anyio==4.0.0, we can conclude that your requirements are unsatisfiable.
+    "
+    );
+
+    Ok(())
+}
+
 /// Compile requirements in a `pyproject.toml` file that cannot be resolved due to
 /// a requirement with a version that is not available online.
 #[test]
@@ -3817,6 +3903,49 @@ fn compile_yanked_version_indirect() -> Result<()> {
     Ok(())
 }
 
+/// Skip the yanked version of `attrs` (`21.1.0`) when it's requested transitively, in favor of an
+/// older, un-yanked version (`20.3.0`).
+#[test]
+fn compile_yanked_version_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"[project]
+name = "child"
+version = "0.1.0"
+requires-python = ">=3.12"
+dependencies = ["attrs>=20.3.0,<21.2.0"]
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+"#,
+    )?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("child @ ./child")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    attrs==20.3.0
+        # via child
+    ./child
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
 /// requirement with an incompatible version.
 #[test]
@@ -5381,6 +5510,74 @@ fn find_links_uv_env_var() -> Result<()> {
     Ok(())
 }
 
+/// Compile using `--find-links` with `abi3` wheels for multiple CPython versions, ensuring that the
+/// most specific compatible `abi3` wheel is preferred.
+#[test]
+fn find_links_abi3() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("wheel-tag-test")?;
+
+    // Copy a wheel into the `--find-links` directory under both `cp39-abi3` and `cp312-abi3` tags.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/wheel_tag_test-0.1.0-py3-none-win_amd64.whl");
+    for tag in ["cp39-abi3", "cp312-abi3"] {
+        fs_err::copy(
+            &wheel,
+            links.child(format!(
+                "wheel_tag_test-0.1.0-{tag}-manylinux_2_17_x86_64.whl"
+            )),
+        )?;
+    }
+
+    // On CPython 3.12, the `cp312-abi3` wheel should be preferred.
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--verbose")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("(wheel_tag_test-0.1.0-cp312-abi3-manylinux_2_17_x86_64.whl)"),
+        "{stderr}"
+    );
+
+    // If only the `cp39-abi3` wheel is available, it should still be considered compatible.
+    fs_err::remove_file(links.child("wheel_tag_test-0.1.0-cp312-abi3-manylinux_2_17_x86_64.whl"))?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--verbose")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("(wheel_tag_test-0.1.0-cp39-abi3-manylinux_2_17_x86_64.whl)"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
 /// `extras==0.0.2` fails to build (i.e., it always throws). Since `extras==0.0.1` is pinned, we
 /// should never even attempt to build `extras==0.0.2`, despite an unpinned `extras[dev]`
 /// requirement.
@@ -7445,6 +7642,53 @@ fn no_strip_extra() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package with `--strip-extras`, overriding `no-strip-extras` in the configuration.
+#[test]
+fn strip_extra_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r"
+        [pip]
+        no-strip-extras = true
+    "})?;
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask[dotenv]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--strip-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --strip-extras
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package with `--no-strip-extras`.
 #[test]
 #[cfg(not(windows))]
@@ -7904,6 +8148,56 @@ fn universal_constraint_marker() -> Result<()> {
     Ok(())
 }
 
+/// Platform-specific requirements retain their markers in a universal resolution, rather than
+/// being resolved against the current platform, such that the output can be synced elsewhere.
+#[test]
+#[cfg(not(windows))]
+fn universal_platform_specific_round_trip() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig
+        pywin32 ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --output-file requirements.txt
+    iniconfig==2.0.0
+        # via -r requirements.in
+    pywin32==306 ; sys_platform == 'win32'
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // Syncing on another platform skips the Windows-only requirement.
+    uv_snapshot!(context.filters(), context.pip_sync()
+            .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
 /// Perform a universal resolution with a divergent requirement, and a third requirement that's
 /// compatible with both forks.
 ///
@@ -12322,6 +12616,39 @@ fn compile_index_url_first_match_base() -> Result<()> {
     Ok(())
 }
 
+/// With `--index-strategy failover`, subsequent indexes are only queried if an index can't be
+/// reached, so the hint for unchecked indexes should be shown too.
+#[test]
+fn compile_index_url_failover_base() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("jinja2==3.1.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("--index-url")
+        .arg("https://pypi.org/simple")
+        .arg("--extra-index-url")
+        .arg("https://astral-sh.github.io/pytorch-mirror/whl/cpu")
+        .arg("--index-strategy")
+        .arg("failover")
+        .arg("requirements.in")
+        .arg("--no-deps"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of jinja2==3.1.0 and you require jinja2==3.1.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: `jinja2` was found on https://astral-sh.github.io/pytorch-mirror/whl/cpu, but not at the requested version (jinja2==3.1.0). A compatible version may be available on a subsequent index (e.g., https://pypi.org/simple). With `--index-strategy failover`, subsequent indexes are only queried if an index can't be reached. If the indexes aren't mirrors of one another, use `--index-strategy unsafe-best-match` to consider all versions from all indexes, regardless of the order in which they were defined.
+    "
+    );
+
+    Ok(())
+}
+
 /// Install a package via `--extra-index-url`.
 ///
 /// If the package exists on the "extra" index, but at an incompatible version, the resolution
@@ -17289,6 +17616,100 @@ fn pep_751_compile_no_emit_package() -> Result<()> {
     Ok(())
 }
 
+/// Revalidate cached index responses that are older than `--index-cache-ttl`, even if the index
+/// marked them as fresh.
+#[tokio::test]
+async fn compile_index_cache_ttl() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let server = MockServer::start().await;
+
+    let iniconfig_page = r#"
+    <!DOCTYPE html>
+    <html>
+        <body>
+        <h1>Links for iniconfig</h1>
+        <a href="https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374">iniconfig-2.0.0-py3-none-any.whl</a><br/>
+    </body>
+    </html>
+    "#;
+
+    // The index marks its responses as fresh for an hour; it should be queried once to populate
+    // the cache, and once more to revalidate.
+    Mock::given(method("GET"))
+        .and(path("/iniconfig/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(iniconfig_page, "text/html")
+                .insert_header("Cache-Control", "max-age=3600"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    // Populate the cache.
+    uv_snapshot!(context
+        .pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // The cached response is still fresh, so the index isn't queried.
+    uv_snapshot!(context
+        .pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // With a TTL of zero, the cached response is revalidated.
+    uv_snapshot!(context
+        .pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_INDEX_CACHE_TTL, "0")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Check that we reject versions that have an incompatible `Requires-Python`, but don't
 /// have a `data-requires-python` key on the index page.
 #[tokio::test]
@@ -17603,3 +18024,62 @@ fn omit_python_patch_universal() -> Result<()> {
 
     Ok(())
 }
+
+/// Resolve for multiple `--python-platform` values, emitting a separate entry for each platform
+/// when their wheels differ.
+#[test]
+fn python_platform_multiple() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2024-12-18T00:00:00Z");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("pyqt5-qt5")?;
+
+    // The latest version of `pyqt5-qt5` lacks Windows wheels, so Windows is pinned separately.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg("linux")
+            .arg("--python-platform")
+            .arg("windows"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform linux --python-platform windows
+    pyqt5-qt5==5.15.2 ; sys_platform == 'win32'
+        # via -r requirements.in
+    pyqt5-qt5==5.15.15 ; sys_platform == 'linux'
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Reject multiple `--python-platform` values that target the same operating system, since they
+/// can't be distinguished by `sys_platform`.
+#[test]
+fn python_platform_multiple_same_os() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg("x86_64-manylinux_2_28")
+            .arg("--python-platform")
+            .arg("aarch64-manylinux_2_28"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Multiple `--python-platform` values target the same operating system (`sys_platform == 'linux'`); provide at most one platform per operating system
+    "
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
-use std::io::Cursor;
-use std::process::Command;
+use std::io::{BufRead, BufReader, Cursor, Read, Write};
+use std::process::{Command, Stdio};
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
@@ -7,8 +7,8 @@ use assert_fs::prelude::*;
 use flate2::write::GzEncoder;
 use fs_err as fs;
 use fs_err::File;
-use indoc::indoc;
-use predicates::prelude::predicate;
+use indoc::{formatdoc, indoc};
+use predicates::prelude::{PredicateBooleanExt, predicate};
 use url::Url;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
@@ -68,6 +68,54 @@ fn empty_requirements_txt() -> Result<()> {
     Ok(())
 }
 
+/// Unlike `uv pip sync`, installing from an empty requirements file leaves the environment
+/// untouched.
+#[test]
+fn empty_requirements_txt_existing_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.touch()?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Requirements file `requirements.txt` does not contain any dependencies
+    Audited in [TIME]
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    // `uv pip sync` requires an explicit opt-in before clearing the environment.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Requirements file `requirements.txt` does not contain any dependencies
+    No requirements found (hint: use `--allow-empty-requirements` to clear the environment)
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
 #[test]
 fn missing_pyproject_toml() {
     let context = TestContext::new("3.12");
@@ -582,6 +630,69 @@ async fn install_http_retries() {
     );
 }
 
+/// Install a package from an index that only implements PyPI's legacy JSON API.
+#[tokio::test]
+async fn install_json_api_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pypi/iniconfig/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            indoc! {r#"
+                {
+                    "info": {"name": "iniconfig"},
+                    "releases": {
+                        "2.0.0": [
+                            {
+                                "filename": "iniconfig-2.0.0-py3-none-any.whl",
+                                "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+                                "digests": {"sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"},
+                                "requires_python": ">=3.7",
+                                "size": 5892,
+                                "upload_time_iso_8601": "2023-01-07T11:08:09.864Z",
+                                "yanked": false,
+                                "yanked_reason": null
+                            }
+                        ]
+                    }
+                }
+            "#},
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(&formatdoc! {r#"
+        [[index]]
+        name = "mirror"
+        url = "{}/pypi"
+        format = "json-api"
+        default = true
+        "#,
+        server.uri()
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -664,6 +775,7 @@ async fn install_remote_requirements_txt() -> Result<()> {
 
     ----- stderr -----
     error: Error while accessing remote requirements file: `http://[LOCALHOST]/requirements.txt`
+      Caused by: HTTP status client error (401 Unauthorized)
     "
     );
 
@@ -723,6 +835,74 @@ async fn install_remote_requirements_txt() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from a `--find-links` URL that requires authentication.
+#[tokio::test]
+async fn install_find_links_authenticated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/wheels/"))
+        .and(basic_auth("user", "password"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="/wheels/ok-1.0.0-py3-none-any.whl">ok-1.0.0-py3-none-any.whl</a>"#,
+            "text/html",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/wheels/ok-1.0.0-py3-none-any.whl"))
+        .and(basic_auth("user", "password"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    // Without credentials, the `--find-links` URL can't be read.
+    context
+        .pip_install()
+        .arg("ok")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(format!("{}/wheels/", server.uri()))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to read `--find-links` URL",
+        ))
+        .stderr(predicate::str::contains("401 Unauthorized"));
+
+    // With credentials in the URL, they're used for both the page and the wheels it links to.
+    let find_links = server.uri().replace("http://", "http://user:password@");
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(format!("{find_links}/wheels/")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+
+    Ok(())
+}
+
 async fn start_requirements_server(
     username: &str,
     password: &str,
@@ -2020,17 +2200,113 @@ async fn install_deduplicated_indices() {
     ");
 }
 
-/// Install a package from a public GitHub repository
-#[test]
-#[cfg(feature = "git")]
-fn install_git_public_https() {
-    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+/// Log the final URL (after redirects) and hash of each downloaded artifact in verbose mode.
+#[tokio::test]
+async fn install_verbose_download_provenance() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    uv_snapshot!(
-        context
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files/ok-1.0.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(302).insert_header(
+            "Location",
+            format!("{}/mirror/ok-1.0.0-py3-none-any.whl", server.uri()),
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/mirror/ok-1.0.0-py3-none-any.whl"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&server)
+        .await;
+
+    let hash = "sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f";
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "ok @ {}/files/ok-1.0.0-py3-none-any.whl --hash={hash}",
+        server.uri()
+    ))?;
+
+    let provenance = format!(
+        "from: {}/mirror/ok-1.0.0-py3-none-any.whl ({hash})",
+        server.uri()
+    );
+
+    // At normal verbosity, the download isn't logged.
+    context
         .pip_install()
-        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage"),
-        @r###"
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(" + ok==1.0.0"))
+        .stderr(predicate::str::contains(&provenance).not());
+
+    // With `--verbose`, the final URL and hash are logged when the artifact is downloaded again.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--verbose")
+        .arg("--refresh")
+        .arg("--reinstall")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(&provenance));
+
+    Ok(())
+}
+
+/// Redact credentials embedded in index and direct URLs, both in errors and in the `(from ...)`
+/// annotations of the installation summary.
+#[tokio::test]
+async fn install_redacted_url_credentials() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="/missing/ok-1.0.0-py3-none-any.whl">ok-1.0.0-py3-none-any.whl</a>"#,
+            "text/html",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/ok-1.0.0-py3-none-any.whl"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&server)
+        .await;
+
+    let authority = server.uri().replace("http://", "http://user:secret@");
+
+    // The index links to a wheel that doesn't exist; the resulting 404 must not leak the password.
+    context
+        .pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(format!("{authority}/simple"))
+        .arg("--verbose")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("404 Not Found"))
+        .stderr(predicate::str::contains("secret").not());
+
+    // A direct URL with credentials is annotated with the redacted URL.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(format!("ok @ {authority}/files/ok-1.0.0-py3-none-any.whl")), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2039,48 +2315,158 @@ fn install_git_public_https() {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###);
+     + ok==1.0.0 (from http://user:****@[LOCALHOST]/files/ok-1.0.0-py3-none-any.whl)
+    "
+    );
 
-    context.assert_installed("uv_public_pypackage", "0.1.0");
+    Ok(())
 }
 
-/// Install a package from a public GitHub repository, omitting the `git+` prefix
+/// Installing into an environment whose paths exceed the Windows maximum path length should either
+/// succeed or fail with an actionable error, rather than an opaque "path not found".
 #[test]
-#[cfg(feature = "git")]
-fn install_implicit_git_public_https() {
-    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+#[cfg(windows)]
+fn install_long_path() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    uv_snapshot!(
-        context
+    let venv = context
+        .temp_dir
+        .child("a".repeat(100))
+        .child("b".repeat(100))
+        .child(".venv");
+    context.venv().arg(venv.path()).assert().success();
+
+    let output = context
         .pip_install()
-        .arg("uv-public-pypackage @ https://github.com/astral-test/uv-public-pypackage.git"),
-        @r###"
+        .arg("iniconfig==2.0.0")
+        .env(EnvVars::VIRTUAL_ENV, venv.path())
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success() || stderr.contains("enable long path support"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Warn when installing from a Git repository that uses Git LFS without fetching LFS objects.
+#[test]
+#[cfg(feature = "git")]
+fn install_git_lfs_not_enabled() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a local Git repository that tracks some files with Git LFS.
+    let repo = context.temp_dir.child("repo");
+    repo.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "lfs-package"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    repo.child("src/lfs_package/__init__.py").touch()?;
+    repo.child(".gitattributes")
+        .write_str("*.bin filter=lfs diff=lfs merge=lfs -text\n")?;
+
+    for args in [
+        &["init"][..],
+        &["add", "."][..],
+        &[
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "-c",
+            "commit.gpgsign=false",
+            "commit",
+            "-m",
+            "Initial commit",
+        ][..],
+    ] {
+        Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .assert()
+            .success();
+    }
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"@[0-9a-f]{40}", "@[COMMIT]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+        .env_remove(EnvVars::UV_GIT_LFS)
+        .arg(format!("lfs-package @ git+{}", Url::from_file_path(repo.path()).unwrap())), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    warning: `file://[TEMP_DIR]/repo` uses Git LFS, but LFS objects were not fetched; any files tracked by Git LFS will be replaced by pointer files (hint: set `UV_GIT_LFS=1` to fetch them)
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###);
+     + lfs-package==0.1.0 (from git+file://[TEMP_DIR]/repo@[COMMIT])
+    "###
+    );
 
-    context.assert_installed("uv_public_pypackage", "0.1.0");
+    Ok(())
 }
 
-/// Install and update a package from a public GitHub repository
+/// Install a pre-built wheel that's committed to a Git repository, by pointing the subdirectory at
+/// the wheel file.
 #[test]
 #[cfg(feature = "git")]
-fn update_ref_git_public_https() {
-    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+fn install_git_committed_wheel() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    uv_snapshot!(
-        context
-        .pip_install()
-        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979"),
-        @r###"
+    // Create a local Git repository that contains a wheel, but no buildable package.
+    let repo = context.temp_dir.child("repo");
+    repo.child("dist/ok-1.0.0-py3-none-any.whl")
+        .write_binary(&fs_err::read(
+            context
+                .workspace_root
+                .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        )?)?;
+
+    for args in [
+        &["init"][..],
+        &["add", "."][..],
+        &[
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "-c",
+            "commit.gpgsign=false",
+            "commit",
+            "-m",
+            "Initial commit",
+        ][..],
+    ] {
+        Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .assert()
+            .success();
+    }
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"@[0-9a-f]{40}", "@[COMMIT]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!(
+            "ok @ git+{}#subdirectory=dist/ok-1.0.0-py3-none-any.whl",
+            Url::from_file_path(repo.path()).unwrap()
+        )), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2089,19 +2475,316 @@ fn update_ref_git_public_https() {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
-    "###);
+     + ok==1.0.0 (from git+file://[TEMP_DIR]/repo@[COMMIT]#subdirectory=dist/ok-1.0.0-py3-none-any.whl)
+    "###
+    );
 
-    context.assert_installed("uv_public_pypackage", "0.1.0");
+    context.assert_installed("ok", "1.0.0");
 
-    // Update to a newer commit.
-    uv_snapshot!(
-        context
-        .pip_install()
-        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")
-        .arg("--refresh"),
-        @r###"
-    success: true
+    Ok(())
+}
+
+/// With `--exclude-newer`, a Git branch should resolve to the most recent commit at or before the
+/// cutoff, rather than the branch head.
+#[test]
+#[cfg(feature = "git")]
+fn install_git_branch_exclude_newer() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let repo = context.temp_dir.child("repo");
+    let git = |args: &[&str], date: &str| {
+        Command::new("git")
+            .args(args)
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(&repo)
+            .assert()
+            .success();
+    };
+    let commit = [
+        "-c",
+        "user.name=Alice",
+        "-c",
+        "user.email=alice@example.com",
+        "-c",
+        "commit.gpgsign=false",
+        "commit",
+        "-m",
+    ];
+
+    // Commit the first version of the package in January, and the second in June.
+    for (version, date) in [
+        ("0.1.0", "2024-01-01T00:00:00Z"),
+        ("0.2.0", "2024-06-01T00:00:00Z"),
+    ] {
+        repo.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "dated-package"
+            version = "{version}"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+        repo.child("src/dated_package/__init__.py")
+            .write_str(&format!("__version__ = \"{version}\"\n"))?;
+        if version == "0.1.0" {
+            git(&["init"], date);
+            git(&["checkout", "-b", "main"], date);
+        }
+        git(&["add", "."], date);
+        git(&[&commit[..], &[version][..]].concat(), date);
+    }
+
+    let first = Command::new("git")
+        .args(["rev-parse", "HEAD~1"])
+        .current_dir(&repo)
+        .output()?;
+    let first = String::from_utf8(first.stdout)?.trim().to_string();
+
+    let output = context
+        .pip_install()
+        .arg(format!(
+            "dated-package @ git+{}@main",
+            Url::from_file_path(repo.path()).unwrap()
+        ))
+        .arg("--exclude-newer")
+        .arg("2024-03-01T00:00:00Z")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains(&format!("@{first}")), "{stderr}");
+    context.assert_installed("dated_package", "0.1.0");
+
+    // If the branch has no commit before the cutoff, fail without discarding the cached
+    // repository.
+    let db = fs_err::read_dir(context.cache_dir.child("git-v0").child("db"))?
+        .next()
+        .unwrap()?
+        .path();
+    let marker = db.join("marker");
+    fs_err::write(&marker, "")?;
+
+    let output = context
+        .pip_install()
+        .arg(format!(
+            "dated-package @ git+{}@main",
+            Url::from_file_path(repo.path()).unwrap()
+        ))
+        .arg("--exclude-newer")
+        .arg("2023-01-01T00:00:00Z")
+        .arg("--reinstall")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("No commit on the branch was made at or before"),
+        "{stderr}"
+    );
+    assert!(marker.exists());
+
+    Ok(())
+}
+
+/// Install a package from a public GitHub repository
+#[test]
+#[cfg(feature = "git")]
+fn install_git_public_https() {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    uv_snapshot!(
+        context
+        .pip_install()
+        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###);
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+}
+
+/// Install an editable Git requirement, which should be cloned into the `src` directory.
+#[test]
+#[cfg(feature = "git")]
+fn install_editable_git() -> Result<()> {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("-e git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#egg=uv-public-pypackage")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from file://[TEMP_DIR]/src/uv-public-pypackage)
+    "
+    );
+
+    // The repository should be checked out into `src`.
+    let checkout = context.temp_dir.child("src").child("uv-public-pypackage");
+    checkout.child(".git").assert(predicate::path::is_dir());
+
+    // Modify the checkout. Installing again should reuse it, rather than cloning it again.
+    let marker = checkout.child("marker.txt");
+    marker.touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "
+    );
+
+    marker.assert(predicate::path::exists());
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
+
+    // Requesting a different commit shouldn't silently reuse the existing checkout.
+    requirements_txt.write_str(
+        "-e git+https://github.com/astral-test/uv-public-pypackage@0.0.1#egg=uv-public-pypackage",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The existing checkout at `src/uv-public-pypackage` is not at the requested commit `0dacfd662c64cb4ceb16e6cf65a157a8b715b979`; remove it, or check out `0dacfd662c64cb4ceb16e6cf65a157a8b715b979`, to continue
+    "
+    );
+
+    Ok(())
+}
+
+/// Editable Git requirements are only checked out when installing, not during a dry run or when
+/// compiling.
+#[test]
+#[cfg(feature = "git")]
+fn install_editable_git_dry_run() -> Result<()> {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("-e git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#egg=uv-public-pypackage")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Editable `uv-public-pypackage` must refer to a local directory, not a Git URL: `git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#egg=uv-public-pypackage`
+    "
+    );
+
+    context
+        .temp_dir
+        .child("src")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Install a package from a public GitHub repository, omitting the `git+` prefix
+#[test]
+#[cfg(feature = "git")]
+fn install_implicit_git_public_https() {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    uv_snapshot!(
+        context
+        .pip_install()
+        .arg("uv-public-pypackage @ https://github.com/astral-test/uv-public-pypackage.git"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###);
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+}
+
+/// Install and update a package from a public GitHub repository
+#[test]
+#[cfg(feature = "git")]
+fn update_ref_git_public_https() {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    uv_snapshot!(
+        context
+        .pip_install()
+        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    "###);
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+
+    // Update to a newer commit.
+    uv_snapshot!(
+        context
+        .pip_install()
+        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")
+        .arg("--refresh"),
+        @r###"
+    success: true
     exit_code: 0
     ----- stdout -----
 
@@ -2896,6 +3579,39 @@ fn install_no_binary_cache() {
     );
 }
 
+/// Verbose output should report, per distribution, whether a wheel was served from the cache.
+#[test]
+fn reinstall_cached_wheel_verbose() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install a binary distribution, populating the cache.
+    let output = context.pip_install().arg("idna==3.6").arg("-v").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Downloaded `idna==3.6`"), "{stderr}");
+    assert!(
+        !stderr.contains("Using cached wheel for: idna==3.6"),
+        "{stderr}"
+    );
+
+    // Re-install. The wheel should be served from the cache, rather than downloaded.
+    let output = context
+        .pip_install()
+        .arg("idna==3.6")
+        .arg("--reinstall")
+        .arg("-v")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Using cached wheel for: idna==3.6"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("Downloaded `idna==3.6`"), "{stderr}");
+
+    Ok(())
+}
+
 /// Respect `--only-binary` flags in `requirements.txt`
 #[test]
 fn only_binary_requirements_txt() {
@@ -2927,6 +3643,46 @@ fn only_binary_requirements_txt() {
     );
 }
 
+/// Respect `UV_ONLY_BINARY`, unless overridden on the command line.
+#[test]
+fn only_binary_env() {
+    let context = TestContext::new("3.12");
+
+    // `django-allauth==0.51.0` is only available as a source distribution.
+    uv_snapshot!(context.pip_install()
+        .arg("django_allauth==0.51.0")
+        .env(EnvVars::UV_ONLY_BINARY, ":all:"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because django-allauth==0.51.0 has no usable wheels and you require django-allauth==0.51.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Wheels are required for `django-allauth` because building from source is disabled for all packages (i.e., with `--no-build`)
+    "###
+    );
+
+    // `--no-binary` should allow the package to be built from source.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::UV_ONLY_BINARY, ":all:"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+}
+
 /// `--only-binary` does not apply to editable requirements
 #[test]
 fn only_binary_editable() {
@@ -3213,19 +3969,60 @@ fn install_executable_hardlink() {
     Command::new(executable).arg("--version").assert().success();
 }
 
-/// Install a package from the command line into a virtual environment, ignoring its dependencies.
+/// Install a package with `--link-mode reflink` across filesystems, where cloning is unsupported,
+/// and ensure that uv fails rather than falling back to copying.
 #[test]
-fn no_deps() {
+#[cfg(target_os = "linux")]
+fn install_reflink_unsupported() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Install Flask.
-    uv_snapshot!(context.pip_install()
-        .arg("Flask")
-        .arg("--no-deps")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    // Cloning is never supported across filesystem boundaries, so install into a `tmpfs` mount.
+    let shm = std::path::Path::new("/dev/shm");
+    if !shm.is_dir() {
+        return Ok(());
+    }
+    let target = tempfile::tempdir_in(shm)?;
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--target")
+        .arg(target.path())
+        .arg("--link-mode")
+        .arg("reflink")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("Failed to reflink"), "{stderr}");
+    assert!(!stderr.contains("falling back to full copy"), "{stderr}");
+
+    // With `--link-mode clone`, uv should fall back to copying.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--target")
+        .arg(target.path())
+        .arg("--link-mode")
+        .arg("clone")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Install a package from the command line into a virtual environment, ignoring its dependencies.
+#[test]
+fn no_deps() {
+    let context = TestContext::new("3.12");
+
+    // Install Flask.
+    uv_snapshot!(context.pip_install()
+        .arg("Flask")
+        .arg("--no-deps")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -3243,6 +4040,98 @@ fn no_deps() {
     context.assert_command("import flask").failure();
 }
 
+/// Install only the dependencies of a package, omitting the package itself. Packages that are
+/// requested directly, but are also required by another package, should still be installed.
+#[test]
+fn only_deps() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("Flask")
+        .arg("Jinja2")
+        .arg("--only-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+
+    context.assert_command("import flask").failure();
+    context.assert_command("import jinja2").success();
+}
+
+/// Install a package, omitting any dependencies beyond the given depth.
+#[test]
+fn max_depth() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("Flask")
+        .arg("--max-depth")
+        .arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    warning: The dependency graph was truncated at a depth of 1, omitting 1 package; the environment may be non-functional
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + werkzeug==3.0.1
+    "###
+    );
+
+    context.assert_command("import markupsafe").failure();
+}
+
+/// List packages that aren't included in the requirements, without removing them.
+#[test]
+fn list_extraneous() {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_install()
+        .arg("typing-extensions==4.10.0")
+        .arg("--list-extraneous"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Found 1 extraneous package
+     ? iniconfig==2.0.0
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.10.0
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+}
+
 /// Install an editable package from the command line into a virtual environment, ignoring its
 /// dependencies.
 #[test]
@@ -4300,6 +5189,128 @@ requires-python = ">=3.8"
     Ok(())
 }
 
+/// For a pure-Python editable with static metadata, changes that don't affect the metadata (like
+/// editing a module, or changing `[tool.ruff]`) shouldn't trigger a rebuild.
+#[test]
+fn invalidate_editable_on_metadata_change() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("-e ./editable")?;
+
+    // Create a pure-Python editable package.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.8"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+"#,
+    )?;
+    let init_py = editable_dir.child("src/example/__init__.py");
+    init_py.touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.0.0
+     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    // Modify the source code, along with unrelated tool configuration.
+    init_py.write_str("def main(): pass")?;
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.8"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[tool.ruff]
+line-length = 100
+"#,
+    )?;
+
+    // Installing again should be a no-op.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "
+    );
+
+    // Modify the dependencies.
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==3.7.1"
+]
+requires-python = ">=3.8"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[tool.ruff]
+line-length = 100
+"#,
+    )?;
+
+    // Installing again should rebuild the package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - anyio==4.0.0
+     + anyio==3.7.1
+     ~ example==0.0.0 (from file://[TEMP_DIR]/editable)
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn editable_dynamic() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4448,38 +5459,38 @@ requires-python = ">=3.8"
     Ok(())
 }
 
+/// Unlike an editable, a non-editable install of a pure-Python project should be rebuilt when any
+/// part of the `pyproject.toml` changes.
 #[test]
-fn invalidate_path_on_cache_key() -> Result<()> {
+fn invalidate_path_on_tool_change() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("example @ ./editable")?;
+    requirements_txt.write_str("example @ ./example")?;
 
-    // Create a local package.
-    let editable_dir = context.temp_dir.child("editable");
-    editable_dir.create_dir_all()?;
-    let pyproject_toml = editable_dir.child("pyproject.toml");
+    // Create a pure-Python package.
+    let project_dir = context.temp_dir.child("example");
+    project_dir.create_dir_all()?;
+    let pyproject_toml = project_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"[project]
-        name = "example"
-        version = "0.0.0"
-        dependencies = ["anyio==4.0.0"]
-        requires-python = ">=3.8"
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.8"
 
-        [tool.uv]
-        cache-keys = ["constraints.txt", { file = "overrides.txt" }]
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
 "#,
     )?;
-
-    let overrides_txt = editable_dir.child("overrides.txt");
-    overrides_txt.write_str("idna")?;
-
-    let constraints_txt = editable_dir.child("constraints.txt");
-    constraints_txt.write_str("idna<3.4")?;
+    project_dir.child("src/example/__init__.py").touch()?;
 
     uv_snapshot!(context.filters(), context.pip_install()
         .arg("-r")
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4489,23 +5500,109 @@ fn invalidate_path_on_cache_key() -> Result<()> {
     Prepared 4 packages in [TIME]
     Installed 4 packages in [TIME]
      + anyio==4.0.0
-     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+     + example==0.0.0 (from file://[TEMP_DIR]/example)
      + idna==3.6
      + sniffio==1.3.1
-    "###
+    "
     );
 
-    // Installing again should be a no-op.
+    // Modify unrelated tool configuration.
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.8"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[tool.ruff]
+line-length = 100
+"#,
+    )?;
+
+    // Installing again should rebuild the package.
     uv_snapshot!(context.filters(), context.pip_install()
         .arg("-r")
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Audited 1 package in [TIME]
-    "###
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example==0.0.0 (from file://[TEMP_DIR]/example)
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalidate_path_on_cache_key() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("example @ ./editable")?;
+
+    // Create a local package.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = ["anyio==4.0.0"]
+        requires-python = ">=3.8"
+
+        [tool.uv]
+        cache-keys = ["constraints.txt", { file = "overrides.txt" }]
+"#,
+    )?;
+
+    let overrides_txt = editable_dir.child("overrides.txt");
+    overrides_txt.write_str("idna")?;
+
+    let constraints_txt = editable_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.4")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.0.0
+     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Installing again should be a no-op.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
     );
 
     // Modify the constraints file.
@@ -5072,6 +6169,80 @@ fn no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Install with `--no-build-isolation`, providing the build dependencies via
+/// `--build-requirements`.
+#[test]
+fn no_build_isolation_build_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz")?;
+
+    let build_txt = context.temp_dir.child("build.txt");
+    build_txt.write_str("setuptools\nwheel")?;
+
+    // The build requirements are installed ahead of the build, so the build should succeed.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.in")
+        .arg("--build-requirements")
+        .arg("build.txt")
+        .arg("--no-build-isolation"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + setuptools==69.2.0
+     + wheel==0.43.0
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==0.0.0 (from https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz)
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// With `--dry-run`, the build requirements should be neither resolved nor installed.
+#[test]
+fn build_requirements_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let build_txt = context.temp_dir.child("build.txt");
+    build_txt.write_str("setuptools\nwheel")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.in")
+        .arg("--build-requirements")
+        .arg("build.txt")
+        .arg("--no-build-isolation")
+        .arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0
+    "
+    );
+
+    context.assert_not_installed("setuptools");
+
+    Ok(())
+}
+
 /// Ensure that `UV_NO_BUILD_ISOLATION` env var does the same as the `--no-build-isolation` flag
 #[test]
 fn respect_no_build_isolation_env_var() -> Result<()> {
@@ -7017,6 +8188,47 @@ fn require_hashes_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Pin the hashes of transitive dependencies in a constraints file.
+#[test]
+fn require_hashes_constraint_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Only the direct dependency is listed in the requirements file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    // The transitive dependencies are pinned (with hashes) in the constraints file.
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna==3.6 --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        sniffio==1.3.1 --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("-c")
+        .arg(constraints_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// We allow `--require-hashes` for unnamed URL dependencies.
 #[test]
 fn require_hashes_unnamed() -> Result<()> {
@@ -7845,6 +9057,50 @@ fn recursive_extra_transitive_url() -> Result<()> {
     Ok(())
 }
 
+/// Expand extras that include the package's own extras, including extras that include each other.
+#[test]
+fn recursive_extra_cycle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        full = [
+            "project[a]",
+            "project[b]",
+        ]
+        a = [
+            "iniconfig==2.0.0",
+        ]
+        b = [
+            "sniffio==1.3.1",
+            "project[full]",
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".[full]"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.0.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// If a package is requested as both editable and non-editable, always install it as editable.
 #[test]
 fn prefer_editable() -> Result<()> {
@@ -8410,9 +9666,9 @@ fn install_incompatible_python_version_interpreter_broken_in_path() -> Result<()
     Ok(())
 }
 
-/// Emit dedicated error message when installing Conda `environment.yml`
+/// Install the `pip` dependencies from a Conda `environment.yml`, skipping the Conda dependencies.
 #[test]
-fn install_unsupported_environment_yml() -> Result<()> {
+fn install_environment_yml() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let environment_yml = context.temp_dir.child("environment.yml");
@@ -8424,13 +9680,40 @@ fn install_unsupported_environment_yml() -> Result<()> {
           - python>=3.12
     "})?;
 
+    // Without a `pip` section, there's nothing to install.
     uv_snapshot!(context.filters(), context.pip_install().arg("-r").arg("environment.yml"), @r"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Conda environment files (i.e., `environment.yml`) are not supported
+    warning: Skipping Conda dependencies in `environment.yml`, which are not supported: python>=3.12
+    warning: Conda environment file `environment.yml` does not contain any `pip` dependencies
+    Audited in [TIME]
+    ");
+
+    environment_yml.write_str(indoc! {r"
+        name: test-env
+        channels:
+          - conda-forge
+        dependencies:
+          - python>=3.12
+          - pip
+          - pip:
+            - iniconfig==2.0.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("-r").arg("environment.yml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping Conda dependencies in `environment.yml`, which are not supported: python>=3.12, pip
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
     ");
 
     Ok(())
@@ -8985,127 +10268,427 @@ fn missing_top_level() {
     );
 
     uv_snapshot!(context.filters(), context.pip_install()
-        .arg("suds-community==0.8.5"), @r###"
+        .arg("suds-community==0.8.5"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Failed to uninstall package at [SITE_PACKAGES]/suds_community.egg-info due to missing `top-level.txt` file. Installation may result in an incomplete environment.
+    Uninstalled 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     ~ suds-community==0.8.5
+    "###
+    );
+}
+
+/// Show a dedicated error when the user attempts to install `sklearn`.
+#[test]
+fn sklearn() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("sklearn"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `sklearn==0.0.post12`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          The 'sklearn' PyPI package is deprecated, use 'scikit-learn'
+          rather than 'sklearn' for pip commands.
+
+          Here is how to fix this error in the main use cases:
+          - use 'pip install scikit-learn' rather than 'pip install sklearn'
+          - replace 'sklearn' by 'scikit-learn' in your pip requirements files
+            (requirements.txt, setup.py, setup.cfg, Pipfile, etc ...)
+          - if the 'sklearn' package is used by one of your dependencies,
+            it would be great if you take some time to track which package uses
+            'sklearn' instead of 'scikit-learn' and report it to their issue tracker
+          - as a last resort, set the environment variable
+            SKLEARN_ALLOW_DEPRECATED_SKLEARN_PACKAGE_INSTALL=True to avoid this error
+
+          More information is available at
+          https://github.com/scikit-learn/sklearn-pypi-package
+
+          hint: This usually indicates a problem with the package or the build environment.
+      help: `sklearn` is often confused for `scikit-learn` Did you mean to install `scikit-learn` instead?
+    "###
+    );
+}
+
+#[test]
+fn resolve_derivation_chain() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["wsgiref"]
+        "#
+    })?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"/.*/src", "/[TMP]/src")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("-e")
+        .arg("."), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `wsgiref==0.1.2`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Traceback (most recent call last):
+            File "<string>", line 14, in <module>
+            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 325, in get_requires_for_build_wheel
+              return self._get_build_requires(config_settings, requirements=['wheel'])
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 295, in _get_build_requires
+              self.run_setup()
+            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 487, in run_setup
+              super().run_setup(setup_script=setup_script)
+            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 311, in run_setup
+              exec(code, locals())
+            File "<string>", line 5, in <module>
+            File "[CACHE_DIR]/[TMP]/src/ez_setup/__init__.py", line 170
+              print "Setuptools version",version,"or greater has been installed."
+              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+          SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
+
+          hint: This usually indicates a problem with the package or the build environment.
+      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `wsgiref`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ensure that `UV_NO_INSTALLER_METADATA` env var is respected.
+#[test]
+fn respect_no_installer_metadata_env_var() {
+    let context = TestContext::new("3.12");
+
+    // Install urllib3.
+    uv_snapshot!(context.pip_install()
+        .arg("urllib3==2.2.1")
+        .arg("--strict")
+        .env(EnvVars::UV_NO_INSTALLER_METADATA, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + urllib3==2.2.1
+    "###
+    );
+
+    context.assert_command("import urllib3").success();
+
+    // Assert INSTALLER file was _not_ created.
+    let installer_file = context
+        .site_packages()
+        .join("urllib3-2.2.3.dist-info")
+        .join("INSTALLER");
+    assert!(!installer_file.exists());
+}
+
+/// Install a wheel that is incompatible with the current platform, by providing its platform tag
+/// via `--platform-tag`.
+#[test]
+#[cfg(not(windows))]
+fn platform_tag_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Copy a `win_amd64` wheel into the project directory.
+    let wheel = context
+        .temp_dir
+        .child("wheel_tag_test-0.1.0-py3-none-win_amd64.whl");
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/wheel_tag_test-0.1.0-py3-none-win_amd64.whl"),
+        &wheel,
+    )?;
+
+    // Without an override, the wheel should be rejected.
+    context
+        .pip_install()
+        .arg("./wheel_tag_test-0.1.0-py3-none-win_amd64.whl")
+        .assert()
+        .failure();
+
+    // With an override, the wheel should be installed, with a warning.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./wheel_tag_test-0.1.0-py3-none-win_amd64.whl")
+        .arg("--platform-tag")
+        .arg("win_amd64"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Installing `wheel_tag_test-0.1.0-py3-none-win_amd64.whl`, which is incompatible with the current platform, due to `--platform-tag`; the installed package may not work
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + wheel-tag-test==0.1.0 (from file://[TEMP_DIR]/wheel_tag_test-0.1.0-py3-none-win_amd64.whl)
+    "
+    );
+
+    Ok(())
+}
+
+/// Warn about installed modules that shadow the standard library with `--warn-shadowing`.
+#[test]
+fn warn_shadowing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a project that provides a `colorsys` module, shadowing the standard library.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.setuptools]
+        py-modules = ["colorsys"]
+    "#})?;
+    context.temp_dir.child("colorsys.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--warn-shadowing"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    warning: The package `project` provides a top-level module `colorsys`, which shadows a module in the Python standard library
+    "
+    );
+
+    Ok(())
+}
+
+/// Warn about installed packages that provide the same top-level module with
+/// `--warn-import-conflicts`.
+#[test]
+fn warn_import_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create two projects that both provide a `conflict` module.
+    for name in ["first", "second"] {
+        let project = context.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+
+            [tool.setuptools]
+            py-modules = ["conflict"]
+        "#})?;
+        project.child("conflict.py").touch()?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./first")
+        .arg("./second")
+        .arg("--warn-import-conflicts"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    warning: Multiple packages provide the top-level module `conflict`: `first`, `second`
+    "
+    );
+
+    Ok(())
+}
+
+/// Warn about packages whose wheel and source distribution declare different dependencies with
+/// `--verify-metadata-consistency`.
+#[test]
+fn verify_metadata_consistency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    let pyproject_toml = project.child("pyproject.toml");
+    project
+        .child("src")
+        .child("uv_consistency_fixture")
+        .child("__init__.py")
+        .touch()?;
+
+    // Build a source distribution that depends on `iniconfig`.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "uv-consistency-fixture"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .build()
+        .arg("--sdist")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    // Build a wheel for the same version that also depends on `sniffio`.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "uv-consistency-fixture"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("uv-consistency-fixture")
+        .arg("--find-links")
+        .arg(project.child("dist").path())
+        .arg("--verify-metadata-consistency"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    warning: Failed to uninstall package at [SITE_PACKAGES]/suds_community.egg-info due to missing `top-level.txt` file. Installation may result in an incomplete environment.
-    Uninstalled 2 packages in [TIME]
-    Installed 1 package in [TIME]
-     ~ suds-community==0.8.5
-    "###
+    Resolved 3 packages in [TIME]
+    warning: The wheel and source distribution for `uv-consistency-fixture==0.1.0` declare different dependencies (only the wheel requires `sniffio`)
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+     + uv-consistency-fixture==0.1.0
+    "
     );
-}
-
-/// Show a dedicated error when the user attempts to install `sklearn`.
-#[test]
-fn sklearn() {
-    let context = TestContext::new("3.12");
-
-    uv_snapshot!(context.filters(), context.pip_install().arg("sklearn"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-      × Failed to build `sklearn==0.0.post12`
-      ├─▶ The build backend returned an error
-      ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
-
-          [stderr]
-          The 'sklearn' PyPI package is deprecated, use 'scikit-learn'
-          rather than 'sklearn' for pip commands.
-
-          Here is how to fix this error in the main use cases:
-          - use 'pip install scikit-learn' rather than 'pip install sklearn'
-          - replace 'sklearn' by 'scikit-learn' in your pip requirements files
-            (requirements.txt, setup.py, setup.cfg, Pipfile, etc ...)
-          - if the 'sklearn' package is used by one of your dependencies,
-            it would be great if you take some time to track which package uses
-            'sklearn' instead of 'scikit-learn' and report it to their issue tracker
-          - as a last resort, set the environment variable
-            SKLEARN_ALLOW_DEPRECATED_SKLEARN_PACKAGE_INSTALL=True to avoid this error
 
-          More information is available at
-          https://github.com/scikit-learn/sklearn-pypi-package
-
-          hint: This usually indicates a problem with the package or the build environment.
-      help: `sklearn` is often confused for `scikit-learn` Did you mean to install `scikit-learn` instead?
-    "###
-    );
+    Ok(())
 }
 
+/// Wait for an environment that's locked by another process to be released, or exit immediately
+/// with `--no-wait`.
 #[test]
-fn resolve_derivation_chain() -> Result<()> {
+fn install_environment_locked() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(indoc! {r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["wsgiref"]
-        "#
-    })?;
-
-    let filters = context
-        .filters()
-        .into_iter()
-        .chain([(r"/.*/src", "/[TMP]/src")])
-        .collect::<Vec<_>>();
+    // Hold the environment lock, as a concurrent uv process would.
+    let lock = uv_fs::LockedFile::acquire_blocking(context.venv.join(".lock"), "test")?;
 
-    uv_snapshot!(filters, context.pip_install()
-        .arg("-e")
-        .arg("."), @r###"
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-wait"), @r"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to build `wsgiref==0.1.2`
-      ├─▶ The build backend returned an error
-      ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
-
-          [stderr]
-          Traceback (most recent call last):
-            File "<string>", line 14, in <module>
-            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 325, in get_requires_for_build_wheel
-              return self._get_build_requires(config_settings, requirements=['wheel'])
-                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 295, in _get_build_requires
-              self.run_setup()
-            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 487, in run_setup
-              super().run_setup(setup_script=setup_script)
-            File "[CACHE_DIR]/builds-v0/[TMP]/build_meta.py", line 311, in run_setup
-              exec(code, locals())
-            File "<string>", line 5, in <module>
-            File "[CACHE_DIR]/[TMP]/src/ez_setup/__init__.py", line 170
-              print "Setuptools version",version,"or greater has been installed."
-              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-          SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
+    error: The environment at .venv is locked by another process
+    "
+    );
 
-          hint: This usually indicates a problem with the package or the build environment.
-      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `wsgiref`
-    "###
+    // Without `--no-wait`, uv should wait until the lock is released.
+    let mut child = context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stderr = BufReader::new(child.stderr.take().expect("stderr is piped"));
+    let mut line = String::new();
+    stderr.read_line(&mut line)?;
+    assert!(
+        line.starts_with("Waiting for another process to release the lock on: .venv"),
+        "{line}"
     );
 
+    // Release the lock; the installation should then proceed.
+    drop(lock);
+    let mut rest = String::new();
+    stderr.read_to_string(&mut rest)?;
+    assert!(child.wait()?.success(), "{rest}");
+    assert!(rest.contains("+ iniconfig==2.0.0"), "{rest}");
+
     Ok(())
 }
 
-/// Ensure that `UV_NO_INSTALLER_METADATA` env var is respected.
+/// Ensure that the `INSTALLER` file is written, and respects `--installer-name`.
 #[test]
-fn respect_no_installer_metadata_env_var() {
+fn installer_name() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Install urllib3.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // By default, the `INSTALLER` file should name uv.
+    let installer_file = context
+        .site_packages()
+        .join("iniconfig-2.0.0.dist-info")
+        .join("INSTALLER");
+    assert_eq!(fs_err::read_to_string(&installer_file)?, "uv");
+
     uv_snapshot!(context.pip_install()
-        .arg("urllib3==2.2.1")
-        .arg("--strict")
-        .env(EnvVars::UV_NO_INSTALLER_METADATA, "1"), @r###"
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .arg("--installer-name")
+        .arg("my-ci"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -9113,19 +10696,15 @@ fn respect_no_installer_metadata_env_var() {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + urllib3==2.2.1
+     ~ iniconfig==2.0.0
     "###
     );
 
-    context.assert_command("import urllib3").success();
+    assert_eq!(fs_err::read_to_string(&installer_file)?, "my-ci");
 
-    // Assert INSTALLER file was _not_ created.
-    let installer_file = context
-        .site_packages()
-        .join("urllib3-2.2.3.dist-info")
-        .join("INSTALLER");
-    assert!(!installer_file.exists());
+    Ok(())
 }
 
 /// Check that we error if a source dist lies about its built wheel version.
@@ -9186,6 +10765,85 @@ fn test_dynamic_version_sdist_wrong_version() -> Result<()> {
     Ok(())
 }
 
+/// Install a wheel that contains an empty file, whose `RECORD` entry uses the digest of the
+/// empty string.
+#[test]
+fn install_wheel_with_empty_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context.temp_dir.child("empty_file-0.1.0-py3-none-any.whl");
+    // Flush the file after we're done.
+    {
+        let file = File::create(wheel.path())?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+
+        for (path, contents) in [
+            ("empty_file/__init__.py", ""),
+            (
+                "empty_file-0.1.0.dist-info/METADATA",
+                "Metadata-Version: 2.1\nName: empty-file\nVersion: 0.1.0\n",
+            ),
+            (
+                "empty_file-0.1.0.dist-info/WHEEL",
+                "Wheel-Version: 1.0\nGenerator: uv\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+            ),
+            (
+                "empty_file-0.1.0.dist-info/RECORD",
+                indoc! {"
+                    empty_file/__init__.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0
+                    empty_file-0.1.0.dist-info/METADATA,sha256=rJJqxKjOeO4Vzz3WQ_wtL1e0DOHZa8cbJ2A71wIgTGU,54
+                    empty_file-0.1.0.dist-info/WHEEL,sha256=4Sf1u7FGTjSwiMzUozZcP0GTFUJ49avwZdB2gPp9ZkY,73
+                    empty_file-0.1.0.dist-info/RECORD,,
+                "},
+            ),
+        ] {
+            zip.start_file(path, options)?;
+            zip.write_all(contents.as_bytes())?;
+        }
+        zip.finish()?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(wheel.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + empty-file==0.1.0 (from file://[TEMP_DIR]/empty_file-0.1.0-py3-none-any.whl)
+    "###
+    );
+
+    // The empty file is installed, and recorded with the digest of the empty string.
+    let init = context
+        .site_packages()
+        .join("empty_file")
+        .join("__init__.py");
+    assert_eq!(fs::read_to_string(&init)?, "");
+    let record = fs::read_to_string(
+        context
+            .site_packages()
+            .join("empty_file-0.1.0.dist-info")
+            .join("RECORD"),
+    )?;
+    assert!(
+        record.contains(
+            "empty_file/__init__.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0"
+        ),
+        "{record}"
+    );
+
+    // The package can be uninstalled cleanly, based on its `RECORD`.
+    context.pip_uninstall().arg("empty-file").assert().success();
+    assert!(!init.exists());
+
+    Ok(())
+}
+
 /// Install a package with multiple wheels at the same version, differing only in the build tag. We
 /// should choose the wheel with the highest build tag.
 #[test]
@@ -9609,6 +11267,78 @@ fn direct_url_json_direct_url() -> Result<()> {
     Ok(())
 }
 
+/// An editable install should write a PEP 660-compliant `direct_url.json` that marks it as
+/// editable, such that other tools (and `uv pip freeze`) recognize it.
+#[test]
+fn direct_url_json_editable() -> Result<()> {
+    use uv_fs::copy_dir_all;
+
+    let context = TestContext::new("3.12");
+    let project = context.temp_dir.child("poetry_editable");
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable"),
+        &project,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps")
+        .arg("-e")
+        .arg(".")
+        .current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+    "###
+    );
+
+    let direct_url = context.venv.child(if cfg!(windows) {
+        "Lib\\site-packages\\poetry_editable-0.1.0.dist-info\\direct_url.json"
+    } else {
+        "lib/python3.12/site-packages/poetry_editable-0.1.0.dist-info/direct_url.json"
+    });
+    direct_url.assert(predicates::path::is_file());
+
+    let direct_url_content: serde_json::Value =
+        serde_json::from_str(&fs_err::read_to_string(direct_url.path())?)?;
+    assert_eq!(direct_url_content["dir_info"]["editable"], true);
+    assert!(
+        direct_url_content["url"]
+            .as_str()
+            .is_some_and(|url| url.starts_with("file://"))
+    );
+
+    // The editable should be recognized by `pip freeze`.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    -e file://[TEMP_DIR]/poetry_editable
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--exclude-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn dependency_group() -> Result<()> {
     // testing basic `uv pip install --group` functionality
@@ -12093,3 +13823,69 @@ fn config_settings_package() -> Result<()> {
 
     Ok(())
 }
+
+/// Warn about packages that ship the same file with `--strict`.
+#[test]
+fn install_strict_file_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build two packages that both ship a top-level `config.py`.
+    for name in ["first", "second"] {
+        let project = context.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+
+            [tool.hatch.build.targets.wheel]
+            only-include = ["config.py"]
+        "#})?;
+        project
+            .child("config.py")
+            .write_str(&format!("OWNER = {name:?}\n"))?;
+        context
+            .build()
+            .arg("--wheel")
+            .arg("--out-dir")
+            .arg(context.temp_dir.child("dist").path())
+            .current_dir(&project)
+            .assert()
+            .success();
+    }
+
+    context
+        .pip_install()
+        .arg("first==0.1.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("dist").path())
+        .assert()
+        .success();
+
+    // The conflict is reported, but the package is installed.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("second==0.1.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("dist").path())
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    warning: Installing `second` would overwrite `[SITE_PACKAGES]/config.py`, which is owned by `first`
+    Installed 1 package in [TIME]
+     + second==0.1.0
+    "
+    );
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use indoc::indoc;
 
 use uv_static::EnvVars;
 
-use crate::common::{TestContext, uv_snapshot};
+use crate::common::{TestContext, make_backend_project, uv_snapshot};
 
 #[test]
 fn show_empty() {
@@ -534,3 +534,81 @@ fn show_files() {
     ----- stderr -----
     "#);
 }
+
+/// Show a package with non-ASCII metadata and file names.
+#[test]
+#[cfg(not(windows))]
+fn show_files_non_ascii() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package whose build backend produces a wheel with non-ASCII metadata, a non-ASCII
+    // module name, and a non-ASCII data file.
+    make_backend_project(
+        context.temp_dir.child("unicode").path(),
+        "unicode",
+        indoc! {r#"
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(wheel_directory, "unicode", {
+                    "café.py": "GREETING = 'héllo'\n",
+                    "unicode-0.1.0.data/data/share/naïve.txt": "naïve\n",
+                    "unicode-0.1.0.dist-info/METADATA": (
+                        "Metadata-Version: 2.1\nName: unicode\nVersion: 0.1.0\n"
+                        "Summary: Ünïcödé métadata\nAuthor: Zoë Brontë\n"
+                    ),
+                })
+        "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./unicode"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + unicode==0.1.0 (from file://[TEMP_DIR]/unicode)
+    "###
+    );
+
+    context
+        .assert_command("import café; assert café.GREETING == 'héllo'")
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("unicode").arg("--files"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: unicode
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+      ../../../share/naïve.txt
+      café.py
+      unicode-0.1.0.dist-info/INSTALLER
+      unicode-0.1.0.dist-info/METADATA
+      unicode-0.1.0.dist-info/RECORD
+      unicode-0.1.0.dist-info/REQUESTED
+      unicode-0.1.0.dist-info/WHEEL
+      unicode-0.1.0.dist-info/direct_url.json
+      unicode-0.1.0.dist-info/uv_cache.json
+
+    ----- stderr -----
+    "###
+    );
+
+    // The non-ASCII metadata is preserved as UTF-8.
+    let metadata = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("unicode-0.1.0.dist-info")
+            .join("METADATA"),
+    )?;
+    assert!(metadata.contains("Author: Zoë Brontë"));
+
+    Ok(())
+}
@@ -1,15 +1,23 @@
 use std::env::consts::EXE_SUFFIX;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use fs_err as fs;
-use indoc::indoc;
-use predicates::Predicate;
+use indoc::{formatdoc, indoc};
+use predicates::{Predicate, PredicateBooleanExt};
 use url::Url;
-
-use crate::common::{TestContext, download_to_disk, site_packages_path, uv_snapshot};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+use crate::common::{
+    TestContext, download_to_disk, make_backend_project, python_installations_for_versions,
+    site_packages_path, uv_snapshot, venv_bin_path,
+};
 use uv_fs::{Simplified, copy_dir_all};
 use uv_static::EnvVars;
 
@@ -254,6 +262,131 @@ fn install_symlink() -> Result<()> {
     Ok(())
 }
 
+/// Report the link mode that was used for each package under `--verbose`.
+#[test]
+fn install_link_mode_verbose() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Installed `markupsafe` using link mode `copy`"),
+        "{stderr}"
+    );
+
+    // Whether hard links are supported depends on the filesystem, so either outcome is reported.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .arg("--reinstall")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Installed `markupsafe` using link mode `hardlink`")
+            || stderr.contains(
+                "Installed `markupsafe` using link mode `copy` (fell back from `hardlink`)"
+            ),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Report the time taken to fetch the index metadata for each package under `--verbose`.
+#[test]
+fn index_metadata_timing_verbose() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for package in ["anyio", "idna", "sniffio"] {
+        assert!(
+            stderr.contains(&format!("Fetched index metadata for `{package}` in ")),
+            "{stderr}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Load settings from an explicit `--config-file`, which takes precedence over any discovered
+/// configuration, but not over the command-line.
+#[test]
+#[cfg(unix)]
+fn install_config_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The discovered configuration should be ignored in favor of the explicit file.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"link-mode = "copy""#)?;
+
+    let config_file = context.temp_dir.child("config").child("uv.toml");
+    config_file.write_str(r#"link-mode = "symlink""#)?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    context
+        .pip_sync()
+        .arg("--config-file")
+        .arg(config_file.path())
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // The package should be symlinked from the cache.
+    let init = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__init__.py");
+    assert!(init.is_symlink());
+
+    // An explicit `--link-mode` should take precedence over the file.
+    context
+        .pip_sync()
+        .arg("--config-file")
+        .arg(config_file.path())
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    assert!(!init.is_symlink());
+
+    Ok(())
+}
+
 /// Reject attempts to use symlink semantics with `--no-cache`.
 #[test]
 fn install_symlink_no_cache() -> Result<()> {
@@ -610,6 +743,51 @@ fn install_url() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from a URL with a trailing marker that isn't separated by whitespace.
+#[test]
+fn install_url_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The marker excludes the requirement on the current interpreter.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("werkzeug @ https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl;python_version < '3.8'")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved in [TIME]
+    Audited in [TIME]
+    "###
+    );
+
+    // The marker includes the requirement on the current interpreter.
+    requirements_txt.write_str("werkzeug @ https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl;python_version >= '3.8'")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + werkzeug==2.0.0 (from https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl)
+    "###
+    );
+
+    context.assert_command("import werkzeug").success();
+
+    Ok(())
+}
+
 /// Install a package into a virtual environment from a Git repository.
 #[test]
 #[cfg(feature = "git")]
@@ -706,6 +884,148 @@ fn install_git_subdirectories() -> Result<()> {
     Ok(())
 }
 
+/// Install two packages that share a namespace, then uninstall one of them. The shared namespace
+/// directory, including the bytecode cache of the other package, should be left intact.
+#[test]
+fn uninstall_shared_namespace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create two packages that contribute modules to the same (implicit) namespace.
+    for name in ["a", "b"] {
+        let project = context.temp_dir.child(format!("pkg-{name}"));
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "pkg-{name}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+
+            [tool.hatch.build.targets.wheel]
+            packages = ["src/shared_ns"]
+        "#})?;
+        project
+            .child("src")
+            .child("shared_ns")
+            .child(format!("{name}.py"))
+            .touch()?;
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./pkg-a\n./pkg-b")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    // Populate the bytecode cache for the shared namespace.
+    context
+        .assert_command("import shared_ns.a, shared_ns.b")
+        .success();
+
+    let pycache = context
+        .site_packages()
+        .join("shared_ns")
+        .join("__pycache__");
+    assert!(pycache.join("b.cpython-312.pyc").is_file());
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("pkg-a"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - pkg-a==0.1.0 (from file://[TEMP_DIR]/pkg-a)
+    "###
+    );
+
+    // The bytecode for `shared_ns.b` lives in the shared `__pycache__`, and should be retained.
+    assert!(
+        !context
+            .site_packages()
+            .join("shared_ns")
+            .join("a.py")
+            .exists()
+    );
+    assert!(pycache.join("b.cpython-312.pyc").is_file());
+
+    context.assert_command("import shared_ns.a").failure();
+    context.assert_command("import shared_ns.b").success();
+
+    Ok(())
+}
+
+/// Install two packages from the same Git repository, and verify that the repository is only
+/// fetched once.
+#[test]
+#[cfg(feature = "git")]
+fn install_git_subdirectories_single_fetch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("example-pkg-a @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_a\nexample-pkg-b @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_b")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(
+        stderr.matches("Updating Git source").count(),
+        1,
+        "{}",
+        stderr
+    );
+
+    context.assert_command("import example_pkg.a").success();
+    context.assert_command("import example_pkg.b").success();
+
+    Ok(())
+}
+
+/// Build a single package from source with `--no-binary-package`, while installing its sibling
+/// from a wheel.
+#[test]
+fn no_binary_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0\ntyping-extensions==4.10.0")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary-package")
+        .arg("iniconfig")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+
+    // `iniconfig` should be built from source, but `typing-extensions` should not.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Building: iniconfig==2.0.0"), "{}", stderr);
+    assert!(
+        !stderr.contains("Building: typing-extensions"),
+        "{}",
+        stderr
+    );
+
+    context.assert_command("import iniconfig").success();
+    context.assert_command("import typing_extensions").success();
+
+    Ok(())
+}
+
 /// Install a source distribution into a virtual environment.
 #[test]
 fn install_sdist() -> Result<()> {
@@ -736,6 +1056,55 @@ fn install_sdist() -> Result<()> {
     Ok(())
 }
 
+/// Annotate each installed package with how it was obtained, with `--emit-build-tags`.
+#[test]
+fn emit_build_tags() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        source-distribution==0.0.1
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--emit-build-tags"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0 (wheel)
+     + source-distribution==0.0.1 (built from sdist)
+    "
+    );
+
+    // With `--verbose`, the tags of the installed wheels are included.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--emit-build-tags")
+        .arg("--reinstall")
+        .arg("--verbose")
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(" ~ iniconfig==2.0.0 (wheel py3-none-any)"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains(" ~ source-distribution==0.0.1 (built from sdist as py3-none-any)"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
 /// Install a source distribution into a virtual environment.
 #[test]
 fn install_sdist_url() -> Result<()> {
@@ -940,47 +1309,67 @@ fn install_numpy_py38() -> Result<()> {
     Ok(())
 }
 
-/// Attempt to install a package without using a remote index.
+/// Ensure that an upper bound on `requires-python` excludes versions that don't support the
+/// current interpreter, such that an older, compatible version is selected instead.
 #[test]
-fn install_no_index() -> Result<()> {
+fn install_requires_python_upper_bound() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("iniconfig==2.0.0")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--no-index")
-        .arg("--strict"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because iniconfig was not found in the provided package locations and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
-
-          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
-    "###
-    );
+    let project = context.temp_dir.child("project");
+    let pyproject_toml = project.child("pyproject.toml");
+    project
+        .child("src")
+        .child("uv_upper_bound_fixture")
+        .child("__init__.py")
+        .touch()?;
 
-    context.assert_command("import iniconfig").failure();
+    // Build an older version that supports any Python 3.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "uv-upper-bound-fixture"
+        version = "0.1.0"
+        requires-python = ">=3.8"
 
-    Ok(())
-}
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
 
-/// Attempt to install a package without using a remote index
-/// after a previous successful installation.
-#[test]
-fn install_no_index_cached() -> Result<()> {
-    let context = TestContext::new("3.12");
+    // Build a newer version that excludes Python 3.12 via an upper bound.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "uv-upper-bound-fixture"
+        version = "0.2.0"
+        requires-python = ">=3.8,<3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("iniconfig==2.0.0")?;
+    requirements_txt.write_str("uv-upper-bound-fixture")?;
 
-    uv_snapshot!(context.pip_sync()
+    // The newest version is incompatible with Python 3.12, so the older version should be
+    // selected.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(project.child("dist").path())
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -989,46 +1378,45 @@ fn install_no_index_cached() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0
-    "###
+     + uv-upper-bound-fixture==0.1.0
+    "
     );
 
-    context.assert_command("import iniconfig").success();
-
-    context.pip_uninstall().arg("iniconfig").assert().success();
+    // Requesting the newest version explicitly should fail.
+    requirements_txt.write_str("uv-upper-bound-fixture==0.2.0")?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
         .arg("--no-index")
-        .arg("--strict"), @r###"
+        .arg("--find-links")
+        .arg(project.child("dist").path())
+        .arg("--strict"), @r"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because iniconfig was not found in the provided package locations and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
-
-          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
-    "###
+      ╰─▶ Because the current Python version (3.12.[X]) does not satisfy Python>=3.8,<3.12 and uv-upper-bound-fixture==0.2.0 depends on Python>=3.8,<3.12, we can conclude that uv-upper-bound-fixture==0.2.0 cannot be used.
+          And because you require uv-upper-bound-fixture==0.2.0, we can conclude that your requirements are unsatisfiable.
+    "
     );
 
-    context.assert_command("import iniconfig").failure();
-
     Ok(())
 }
 
+/// Select the lowest compatible version of each requirement with `--resolution lowest`.
 #[test]
-fn warn_on_yanked() -> Result<()> {
+fn sync_resolution_lowest() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // This version is yanked.
-    let requirements_in = context.temp_dir.child("requirements.txt");
-    requirements_in.write_str("colorama==0.4.2")?;
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
 
-    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.in")
+        .arg("--resolution")
+        .arg("lowest"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1037,436 +1425,543 @@ fn warn_on_yanked() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + colorama==0.4.2
-    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
-    "###
+     + anyio==1.0.0
+    "
     );
 
-    Ok(())
-}
-
-#[test]
-fn warn_on_yanked_dry_run() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // This version is yanked.
-    let requirements_in = context.temp_dir.child("requirements.txt");
-    requirements_in.write_str("colorama==0.4.2")?;
-
-    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--dry-run")
-        .arg("--strict"), @r###"
+    // Since dependencies aren't installed, `lowest-direct` should select the same version.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.in")
+        .arg("--resolution")
+        .arg("lowest-direct"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Would download 1 package
-    Would install 1 package
-     + colorama==0.4.2
-    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
-    "###
+    Audited 1 package in [TIME]
+    "
     );
 
     Ok(())
 }
 
-/// Resolve a local wheel.
+/// Download and build the resolved packages into a wheelhouse with `--download-only`, then
+/// install from it without an index.
 #[test]
-fn install_local_wheel() -> Result<()> {
+fn sync_download_only() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download a wheel.
-    let archive = context.temp_dir.child("tomli-2.0.1-py3-none-any.whl");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
-        &archive,
-    );
+    // Create a local project, which must be built into a wheel.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tomli @ {}",
-        Url::from_file_path(archive.path()).unwrap()
-    ))?;
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        ./project
+    "})?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--download-only")
+        .arg("--dest")
+        .arg("wheelhouse"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Wrote 2 wheels to: wheelhouse
+    "
     );
 
-    context.assert_command("import tomli").success();
+    // The destination should contain a wheel for each package.
+    let mut wheels = fs::read_dir(context.temp_dir.child("wheelhouse"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    wheels.sort();
+    assert_eq!(
+        wheels,
+        [
+            "iniconfig-2.0.0-py3-none-any.whl",
+            "project-0.1.0-py3-none-any.whl"
+        ]
+    );
 
-    // Create a new virtual environment.
-    context.reset_venv();
+    // Nothing should be installed into the environment.
+    context.assert_command("import iniconfig").failure();
+
+    // The wheelhouse should be usable as a `--find-links` source.
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        project==0.1.0
+    "})?;
 
-    // Reinstall. The wheel should come from the cache, so there shouldn't be a "download".
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("wheelhouse"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0
+    "
     );
 
-    context.assert_command("import tomli").success();
+    context.assert_command("import project").success();
 
-    // Create a new virtual environment.
-    context.reset_venv();
+    Ok(())
+}
 
-    // "Modify" the wheel.
-    // The `filetime` crate works on Windows unlike the std.
-    filetime::set_file_mtime(&archive, filetime::FileTime::now()).unwrap();
+/// Log the result of evaluating each requirement's markers under `--verbose`.
+#[test]
+fn sync_explain_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Reinstall. The wheel should be "downloaded" again.
-    uv_snapshot!(context.filters(), context.pip_sync()
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0 ; python_version >= '3.8'
+        colorama==0.4.6 ; python_version < '3'
+    "})?;
+
+    let output = context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
+        .arg("--verbose")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains(
+            "Including iniconfig because `python_full_version >= '3.8'` evaluates to true for the target environment"
+        ),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains(
+            "Excluding colorama because `python_full_version < '3'` evaluates to false for the target environment"
+        ),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Reject requirements whose markers reference an unknown environment marker variable, rather
+/// than silently evaluating them to false.
+#[test]
+fn sync_unknown_marker_variable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("colorama ; platfrom_system == 'Windows'")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+    error: Couldn't parse requirement in `requirements.txt` at position 0
+      Caused by: Expected a quoted string or a valid marker name, found `platfrom_system`
+    colorama ; platfrom_system == 'Windows'
+               ^^^^^^^^^^^^^^^
+    "
     );
 
-    context.assert_command("import tomli").success();
+    Ok(())
+}
 
-    // "Modify" the wheel.
-    filetime::set_file_mtime(&archive, filetime::FileTime::now()).unwrap();
+/// Evaluate requirement markers against `--marker` overrides, e.g., to install Windows-only
+/// requirements on Linux.
+#[test]
+#[cfg(not(windows))]
+fn sync_marker_override() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Reinstall into the same virtual environment. The wheel should be reinstalled.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0 ; sys_platform == 'win32'")?;
+
+    // The marker excludes the requirement on the current platform.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("requirements.txt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     ~ tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+    Resolved in [TIME]
+    Audited in [TIME]
+    "
     );
 
-    // Reinstall into the same virtual environment. The wheel should _not_ be reinstalled.
+    // With the override, the requirement is included.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--marker")
+        .arg("sys_platform=win32")
+        .arg("--marker")
+        .arg("os_name=nt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Audited 1 package in [TIME]
-    "###
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
     );
 
-    context.assert_command("import tomli").success();
-
-    // Reinstall without the package name.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!("{}", Url::from_file_path(archive.path()).unwrap()))?;
-
+    // Unsupported marker variables are rejected.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--marker")
+        .arg("python_version=3.8"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Audited 1 package in [TIME]
-    "###
-    );
+    error: invalid value 'python_version=3.8' for '--marker <KEY=VALUE>': Invalid marker override: `python_version` is not a supported marker (expected one of: `implementation_name`, `os_name`, `platform_machine`, `platform_python_implementation`, `platform_release`, `platform_system`, `platform_version`, `sys_platform`)
 
-    context.assert_command("import tomli").success();
+    For more information, try '--help'.
+    "
+    );
 
     Ok(())
 }
 
-/// Install a wheel whose actual version doesn't match the version encoded in the filename.
+/// Write a copy of the summary to a file with `--summary-file`.
 #[test]
-fn mismatched_version() -> Result<()> {
+fn sync_summary_file() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download a wheel.
-    let archive = context.temp_dir.child("tomli-3.7.2-py3-none-any.whl");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
-        &archive,
-    );
-
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tomli @ {}",
-        Url::from_file_path(archive.path()).unwrap()
-    ))?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
+    let summary_txt = context.temp_dir.child("summary.txt");
+
+    let output = context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: false
-    exit_code: 2
-    ----- stdout -----
+        .arg("--summary-file")
+        .arg(summary_txt.path())
+        .env(EnvVars::FORCE_COLOR, "1")
+        .env_remove(EnvVars::NO_COLOR)
+        .output()?;
+    assert!(output.status.success());
+
+    // The summary should match stderr, without any colors.
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains('\x1b'), "{stderr}");
+    let summary = fs_err::read_to_string(summary_txt.path())?;
+    assert_eq!(
+        summary,
+        anstream::adapter::strip_str(&stderr).to_string(),
+        "{summary}"
+    );
+    assert!(summary.contains(" + iniconfig==2.0.0"), "{summary}");
+
+    // A resolution failure should be included in the summary, along with the exit status.
+    requirements_txt.write_str("iniconfig==2.0.0\nanyio==4.3.0")?;
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--summary-file")
+        .arg(summary_txt.path())
+        .output()?;
+    assert!(!output.status.success());
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    error: Failed to install: tomli-3.7.2-py3-none-any.whl (tomli==3.7.2 (from file://[TEMP_DIR]/tomli-3.7.2-py3-none-any.whl))
-      Caused by: Wheel version does not match filename: 2.0.1 != 3.7.2
-    "###
-    );
+    let summary = fs_err::read_to_string(summary_txt.path())?;
+    assert!(summary.contains("No solution found"), "{summary}");
+
+    // As should an error that's reported upon exit.
+    let output = context
+        .pip_sync()
+        .arg("missing.txt")
+        .arg("--summary-file")
+        .arg(summary_txt.path())
+        .output()?;
+    assert!(!output.status.success());
+
+    let summary = fs_err::read_to_string(summary_txt.path())?;
+    assert!(summary.starts_with("error: File not found"), "{summary}");
 
     Ok(())
 }
 
-/// Install a wheel whose actual name doesn't match the name encoded in the filename.
+/// With `--suggest-cached`, list the versions of a package that are available when the requested
+/// version is not.
 #[test]
-fn mismatched_name() -> Result<()> {
+fn sync_suggest_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download a wheel.
-    let archive = context.temp_dir.child("foo-2.0.1-py3-none-any.whl");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
-        &archive,
-    );
-
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "foo @ {}",
-        Url::from_file_path(archive.path()).unwrap()
-    ))?;
+    requirements_txt.write_str("ok==3.0.0")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/"))
+        .arg("--suggest-cached"), @r"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because foo has an invalid package format and you require foo, we can conclude that your requirements are unsatisfiable.
+      ╰─▶ Because there is no version of ok==3.0.0 and you require ok==3.0.0, we can conclude that your requirements are unsatisfiable.
 
-          hint: The structure of `foo` was invalid:
-            The .dist-info directory tomli-2.0.1 does not start with the normalized package name: foo
-    "###
+          hint: The requested versions of `ok` are unavailable, but the following versions are available: 1.0.0, 2.0.0
+    "
     );
 
-    Ok(())
-}
-
-/// Install a local source distribution.
-#[test]
-fn install_local_source_distribution() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // Download a source distribution.
-    let archive = context.temp_dir.child("wheel-0.42.0.tar.gz");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/b0/b4/bc2baae3970c282fae6c2cb8e0f179923dceb7eaffb0e76170628f9af97b/wheel-0.42.0.tar.gz",
-        &archive,
-    );
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "wheel @ {}",
-        Url::from_file_path(archive.path()).unwrap()
-    ))?;
-
+    // Without `--suggest-cached`, the versions aren't listed.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + wheel==0.42.0 (from file://[TEMP_DIR]/wheel-0.42.0.tar.gz)
-    "###
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of ok==3.0.0 and you require ok==3.0.0, we can conclude that your requirements are unsatisfiable.
+    "
     );
 
-    context.assert_command("import wheel").success();
-
     Ok(())
 }
 
-/// This package includes a `[build-system]`, but no `build-backend`.
-///
-/// It lists some explicit build requirements that are necessary to build the distribution:
-/// ```toml
-/// [build-system]
-/// requires = ["Cython<3", "setuptools", "wheel"]
-/// ```
-///
-/// Like `pip` and `build`, we should use PEP 517 here and respect the `requires`, but use the
-/// default build backend.
-///
-/// The example is based `DTLSSocket==0.1.16`
+/// With `--suggest-cached` and `--offline`, only list the versions of a package that are available
+/// in the cache, rather than every version listed by the index.
 #[test]
-fn install_build_system_no_backend() -> Result<()> {
+fn sync_suggest_cached_offline() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Populate the cache with a single version of `iniconfig`.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("build-system-no-backend @ https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz")?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    uv_snapshot!(context.pip_sync()
+    context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+        .assert()
+        .success();
+
+    // Request a version that doesn't exist. The index lists several other versions, but only the
+    // cached version should be suggested.
+    requirements_txt.write_str("iniconfig==3.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--offline")
+        .arg("--suggest-cached"), @r"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + build-system-no-backend==0.1.0 (from https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz)
-    "###
-    );
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of iniconfig==3.0.0 and you require iniconfig==3.0.0, we can conclude that your requirements are unsatisfiable.
 
-    context
-        .assert_command("import build_system_no_backend")
-        .success();
+          hint: The requested versions of `iniconfig` are unavailable, but the following versions are available: 2.0.0
+    "
+    );
 
     Ok(())
 }
 
-/// Check that we show the right messages on cached, direct URL source distribution installs.
+/// With `--prune-unused-wheels-from-cache`, remove the cached wheels for other versions of the
+/// synced packages, leaving any other packages untouched.
 #[test]
-fn install_url_source_dist_cached() -> Result<()> {
+fn sync_prune_unused_wheels_from_cache() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("source_distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz")?;
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==1.1.1
+        tomli==2.0.1
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+        .assert()
+        .success();
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
-    "###
+    let cached = |package: &str| -> Result<Vec<String>> {
+        let mut entries = fs_err::read_dir(
+            context
+                .cache_dir
+                .child("wheels-v5")
+                .child("pypi")
+                .child(package)
+                .path(),
+        )?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        Ok(entries)
+    };
+    assert!(
+        cached("iniconfig")?
+            .iter()
+            .any(|entry| entry.starts_with("1.1.1-")),
+        "{:?}",
+        cached("iniconfig")?
     );
 
-    context
-        .assert_command("import source_distribution")
-        .success();
-
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
+    // Upgrade `iniconfig`, and drop `tomli` from the sync.
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    uv_snapshot!(context.pip_sync()
+    let output = context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+        .arg("--prune-unused-wheels-from-cache")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Pruned "), "{stderr}");
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
-    "###
+    // The older version of `iniconfig` was pruned, but the installed version was retained.
+    let iniconfig = cached("iniconfig")?;
+    assert!(
+        !iniconfig.iter().any(|entry| entry.starts_with("1.1.1-")),
+        "{iniconfig:?}"
+    );
+    assert!(
+        iniconfig.iter().any(|entry| entry.starts_with("2.0.0-")),
+        "{iniconfig:?}"
     );
 
+    // `tomli` wasn't part of the sync, so its cache entries were left untouched.
+    let tomli = cached("tomli")?;
+    assert!(
+        tomli.iter().any(|entry| entry.starts_with("2.0.1-")),
+        "{tomli:?}"
+    );
+
+    // The installed version is still usable from the cache.
+    context.assert_command("import iniconfig").success();
     context
-        .assert_command("import source_distribution")
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--offline")
+        .assert()
         .success();
 
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
+    Ok(())
+}
 
-    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
-        .chain(context.filters())
-        .collect::<Vec<_>>();
-    uv_snapshot!(
-        filters,
-        context.clean().arg("source_distribution"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+/// Strip debug symbols from native extension modules with `--strip-debug`.
+#[test]
+#[cfg(target_os = "linux")]
+fn sync_strip_debug() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    ----- stderr -----
-    Removed [N] files ([SIZE])
-    "###
-    );
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("markupsafe==2.1.5")?;
 
-    uv_snapshot!(context.pip_sync()
+    let extension = || -> Result<PathBuf> {
+        let extension = fs_err::read_dir(context.site_packages().join("markupsafe"))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .find(|path| path.extension().is_some_and(|extension| extension == "so"))
+            .expect("`markupsafe` should include an extension module");
+        Ok(extension)
+    };
+
+    context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+        .assert()
+        .success();
+    let original = fs_err::metadata(extension()?)?.len();
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
-    "###
-    );
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--strip-debug")
+        .assert()
+        .success();
+    let extension = extension()?;
+    let stripped = fs_err::metadata(&extension)?.len();
+    assert!(stripped < original, "{stripped} >= {original}");
 
+    // The `RECORD` should reflect the stripped module.
+    let record = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("MarkupSafe-2.1.5.dist-info")
+            .join("RECORD"),
+    )?;
+    let name = extension.file_name().unwrap().to_str().unwrap();
+    let entry = record
+        .lines()
+        .find(|line| line.starts_with(&format!("markupsafe/{name},")))
+        .expect("the extension module should be recorded");
+    assert!(entry.ends_with(&format!(",{stripped}")), "{entry}");
+
+    // The stripped module should still be importable.
     context
-        .assert_command("import source_distribution")
+        .assert_command("import markupsafe._speedups")
         .success();
 
     Ok(())
 }
 
-/// Check that we show the right messages on cached, Git source distribution installs.
+/// With `--check-native-deps`, an extension module whose shared library dependencies all resolve
+/// (here, against the C library) shouldn't be reported.
 #[test]
-#[cfg(feature = "git")]
-fn install_git_source_dist_cached() -> Result<()> {
+#[cfg(target_os = "linux")]
+fn check_native_deps() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")?;
+    requirements_txt.write_str("markupsafe==2.1.5")?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--strict")
+        .arg("--check-native-deps"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1475,288 +1970,428 @@ fn install_git_source_dist_cached() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###
+     + markupsafe==2.1.5
+    "
     );
 
     context
-        .assert_command("import uv_public_pypackage")
+        .assert_command("import markupsafe._speedups")
         .success();
 
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
-
-    uv_snapshot!(context.pip_sync()
+    // Without `--strict`, the check isn't performed.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--check-native-deps"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###
+    Audited 1 package in [TIME]
+    warning: `--check-native-deps` has no effect without `--strict`
+    "
     );
 
-    context
-        .assert_command("import uv_public_pypackage")
-        .success();
+    Ok(())
+}
 
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
+/// Install from a requirements file that includes a sibling file via `-r`.
+#[test]
+fn sync_nested_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    let filters = if cfg!(windows) {
-        [("Removed 2 files", "Removed 3 files")]
-            .into_iter()
-            .chain(context.filters())
-            .collect()
-    } else {
-        context.filters()
-    };
-    uv_snapshot!(filters, context.clean()
-        .arg("werkzeug"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    let base_txt = context.temp_dir.child("base.txt");
+    base_txt.write_str("tomli==2.0.1")?;
 
-    ----- stderr -----
-    No cache entries found
-    "###
-    );
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("-r ./base.txt\nMarkupSafe==2.1.3")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
     context
-        .assert_command("import uv_public_pypackage")
+        .assert_command("from markupsafe import Markup; import tomli")
         .success();
 
     Ok(())
 }
 
-/// Check that we show the right messages on cached, registry source distribution installs.
+/// Reject requirements files that include each other in a cycle.
 #[test]
-fn install_registry_source_dist_cached() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn sync_nested_requirements_cycle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let base_txt = context.temp_dir.child("base.txt");
+    base_txt.write_str("-r requirements.txt\ntomli==2.0.1")?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("source_distribution==0.0.1")?;
+    requirements_txt.write_str("-r ./base.txt\nMarkupSafe==2.1.3")?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1
+    error: Error parsing included file in `requirements.txt` at position 0
+      Caused by: Detected a cycle in included requirements files: `requirements.txt` -> `base.txt` -> `requirements.txt`
     "###
     );
 
-    context
-        .assert_command("import source_distribution")
-        .success();
+    Ok(())
+}
 
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
+/// Reject wheels that were selected for a different Python version than the interpreter's, with
+/// `--verify-interpreter-abi`.
+#[test]
+fn verify_interpreter_abi() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    uv_snapshot!(context.pip_sync()
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==3.0.2")?;
+
+    // The CPython 3.13 wheel doesn't match the CPython 3.12 interpreter.
+    let output = context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+        .arg("--python-version")
+        .arg("3.13")
+        .arg("--verify-interpreter-abi")
+        .output()?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("The following wheel is not built for the interpreter's ABI (cp312)"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("markupsafe-3.0.2-cp313-cp313-"), "{stderr}");
+    context.assert_command("import markupsafe").failure();
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1
-    "###
+    // The check should also apply when only downloading the distributions.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--python-version")
+        .arg("3.13")
+        .arg("--verify-interpreter-abi")
+        .arg("--download-only")
+        .arg("--dest")
+        .arg("wheelhouse")
+        .output()?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("The following wheel is not built for the interpreter's ABI (cp312)"),
+        "{stderr}"
     );
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .assert(predicates::path::missing());
 
+    // Without `--python-version`, the CPython 3.12 wheel is selected.
     context
-        .assert_command("import source_distribution")
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-interpreter-abi")
+        .assert()
         .success();
+    context.assert_command("import markupsafe").success();
 
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
+    Ok(())
+}
 
-    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
-        .chain(context.filters())
-        .collect::<Vec<_>>();
-    uv_snapshot!(filters, context.clean()
-        .arg("source_distribution"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+/// Accept wheels built for the free-threaded ABI, with `--verify-interpreter-abi`.
+#[test]
+#[cfg(feature = "python-managed")]
+fn verify_interpreter_abi_freethreaded() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
 
-    ----- stderr -----
-    Removed [N] files ([SIZE])
-    "###
-    );
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("3.13t")
+        .assert()
+        .success();
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.13t")
+        .assert()
+        .success();
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==3.0.2")?;
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1
-    "###
-    );
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-interpreter-abi")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
 
-    context
-        .assert_command("import source_distribution")
-        .success();
+    // The free-threaded wheel should be installed.
+    let wheel = fs_err::read_to_string(
+        site_packages_path(&context.venv, "python3.13t")
+            .join("MarkupSafe-3.0.2.dist-info")
+            .join("WHEEL"),
+    )?;
+    assert!(wheel.contains("Tag: cp313-cp313t-"), "{wheel}");
 
     Ok(())
 }
 
-/// Check that we show the right messages on cached, local source distribution installs.
+/// Build dependencies are installed into an isolated build environment, and so should not be
+/// reported as installed into the target environment.
 #[test]
-fn install_path_source_dist_cached() -> Result<()> {
+fn sync_report_excludes_build_dependencies() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download a source distribution.
-    let archive = context.temp_dir.child("source_distribution-0.0.1.tar.gz");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz",
-        &archive,
-    );
+    // Create a local project that requires `cython` to build, but not at runtime.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["hatchling", "cython==3.0.11"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "source-distribution @ {}",
-        Url::from_file_path(archive.path()).unwrap()
-    ))?;
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        ./project
+    "})?;
+
+    let record = context.temp_dir.child("installed.txt");
+    let summary = context.temp_dir.child("summary.txt");
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--record")
+        .arg(record.path())
+        .arg("--summary-file")
+        .arg(summary.path()), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
-    "###
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    "
     );
 
+    // Neither the record nor the summary should include the build dependencies.
+    let record = fs::read_to_string(&record)?;
+    assert!(!record.to_lowercase().contains("cython"), "{record}");
+    assert!(!record.contains("hatchling"), "{record}");
+    let summary = fs::read_to_string(&summary)?;
+    assert!(!summary.to_lowercase().contains("cython"), "{summary}");
+
+    // Nor should they be installed into the target environment.
+    context.assert_command("import Cython").failure();
+    context.assert_command("import project").success();
+
+    Ok(())
+}
+
+/// Install a package's console scripts without marking them as executable, with
+/// `--sandbox-scripts`.
+#[test]
+#[cfg(unix)]
+fn sync_sandbox_scripts() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    // Create a local project with a console script.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        hello = "project:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main():\n    print('Hello, world!')\n")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./project")?;
+
     context
-        .assert_command("import source_distribution")
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--sandbox-scripts")
+        .assert()
         .success();
 
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
+    // The script should be present, but not executable.
+    let script = venv_bin_path(&context.venv).join("hello");
+    let mode = fs::metadata(&script)?.permissions().mode();
+    assert_eq!(mode & 0o111, 0, "{mode:o}");
+
+    // Without `--sandbox-scripts`, the script should be executable.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let mode = fs::metadata(&script)?.permissions().mode();
+    assert_eq!(mode & 0o111, 0o111, "{mode:o}");
+
+    Ok(())
+}
+
+/// Report requirements that aren't pinned to an exact version, with `--warn-on-unpinned` and
+/// `--require-pinned`.
+#[test]
+fn sync_unpinned_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna>=3.6
+        sniffio
+    "})?;
 
+    // `--require-pinned` should fail without modifying the environment.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
+        .arg("--require-pinned"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
-    "###
+    error: The following requirements are not pinned to an exact version, but `--require-pinned` was provided:
+      idna>=3.6
+      sniffio
+    "
     );
 
-    context
-        .assert_command("import source_distribution")
-        .success();
-
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
+    context.assert_command("import anyio").failure();
 
-    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
-        .chain(context.filters())
-        .collect::<Vec<_>>();
-    uv_snapshot!(
-        filters,
-        context.clean().arg("source-distribution"), @r###"
+    // `--warn-on-unpinned` should warn about each unpinned requirement, but still sync.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--warn-on-unpinned"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
-    "###
+    warning: Requirement `idna>=3.6` is not pinned to an exact version (e.g., with `==`)
+    warning: Requirement `sniffio` is not pinned to an exact version (e.g., with `==`)
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
     );
 
+    // Once every requirement is pinned, neither flag reports anything.
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--require-pinned")
+        .arg("--warn-on-unpinned"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
-    "###
+    Resolved 3 packages in [TIME]
+    Audited 3 packages in [TIME]
+    "
     );
 
-    context
-        .assert_command("import source_distribution")
-        .success();
-
     Ok(())
 }
 
-/// Check that we show the right messages on cached, local source distribution installs.
+/// Git requirements are only considered pinned if they point to a full commit SHA, since branches
+/// and tags can move.
 #[test]
-fn install_path_built_dist_cached() -> Result<()> {
+#[cfg(feature = "git")]
+fn require_pinned_git() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download a wheel.
-    let archive = context.temp_dir.child("tomli-2.0.1-py3-none-any.whl");
-    download_to_disk(
-        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
-        &archive,
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-pinned"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following requirements are not pinned to an exact version, but `--require-pinned` was provided:
+      uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main
+    "
     );
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    let url = Url::from_file_path(archive.path()).unwrap();
-    requirements_txt.write_str(&format!("tomli @ {url}"))?;
+    requirements_txt.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389",
+    )?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--require-pinned"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1765,276 +2400,252 @@ fn install_path_built_dist_cached() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "
     );
 
-    context.assert_command("import tomli").success();
+    Ok(())
+}
 
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
+/// Install into an unactivated Conda environment by passing its interpreter to `--python`.
+#[test]
+#[cfg(unix)]
+fn sync_conda_prefix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a Conda-like prefix by copying a standalone Python installation, and marking it with
+    // a `conda-meta` directory (but no `pyvenv.cfg`).
+    let python = python_installations_for_versions(&context.temp_dir, &["3.12"])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let prefix = fs_err::canonicalize(python)?
+        .parent()
+        .and_then(Path::parent)
+        .unwrap()
+        .to_path_buf();
+    let conda_env = context.temp_dir.child("conda-env");
+    copy_dir_all(&prefix, &conda_env)?;
+    conda_env.child("conda-meta").create_dir_all()?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--python")
+        .arg(conda_env.join("bin").join("python3.12")), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Using Python 3.12.[X] environment at: conda-env
     Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
-    );
-
-    context.assert_command("import tomli").success();
-
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
-
-    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
-        .chain(context.filters())
-        .collect::<Vec<_>>();
-    uv_snapshot!(
-        filters,
-        context.clean().arg("tomli"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-
-    ----- stderr -----
-    Removed [N] files ([SIZE])
-    "###
+     + iniconfig==2.0.0
+    "
     );
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
-    "###
+    // The package should be installed into the Conda environment, not the virtual environment.
+    assert!(
+        site_packages_path(&conda_env, "python3.12")
+            .join("iniconfig")
+            .is_dir()
     );
-
-    context.assert_command("import tomli").success();
+    assert!(!context.site_packages().join("iniconfig").exists());
 
     Ok(())
 }
 
-/// Check that we show the right messages on cached, direct URL built distribution installs.
+/// Install into a named virtual environment in the managed virtual environment directory.
 #[test]
-fn install_url_built_dist_cached() -> Result<()> {
+fn sync_named_venv() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    let venv_dir = context.temp_dir.child("venvs");
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("tqdm @ https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl")?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    let context_filters = if cfg!(windows) {
-        [("warning: The package `tqdm` requires `colorama ; sys_platform == 'win32'`, but it's not installed\n", "")]
-            .into_iter()
-            .chain(context.filters())
-            .collect()
-    } else {
-        context.filters()
-    };
-    uv_snapshot!(context_filters, context.pip_sync()
+    // The named environment doesn't exist, and `--create` wasn't provided.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--venv")
+        .arg("project")
+        .env(EnvVars::UV_VENV_DIR, venv_dir.as_os_str()), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
-    "###
-    );
-
-    context.assert_command("import tqdm").success();
+    error: No virtual environment named `project` found at: venvs/project
 
-    // Re-run the installation in a new virtual environment.
-    context.reset_venv();
+    hint: Use `--create` to create it
+    "
+    );
 
-    uv_snapshot!(context_filters, context.pip_sync()
+    // Create the named environment.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
+        .arg("--venv")
+        .arg("project")
+        .arg("--create")
+        .env(EnvVars::UV_VENV_DIR, venv_dir.as_os_str()), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Using Python 3.12.[X] environment at: venvs/project
     Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
-    "###
+     + iniconfig==2.0.0
+    "
     );
 
-    context.assert_command("import tqdm").success();
-
-    // Clear the cache, then re-run the installation in a new virtual environment.
-    context.reset_venv();
+    venv_dir
+        .child("project")
+        .child("pyvenv.cfg")
+        .assert(predicates::path::is_file());
 
-    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
-        .chain(context_filters.clone())
-        .collect::<Vec<_>>();
-    uv_snapshot!(
-        filters,
-        context.clean().arg("tqdm"), @r###"
+    // The named environment should be reused.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--venv")
+        .arg("project")
+        .env(EnvVars::UV_VENV_DIR, venv_dir.as_os_str()), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
-    "###
+    Using Python 3.12.[X] environment at: venvs/project
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "
     );
 
-    uv_snapshot!(context_filters, context.pip_sync()
+    // The directory can also be provided on the command line. An existing environment that
+    // doesn't match the requested interpreter should be rejected, rather than silently used.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
+        .arg("--venv")
+        .arg("project")
+        .arg("--venv-dir")
+        .arg(venv_dir.path())
+        .arg("--python")
+        .arg("3.11"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
-    "###
+    error: The virtual environment named `project` uses Python 3.12.[X], which does not satisfy the requested interpreter: `3.11`
+    "
     );
 
-    context.assert_command("import tqdm").success();
-
-    Ok(())
-}
-
-/// Verify that fail with an appropriate error when a package is repeated.
-#[test]
-fn duplicate_package_overlap() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\nMarkupSafe==2.1.2")?;
-
-    uv_snapshot!(context.pip_sync()
+    // Names can't escape the managed directory.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--venv")
+        .arg("../project")
+        .env(EnvVars::UV_VENV_DIR, venv_dir.as_os_str()), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because you require markupsafe==2.1.3 and markupsafe==2.1.2, we can conclude that your requirements are unsatisfiable.
-    "###
+    error: Invalid virtual environment name `../project`: expected a single directory name
+    "
     );
 
     Ok(())
 }
 
-/// Verify that allow duplicate packages when they are disjoint.
+/// Report any newer versions of the synced packages with `--show-upgrades`.
 #[test]
-fn duplicate_package_disjoint() -> Result<()> {
+fn sync_show_upgrades() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\nMarkupSafe==2.1.2 ; python_version < '3.6'")?;
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--show-upgrades"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + markupsafe==2.1.3
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    Upgrades available for 1 package:
+     anyio 4.0.0 -> 4.3.0 available
     "###
     );
 
-    Ok(())
-}
-
-/// Verify that we can force reinstall of packages.
-#[test]
-fn reinstall() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
-
+    // The report can also be written as JSON, for packages that are already installed.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--show-upgrades")
+        .arg("--upgrades-format")
+        .arg("json"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
+    [{"name":"anyio","version":"4.0.0","latest_version":"4.3.0"}]
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
-     + tomli==2.0.1
+    Audited 3 packages in [TIME]
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
-
-    // Re-run the installation with `--reinstall`.
+    // The check is skipped when offline.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--strict"), @r###"
+        .arg("--show-upgrades")
+        .arg("--offline"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Uninstalled 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     ~ markupsafe==2.1.3
-     ~ tomli==2.0.1
+    Audited 3 packages in [TIME]
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
-
     Ok(())
 }
 
-/// Verify that we can force reinstall of selective packages.
+/// Write the files installed by a sync to a combined record with `--record`.
 #[test]
-fn reinstall_package() -> Result<()> {
+fn sync_record() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        tomli==2.0.1
+    "})?;
+
+    let record = context.temp_dir.child("installed.txt");
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--record")
+        .arg(record.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2043,20 +2654,38 @@ fn reinstall_package() -> Result<()> {
     Resolved 2 packages in [TIME]
     Prepared 2 packages in [TIME]
     Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
+     + iniconfig==2.0.0
      + tomli==2.0.1
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
+    // The record should include the files from each package, as absolute paths.
+    let contents = fs::read_to_string(&record)?;
+    let paths = contents.lines().map(Path::new).collect::<Vec<_>>();
+    assert!(paths.iter().all(|path| path.is_absolute()), "{contents}");
+    for suffix in [
+        Path::new("site-packages")
+            .join("iniconfig")
+            .join("__init__.py"),
+        Path::new("site-packages").join("tomli").join("__init__.py"),
+        Path::new("site-packages")
+            .join("tomli-2.0.1.dist-info")
+            .join("RECORD"),
+    ] {
+        assert!(
+            paths.iter().any(|path| path.ends_with(&suffix)),
+            "{contents}"
+        );
+    }
 
-    // Re-run the installation with `--reinstall`.
+    // Reinstall, writing the paths relative to the environment.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
         .arg("--reinstall-package")
         .arg("tomli")
-        .arg("--strict"), @r###"
+        .arg("--record")
+        .arg(record.path())
+        .arg("--record-relative"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2070,73 +2699,59 @@ fn reinstall_package() -> Result<()> {
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
+    // Only the reinstalled package should be included.
+    let site_packages = context
+        .site_packages()
+        .strip_prefix(context.venv.path())?
+        .to_path_buf();
+    let contents = fs::read_to_string(&record)?;
+    let paths = contents.lines().map(Path::new).collect::<Vec<_>>();
+    assert!(
+        paths.contains(&site_packages.join("tomli").join("__init__.py").as_path()),
+        "{contents}"
+    );
+    assert!(!contents.contains("iniconfig"), "{contents}");
 
     Ok(())
 }
 
-/// Verify that we can force reinstall of Git dependencies.
+/// Attempt to install a package without using a remote index.
 #[test]
-#[cfg(feature = "git")]
-fn reinstall_git() -> Result<()> {
+fn install_no_index() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
+        .arg("--no-index")
         .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###
-    );
-
-    context
-        .assert_command("import uv_public_pypackage")
-        .success();
-
-    // Re-run the installation with `--reinstall`.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall-package")
-        .arg("uv-public-pypackage")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the provided package locations and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     ~ uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
     "###
     );
 
-    context
-        .assert_command("import uv_public_pypackage")
-        .success();
+    context.assert_command("import iniconfig").failure();
 
     Ok(())
 }
 
-/// Verify that we can force refresh of cached data.
+/// Attempt to install a package without using a remote index
+/// after a previous successful installation.
 #[test]
-fn refresh() -> Result<()> {
+fn install_no_index_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
@@ -2146,289 +2761,268 @@ fn refresh() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
-     + tomli==2.0.1
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
+    context.assert_command("import iniconfig").success();
 
-    // Re-run the installation into with `--refresh`. Ensure that we resolve and download the
-    // latest versions of the packages.
-    context.reset_venv();
+    context.pip_uninstall().arg("iniconfig").assert().success();
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--refresh")
-        .arg("--strict")
-        , @r###"
-    success: true
-    exit_code: 0
+        .arg("--no-index")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
-     + tomli==2.0.1
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the provided package locations and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
     "###
     );
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
+    context.assert_command("import iniconfig").failure();
 
     Ok(())
 }
 
-/// Verify that we can force refresh of selective packages.
+/// Under `--no-index`, requirements with a direct URL should still install, since they don't
+/// require an index; only the registry requirements should fail.
 #[test]
-fn refresh_package() -> Result<()> {
+fn install_no_index_direct_url() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Download a wheel.
+    let archive = context.temp_dir.child("tomli-2.0.1-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
+    );
+
+    // A registry requirement can't be satisfied without an index.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+    requirements_txt.write_str(&formatdoc! {r"
+        idna==3.6
+        iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl
+        tomli @ {}
+        ",
+        Url::from_file_path(archive.path()).unwrap()
+    })?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--no-index")
+        .arg("--strict"), @r"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
-     + tomli==2.0.1
-    "###
-    );
+      × No solution found when resolving dependencies:
+      ╰─▶ Because idna was not found in the provided package locations and you require idna==3.6, we can conclude that your requirements are unsatisfiable.
 
-    context.assert_command("import markupsafe").success();
-    context.assert_command("import tomli").success();
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
+    "
+    );
 
-    // Re-run the installation into with `--refresh`. Ensure that we resolve and download the
-    // latest versions of the packages.
-    context.reset_venv();
+    // The direct URL requirements install on their own.
+    requirements_txt.write_str(&formatdoc! {r"
+        iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl
+        tomli @ {}
+        ",
+        Url::from_file_path(archive.path()).unwrap()
+    })?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--refresh-package")
-        .arg("tomli")
-        .arg("--strict")
-        , @r###"
+        .arg("--no-index")
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    Prepared 1 package in [TIME]
+    Prepared 2 packages in [TIME]
     Installed 2 packages in [TIME]
-     + markupsafe==2.1.3
-     + tomli==2.0.1
-    "###
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
+    "
     );
 
-    context.assert_command("import markupsafe").success();
+    context.assert_command("import iniconfig").success();
     context.assert_command("import tomli").success();
 
     Ok(())
 }
 
 #[test]
-fn sync_editable() -> Result<()> {
+fn warn_on_yanked() -> Result<()> {
     let context = TestContext::new("3.12");
-    let poetry_editable = context.temp_dir.child("poetry_editable");
-
-    // Copy into the temporary directory so we can mutate it.
-    copy_dir_all(
-        context
-            .workspace_root
-            .join("scripts/packages/poetry_editable"),
-        &poetry_editable,
-    )?;
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&indoc::formatdoc! {r"
-        anyio==3.7.0
-        -e file://{poetry_editable}
-        ",
-        poetry_editable = poetry_editable.display()
-    })?;
+    // This version is yanked.
+    let requirements_in = context.temp_dir.child("requirements.txt");
+    requirements_in.write_str("colorama==0.4.2")?;
 
-    // Install the editable package.
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + anyio==3.7.0
-     + poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + colorama==0.4.2
+    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
     "###
     );
 
-    // Re-install the editable package. This is a no-op.
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+    Ok(())
+}
+
+#[test]
+fn warn_on_yanked_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // This version is yanked.
+    let requirements_in = context.temp_dir.child("requirements.txt");
+    requirements_in.write_str("colorama==0.4.2")?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Audited 2 packages in [TIME]
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + colorama==0.4.2
+    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
     "###
     );
 
-    // Reinstall the editable package. This won't trigger a rebuild, but it will trigger an install.
+    Ok(())
+}
+
+/// Resolve a local wheel.
+#[test]
+fn install_local_wheel() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Download a wheel.
+    let archive = context.temp_dir.child("tomli-2.0.1-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
+    );
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tomli @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
+
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path())
-        .arg("--reinstall-package")
-        .arg("poetry-editable"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    let python_source_file = poetry_editable.path().join("poetry_editable/__init__.py");
-    let check_installed = indoc::indoc! {r#"
-        from poetry_editable import a
-
-        assert a() == "a", a()
-   "#};
-    context.assert_command(check_installed).success();
-
-    // Edit the sources and make sure the changes are respected without syncing again.
-    let python_version_1 = indoc::indoc! {r"
-        version = 1
-   "};
-    fs_err::write(&python_source_file, python_version_1)?;
-
-    let check_installed = indoc::indoc! {r"
-        from poetry_editable import version
-
-        assert version == 1, version
-   "};
-    context.assert_command(check_installed).success();
-
-    let python_version_2 = indoc::indoc! {r"
-        version = 2
-   "};
-    fs_err::write(&python_source_file, python_version_2)?;
-
-    let check_installed = indoc::indoc! {r"
-        from poetry_editable import version
+    context.assert_command("import tomli").success();
 
-        assert version == 2, version
-   "};
-    context.assert_command(check_installed).success();
+    // Create a new virtual environment.
+    context.reset_venv();
 
-    // Reinstall the editable package. This won't trigger a rebuild or reinstall, since we only
-    // detect changes to metadata files (like `pyproject.toml`).
+    // Reinstall. The wheel should come from the cache, so there shouldn't be a "download".
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Audited 2 packages in [TIME]
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    // Modify the `pyproject.toml` file.
-    let pyproject_toml = poetry_editable.path().join("pyproject.toml");
-    let pyproject_toml_contents = fs_err::read_to_string(&pyproject_toml)?;
-    fs_err::write(
-        &pyproject_toml,
-        pyproject_toml_contents.replace("0.1.0", "0.1.1"),
-    )?;
+    context.assert_command("import tomli").success();
 
-    // Reinstall the editable package. This will trigger a rebuild and reinstall.
+    // Create a new virtual environment.
+    context.reset_venv();
+
+    // "Modify" the wheel.
+    // The `filetime` crate works on Windows unlike the std.
+    filetime::set_file_mtime(&archive, filetime::FileTime::now()).unwrap();
+
+    // Reinstall. The wheel should be "downloaded" again.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     - poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
-     + poetry-editable==0.1.1 (from file://[TEMP_DIR]/poetry_editable)
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    // Modify the `pyproject.toml` file.
-    let pyproject_toml = poetry_editable.path().join("pyproject.toml");
-    let pyproject_toml_contents = fs_err::read_to_string(&pyproject_toml)?;
-    fs_err::write(
-        &pyproject_toml,
-        pyproject_toml_contents.replace("0.1.0", "0.1.1"),
-    )?;
+    context.assert_command("import tomli").success();
 
-    // Reinstall the editable package. This will trigger a rebuild and reinstall.
+    // "Modify" the wheel.
+    filetime::set_file_mtime(&archive, filetime::FileTime::now()).unwrap();
+
+    // Reinstall into the same virtual environment. The wheel should be reinstalled.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ poetry-editable==0.1.1 (from file://[TEMP_DIR]/poetry_editable)
+     ~ tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    Ok(())
-}
-
-#[test]
-fn sync_editable_and_registry() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // Copy the black test editable into the "current" directory
-    copy_dir_all(
-        context
-            .workspace_root
-            .join("scripts/packages/black_editable"),
-        context.temp_dir.join("black_editable"),
-    )?;
-
-    // Install the registry-based version of Black.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        black==24.1.0
-        "
-    })?;
-
+    // Reinstall into the same virtual environment. The wheel should _not_ be reinstalled.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path())
+        .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: true
     exit_code: 0
@@ -2436,71 +3030,56 @@ fn sync_editable_and_registry() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + black==24.1.0
-    warning: The package `black` requires `click>=8.0.0`, but it's not installed
-    warning: The package `black` requires `mypy-extensions>=0.4.3`, but it's not installed
-    warning: The package `black` requires `packaging>=22.0`, but it's not installed
-    warning: The package `black` requires `pathspec>=0.9.0`, but it's not installed
-    warning: The package `black` requires `platformdirs>=2`, but it's not installed
+    Audited 1 package in [TIME]
     "###
     );
 
-    // Install the editable version of Black. This should remove the registry-based version.
-    // Use the `file:` syntax for extra coverage.
+    context.assert_command("import tomli").success();
+
+    // Reinstall without the package name.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        -e file:./black_editable
-        "
-    })?;
+    requirements_txt.write_str(&format!("{}", Url::from_file_path(archive.path()).unwrap()))?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     - black==24.1.0
-     + black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    Audited 1 package in [TIME]
     "###
     );
 
-    // Re-install the registry-based version of Black. This should be a no-op, since we have a
-    // version of Black installed (the editable version) that satisfies the requirements.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        black
-        "
-    })?;
+    context.assert_command("import tomli").success();
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    Ok(())
+}
 
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-    Audited 1 package in [TIME]
-    "###
+/// Reinstall a wheel from a `--find-links` directory when it's rebuilt in place.
+#[test]
+fn install_find_links_wheel_rebuilt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Download a wheel into a `--find-links` directory.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    let archive = links.child("tomli-2.0.1-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
     );
 
-    // Re-install Black at a specific version. This should replace the editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        black==23.10.0
-        "
-    })?;
+    requirements_txt.write_str("tomli==2.0.1")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path())
-        .arg("--strict"), @r###"
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2508,63 +3087,36 @@ fn sync_editable_and_registry() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     - black==0.1.0 (from file://[TEMP_DIR]/black_editable)
-     + black==23.10.0
-    warning: The package `black` requires `click>=8.0.0`, but it's not installed
-    warning: The package `black` requires `mypy-extensions>=0.4.3`, but it's not installed
-    warning: The package `black` requires `packaging>=22.0`, but it's not installed
-    warning: The package `black` requires `pathspec>=0.9.0`, but it's not installed
-    warning: The package `black` requires `platformdirs>=2`, but it's not installed
+     + tomli==2.0.1
     "###
     );
 
-    Ok(())
-}
-
-#[test]
-fn sync_editable_and_local() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // Copy the black test editable into the "current" directory
-    copy_dir_all(
-        context
-            .workspace_root
-            .join("scripts/packages/black_editable"),
-        context.temp_dir.join("black_editable"),
-    )?;
-
-    // Install the editable version of Black.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        -e file:./black_editable
-        "
-    })?;
-
+    // Sync again. The wheel is unchanged, so it should _not_ be reinstalled.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    Audited 1 package in [TIME]
     "###
     );
 
-    // Install the non-editable version of Black. This should replace the editable version.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        black @ file:./black_editable
-        "
-    })?;
+    // "Rebuild" the wheel.
+    filetime::set_file_mtime(&archive, filetime::FileTime::now()).unwrap();
 
+    // Sync again. The wheel should be unzipped and reinstalled.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2574,42 +3126,68 @@ fn sync_editable_and_local() -> Result<()> {
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+     ~ tomli==2.0.1
     "###
     );
 
-    // Reinstall the editable version of Black. This should replace the non-editable version.
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Install a wheel whose actual version doesn't match the version encoded in the filename.
+#[test]
+fn mismatched_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Download a wheel.
+    let archive = context.temp_dir.child("tomli-3.7.2-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
+    );
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
-        -e file:./black_editable
-        "
-    })?;
+    requirements_txt.write_str(&format!(
+        "tomli @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path()), @r###"
-    success: true
-    exit_code: 0
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     ~ black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    Prepared 1 package in [TIME]
+    error: Failed to install: tomli-3.7.2-py3-none-any.whl (tomli==3.7.2 (from file://[TEMP_DIR]/tomli-3.7.2-py3-none-any.whl))
+      Caused by: Wheel version does not match filename: 2.0.1 != 3.7.2
     "###
     );
 
     Ok(())
 }
 
+/// Install a wheel whose actual name doesn't match the name encoded in the filename.
 #[test]
-fn incompatible_wheel() -> Result<()> {
+fn mismatched_name() -> Result<()> {
     let context = TestContext::new("3.12");
-    let wheel = context.temp_dir.child("foo-1.2.3-py3-none-any.whl");
-    wheel.touch()?;
+
+    // Download a wheel.
+    let archive = context.temp_dir.child("foo-2.0.1-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
+    );
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!("foo @ {}", wheel.path().simplified_display()))?;
+    requirements_txt.write_str(&format!(
+        "foo @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
@@ -2623,88 +3201,137 @@ fn incompatible_wheel() -> Result<()> {
       ╰─▶ Because foo has an invalid package format and you require foo, we can conclude that your requirements are unsatisfiable.
 
           hint: The structure of `foo` was invalid:
-            Failed to read from zip file
+            The wheel declares the name `tomli` (in `tomli-2.0.1.dist-info`), but was requested as `foo`
     "###
     );
 
     Ok(())
 }
 
-/// Install a project without a `pyproject.toml`, using the PEP 517 build backend.
+/// Sync the `pip` dependencies from a Conda `environment.yml`, skipping the Conda dependencies.
 #[test]
-fn sync_legacy_sdist_pep_517() -> Result<()> {
+fn environment_yml() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flake8 @ https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz")?;
+    let environment_yml = context.temp_dir.child("environment.yml");
+    environment_yml.write_str(indoc! {r"
+        name: test-env
+        channels:
+          - conda-forge
+        dependencies:
+          - python=3.12
+          - numpy
+          - pip:
+            - anyio==4.0.0
+            - idna==3.6
+            - sniffio==1.3.1
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("environment.yml")
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
-    "###
+    warning: Skipping Conda dependencies in `environment.yml`, which are not supported: python=3.12, numpy
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
     );
 
     Ok(())
 }
 
-/// Sync using `--find-links` with a local directory.
+/// Reject `environment.yml` syntax outside of the supported subset, rather than misreading it.
 #[test]
-fn find_links() -> Result<()> {
+fn environment_yml_unsupported_syntax() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        markupsafe==2.1.3
-        numpy==1.26.3
-        tqdm==1000.0.0
-        werkzeug @ https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl
+    let environment_yml = context.temp_dir.child("environment.yml");
+    environment_yml.write_str(indoc! {r"
+        name: test-env
+        dependencies:
+        - python=3.12  # Sequences may start at the key's indentation.
+        - pip: [anyio==4.0.0, idna==3.6]
     "})?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+        .arg("environment.yml")
+        .arg("--strict"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
-    Prepared 4 packages in [TIME]
-    Installed 4 packages in [TIME]
-     + markupsafe==2.1.3
-     + numpy==1.26.3
-     + tqdm==1000.0.0
-     + werkzeug==3.0.1 (from https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl)
-    "###
+    error: Failed to parse: `environment.yml`
+      Caused by: Unsupported syntax on line 4: `pip` must be a block sequence
+    "
     );
 
     Ok(())
 }
 
-/// Sync using `--find-links` with `--no-index`, which should accept the local wheel.
+/// Sync the locked packages from a Pipenv `Pipfile.lock`, with `--dev` to include `develop`.
 #[test]
-fn find_links_no_index_match() -> Result<()> {
+fn pipfile_lock() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        tqdm==1000.0.0
-    "})?;
+    let pipfile_lock = context.temp_dir.child("Pipfile.lock");
+    pipfile_lock.write_str(indoc! {r#"
+        {
+            "_meta": {
+                "hash": {
+                    "sha256": "0000000000000000000000000000000000000000000000000000000000000000"
+                },
+                "pipfile-spec": 6,
+                "requires": {
+                    "python_version": "3.12"
+                },
+                "sources": [
+                    {
+                        "name": "pypi",
+                        "url": "https://pypi.org/simple",
+                        "verify_ssl": true
+                    }
+                ]
+            },
+            "default": {
+                "colorama": {
+                    "hashes": [],
+                    "markers": "sys_platform == 'win32'",
+                    "version": "==0.4.6"
+                },
+                "iniconfig": {
+                    "hashes": [
+                        "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                    ],
+                    "index": "pypi",
+                    "markers": "python_version >= '3.7'",
+                    "version": "==2.0.0"
+                }
+            },
+            "develop": {
+                "idna": {
+                    "hashes": [
+                        "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                    ],
+                    "index": "pypi",
+                    "markers": "python_version >= '3.5'",
+                    "version": "==3.6"
+                }
+            }
+        }
+    "#})?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--no-index")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg("Pipfile.lock")
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2713,89 +3340,117 @@ fn find_links_no_index_match() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==1000.0.0
-    "###
+     + iniconfig==2.0.0
+    "
     );
 
-    Ok(())
-}
-
-/// Sync using `--find-links` with `--offline`, which should accept the local wheel.
-#[test]
-fn find_links_offline_match() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        tqdm==1000.0.0
-    "})?;
-
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--offline")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg("Pipfile.lock")
+        .arg("--dev")
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==1000.0.0
-    "###
+     + idna==3.6
+    "
     );
 
-    Ok(())
-}
-
-/// Sync using `--find-links` with `--offline`, which should fail to find `numpy`.
-#[test]
-fn find_links_offline_no_match() -> Result<()> {
-    let context = TestContext::new("3.12");
-
+    // `--dev` requires a `Pipfile.lock`.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        numpy
-        tqdm==1000.0.0
-    "})?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--offline")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg("--dev"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because numpy was not found in the cache and you require numpy, we can conclude that your requirements are unsatisfiable.
+    error: `--dev` can only be used with a `Pipfile.lock`
+    "
+    );
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
-    "###
+    // Entries can't smuggle additional requirements or options via control characters.
+    pipfile_lock.write_str(indoc! {r#"
+        {
+            "_meta": {},
+            "default": {
+                "iniconfig": {
+                    "hashes": [],
+                    "version": "==2.0.0\n--index-url https://example.com/simple"
+                }
+            },
+            "develop": {}
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("Pipfile.lock"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse the locked packages in `Pipfile.lock`
+      Caused by: Entry `iniconfig` contains a control character: "==2.0.0\n--index-url https://example.com/simple"
+    "#
+    );
+
+    // Local path entries aren't supported.
+    pipfile_lock.write_str(indoc! {r#"
+        {
+            "_meta": {},
+            "default": {
+                "example": {
+                    "editable": true,
+                    "path": "."
+                }
+            },
+            "develop": {}
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("Pipfile.lock"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `Pipfile.lock` contains entries that are neither pinned to a version nor Git dependencies, which are not supported: example
+    "
     );
 
     Ok(())
 }
 
-/// Sync using `--find-links` with a local directory. Ensure that cached wheels are reused.
+/// Install a local source distribution.
 #[test]
-fn find_links_wheel_cache() -> Result<()> {
+fn install_local_source_distribution() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Download a source distribution.
+    let archive = context.temp_dir.child("wheel-0.42.0.tar.gz");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/b0/b4/bc2baae3970c282fae6c2cb8e0f179923dceb7eaffb0e76170628f9af97b/wheel-0.42.0.tar.gz",
+        &archive,
+    );
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        tqdm==1000.0.0
-    "})?;
+    requirements_txt.write_str(&format!(
+        "wheel @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
 
-    // Install `tqdm`.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2804,16 +3459,37 @@ fn find_links_wheel_cache() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==1000.0.0
+     + wheel==0.42.0 (from file://[TEMP_DIR]/wheel-0.42.0.tar.gz)
     "###
     );
 
-    // Reinstall `tqdm` with `--reinstall`. Ensure that the wheel is reused.
-    uv_snapshot!(context.filters(), context.pip_sync()
+    context.assert_command("import wheel").success();
+
+    Ok(())
+}
+
+/// This package includes a `[build-system]`, but no `build-backend`.
+///
+/// It lists some explicit build requirements that are necessary to build the distribution:
+/// ```toml
+/// [build-system]
+/// requires = ["Cython<3", "setuptools", "wheel"]
+/// ```
+///
+/// Like `pip` and `build`, we should use PEP 517 here and respect the `requires`, but use the
+/// default build backend.
+///
+/// The example is based `DTLSSocket==0.1.16`
+#[test]
+fn install_build_system_no_backend() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("build-system-no-backend @ https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz")?;
+
+    uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2821,49 +3497,58 @@ fn find_links_wheel_cache() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ tqdm==1000.0.0
+     + build-system-no-backend==0.1.0 (from https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz)
     "###
     );
 
+    context
+        .assert_command("import build_system_no_backend")
+        .success();
+
     Ok(())
 }
 
-/// Sync using `--find-links` with a local directory. Ensure that cached source distributions are
-/// reused.
+/// Under `--no-index`, the build requirements of a source distribution should be sourced from the
+/// `--find-links` locations, like any other requirement.
 #[test]
-fn find_links_source_cache() -> Result<()> {
+fn install_build_system_no_backend_no_index() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc! {r"
-        tqdm==999.0.0
-    "})?;
+    requirements_txt.write_str("build-system-no-backend @ https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz")?;
 
-    // Install `tqdm`.
+    // Without any package locations, the build requirements can't be resolved.
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
-    success: true
-    exit_code: 0
+        .arg("--no-index"), @r###"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tqdm==999.0.0
+      × Failed to build `build-system-no-backend @ https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz`
+      ├─▶ Failed to resolve requirements from `build-system.requires`
+      ├─▶ No solution found when resolving: `setuptools>=40.8.0`
+      ╰─▶ Because setuptools was not found in the provided package locations and you require setuptools>=40.8.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
     "###
     );
 
-    // Reinstall `tqdm` with `--reinstall`. Ensure that the wheel is reused.
+    // With a `--find-links` location that provides `setuptools`, the build should succeed.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/69/8a/b9dc7678803429e4a3bc9ba462fa3dd9066824d3c607490235c6a796be5a/setuptools-75.8.0-py3-none-any.whl",
+        &links.child("setuptools-75.8.0-py3-none-any.whl"),
+    );
+
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
+        .arg("--no-index")
         .arg("--find-links")
-        .arg(context.workspace_root.join("scripts/links/")), @r###"
+        .arg(links.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2871,41 +3556,25 @@ fn find_links_source_cache() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ tqdm==999.0.0
+     + build-system-no-backend==0.1.0 (from https://files.pythonhosted.org/packages/ec/25/1e531108ca027dc3a3b37d351f4b86d811df4884c6a81cd99e73b8b589f5/build-system-no-backend-0.1.0.tar.gz)
     "###
     );
 
     Ok(())
 }
 
-/// Install without network access via the `--offline` flag.
+/// Check that we show the right messages on cached, direct URL source distribution installs.
 #[test]
-fn offline() -> Result<()> {
+fn install_url_source_dist_cached() -> Result<()> {
     let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("black==23.10.1")?;
-
-    // Install with `--offline` with an empty cache.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.in")
-        .arg("--offline"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because black was not found in the cache and you require black==23.10.1, we can conclude that your requirements are unsatisfiable.
 
-          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
-    "###
-    );
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source_distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz")?;
 
-    // Populate the cache.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2914,16 +3583,20 @@ fn offline() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + black==23.10.1
+     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
     "###
     );
 
-    // Install with `--offline` with a populated cache.
+    context
+        .assert_command("import source_distribution")
+        .success();
+
+    // Re-run the installation in a new virtual environment.
     context.reset_venv();
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in")
-        .arg("--offline")
+        .arg("requirements.txt")
+        .arg("--strict")
         , @r###"
     success: true
     exit_code: 0
@@ -2932,83 +3605,67 @@ fn offline() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Installed 1 package in [TIME]
-     + black==23.10.1
+     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
     "###
     );
 
-    Ok(())
-}
-
-/// Include a `constraints.txt` file with a compatible constraint.
-#[test]
-fn compatible_constraint() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==3.7.0")?;
+    context
+        .assert_command("import source_distribution")
+        .success();
 
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("anyio==3.7.0")?;
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--constraint")
-        .arg("constraints.txt"), @r###"
+    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(
+        filters,
+        context.clean().arg("source_distribution"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + anyio==3.7.0
+    Removed [N] files ([SIZE])
     "###
     );
 
-    Ok(())
-}
-
-/// Include a `constraints.txt` file with an incompatible constraint.
-#[test]
-fn incompatible_constraint() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==3.7.0")?;
-
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("anyio==3.6.0")?;
-
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--constraint")
-        .arg("constraints.txt"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--strict")
+        , @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because you require anyio==3.7.0 and anyio==3.6.0, we can conclude that your requirements are unsatisfiable.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
     "###
     );
 
+    context
+        .assert_command("import source_distribution")
+        .success();
+
     Ok(())
 }
 
-/// Include a `constraints.txt` file with an irrelevant constraint.
+/// Check that we show the right messages on cached, Git source distribution installs.
 #[test]
-fn irrelevant_constraint() -> Result<()> {
+#[cfg(feature = "git")]
+fn install_git_source_dist_cached() -> Result<()> {
     let context = TestContext::new("3.12");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==3.7.0")?;
 
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("black==23.10.1")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--constraint")
-        .arg("constraints.txt"), @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3017,114 +3674,150 @@ fn irrelevant_constraint() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==3.7.0
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
     "###
     );
 
-    Ok(())
-}
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
 
-/// Sync with a repeated `anyio` requirement.
-#[test]
-fn repeat_requirement_identical() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio\nanyio")?;
+    // Re-run the installation in a new virtual environment.
+    context.reset_venv();
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.3.0
-    "###);
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###
+    );
 
-    Ok(())
-}
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
 
-/// Sync with a repeated `anyio` requirement, with compatible versions.
-#[test]
-fn repeat_requirement_compatible() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio\nanyio==4.0.0")?;
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
+
+    let filters = if cfg!(windows) {
+        [("Removed 2 files", "Removed 3 files")]
+            .into_iter()
+            .chain(context.filters())
+            .collect()
+    } else {
+        context.filters()
+    };
+    uv_snapshot!(filters, context.clean()
+        .arg("werkzeug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found
+    "###
+    );
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
-    "###);
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###
+    );
+
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
 
     Ok(())
 }
 
-/// Sync with a repeated, but conflicting `anyio` requirement.
+/// Check that we show the right messages on cached, registry source distribution installs.
 #[test]
-fn repeat_requirement_incompatible() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio<4.0.0\nanyio==4.0.0")?;
+fn install_registry_source_dist_cached() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source_distribution==0.0.1")?;
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
-    success: false
-    exit_code: 1
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because you require anyio<4.0.0 and anyio==4.0.0, we can conclude that your requirements are unsatisfiable.
-    "###);
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
 
-    Ok(())
-}
+    context
+        .assert_command("import source_distribution")
+        .success();
 
-/// Don't preserve the mtime from .tar.gz files, it may be the unix epoch (1970-01-01), while Python's zip
-/// implementation can't handle files with an mtime older than 1980.
-/// See also <https://github.com/alexcrichton/tar-rs/issues/349>.
-#[test]
-fn tar_dont_preserve_mtime() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("tomli @ https://files.pythonhosted.org/packages/c0/3f/d7af728f075fb08564c5949a9c95e44352e23dee646869fa104a3b2060a3/tomli-2.0.1.tar.gz")?;
+    // Re-run the installation in a new virtual environment.
+    context.reset_venv();
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tomli==2.0.1 (from https://files.pythonhosted.org/packages/c0/3f/d7af728f075fb08564c5949a9c95e44352e23dee646869fa104a3b2060a3/tomli-2.0.1.tar.gz)
-    "###);
+     + source-distribution==0.0.1
+    "###
+    );
 
-    Ok(())
-}
+    context
+        .assert_command("import source_distribution")
+        .success();
 
-/// Avoid creating a file with 000 permissions
-#[test]
-fn set_read_permissions() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("databricks==0.2")?;
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
+
+    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.clean()
+        .arg("source_distribution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed [N] files ([SIZE])
+    "###
+    );
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3133,78 +3826,38 @@ fn set_read_permissions() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + databricks==0.2
-    "###);
+     + source-distribution==0.0.1
+    "###
+    );
+
+    context
+        .assert_command("import source_distribution")
+        .success();
 
     Ok(())
 }
 
-/// Test special case to generate versioned pip launchers.
-/// <https://github.com/pypa/pip/blob/3898741e29b7279e7bffe044ecfbe20f6a438b1e/src/pip/_internal/operations/install/wheel.py#L283>
-/// <https://github.com/astral-sh/uv/issues/1593>
+/// Check that we show the right messages on cached, local source distribution installs.
 #[test]
-fn pip_entrypoints() -> Result<()> {
+fn install_path_source_dist_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    for pip_requirement in [
-        // Test compatibility with launchers in 24.0
-        // https://inspector.pypi.io/project/pip/24.0/packages/8a/6a/19e9fe04fca059ccf770861c7d5721ab4c2aebc539889e97c7977528a53b/pip-24.0-py3-none-any.whl/pip-24.0.dist-info/entry_points.txt
-        "pip==24.0",
-        // Test compatibility with launcher changes from https://github.com/pypa/pip/pull/12536 released in 24.1b1
-        // See https://github.com/astral-sh/uv/pull/1982
-        "pip==24.1b1",
-    ] {
-        let requirements_txt = context.temp_dir.child("requirements.txt");
-        requirements_txt.write_str(pip_requirement)?;
-
-        context
-            .pip_sync()
-            .arg("requirements.txt")
-            .arg("--strict")
-            .output()
-            .expect("Failed to install pip");
-
-        let bin_dir = context.venv.join(if cfg!(unix) {
-            "bin"
-        } else if cfg!(windows) {
-            "Scripts"
-        } else {
-            unimplemented!("Only Windows and Unix are supported")
-        });
-        ChildPath::new(bin_dir.join(format!("pip3.10{EXE_SUFFIX}")))
-            .assert(predicates::path::missing());
-        ChildPath::new(bin_dir.join(format!("pip3.12{EXE_SUFFIX}")))
-            .assert(predicates::path::exists());
-    }
+    // Download a source distribution.
+    let archive = context.temp_dir.child("source_distribution-0.0.1.tar.gz");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz",
+        &archive,
+    );
 
-    Ok(())
-}
-
-#[test]
-fn invalidate_on_change() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // Create an editable package.
-    let editable_dir = context.temp_dir.child("editable");
-    editable_dir.create_dir_all()?;
-    let pyproject_toml = editable_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"[project]
-name = "example"
-version = "0.0.0"
-dependencies = [
-  "anyio==4.0.0"
-]
-requires-python = ">=3.8"
-"#,
-    )?;
-
-    // Write to a requirements file.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(&format!("-e {}", editable_dir.path().display()))?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "source-distribution @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3213,66 +3866,58 @@ requires-python = ">=3.8"
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
     "###
     );
 
-    // Installing again should be a no-op.
+    context
+        .assert_command("import source_distribution")
+        .success();
+
+    // Re-run the installation in a new virtual environment.
+    context.reset_venv();
+
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Audited 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
     "###
     );
 
-    // Modify the editable package.
-    pyproject_toml.write_str(
-        r#"[project]
-name = "example"
-version = "0.0.0"
-dependencies = [
-  "anyio==3.7.1"
-]
-requires-python = ">=3.8"
-"#,
-    )?;
+    context
+        .assert_command("import source_distribution")
+        .success();
 
-    // Re-installing should update the package.
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
+
+    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(
+        filters,
+        context.clean().arg("source-distribution"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     ~ example==0.0.0 (from file://[TEMP_DIR]/editable)
+    Removed [N] files ([SIZE])
     "###
     );
 
-    Ok(())
-}
-
-/// Install with bytecode compilation.
-#[test]
-fn compile() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3")?;
-
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--compile")
-        .arg("--strict"), @r###"
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3281,34 +3926,34 @@ fn compile() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-    Bytecode compiled 3 files in [TIME]
-     + markupsafe==2.1.3
+     + source-distribution==0.0.1 (from file://[TEMP_DIR]/source_distribution-0.0.1.tar.gz)
     "###
     );
 
-    assert!(
-        context
-            .site_packages()
-            .join("markupsafe")
-            .join("__pycache__")
-            .join("__init__.cpython-312.pyc")
-            .exists()
-    );
-
-    context.assert_command("import markupsafe").success();
+    context
+        .assert_command("import source_distribution")
+        .success();
 
     Ok(())
 }
 
-/// Re-install with bytecode compilation.
+/// Check that we show the right messages on cached, local source distribution installs.
 #[test]
-fn recompile() -> Result<()> {
+fn install_path_built_dist_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Download a wheel.
+    let archive = context.temp_dir.child("tomli-2.0.1-py3-none-any.whl");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/97/75/10a9ebee3fd790d20926a90a2547f0bf78f371b2f13aa822c759680ca7b9/tomli-2.0.1-py3-none-any.whl",
+        &archive,
+    );
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+    let url = Url::from_file_path(archive.path()).unwrap();
+    requirements_txt.write_str(&format!("tomli @ {url}"))?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: true
@@ -3319,154 +3964,146 @@ fn recompile() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + markupsafe==2.1.3
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    uv_snapshot!(context.pip_sync()
+    context.assert_command("import tomli").success();
+
+    // Re-run the installation in a new virtual environment.
+    context.reset_venv();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--compile")
-        .arg("--strict"), @r###"
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Bytecode compiled 3 files in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
-    assert!(
-        context
-            .site_packages()
-            .join("markupsafe")
-            .join("__pycache__")
-            .join("__init__.cpython-312.pyc")
-            .exists()
-    );
-
-    context.assert_command("import markupsafe").success();
-
-    Ok(())
-}
+    context.assert_command("import tomli").success();
 
-/// Raise an error when an editable's `Requires-Python` constraint is not met.
-#[test]
-fn requires_python_editable() -> Result<()> {
-    let context = TestContext::new("3.12");
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
 
-    // Create an editable package with a `Requires-Python` constraint that is not met.
-    let editable_dir = context.temp_dir.child("editable");
-    editable_dir.create_dir_all()?;
-    let pyproject_toml = editable_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"[project]
-name = "example"
-version = "0.0.0"
-dependencies = [
-  "anyio==4.0.0"
-]
-requires-python = ">=3.13"
-"#,
-    )?;
+    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(
+        filters,
+        context.clean().arg("tomli"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    // Write to a requirements file.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(&format!("-e {}", editable_dir.path().display()))?;
+    ----- stderr -----
+    Removed [N] files ([SIZE])
+    "###
+    );
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
-    success: false
-    exit_code: 1
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because the current Python version (3.12.[X]) does not satisfy Python>=3.13 and example==0.0.0 depends on Python>=3.13, we can conclude that example==0.0.0 cannot be used.
-          And because only example==0.0.0 is available and you require example, we can conclude that your requirements are unsatisfiable.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1 (from file://[TEMP_DIR]/tomli-2.0.1-py3-none-any.whl)
     "###
     );
 
+    context.assert_command("import tomli").success();
+
     Ok(())
 }
 
-/// Raise an error when a direct URL dependency's `Requires-Python` constraint is not met.
+/// Check that we show the right messages on cached, direct URL built distribution installs.
 #[test]
-fn requires_python_direct_url() -> Result<()> {
+fn install_url_built_dist_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Create an editable package with a `Requires-Python` constraint that is not met.
-    let editable_dir = context.temp_dir.child("editable");
-    editable_dir.create_dir_all()?;
-    let pyproject_toml = editable_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"[project]
-name = "example"
-version = "0.0.0"
-dependencies = [
-  "anyio==4.0.0"
-]
-requires-python = ">=3.13"
-"#,
-    )?;
-
-    // Write to a requirements file.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(&format!("example @ {}", editable_dir.path().display()))?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tqdm @ https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl")?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
-    success: false
-    exit_code: 1
+    let context_filters = if cfg!(windows) {
+        [("warning: The package `tqdm` requires `colorama ; sys_platform == 'win32'`, but it's not installed\n", "")]
+            .into_iter()
+            .chain(context.filters())
+            .collect()
+    } else {
+        context.filters()
+    };
+    uv_snapshot!(context_filters, context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because the current Python version (3.12.[X]) does not satisfy Python>=3.13 and example==0.0.0 depends on Python>=3.13, we can conclude that example==0.0.0 cannot be used.
-          And because only example==0.0.0 is available and you require example, we can conclude that your requirements are unsatisfiable.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
     "###
     );
 
-    Ok(())
-}
-
-/// Use an unknown hash algorithm with `--require-hashes`.
-#[test]
-fn require_hashes_unknown_algorithm() -> Result<()> {
-    let context = TestContext::new("3.12");
+    context.assert_command("import tqdm").success();
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "anyio==4.0.0 --hash=foo:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-    )?;
+    // Re-run the installation in a new virtual environment.
+    context.reset_venv();
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context_filters, context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r"
-    success: false
-    exit_code: 2
+        .arg("--strict")
+        , @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Unsupported hash algorithm (expected one of: `md5`, `sha256`, `sha384`, `sha512`, or `blake2b`) on: `foo`
-    "
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
+    "###
     );
 
-    Ok(())
-}
+    context.assert_command("import tqdm").success();
 
-/// Omit the hash with `--require-hashes`.
-#[test]
-fn require_hashes_missing_hash() -> Result<()> {
-    let context = TestContext::new("3.12");
+    // Clear the cache, then re-run the installation in a new virtual environment.
+    context.reset_venv();
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==4.0.0")?;
+    let filters = std::iter::once(("Removed \\d+ files?", "Removed [N] files"))
+        .chain(context_filters.clone())
+        .collect::<Vec<_>>();
+    uv_snapshot!(
+        filters,
+        context.clean().arg("tqdm"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    // Install without error when `--require-hashes` is omitted.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+    ----- stderr -----
+    Removed [N] files ([SIZE])
+    "###
+    );
+
+    uv_snapshot!(context_filters, context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3475,39 +4112,50 @@ fn require_hashes_missing_hash() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
+     + tqdm==4.66.1 (from https://files.pythonhosted.org/packages/00/e5/f12a80907d0884e6dff9c16d0c0114d81b8cd07dc3ae54c5e962cc83037e/tqdm-4.66.1-py3-none-any.whl)
     "###
     );
 
-    // Error when `--require-hashes` is provided.
+    context.assert_command("import tqdm").success();
+
+    Ok(())
+}
+
+/// Verify that fail with an appropriate error when a package is repeated.
+#[test]
+fn duplicate_package_overlap() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\nMarkupSafe==2.1.2")?;
+
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--strict"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require markupsafe==2.1.3 and markupsafe==2.1.2, we can conclude that your requirements are unsatisfiable.
     "###
     );
 
     Ok(())
 }
 
-/// Omit the version with `--require-hashes`.
+/// Verify that allow duplicate packages when they are disjoint.
 #[test]
-fn require_hashes_missing_version() -> Result<()> {
+fn duplicate_package_disjoint() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-    )?;
+    requirements_txt.write_str("MarkupSafe==2.1.3\nMarkupSafe==2.1.2 ; python_version < '3.6'")?;
 
-    // Install without error when `--require-hashes` is omitted.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3516,115 +4164,129 @@ fn require_hashes_missing_version() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.3.0
-    "###
-    );
-
-    // Error when `--require-hashes` is provided.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
-    ----- stdout -----
-
-    ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+     + markupsafe==2.1.3
     "###
     );
 
     Ok(())
 }
 
-/// Use a non-`==` operator with `--require-hashes`.
+/// Verify that we can force reinstall of packages.
 #[test]
-fn require_hashes_invalid_operator() -> Result<()> {
+fn reinstall() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "anyio>4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-    )?;
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
 
-    // Install without error when `--require-hashes` is omitted.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + anyio==4.3.0
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
-    // Error when `--require-hashes` is provided.
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    // Re-run the installation with `--reinstall`.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
+        .arg("--reinstall")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio>4.0.0
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     ~ markupsafe==2.1.3
+     ~ tomli==2.0.1
     "###
     );
 
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
     Ok(())
 }
 
-/// Include the hash for _just_ the wheel with `--no-binary`.
+/// Verify that we can force reinstall of selective packages.
 #[test]
-fn require_hashes_wheel_no_binary() -> Result<()> {
+fn reinstall_package() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--no-binary")
-        .arg(":all:")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download and build `anyio==4.0.0`
-      ╰─▶ Hash mismatch for `anyio==4.0.0`
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
 
-          Expected:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
 
-          Computed:
-            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+    // Re-run the installation with `--reinstall`.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall-package")
+        .arg("tomli")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tomli==2.0.1
     "###
     );
 
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
     Ok(())
 }
 
-/// Include the hash for _just_ the wheel with `--only-binary`.
+/// Verify that we can force reinstall of Git dependencies.
 #[test]
-fn require_hashes_wheel_only_binary() -> Result<()> {
+#[cfg(feature = "git")]
+fn reinstall_git() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    requirements_txt.write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--only-binary")
-        .arg(":all:")
-        .arg("--require-hashes"), @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3633,27 +4295,20 @@ fn require_hashes_wheel_only_binary() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
     "###
     );
 
-    Ok(())
-}
-
-/// Include the hash for _just_ the source distribution with `--no-binary`.
-#[test]
-fn require_hashes_source_no_binary() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("source-distribution==0.0.1 --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
 
+    // Re-run the installation with `--reinstall`.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--no-binary")
-        .arg(":all:")
-        .arg("--require-hashes"), @r###"
+        .arg("--reinstall-package")
+        .arg("uv-public-pypackage")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3661,291 +4316,299 @@ fn require_hashes_source_no_binary() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + source-distribution==0.0.1
+     ~ uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
     "###
     );
 
+    context
+        .assert_command("import uv_public_pypackage")
+        .success();
+
     Ok(())
 }
 
-/// Include the hash for _just_ the source distribution, with `--binary-only`.
+/// Verify that we can force refresh of cached data.
 #[test]
-fn require_hashes_source_only_binary() -> Result<()> {
+fn refresh() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--only-binary")
-        .arg(":all:")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio==4.0.0`
-      ╰─▶ Hash mismatch for `anyio==4.0.0`
-
-          Expected:
-            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
-
-          Computed:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
-    Ok(())
-}
-
-/// Include the correct hash algorithm, but the wrong digest.
-#[test]
-fn require_hashes_wrong_digest() -> Result<()> {
-    let context = TestContext::new("3.12");
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    // Re-run the installation into with `--refresh`. Ensure that we resolve and download the
+    // latest versions of the packages.
+    context.reset_venv();
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--refresh")
+        .arg("--strict")
+        , @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio==4.0.0`
-      ╰─▶ Hash mismatch for `anyio==4.0.0`
-
-          Expected:
-            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-
-          Computed:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
-    Ok(())
-}
-
-/// Include the correct hash, but the wrong algorithm.
-#[test]
-fn require_hashes_wrong_algorithm() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha512:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio==4.0.0`
-      ╰─▶ Hash mismatch for `anyio==4.0.0`
-
-          Expected:
-            sha512:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-
-          Computed:
-            sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2
-    "###
-    );
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
 
     Ok(())
 }
 
-/// Include the hash for a source distribution specified as a direct URL dependency.
+/// Verify that we can force refresh of selective packages.
 #[test]
-fn require_hashes_source_url() -> Result<()> {
+fn refresh_package() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
-    // Reinstall with the right hash, and verify that it's reused.
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    // Re-run the installation into with `--refresh`. Ensure that we resolve and download the
+    // latest versions of the packages.
+    context.reset_venv();
+
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
+        .arg("--refresh-package")
+        .arg("tomli")
+        .arg("--strict")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     ~ source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
     "###
     );
 
-    // Reinstall with the wrong hash, and verify that it's rejected despite being cached.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-      × Failed to download and build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
-      ╰─▶ Hash mismatch for `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
-
-          Expected:
-            sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
-
-          Computed:
-            sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
-    "###
-    );
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
 
     Ok(())
 }
 
-/// Include the _wrong_ hash for a source distribution specified as a direct URL dependency.
 #[test]
-fn require_hashes_source_url_mismatch() -> Result<()> {
+fn sync_editable() -> Result<()> {
     let context = TestContext::new("3.12");
+    let poetry_editable = context.temp_dir.child("poetry_editable");
+
+    // Copy into the temporary directory so we can mutate it.
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable"),
+        &poetry_editable,
+    )?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        anyio==3.7.0
+        -e file://{poetry_editable}
+        ",
+        poetry_editable = poetry_editable.display()
+    })?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+    // Install the editable package.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to download and build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
-      ╰─▶ Hash mismatch for `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
-
-          Expected:
-            sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
-
-          Computed:
-            sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==3.7.0
+     + poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
     "###
     );
 
-    Ok(())
-}
-
-/// Include the hash for a built distribution specified as a direct URL dependency.
-#[test]
-fn require_hashes_wheel_url() -> Result<()> {
-    let context = TestContext::new("3.12");
+    // Re-install the editable package. This is a no-op.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "###
+    );
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+    // Reinstall the editable package. This won't trigger a rebuild, but it will trigger an install.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--reinstall-package")
+        .arg("poetry-editable"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+     ~ poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
     "###
     );
 
-    // Reinstall with the right hash, and verify that it's reused.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
+    // Reinstall all editable packages. This will trigger a rebuild and reinstall of the editable
+    // package, but leave `anyio` untouched.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--reinstall-editables"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+     ~ poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
     "###
     );
 
-    // Reinstall with the wrong hash, and verify that it's rejected despite being cached.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    let python_source_file = poetry_editable.path().join("poetry_editable/__init__.py");
+    let check_installed = indoc::indoc! {r#"
+        from poetry_editable import a
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
+        assert a() == "a", a()
+   "#};
+    context.assert_command(check_installed).success();
+
+    // Edit the sources and make sure the changes are respected without syncing again.
+    let python_version_1 = indoc::indoc! {r"
+        version = 1
+   "};
+    fs_err::write(&python_source_file, python_version_1)?;
+
+    let check_installed = indoc::indoc! {r"
+        from poetry_editable import version
+
+        assert version == 1, version
+   "};
+    context.assert_command(check_installed).success();
+
+    let python_version_2 = indoc::indoc! {r"
+        version = 2
+   "};
+    fs_err::write(&python_source_file, python_version_2)?;
+
+    let check_installed = indoc::indoc! {r"
+        from poetry_editable import version
+
+        assert version == 2, version
+   "};
+    context.assert_command(check_installed).success();
+
+    // Reinstall the editable package. This won't trigger a rebuild or reinstall, since we only
+    // detect changes to metadata files (like `pyproject.toml`).
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
-      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "###
+    );
 
-          Expected:
-            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    // Modify the `pyproject.toml` file.
+    let pyproject_toml = poetry_editable.path().join("pyproject.toml");
+    let pyproject_toml_contents = fs_err::read_to_string(&pyproject_toml)?;
+    fs_err::write(
+        &pyproject_toml,
+        pyproject_toml_contents.replace("0.1.0", "0.1.1"),
+    )?;
 
-          Computed:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    // Reinstall the editable package. This will trigger a rebuild and reinstall.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+     + poetry-editable==0.1.1 (from file://[TEMP_DIR]/poetry_editable)
     "###
     );
 
-    // Sync a new dependency and include the wrong hash for anyio. Verify that we reuse anyio
-    // despite the wrong hash, like pip, since we don't validate hashes for already-installed
-    // distributions.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f\niniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+    // Modify the `pyproject.toml` file.
+    let pyproject_toml = poetry_editable.path().join("pyproject.toml");
+    let pyproject_toml_contents = fs_err::read_to_string(&pyproject_toml)?;
+    fs_err::write(
+        &pyproject_toml,
+        pyproject_toml_contents.replace("0.1.0", "0.1.1"),
+    )?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+    // Reinstall the editable package. This will trigger a rebuild and reinstall.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3953,113 +4616,226 @@ fn require_hashes_wheel_url() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0
+     ~ poetry-editable==0.1.1 (from file://[TEMP_DIR]/poetry_editable)
     "###
     );
 
     Ok(())
 }
 
-/// Include the _wrong_ hash for a built distribution specified as a direct URL dependency.
+/// Reinstall any packages that were installed by another tool with `--reinstall-foreign`.
 #[test]
-fn require_hashes_wheel_url_mismatch() -> Result<()> {
+fn reinstall_foreign() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        sniffio==1.3.1
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .assert()
+        .success();
+
+    // Mark `iniconfig` as having been installed by `pip`.
+    let installer = context
+        .site_packages()
+        .join("iniconfig-2.0.0.dist-info")
+        .join("INSTALLER");
+    fs::write(&installer, "pip\n")?;
+
+    // Only `iniconfig` should be reinstalled.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall-foreign"), @r"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
-      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "
+    );
 
-          Expected:
-            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    assert_eq!(fs::read_to_string(&installer)?.trim(), "uv");
 
-          Computed:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-    "###
+    // Once every package is managed by uv, this is a no-op.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall-foreign"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "
     );
 
     Ok(())
 }
 
-/// Reject Git dependencies when `--require-hashes` is provided.
+/// Install an editable requirement as a regular build with `--no-editable`.
 #[test]
-#[cfg(feature = "git")]
-fn require_hashes_git() -> Result<()> {
+fn sync_no_editable() -> Result<()> {
     let context = TestContext::new("3.12");
+    let poetry_editable = context.temp_dir.child("poetry_editable");
+
+    // Copy into the temporary directory so we can mutate it.
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable"),
+        &poetry_editable,
+    )?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{poetry_editable}
+        ",
+        poetry_editable = poetry_editable.display()
+    })?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--no-editable"), @r"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to download and build `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
-      ╰─▶ Hash-checking is not supported for Git repositories: `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
-    "###
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + poetry-editable==0.1.0 (from file://[TEMP_DIR]/poetry_editable)
+    "
     );
 
+    let check_installed = indoc::indoc! {r#"
+        from poetry_editable import a
+
+        assert a() == "a", a()
+   "#};
+    context.assert_command(check_installed).success();
+
+    // Edit the sources; since the package isn't editable, the changes should have no effect.
+    let python_source_file = poetry_editable.path().join("poetry_editable/__init__.py");
+    fs_err::write(&python_source_file, "version = 1\n")?;
+
+    let check_installed = indoc::indoc! {r#"
+        import poetry_editable
+
+        assert not hasattr(poetry_editable, "version")
+        assert poetry_editable.a() == "a", poetry_editable.a()
+   "#};
+    context.assert_command(check_installed).success();
+
     Ok(())
 }
 
-/// Reject local directory dependencies when `--require-hashes` is provided.
+/// An editable installed via a `.pth` file should be recognized as present on a subsequent sync,
+/// and unrelated `.pth` path insertions should be left alone.
 #[test]
-fn require_hashes_source_tree() -> Result<()> {
+fn sync_editable_pth() -> Result<()> {
     let context = TestContext::new("3.12");
+    let poetry_editable = context.temp_dir.child("poetry_editable");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "black @ {} --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a",
+    copy_dir_all(
         context
             .workspace_root
-            .join("scripts/packages/black_editable")
-            .display()
-    ))?;
+            .join("scripts/packages/poetry_editable"),
+        &poetry_editable,
+    )?;
+
+    // Install the editable package with `pip install`, rather than `pip sync`.
+    context
+        .pip_install()
+        .arg("--no-deps")
+        .arg("-e")
+        .arg(poetry_editable.path())
+        .assert()
+        .success();
+
+    // The package should be importable via a `.pth` file.
+    let site_packages = context.site_packages();
+    let pth = fs::read_dir(&site_packages)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "pth"))
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name != "_virtualenv.pth")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pth.len(),
+        1,
+        "expected a single editable `.pth` file: {pth:?}"
+    );
+
+    // Add an unrelated path insertion, which doesn't correspond to a distribution.
+    let extra = context.temp_dir.child("extra");
+    extra.create_dir_all()?;
+    let custom_pth = site_packages.join("custom.pth");
+    fs::write(&custom_pth, format!("{}\n", extra.path().display()))?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{poetry_editable}
+        ",
+        poetry_editable = poetry_editable.display()
+    })?;
 
+    // The editable should be recognized as present, so the sync is a no-op.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to build `black @ file://[WORKSPACE]/scripts/packages/black_editable`
-      ╰─▶ Hash-checking is not supported for local directories: `black @ file://[WORKSPACE]/scripts/packages/black_editable`
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
     "###
     );
 
+    assert!(custom_pth.exists());
+
     Ok(())
 }
 
-/// Include the hash for _just_ the wheel with `--only-binary`.
 #[test]
-fn require_hashes_re_download() -> Result<()> {
+fn sync_editable_and_registry() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Copy the black test editable into the "current" directory
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/black_editable"),
+        context.temp_dir.join("black_editable"),
+    )?;
+
+    // Install the registry-based version of Black.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==4.0.0")?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        black==24.1.0
+        "
+    })?;
 
-    // Install without `--require-hashes`.
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4068,45 +4844,69 @@ fn require_hashes_re_download() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
+     + black==24.1.0
+    warning: The package `black` requires `click>=8.0.0`, but it's not installed
+    warning: The package `black` requires `mypy-extensions>=0.4.3`, but it's not installed
+    warning: The package `black` requires `packaging>=22.0`, but it's not installed
+    warning: The package `black` requires `pathspec>=0.9.0`, but it's not installed
+    warning: The package `black` requires `platformdirs>=2`, but it's not installed
     "###
     );
 
-    // Reinstall with `--require-hashes`, and the wrong hash.
+    // Install the editable version of Black. This should remove the registry-based version.
+    // Use the `file:` syntax for extra coverage.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        -e file:./black_editable
+        "
+    })?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to download `anyio==4.0.0`
-      ╰─▶ Hash mismatch for `anyio==4.0.0`
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - black==24.1.0
+     + black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    "###
+    );
 
-          Expected:
-            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    // Re-install the registry-based version of Black. This should be a no-op, since we have a
+    // version of Black installed (the editable version) that satisfies the requirements.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        black
+        "
+    })?;
 
-          Computed:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
     "###
     );
 
-    // Reinstall with `--require-hashes`, and the right hash.
+    // Re-install Black at a specific version. This should replace the editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        black==23.10.0
+        "
+    })?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4116,30 +4916,31 @@ fn require_hashes_re_download() -> Result<()> {
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0
+     - black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+     + black==23.10.0
+    warning: The package `black` requires `click>=8.0.0`, but it's not installed
+    warning: The package `black` requires `mypy-extensions>=0.4.3`, but it's not installed
+    warning: The package `black` requires `packaging>=22.0`, but it's not installed
+    warning: The package `black` requires `pathspec>=0.9.0`, but it's not installed
+    warning: The package `black` requires `platformdirs>=2`, but it's not installed
     "###
     );
 
     Ok(())
 }
 
-/// Include the hash for a built distribution specified as a local path dependency.
+/// Silence warnings for missing dependencies with `--no-warn-conflicts`.
 #[test]
-fn require_hashes_wheel_path() -> Result<()> {
+fn strict_no_warn_conflicts() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tqdm @ {} --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
-        context
-            .workspace_root
-            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl")
-            .display()
-    ))?;
+    requirements_txt.write_str("black==24.1.0")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg(requirements_txt.path())
+        .arg("--strict")
+        .arg("--no-warn-conflicts"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4148,132 +4949,130 @@ fn require_hashes_wheel_path() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl)
+     + black==24.1.0
     "###
     );
 
     Ok(())
 }
 
-/// Include the _wrong_ hash for a built distribution specified as a local path dependency.
 #[test]
-fn require_hashes_wheel_path_mismatch() -> Result<()> {
+fn sync_editable_and_local() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tqdm @ {} --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    // Copy the black test editable into the "current" directory
+    copy_dir_all(
         context
             .workspace_root
-            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl")
-            .display()
-    ))?;
+            .join("scripts/packages/black_editable"),
+        context.temp_dir.join("black_editable"),
+    )?;
+
+    // Install the editable version of Black.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        -e file:./black_editable
+        "
+    })?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to read `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl`
-      ╰─▶ Hash mismatch for `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl`
-
-          Expected:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-
-          Computed:
-            sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + black==0.1.0 (from file://[TEMP_DIR]/black_editable)
     "###
     );
 
-    Ok(())
-}
+    // Install the non-editable version of Black. This should replace the editable version.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        black @ file:./black_editable
+        "
+    })?;
 
-/// Include the hash for a source distribution specified as a local path dependency.
-#[test]
-fn require_hashes_source_path() -> Result<()> {
-    let context = TestContext::new("3.12");
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ black==0.1.0 (from file://[TEMP_DIR]/black_editable)
+    "###
+    );
 
+    // Reinstall the editable version of Black. This should replace the non-editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tqdm @ {} --hash=sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b",
-        context
-            .workspace_root
-            .join("scripts/links/tqdm-999.0.0.tar.gz")
-            .display()
-    ))?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        -e file:./black_editable
+        "
+    })?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg(requirements_txt.path()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tqdm==999.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz)
+     ~ black==0.1.0 (from file://[TEMP_DIR]/black_editable)
     "###
     );
 
     Ok(())
 }
 
-/// Include the _wrong_ hash for a source distribution specified as a local path dependency.
 #[test]
-fn require_hashes_source_path_mismatch() -> Result<()> {
+fn incompatible_wheel() -> Result<()> {
     let context = TestContext::new("3.12");
+    let wheel = context.temp_dir.child("foo-1.2.3-py3-none-any.whl");
+    wheel.touch()?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&format!(
-        "tqdm @ {} --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-        context
-            .workspace_root
-            .join("scripts/links/tqdm-999.0.0.tar.gz")
-            .display()
-    ))?;
+    requirements_txt.write_str(&format!("foo @ {}", wheel.path().simplified_display()))?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--strict"), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to build `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz`
-      ╰─▶ Hash mismatch for `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz`
-
-          Expected:
-            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+      × No solution found when resolving dependencies:
+      ╰─▶ Because foo has an invalid package format and you require foo, we can conclude that your requirements are unsatisfiable.
 
-          Computed:
-            sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b
+          hint: The structure of `foo` was invalid:
+            Failed to read from zip file
     "###
     );
 
     Ok(())
 }
 
-/// We allow `--require-hashes` for direct URL dependencies.
+/// Install a project without a `pyproject.toml`, using the PEP 517 build backend.
 #[test]
-fn require_hashes_unnamed() -> Result<()> {
+fn sync_legacy_sdist_pep_517() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc::indoc! {r"
-            https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-        "} )?;
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flake8 @ https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz")?;
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4282,122 +5081,149 @@ fn require_hashes_unnamed() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+     + flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
     "###
     );
 
     Ok(())
 }
 
-/// We disallow `--require-hashes` for editables.
+/// Sync using `--find-links` with a local directory.
 #[test]
-fn require_hashes_editable() -> Result<()> {
+fn find_links() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&indoc::formatdoc! {r"
-        -e file://{workspace_root}/scripts/packages/black_editable[d]
-        ",
-        workspace_root = context.workspace_root.simplified_display(),
-    })?;
+    requirements_txt.write_str(indoc! {r"
+        markupsafe==2.1.3
+        numpy==1.26.3
+        tqdm==1000.0.0
+        werkzeug @ https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl
+    "})?;
 
-    // Install the editable packages.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg(requirements_txt.path())
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
+        .arg("requirements.txt")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/scripts/packages/black_editable[d]
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + markupsafe==2.1.3
+     + numpy==1.26.3
+     + tqdm==1000.0.0
+     + werkzeug==3.0.1 (from https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl)
     "###
     );
 
     Ok(())
 }
 
-/// If a dependency is repeated, the hash should be required for both instances.
+/// Sync using `--find-links` with `--no-index`, which should accept the local wheel.
 #[test]
-fn require_hashes_repeated_dependency() -> Result<()> {
+fn find_links_no_index_match() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a\nanyio")?;
+    requirements_txt.write_str(indoc! {r"
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
-    ----- stdout -----
-
-    ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
-    "###
-    );
-
-    // Reverse the order.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio\nanyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
     "###
     );
 
     Ok(())
 }
 
-/// If a dependency is repeated, use the last hash provided. pip seems to use the _first_ hash.
+/// Sync using `--find-links` with a `.tar` archive of wheels.
 #[test]
-fn require_hashes_repeated_hash() -> Result<()> {
+fn find_links_archive() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Use the same hash in both cases.
+    // Package a couple of wheels into a single archive, under a top-level directory.
+    let wheelhouse = context.temp_dir.child("wheelhouse.tar");
+    {
+        let mut tar = tar::Builder::new(fs::File::create(wheelhouse.path())?);
+        for filename in [
+            "ok-1.0.0-py3-none-any.whl",
+            "tqdm-1000.0.0-py3-none-any.whl",
+        ] {
+            tar.append_path_with_name(
+                context.workspace_root.join("scripts/links").join(filename),
+                Path::new("wheelhouse").join(filename),
+            )?;
+        }
+        tar.finish()?;
+    }
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc::indoc! { r"
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-    " })?;
+    requirements_txt.write_str(indoc! {r"
+        ok==1.0.0
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("wheelhouse.tar"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
-    "###
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + ok==1.0.0
+     + tqdm==1000.0.0
+    "
     );
 
-    // Use a different hash, but both are correct.
+    Ok(())
+}
+
+/// Sync using a `--find-links` entry in the requirements file, relative to the working directory.
+#[test]
+fn find_links_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        links.child("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc::indoc! { r"
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2
-    " })?;
+    requirements_txt.write_str(indoc! {r"
+        --find-links ./links
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--reinstall"), @r###"
+        .arg("--no-index"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4405,24 +5231,29 @@ fn require_hashes_repeated_hash() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+     + tqdm==1000.0.0
     "###
     );
 
-    // Use a different hash. The first hash is wrong, but that's fine, since we use the last hash.
+    Ok(())
+}
+
+/// Sync using `--find-links` with `--offline`, which should accept the local wheel.
+#[test]
+fn find_links_offline_match() -> Result<()> {
+    let context = TestContext::new("3.12");
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc::indoc! { r"
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=md5:420d85e19168705cdf0223621b18831a
-    " })?;
+    requirements_txt.write_str(indoc! {r"
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--reinstall"), @r###"
+        .arg("--offline")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4430,57 +5261,60 @@ fn require_hashes_repeated_hash() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+     + tqdm==1000.0.0
     "###
     );
 
-    // Use a different hash. The second hash is wrong. This should fail, since we use the last hash.
+    Ok(())
+}
+
+/// Sync using `--find-links` with `--offline`, which should fail to find `numpy`.
+#[test]
+fn find_links_offline_no_match() -> Result<()> {
+    let context = TestContext::new("3.12");
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc::indoc! { r"
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
-            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=md5:520d85e19168705cdf0223621b18831a
-    " })?;
+    requirements_txt.write_str(indoc! {r"
+        numpy
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--reinstall"), @r###"
+        .arg("--offline")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
-      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
-
-          Expected:
-            md5:520d85e19168705cdf0223621b18831a
+      × No solution found when resolving dependencies:
+      ╰─▶ Because numpy was not found in the cache and you require numpy, we can conclude that your requirements are unsatisfiable.
 
-          Computed:
-            md5:420d85e19168705cdf0223621b18831a
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
     "###
     );
 
     Ok(())
 }
 
-/// If a dependency is repeated, the hash should be required for both instances.
+/// Sync using `--find-links` with a local directory. Ensure that cached wheels are reused.
 #[test]
-fn require_hashes_at_least_one() -> Result<()> {
+fn find_links_wheel_cache() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Request `anyio` with a `sha256` hash.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+    requirements_txt.write_str(indoc! {r"
+        tqdm==1000.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    // Install `tqdm`.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4489,20 +5323,16 @@ fn require_hashes_at_least_one() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.0.0
+     + tqdm==1000.0.0
     "###
     );
 
-    // Reinstall, requesting both `sha256` and `sha512`. We should reinstall from the cache, since
-    // at least one hash matches.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a --hash=md5:420d85e19168705cdf0223621b18831a")?;
-
-    uv_snapshot!(context.pip_sync()
+    // Reinstall `tqdm` with `--reinstall`. Ensure that the wheel is reused.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
         .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4512,19 +5342,29 @@ fn require_hashes_at_least_one() -> Result<()> {
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0
+     ~ tqdm==1000.0.0
     "###
     );
 
-    // This should be true even if the second hash is wrong.
+    Ok(())
+}
+
+/// Sync using `--find-links` with a local directory. Ensure that cached source distributions are
+/// reused.
+#[test]
+fn find_links_source_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a --hash=md5:1234")?;
+    requirements_txt.write_str(indoc! {r"
+        tqdm==999.0.0
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
+    // Install `tqdm`.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes"), @r###"
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4532,31 +5372,17 @@ fn require_hashes_at_least_one() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ anyio==4.0.0
+     + tqdm==999.0.0
     "###
     );
 
-    Ok(())
-}
-
-/// Using `--find-links`, but the registry doesn't provide us with a hash.
-#[test]
-fn require_hashes_find_links_no_hash() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // First, use the correct hash.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
-
-    uv_snapshot!(context.pip_sync()
+    // Reinstall `tqdm` with `--reinstall`. Ensure that the wheel is reused.
+    uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.txt")
         .arg("--reinstall")
-        .arg("--require-hashes")
         .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4564,110 +5390,88 @@ fn require_hashes_find_links_no_hash() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example-a-961b4c22==1.0.0
+     ~ tqdm==999.0.0
     "###
     );
 
-    // Second, use an incorrect hash.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
+    Ok(())
+}
+
+/// Install without network access via the `--offline` flag.
+#[test]
+fn offline() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
 
+    // Install with `--offline` with an empty cache.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("requirements.in")
+        .arg("--offline"), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:123
+      × No solution found when resolving dependencies:
+      ╰─▶ Because black was not found in the cache and you require black==23.10.1, we can conclude that your requirements are unsatisfiable.
 
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
     "###
     );
 
-    // Third, use the hash from the source distribution. This will actually fail, when it _could_
-    // succeed, but pip has the same behavior.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3")?;
-
+    // Populate the cache.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
-    success: false
-    exit_code: 1
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
-
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + black==23.10.1
     "###
     );
 
-    // Fourth, use the hash from the source distribution, and disable wheels. This should succeed.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3")?;
+    // Install with `--offline` with a populated cache.
+    context.reset_venv();
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--no-binary")
-        .arg(":all:")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("requirements.in")
+        .arg("--offline")
+        , @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ example-a-961b4c22==1.0.0
+     + black==23.10.1
     "###
     );
 
     Ok(())
 }
 
-/// Using `--find-links`, and the registry serves us a correct hash.
+/// Include a `constraints.txt` file with a compatible constraint.
 #[test]
-fn require_hashes_find_links_valid_hash() -> Result<()> {
+fn compatible_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
-
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+    requirements_txt.write_str("anyio==3.7.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio==3.7.0")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/valid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("--constraint")
+        .arg("constraints.txt"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4676,85 +5480,54 @@ fn require_hashes_find_links_valid_hash() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example-a-961b4c22==1.0.0
+     + anyio==3.7.0
     "###
     );
 
     Ok(())
 }
 
-/// Using `--find-links`, and the registry serves us an incorrect hash.
+/// Include a `constraints.txt` file with an incompatible constraint.
 #[test]
-fn require_hashes_find_links_invalid_hash() -> Result<()> {
+fn incompatible_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
-
-    // First, request some other hash.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:123
-
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
-    "###
-    );
+    requirements_txt.write_str("anyio==3.7.0")?;
 
-    // Second, request the invalid hash, that the registry _thinks_ is correct. We should reject it.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea")?;
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio==3.6.0")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("--constraint")
+        .arg("constraints.txt"), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea
-
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require anyio==3.7.0 and anyio==3.6.0, we can conclude that your requirements are unsatisfiable.
     "###
     );
 
-    // Third, request the correct hash, that the registry _thinks_ is correct. We should accept
-    // it, since it's already cached under this hash.
+    Ok(())
+}
+
+/// Include a `constraints.txt` file with an irrelevant constraint.
+#[test]
+fn irrelevant_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+    requirements_txt.write_str("anyio==3.7.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("black==23.10.1")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("--constraint")
+        .arg("constraints.txt"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4763,23 +5536,22 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example-a-961b4c22==1.0.0
+     + anyio==3.7.0
     "###
     );
 
-    // Fourth, request the correct hash, that the registry _thinks_ is correct, but without the
-    // cache. We _should_ accept it, but we currently don't.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+    Ok(())
+}
+
+/// Sync with a repeated `anyio` requirement.
+#[test]
+fn repeat_requirement_identical() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio\nanyio")?;
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--refresh")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4787,61 +5559,22 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ example-a-961b4c22==1.0.0
-    "###
-    );
-
-    // Finally, request the correct hash, along with the incorrect hash for the source distribution.
-    // Resolution will fail, since the incorrect hash matches the registry's hash.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e --hash=sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee")?;
-
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--refresh")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download and build `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
-            sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee
-
-          Computed:
-            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
-    "###
-    );
+     + anyio==4.3.0
+    "###);
 
     Ok(())
 }
 
-/// Using `--index-url`, but the registry doesn't provide us with a hash.
+/// Sync with a repeated `anyio` requirement, with compatible versions.
 #[test]
-fn require_hashes_registry_no_hash() -> Result<()> {
+fn repeat_requirement_compatible() -> Result<()> {
     let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio\nanyio==4.0.0")?;
 
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/no-hash/simple-html/"), @r###"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4850,202 +5583,127 @@ fn require_hashes_registry_no_hash() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example-a-961b4c22==1.0.0
-    "###
-    );
+     + anyio==4.0.0
+    "###);
 
     Ok(())
 }
 
-/// Using `--index-url`, and the registry serves us a correct hash.
+/// Sync with a repeated, but conflicting `anyio` requirement.
 #[test]
-fn require_hashes_registry_valid_hash() -> Result<()> {
+fn repeat_requirement_incompatible() -> Result<()> {
     let context = TestContext::new("3.12");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio<4.0.0\nanyio==4.0.0")?;
 
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--require-hashes")
-        .arg("--find-links")
-        .arg("https://astral-test.github.io/astral-test-hash/valid-hash/simple-html/"), @r###"
+        .arg("requirements.in"), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because example-a-961b4c22 was not found in the package registry and you require example-a-961b4c22==1.0.0, we can conclude that your requirements are unsatisfiable.
-    "###
-    );
+      ╰─▶ Because you require anyio<4.0.0 and anyio==4.0.0, we can conclude that your requirements are unsatisfiable.
+    "###);
 
     Ok(())
 }
 
-/// Using `--index-url`, and the registry serves us an incorrect hash.
+/// Sync with conflicting pins for `anyio`, under each `--on-conflict` policy.
 #[test]
-fn require_hashes_registry_invalid_hash() -> Result<()> {
+fn repeat_requirement_on_conflict() -> Result<()> {
     let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.0.0\nanyio==3.7.1")?;
 
-    // First, request some other hash.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
-
+    // By default, the conflicting pins fail the resolution.
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+        .arg("requirements.in")
+        .arg("--on-conflict")
+        .arg("error"), @r"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:123
-
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
-    "###
-    );
-
-    // Second, request the invalid hash, that the registry _thinks_ is correct. We should reject it.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea")?;
-
-    uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea
-
-          Computed:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
-    "###
-    );
-
-    // Third, request the correct hash, that the registry _thinks_ is correct. We should accept
-    // it, since it's already cached under this hash.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require anyio==4.0.0 and anyio==3.7.1, we can conclude that your requirements are unsatisfiable.
+    ");
 
+    // With `warn`, the last pin is used.
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+        .arg("requirements.in")
+        .arg("--on-conflict")
+        .arg("warn"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    warning: Found conflicting pins for `anyio` (`anyio==4.0.0`, `anyio==3.7.1`); using `anyio==3.7.1`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + example-a-961b4c22==1.0.0
-    "###
-    );
-
-    // Fourth, request the correct hash, that the registry _thinks_ is correct, but without the
-    // cache. We _should_ accept it, but we currently don't.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+     + anyio==3.7.1
+    ");
 
+    // With `newest`, the highest pin is used.
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--refresh")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+        .arg("requirements.in")
+        .arg("--on-conflict")
+        .arg("newest"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    warning: Found conflicting pins for `anyio` (`anyio==4.0.0`, `anyio==3.7.1`); using `anyio==4.0.0`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     ~ example-a-961b4c22==1.0.0
-    "###
-    );
+     - anyio==3.7.1
+     + anyio==4.0.0
+    ");
 
-    // Finally, request the correct hash, along with the incorrect hash for the source distribution.
-    // Resolution will fail, since the incorrect hash matches the registry's hash.
+    Ok(())
+}
+
+/// Don't preserve the mtime from .tar.gz files, it may be the unix epoch (1970-01-01), while Python's zip
+/// implementation can't handle files with an mtime older than 1980.
+/// See also <https://github.com/alexcrichton/tar-rs/issues/349>.
+#[test]
+fn tar_dont_preserve_mtime() -> Result<()> {
+    let context = TestContext::new("3.12");
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e --hash=sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee")?;
+    requirements_txt.write_str("tomli @ https://files.pythonhosted.org/packages/c0/3f/d7af728f075fb08564c5949a9c95e44352e23dee646869fa104a3b2060a3/tomli-2.0.1.tar.gz")?;
 
     uv_snapshot!(context.pip_sync()
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("requirements.txt")
-        .arg("--refresh")
-        .arg("--reinstall")
-        .arg("--require-hashes")
-        .arg("--index-url")
-        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
-    success: false
-    exit_code: 1
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to download and build `example-a-961b4c22==1.0.0`
-      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
-
-          Expected:
-            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
-            sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee
-
-          Computed:
-            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
-    "###
-    );
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1 (from https://files.pythonhosted.org/packages/c0/3f/d7af728f075fb08564c5949a9c95e44352e23dee646869fa104a3b2060a3/tomli-2.0.1.tar.gz)
+    "###);
 
     Ok(())
 }
 
-/// Include the hash in the URL directly.
+/// Avoid creating a file with 000 permissions
 #[test]
-fn require_hashes_url() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
-
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+fn set_read_permissions() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("databricks==0.2")?;
 
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5054,113 +5712,194 @@ fn require_hashes_url() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374)
-    "###
-    );
+     + databricks==0.2
+    "###);
 
     Ok(())
 }
 
-/// Include an irrelevant fragment in the URL.
+/// Test special case to generate versioned pip launchers.
+/// <https://github.com/pypa/pip/blob/3898741e29b7279e7bffe044ecfbe20f6a438b1e/src/pip/_internal/operations/install/wheel.py#L283>
+/// <https://github.com/astral-sh/uv/issues/1593>
 #[test]
-fn require_hashes_url_other_fragment() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn pip_entrypoints() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#foo=bar")?;
+    for pip_requirement in [
+        // Test compatibility with launchers in 24.0
+        // https://inspector.pypi.io/project/pip/24.0/packages/8a/6a/19e9fe04fca059ccf770861c7d5721ab4c2aebc539889e97c7977528a53b/pip-24.0-py3-none-any.whl/pip-24.0.dist-info/entry_points.txt
+        "pip==24.0",
+        // Test compatibility with launcher changes from https://github.com/pypa/pip/pull/12536 released in 24.1b1
+        // See https://github.com/astral-sh/uv/pull/1982
+        "pip==24.1b1",
+    ] {
+        let requirements_txt = context.temp_dir.child("requirements.txt");
+        requirements_txt.write_str(pip_requirement)?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 2
-    ----- stdout -----
+        context
+            .pip_sync()
+            .arg("requirements.txt")
+            .arg("--strict")
+            .output()
+            .expect("Failed to install pip");
 
-    ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#foo=bar
-    "###
-    );
+        let bin_dir = context.venv.join(if cfg!(unix) {
+            "bin"
+        } else if cfg!(windows) {
+            "Scripts"
+        } else {
+            unimplemented!("Only Windows and Unix are supported")
+        });
+        ChildPath::new(bin_dir.join(format!("pip3.10{EXE_SUFFIX}")))
+            .assert(predicates::path::missing());
+        ChildPath::new(bin_dir.join(format!("pip3.12{EXE_SUFFIX}")))
+            .assert(predicates::path::exists());
+    }
 
     Ok(())
 }
 
-/// Include an invalid hash in the URL directly.
+/// Console scripts synced into a relocatable virtual environment should continue to work after
+/// the environment is moved.
 #[test]
-fn require_hashes_url_invalid() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn sync_relocatable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Remake the venv as relocatable.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--relocatable")
+        .assert()
+        .success();
 
+    // Sync a package with a hello-world console script entrypoint.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+    requirements_txt.write_str(&format!(
+        "black @ {}",
+        Url::from_directory_path(
+            context
+                .workspace_root
+                .join("scripts/packages/black_editable")
+        )
+        .unwrap()
+    ))?;
 
-    uv_snapshot!(context.pip_sync()
+    context
+        .pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-    Resolved 1 package in [TIME]
-      × Failed to download `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
-      ╰─▶ Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
+        .assert()
+        .success();
 
-          Expected:
-            sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    // Relocate the venv, and see if the script still works.
+    let relocated = context.venv.with_file_name("relocated");
+    fs::rename(&context.venv, &relocated)?;
 
-          Computed:
-            sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
-    "###
-    );
+    let script_path = relocated.join(if cfg!(windows) {
+        format!(r"Scripts\black{EXE_SUFFIX}")
+    } else {
+        "bin/black".to_string()
+    });
+    std::process::Command::new(script_path.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Hello world!"));
 
     Ok(())
 }
 
-/// Ignore the (valid) hash on the fragment if (invalid) hashes are provided directly.
+/// Reinstalling a package should regenerate its entrypoint launchers, including the versioned
+/// `pip` launchers, even if they were removed or modified after installation.
 #[test]
-fn require_hashes_url_ignore() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn reinstall_entrypoints() -> Result<()> {
+    let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374 --hash sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+    requirements_txt.write_str("pip==24.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let bin_dir = context.venv.join(if cfg!(unix) {
+        "bin"
+    } else if cfg!(windows) {
+        "Scripts"
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    });
+    let pip = ChildPath::new(bin_dir.join(format!("pip{EXE_SUFFIX}")));
+    let pip3 = ChildPath::new(bin_dir.join(format!("pip3{EXE_SUFFIX}")));
+    let pip312 = ChildPath::new(bin_dir.join(format!("pip3.12{EXE_SUFFIX}")));
+
+    // Remove the launchers, and corrupt one of them.
+    fs::remove_file(&pip)?;
+    fs::remove_file(&pip312)?;
+    pip3.write_str("corrupted")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--reinstall-package")
+        .arg("pip"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to download `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
-      ╰─▶ Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
-
-          Expected:
-            sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
-
-          Computed:
-            sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ pip==24.0
     "###
     );
 
-    Ok(())
+    // The launchers should be restored.
+    pip.assert(predicates::path::exists());
+    pip312.assert(predicates::path::exists());
+    pip3.assert(predicates::str::contains("corrupted").not());
+
+    context
+        .python_command()
+        .arg("-m")
+        .arg("pip")
+        .arg("--version")
+        .assert()
+        .success();
+
+    Ok(())
 }
 
-/// Include the hash in the URL directly.
 #[test]
-fn require_hashes_url_unnamed() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn invalidate_on_change() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str("https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+    // Create an editable package.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.8"
+"#,
+    )?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+    // Write to a requirements file.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&format!("-e {}", editable_dir.path().display()))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5169,348 +5908,465 @@ fn require_hashes_url_unnamed() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374)
+     + example==0.0.0 (from file://[TEMP_DIR]/editable)
     "###
     );
 
-    Ok(())
-}
-
-/// Sync to a `--target` directory with a built distribution.
-#[test]
-fn target_built_distribution() -> Result<()> {
-    let context = TestContext::new("3.12")
-        .with_filtered_python_names()
-        .with_filtered_virtualenv_bin()
-        .with_filtered_exe_suffix();
-
-    // Install `iniconfig` to the target directory.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==2.0.0")?;
-
+    // Installing again should be a no-op.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--target")
-        .arg("target"), @r"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
-    ");
-
-    // Ensure that the package is present in the target directory.
-    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
-
-    // Ensure that we can't import the package.
-    context.assert_command("import iniconfig").failure();
-
-    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
-    context
-        .python_command()
-        .arg("-c")
-        .arg("import iniconfig")
-        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
-        .current_dir(&context.temp_dir)
-        .assert()
-        .success();
+    Audited 1 package in [TIME]
+    "###
+    );
 
-    // Upgrade it.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==1.1.1")?;
+    // Modify the editable package.
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==3.7.1"
+]
+requires-python = ">=3.8"
+"#,
+    )?;
 
+    // Re-installing should update the package.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--target")
-        .arg("target"), @r"
+        .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     - iniconfig==2.0.0
-     + iniconfig==1.1.1
-    ");
+     ~ example==0.0.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
 
-    // Remove it, and replace with `flask`, which includes a binary.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask")?;
+    Ok(())
+}
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--target")
-        .arg("target"), @r"
+/// Install with bytecode compilation.
+#[test]
+fn compile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + flask==3.0.2
-     - iniconfig==1.1.1
-    ");
-    // Ensure that the binary is present in the target directory.
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
     assert!(
         context
-            .temp_dir
-            .child("target")
-            .child("bin")
-            .child(format!("flask{EXE_SUFFIX}"))
-            .is_file()
+            .site_packages()
+            .join("markupsafe")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
     );
 
+    context.assert_command("import markupsafe").success();
+
     Ok(())
 }
 
-/// Sync to a `--target` directory with a package that requires building from source.
+/// Install and compile bytecode at a specific optimization level.
 #[test]
-fn target_source_distribution() -> Result<()> {
-    let context = TestContext::new("3.12")
-        .with_filtered_python_names()
-        .with_filtered_virtualenv_bin()
-        .with_filtered_exe_suffix();
+fn compile_optimize() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Install `iniconfig` to the target directory.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==2.0.0")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--no-binary")
-        .arg("iniconfig")
-        .arg("--target")
-        .arg("target"), @r"
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--optimize")
+        .arg("2")
+        .arg("--strict"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0
-    ");
-
-    // Ensure that the build requirements are not present in the target directory.
-    assert!(!context.temp_dir.child("target").child("hatchling").is_dir());
-
-    // Ensure that the package is present in the target directory.
-    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "
+    );
 
-    // Ensure that we can't import the package.
-    context.assert_command("import iniconfig").failure();
+    let pycache = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__");
+    assert!(pycache.join("__init__.cpython-312.opt-2.pyc").exists());
+    assert!(!pycache.join("__init__.cpython-312.pyc").exists());
 
-    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
+    // Multiple levels can be requested at once.
     context
-        .python_command()
-        .arg("-c")
-        .arg("import iniconfig")
-        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
-        .current_dir(&context.temp_dir)
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--compile")
+        .arg("--optimize")
+        .arg("0")
+        .arg("--optimize")
+        .arg("2")
         .assert()
         .success();
 
+    assert!(pycache.join("__init__.cpython-312.opt-2.pyc").exists());
+    assert!(pycache.join("__init__.cpython-312.pyc").exists());
+
+    context.assert_command("import markupsafe").success();
+
     Ok(())
 }
 
-/// Sync to a `--target` directory with a package that requires building from source, along with
-/// `--no-build-isolation`.
+/// Install with bytecode compilation limited to a specific package.
 #[test]
-fn target_no_build_isolation() -> Result<()> {
-    let context = TestContext::new("3.12")
-        .with_filtered_python_names()
-        .with_filtered_virtualenv_bin()
-        .with_filtered_exe_suffix();
+fn compile_package() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Install `hatchling` into the current environment.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flit_core")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        MarkupSafe==2.1.3
+        tomli==2.0.1
+    "})?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in"), @r###"
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile-package")
+        .arg("markupsafe")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + flit-core==3.9.0
-    "###);
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
 
-    // Install `iniconfig` to the target directory.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("wheel")?;
+    assert!(
+        context
+            .site_packages()
+            .join("markupsafe")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+    assert!(
+        !context
+            .site_packages()
+            .join("tomli")
+            .join("__pycache__")
+            .exists()
+    );
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--no-build-isolation")
-        .arg("--no-binary")
-        .arg("wheel")
-        .arg("--target")
-        .arg("target"), @r"
+    // Combined with `--compile`, the entire environment should be compiled.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--compile-package")
+        .arg("markupsafe")
+        .assert()
+        .success();
+
+    assert!(
+        context
+            .site_packages()
+            .join("tomli")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+
+    Ok(())
+}
+
+/// Files that fail to compile should be reported as warnings, unless `--compile-strict` is set.
+#[test]
+fn compile_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build a wheel that contains a module with a syntax error.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "broken"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("broken")
+        .child("__init__.py")
+        .touch()?;
+    project
+        .child("src")
+        .child("broken")
+        .child("invalid.py")
+        .write_str("def f(:\n")?;
+    context
+        .build()
+        .arg("--wheel")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("broken==0.1.0")?;
+
+    // By default, the failure is reported, but the remaining files are still compiled.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(project.child("dist").path())
+        .arg("--compile"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + wheel==0.43.0
-    ");
-
-    // Ensure that the build requirements are not present in the target directory.
-    assert!(!context.temp_dir.child("target").child("flit_core").is_dir());
+    Bytecode compiled 1 file in [TIME]
+    warning: Failed to bytecode-compile 1 file:
+      - [SITE_PACKAGES]/broken/invalid.py
+     + broken==0.1.0
+    "
+    );
 
-    // Ensure that the package is present in the target directory.
-    assert!(context.temp_dir.child("target").child("wheel").is_dir());
+    assert!(
+        context
+            .site_packages()
+            .join("broken")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
 
-    // Ensure that we can't import the package.
-    context.assert_command("import wheel").failure();
+    // With `--compile-strict`, the failure is an error.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(project.child("dist").path())
+        .arg("--compile")
+        .arg("--compile-strict")
+        .arg("--reinstall"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
-    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
-    context
-        .python_command()
-        .arg("-c")
-        .arg("import wheel")
-        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
-        .current_dir(&context.temp_dir)
-        .assert()
-        .success();
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+    error: Failed to bytecode-compile Python file in: [SITE_PACKAGES]/
+      Caused by: Failed to compile Python file: `[SITE_PACKAGES]/broken/invalid.py`
+    "
+    );
 
     Ok(())
 }
 
-/// Sync to a `--target` directory without a virtual environment.
+/// Abort before resolving if the interpreter doesn't satisfy `--verify-python-version`.
 #[test]
-fn target_system() -> Result<()> {
-    let context = TestContext::new_with_versions(&["3.12"]);
+fn verify_python_version() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Install `iniconfig` to the target directory.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==2.0.0")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--target")
-        .arg("target"), @r###"
+        .arg("requirements.txt")
+        .arg("--verify-python-version")
+        .arg(">=3.13"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The Python interpreter's version (3.12.[X]) does not satisfy `--verify-python-version >=3.13`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-python-version")
+        .arg(">=3.11, <3.13"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
      + iniconfig==2.0.0
-    "###);
-
-    // Ensure that the package is present in the target directory.
-    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+    "###
+    );
 
     Ok(())
 }
 
-/// Sync to a `--prefix` directory.
+/// Exclude a dependency from the environment with `--exclude-dependency`, even though another
+/// package requires it.
 #[test]
-fn prefix() -> Result<()> {
-    let context = TestContext::new("3.12")
-        .with_filtered_python_names()
-        .with_filtered_virtualenv_bin()
-        .with_filtered_exe_suffix();
+fn exclude_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Install `iniconfig` to the target directory.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==2.0.0")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
 
-    let prefix = context.temp_dir.child("prefix");
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--exclude-dependency")
+        .arg("sniffio")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--prefix")
-        .arg(prefix.path()), @r"
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+    warning: The package `anyio` requires `sniffio>=1.1`, but it's not installed
+    "###
+    );
+
+    // Syncing without the flag should install the excluded dependency.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
-    Resolved 1 package in [TIME]
+    Resolved 3 packages in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + iniconfig==2.0.0
-    ");
+     + sniffio==1.3.1
+    "###
+    );
 
-    // Ensure that we can't import the package.
-    context.assert_command("import iniconfig").failure();
+    Ok(())
+}
 
-    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
-    context
-        .python_command()
-        .arg("-c")
-        .arg("import iniconfig")
-        .env(
-            EnvVars::PYTHONPATH,
-            site_packages_path(&context.temp_dir.join("prefix"), "python3.12"),
-        )
-        .current_dir(&context.temp_dir)
-        .assert()
-        .success();
+/// A package that no other package depends on is a direct requirement, and survives
+/// `--exclude-dependency`.
+#[test]
+fn exclude_dependency_direct() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    // Upgrade it.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig==1.1.1")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        idna==3.6
+        iniconfig==2.0.0
+        sniffio==1.3.1
+    "})?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.in")
-        .arg("--prefix")
-        .arg(prefix.path()), @r"
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--exclude-dependency")
+        .arg("iniconfig")
+        .arg("--exclude-dependency")
+        .arg("sniffio"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     - iniconfig==2.0.0
-     + iniconfig==1.1.1
-    ");
+    Resolved 4 packages in [TIME]
+    warning: Not excluding `iniconfig`, since no other package depends on it
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + iniconfig==2.0.0
+    "
+    );
 
     Ok(())
 }
 
-/// Ensure that we install packages with markers on them.
+/// `--no-compile` should take precedence over `UV_COMPILE_BYTECODE`, which in turn should take
+/// precedence over the configuration file.
 #[test]
-fn preserve_markers() -> Result<()> {
+fn compile_precedence() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio ; python_version > '3.7'")?;
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r"
+        [pip]
+        compile-bytecode = false
+    "})?;
+
+    let bytecode = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc");
 
+    // The command-line flag takes precedence over the environment variable.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--no-compile")
+        .env(EnvVars::UV_COMPILE_BYTECODE, "1"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5519,82 +6375,4648 @@ fn preserve_markers() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + anyio==4.3.0
+     + markupsafe==2.1.3
     "###
     );
 
-    Ok(())
-}
-
-/// Include a `build_constraints.txt` file with an incompatible constraint.
-#[test]
-fn incompatible_build_constraint() -> Result<()> {
-    let context = TestContext::new("3.9");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("requests==1.2")?;
-
-    let constraints_txt = context.temp_dir.child("build_constraints.txt");
-    constraints_txt.write_str("setuptools==1")?;
+    assert!(!bytecode.exists());
 
+    // The environment variable takes precedence over the configuration file.
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--build-constraint")
-        .arg("build_constraints.txt"), @r###"
-    success: false
-    exit_code: 1
+        .arg("--config-file")
+        .arg("uv.toml")
+        .env(EnvVars::UV_COMPILE_BYTECODE, "1"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-      × Failed to download and build `requests==1.2.0`
-      ├─▶ Failed to resolve requirements from `setup.py` build
-      ├─▶ No solution found when resolving: `setuptools>=40.8.0`
-      ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+    Bytecode compiled 3 files in [TIME]
     "###
     );
 
+    assert!(bytecode.exists());
+
     Ok(())
 }
 
-/// Include a `build_constraints.txt` file with a compatible constraint.
+/// Re-install with bytecode compilation.
 #[test]
-fn compatible_build_constraint() -> Result<()> {
-    let context = TestContext::new("3.9");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("requests==1.2")?;
+fn recompile() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    let constraints_txt = context.temp_dir.child("build_constraints.txt");
-    constraints_txt.write_str("setuptools>=40")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
 
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt")
-        .arg("--build-constraint")
-        .arg("build_constraints.txt"), @r###"
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+    "###
+    );
+
+    assert!(
+        context
+            .site_packages()
+            .join("markupsafe")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Raise an error when an editable's `Requires-Python` constraint is not met.
+#[test]
+fn requires_python_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an editable package with a `Requires-Python` constraint that is not met.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.13"
+"#,
+    )?;
+
+    // Write to a requirements file.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&format!("-e {}", editable_dir.path().display()))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because the current Python version (3.12.[X]) does not satisfy Python>=3.13 and example==0.0.0 depends on Python>=3.13, we can conclude that example==0.0.0 cannot be used.
+          And because only example==0.0.0 is available and you require example, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Raise an error when a direct URL dependency's `Requires-Python` constraint is not met.
+#[test]
+fn requires_python_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an editable package with a `Requires-Python` constraint that is not met.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.0.0"
+]
+requires-python = ">=3.13"
+"#,
+    )?;
+
+    // Write to a requirements file.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&format!("example @ {}", editable_dir.path().display()))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because the current Python version (3.12.[X]) does not satisfy Python>=3.13 and example==0.0.0 depends on Python>=3.13, we can conclude that example==0.0.0 cannot be used.
+          And because only example==0.0.0 is available and you require example, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Use an unknown hash algorithm with `--require-hashes`.
+#[test]
+fn require_hashes_unknown_algorithm() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio==4.0.0 --hash=foo:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unsupported hash algorithm (expected one of: `md5`, `sha256`, `sha384`, `sha512`, or `blake2b`) on: `foo`
+    "
+    );
+
+    Ok(())
+}
+
+/// Omit the hash with `--require-hashes`.
+#[test]
+fn require_hashes_missing_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    // Install without error when `--require-hashes` is omitted.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    // Error when `--require-hashes` is provided.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Exempt a package from `--require-hashes` with `--exclude-hashes-for`.
+#[test]
+fn require_hashes_exclude_hashes_for() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        iniconfig==2.0.0
+        idna==3.6
+    "})?;
+
+    // Hashes are still required for any package that isn't exempt.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--exclude-hashes-for")
+        .arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: idna==3.6
+    "
+    );
+
+    // Install without error when every unhashed package is exempt.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--exclude-hashes-for")
+        .arg("iniconfig")
+        .arg("--exclude-hashes-for")
+        .arg("idna"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Unnamed requirements can't be exempted from `--require-hashes`, since their names aren't known
+/// until they're built, but named direct URL requirements can.
+#[test]
+fn require_hashes_exclude_hashes_for_unnamed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Use a `setup.py`-only project, such that the name can only be determined by a build.
+    let project = context.temp_dir.child("internal");
+    project.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(name="internal", version="0.1.0")
+    "#})?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        ./internal
+    "})?;
+
+    // The unnamed requirement is rejected upfront, before it's built, even if the package it
+    // would resolve to is exempt.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--exclude-hashes-for")
+        .arg("internal"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[TEMP_DIR]/internal
+    "
+    );
+
+    // Install without error when the requirement is named, and the name is exempt.
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        internal @ ./internal
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--exclude-hashes-for")
+        .arg("internal"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.0.0
+     + internal==0.1.0 (from file://[TEMP_DIR]/internal)
+    "
+    );
+
+    Ok(())
+}
+
+/// Omit the version with `--require-hashes`.
+#[test]
+fn require_hashes_missing_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    // Install without error when `--require-hashes` is omitted.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "###
+    );
+
+    // Error when `--require-hashes` is provided.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    "###
+    );
+
+    Ok(())
+}
+
+/// Use a non-`==` operator with `--require-hashes`.
+#[test]
+fn require_hashes_invalid_operator() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio>4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    // Install without error when `--require-hashes` is omitted.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "###
+    );
+
+    // Error when `--require-hashes` is provided.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio>4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for _just_ the wheel with `--no-binary`.
+#[test]
+fn require_hashes_wheel_no_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download and build `anyio==4.0.0`
+      ╰─▶ Hash mismatch for `anyio==4.0.0`
+
+          Expected:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for _just_ the wheel with `--only-binary`.
+#[test]
+fn require_hashes_wheel_only_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for _just_ the source distribution with `--no-binary`.
+#[test]
+fn require_hashes_source_no_binary() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution==0.0.1 --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for _just_ the source distribution, with `--binary-only`.
+#[test]
+fn require_hashes_source_only_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio==4.0.0`
+      ╰─▶ Hash mismatch for `anyio==4.0.0`
+
+          Expected:
+            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the correct hash algorithm, but the wrong digest.
+#[test]
+fn require_hashes_wrong_digest() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio==4.0.0`
+      ╰─▶ Hash mismatch for `anyio==4.0.0`
+
+          Expected:
+            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the correct hash, but the wrong algorithm.
+#[test]
+fn require_hashes_wrong_algorithm() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha512:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio==4.0.0`
+      ╰─▶ Hash mismatch for `anyio==4.0.0`
+
+          Expected:
+            sha512:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include only a `sha512` hash, which the index doesn't publish; uv should compute a matching
+/// digest from the downloaded archive.
+#[test]
+fn require_hashes_sha512() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for a source distribution specified as a direct URL dependency.
+#[test]
+fn require_hashes_source_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
+    "###
+    );
+
+    // Reinstall with the right hash, and verify that it's reused.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ source-distribution==0.0.1 (from https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz)
+    "###
+    );
+
+    // Reinstall with the wrong hash, and verify that it's rejected despite being cached.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+      ╰─▶ Hash mismatch for `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+
+          Expected:
+            sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the _wrong_ hash for a source distribution specified as a direct URL dependency.
+#[test]
+fn require_hashes_source_url_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+      ╰─▶ Hash mismatch for `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+
+          Expected:
+            sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for a built distribution specified as a direct URL dependency.
+#[test]
+fn require_hashes_wheel_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Reinstall with the right hash, and verify that it's reused.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Reinstall with the wrong hash, and verify that it's rejected despite being cached.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+
+          Expected:
+            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    // Sync a new dependency and include the wrong hash for anyio. Verify that we reuse anyio
+    // despite the wrong hash, like pip, since we don't validate hashes for already-installed
+    // distributions.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f\niniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the _wrong_ hash for a built distribution specified as a direct URL dependency.
+#[test]
+fn require_hashes_wheel_url_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+
+          Expected:
+            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject Git dependencies when `--require-hashes` is provided.
+#[test]
+#[cfg(feature = "git")]
+fn require_hashes_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
+      ╰─▶ Hash-checking is not supported for Git repositories: `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject local directory dependencies when `--require-hashes` is provided.
+#[test]
+fn require_hashes_source_tree() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "black @ {} --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a",
+        context
+            .workspace_root
+            .join("scripts/packages/black_editable")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `black @ file://[WORKSPACE]/scripts/packages/black_editable`
+      ╰─▶ Hash-checking is not supported for local directories: `black @ file://[WORKSPACE]/scripts/packages/black_editable`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for _just_ the wheel with `--only-binary`.
+#[test]
+fn require_hashes_re_download() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    // Install without `--require-hashes`.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    // Reinstall with `--require-hashes`, and the wrong hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio==4.0.0`
+      ╰─▶ Hash mismatch for `anyio==4.0.0`
+
+          Expected:
+            sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    // Reinstall with `--require-hashes`, and the right hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for a built distribution specified as a local path dependency.
+#[test]
+fn require_hashes_wheel_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the _wrong_ hash for a built distribution specified as a local path dependency.
+#[test]
+fn require_hashes_wheel_path_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to read `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl`
+      ╰─▶ Hash mismatch for `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl`
+
+          Expected:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash for a source distribution specified as a local path dependency.
+#[test]
+fn require_hashes_source_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b",
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-999.0.0.tar.gz")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==999.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the _wrong_ hash for a source distribution specified as a local path dependency.
+#[test]
+fn require_hashes_source_path_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "tqdm @ {} --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-999.0.0.tar.gz")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz`
+      ╰─▶ Hash mismatch for `tqdm @ file://[WORKSPACE]/scripts/links/tqdm-999.0.0.tar.gz`
+
+          Expected:
+            sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+          Computed:
+            sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b
+    "###
+    );
+
+    Ok(())
+}
+
+/// We allow `--require-hashes` for direct URL dependencies.
+#[test]
+fn require_hashes_unnamed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc::indoc! {r"
+            https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        "} )?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    Ok(())
+}
+
+/// We disallow `--require-hashes` for editables.
+#[test]
+fn require_hashes_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{workspace_root}/scripts/packages/black_editable[d]
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/scripts/packages/black_editable[d]
+    "###
+    );
+
+    Ok(())
+}
+
+/// If a dependency is repeated, the hash should be required for both instances.
+#[test]
+fn require_hashes_repeated_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a\nanyio")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    "###
+    );
+
+    // Reverse the order.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio\nanyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    "###
+    );
+
+    Ok(())
+}
+
+/// If a dependency is repeated, use the last hash provided. pip seems to use the _first_ hash.
+#[test]
+fn require_hashes_repeated_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Use the same hash in both cases.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc::indoc! { r"
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    " })?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Use a different hash, but both are correct.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc::indoc! { r"
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha512:f30761c1e8725b49c498273b90dba4b05c0fd157811994c806183062cb6647e773364ce45f0e1ff0b10e32fe6d0232ea5ad39476ccf37109d6b49603a09c11c2
+    " })?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Use a different hash. The first hash is wrong, but that's fine, since we use the last hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc::indoc! { r"
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=md5:420d85e19168705cdf0223621b18831a
+    " })?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0 (from https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Use a different hash. The second hash is wrong. This should fail, since we use the last hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc::indoc! { r"
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+            anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl --hash=md5:520d85e19168705cdf0223621b18831a
+    " })?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--reinstall"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+      ╰─▶ Hash mismatch for `anyio @ https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl`
+
+          Expected:
+            md5:520d85e19168705cdf0223621b18831a
+
+          Computed:
+            md5:420d85e19168705cdf0223621b18831a
+    "###
+    );
+
+    Ok(())
+}
+
+/// If a dependency is repeated, the hash should be required for both instances.
+#[test]
+fn require_hashes_at_least_one() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Request `anyio` with a `sha256` hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    // Reinstall, requesting both `sha256` and `sha512`. We should reinstall from the cache, since
+    // at least one hash matches.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a --hash=md5:420d85e19168705cdf0223621b18831a")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0
+    "###
+    );
+
+    // This should be true even if the second hash is wrong.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a --hash=md5:1234")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--find-links`, but the registry doesn't provide us with a hash.
+#[test]
+fn require_hashes_find_links_no_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // First, use the correct hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example-a-961b4c22==1.0.0
+    "###
+    );
+
+    // Second, use an incorrect hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:123
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Third, use the hash from the source distribution. This will actually fail, when it _could_
+    // succeed, but pip has the same behavior.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Fourth, use the hash from the source distribution, and disable wheels. This should succeed.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example-a-961b4c22==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--find-links`, and the registry serves us a correct hash.
+#[test]
+fn require_hashes_find_links_valid_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/valid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example-a-961b4c22==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--find-links`, and the registry serves us an incorrect hash.
+#[test]
+fn require_hashes_find_links_invalid_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // First, request some other hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:123
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Second, request the invalid hash, that the registry _thinks_ is correct. We should reject it.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Third, request the correct hash, that the registry _thinks_ is correct. We should accept
+    // it, since it's already cached under this hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example-a-961b4c22==1.0.0
+    "###
+    );
+
+    // Fourth, request the correct hash, that the registry _thinks_ is correct, but without the
+    // cache. We _should_ accept it, but we currently don't.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--refresh")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example-a-961b4c22==1.0.0
+    "###
+    );
+
+    // Finally, request the correct hash, along with the incorrect hash for the source distribution.
+    // Resolution will fail, since the incorrect hash matches the registry's hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e --hash=sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--refresh")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download and build `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+            sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee
+
+          Computed:
+            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--index-url`, but the registry doesn't provide us with a hash.
+#[test]
+fn require_hashes_registry_no_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/no-hash/simple-html/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example-a-961b4c22==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--index-url`, and the registry serves us a correct hash.
+#[test]
+fn require_hashes_registry_valid_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("https://astral-test.github.io/astral-test-hash/valid-hash/simple-html/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because example-a-961b4c22 was not found in the package registry and you require example-a-961b4c22==1.0.0, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Using `--index-url`, and the registry serves us an incorrect hash.
+#[test]
+fn require_hashes_registry_invalid_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // First, request some other hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("example-a-961b4c22==1.0.0 --hash=sha256:123")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:123
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Second, request the invalid hash, that the registry _thinks_ is correct. We should reject it.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:8838f9d005ff0432b258ba648d9cabb1cbdf06ac29d14f788b02edae544032ea
+
+          Computed:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+    "###
+    );
+
+    // Third, request the correct hash, that the registry _thinks_ is correct. We should accept
+    // it, since it's already cached under this hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example-a-961b4c22==1.0.0
+    "###
+    );
+
+    // Fourth, request the correct hash, that the registry _thinks_ is correct, but without the
+    // cache. We _should_ accept it, but we currently don't.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--refresh")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ example-a-961b4c22==1.0.0
+    "###
+    );
+
+    // Finally, request the correct hash, along with the incorrect hash for the source distribution.
+    // Resolution will fail, since the incorrect hash matches the registry's hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("example-a-961b4c22==1.0.0 --hash=sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e --hash=sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.txt")
+        .arg("--refresh")
+        .arg("--reinstall")
+        .arg("--require-hashes")
+        .arg("--index-url")
+        .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download and build `example-a-961b4c22==1.0.0`
+      ╰─▶ Hash mismatch for `example-a-961b4c22==1.0.0`
+
+          Expected:
+            sha256:5d69f0b590514103234f0c3526563856f04d044d8d0ea1073a843ae429b3187e
+            sha256:a3cf07a05aac526131a2e8b6e4375ee6c6eaac8add05b88035e960ac6cd999ee
+
+          Computed:
+            sha256:294e788dbe500fdc39e8b88e82652ab67409a1dc9dd06543d0fe0ae31b713eb3
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash in the URL directly.
+#[test]
+fn require_hashes_url() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include an irrelevant fragment in the URL.
+#[test]
+fn require_hashes_url_other_fragment() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#foo=bar")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#foo=bar
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include an invalid hash in the URL directly.
+#[test]
+fn require_hashes_url_invalid() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
+      ╰─▶ Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
+
+          Expected:
+            sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+
+          Computed:
+            sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ignore the (valid) hash on the fragment if (invalid) hashes are provided directly.
+#[test]
+fn require_hashes_url_ignore() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374 --hash sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
+      ╰─▶ Hash mismatch for `iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374`
+
+          Expected:
+            sha256:c6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+
+          Computed:
+            sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include the hash in the URL directly.
+#[test]
+fn require_hashes_url_unnamed() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Sync to a `--target` directory with a built distribution.
+#[test]
+fn target_built_distribution() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install `iniconfig` to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Ensure that the package is present in the target directory.
+    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+
+    // Ensure that we can't import the package.
+    context.assert_command("import iniconfig").failure();
+
+    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import iniconfig")
+        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // Upgrade it.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==1.1.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==1.1.1
+    ");
+
+    // Remove it, and replace with `flask`, which includes a binary.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flask==3.0.2
+     - iniconfig==1.1.1
+    ");
+    // Ensure that the binary is present in the target directory.
+    assert!(
+        context
+            .temp_dir
+            .child("target")
+            .child("bin")
+            .child(format!("flask{EXE_SUFFIX}"))
+            .is_file()
+    );
+
+    Ok(())
+}
+
+/// Sync to a `--target` directory with a package that requires building from source.
+#[test]
+fn target_source_distribution() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install `iniconfig` to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Ensure that the build requirements are not present in the target directory.
+    assert!(!context.temp_dir.child("target").child("hatchling").is_dir());
+
+    // Ensure that the package is present in the target directory.
+    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+
+    // Ensure that we can't import the package.
+    context.assert_command("import iniconfig").failure();
+
+    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import iniconfig")
+        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Sync to a `--target` directory with a package that requires building from source, along with
+/// `--no-build-isolation`.
+#[test]
+fn target_no_build_isolation() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install `hatchling` into the current environment.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flit_core")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flit-core==3.9.0
+    "###);
+
+    // Install `iniconfig` to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("wheel")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--no-build-isolation")
+        .arg("--no-binary")
+        .arg("wheel")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + wheel==0.43.0
+    ");
+
+    // Ensure that the build requirements are not present in the target directory.
+    assert!(!context.temp_dir.child("target").child("flit_core").is_dir());
+
+    // Ensure that the package is present in the target directory.
+    assert!(context.temp_dir.child("target").child("wheel").is_dir());
+
+    // Ensure that we can't import the package.
+    context.assert_command("import wheel").failure();
+
+    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import wheel")
+        .env(EnvVars::PYTHONPATH, context.temp_dir.child("target").path())
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Sync to a `--target` directory without a virtual environment.
+#[test]
+fn target_system() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Install `iniconfig` to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--target")
+        .arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // Ensure that the package is present in the target directory.
+    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+
+    Ok(())
+}
+
+/// Sync to a `--prefix` directory.
+#[test]
+fn prefix() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install `iniconfig` to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let prefix = context.temp_dir.child("prefix");
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Ensure that we can't import the package.
+    context.assert_command("import iniconfig").failure();
+
+    // Ensure that we can import the package by augmenting the `PYTHONPATH`.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import iniconfig")
+        .env(
+            EnvVars::PYTHONPATH,
+            site_packages_path(&context.temp_dir.join("prefix"), "python3.12"),
+        )
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // Upgrade it.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==1.1.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==1.1.1
+    ");
+
+    Ok(())
+}
+
+/// Sync a changed set of requirements into an existing `--prefix` directory, removing any
+/// packages that are no longer required.
+#[test]
+fn prefix_remove() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig==2.0.0
+        sniffio==1.3.1
+    "})?;
+
+    let prefix = context.temp_dir.child("prefix");
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    // Drop `sniffio`; it should be removed from the prefix, while `iniconfig` is left in place.
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - sniffio==1.3.1
+    ");
+
+    let site_packages = site_packages_path(prefix.path(), "python3.12");
+    assert!(site_packages.join("iniconfig").is_dir());
+    assert!(!site_packages.join("sniffio").exists());
+
+    // The virtual environment itself should be untouched.
+    context.assert_command("import sniffio").failure();
+
+    Ok(())
+}
+
+/// Sync to a `--prefix` directory, staged beneath a `--root` directory.
+#[test]
+fn root() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    let prefix = context.temp_dir.child("prefix");
+    let root = context.temp_dir.child("stage");
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--prefix")
+        .arg(prefix.path())
+        .arg("--root")
+        .arg(root.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Ensure that nothing was written to the `--prefix` directory itself.
+    assert!(
+        !site_packages_path(prefix.path(), "python3.12")
+            .join("iniconfig")
+            .exists()
+    );
+
+    // Ensure that the files were staged beneath the `--root` directory.
+    let staged = root.path().join(
+        prefix
+            .path()
+            .components()
+            .filter(|component| matches!(component, std::path::Component::Normal(_)))
+            .collect::<std::path::PathBuf>(),
+    );
+    let site_packages = site_packages_path(&staged, "python3.12");
+    assert!(site_packages.join("iniconfig").is_dir());
+
+    // Ensure that the recorded paths are relative to the final installation location.
+    let record = fs::read_to_string(
+        site_packages
+            .join("iniconfig-2.0.0.dist-info")
+            .join("RECORD"),
+    )?;
+    assert!(record.contains("iniconfig/__init__.py"));
+    assert!(!record.contains("stage"));
+
+    Ok(())
+}
+
+/// Ensure that we install packages with markers on them.
+#[test]
+fn preserve_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio ; python_version > '3.7'")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include a `build_constraints.txt` file with an incompatible constraint.
+#[test]
+fn incompatible_build_constraint() -> Result<()> {
+    let context = TestContext::new("3.9");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==1.2")?;
+
+    let constraints_txt = context.temp_dir.child("build_constraints.txt");
+    constraints_txt.write_str("setuptools==1")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--build-constraint")
+        .arg("build_constraints.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download and build `requests==1.2.0`
+      ├─▶ Failed to resolve requirements from `setup.py` build
+      ├─▶ No solution found when resolving: `setuptools>=40.8.0`
+      ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include a `build_constraints.txt` file with a compatible constraint.
+#[test]
+fn compatible_build_constraint() -> Result<()> {
+    let context = TestContext::new("3.9");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==1.2")?;
+
+    let constraints_txt = context.temp_dir.child("build_constraints.txt");
+    constraints_txt.write_str("setuptools>=40")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--build-constraint")
+        .arg("build_constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + requests==1.2.0
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sync_seed() -> Result<()> {
+    let context = TestContext::new("3.9");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==1.2")?;
+
+    // Add `pip` to the environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("pip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pip==24.0
+    "###
+    );
+
+    // Syncing should remove the seed packages.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - pip==24.0
+     + requests==1.2.0
+    "
+    );
+
+    // Re-create the environment with seed packages.
+    uv_snapshot!(context.filters(), context.venv().arg("--clear")
+        .arg("--seed"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.9.[X] interpreter at: [PYTHON-3.9]
+    Creating virtual environment with seed packages at: .venv
+     + pip==24.0
+     + setuptools==69.2.0
+     + wheel==0.43.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    // Syncing should retain the seed packages.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + requests==1.2.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Sanitize zip files during extraction.
+#[test]
+fn sanitize() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install a zip file that includes a path that extends outside the parent.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("payload-package @ https://github.com/astral-sh/sanitize-wheel-test/raw/bc59283d5b4b136a191792e32baa51b477fdf65e/payload_package-0.1.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + payload-package==0.1.0 (from https://github.com/astral-sh/sanitize-wheel-test/raw/bc59283d5b4b136a191792e32baa51b477fdf65e/payload_package-0.1.0-py3-none-any.whl)
+    "###
+    );
+
+    // There should be no `payload` file in the root.
+    if let Some(parent) = context.temp_dir.parent() {
+        assert!(!parent.join("payload").exists());
+    }
+
+    Ok(())
+}
+
+/// Allow semicolons attached to markers, as long as they're preceded by a space.
+#[test]
+fn semicolon_trailing_space() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements = context.temp_dir.child("requirements.txt");
+    requirements.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl; python_version > '3.10'")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Treat a semicolon that's not whitespace-separated as a part of the URL.
+#[test]
+fn semicolon_no_space() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements = context.temp_dir.child("requirements.txt");
+    requirements.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version > '3.10'")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Couldn't parse requirement in `requirements.txt` at position 0
+      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version%20%3E%20'3.10'`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+    iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version > '3.10'
+                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pep_751() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 3 packages in [TIME]
+    "
+    );
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Uninstalled 3 packages in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==4.3.0
+     - idna==3.6
+     + iniconfig==2.0.0
+     - sniffio==1.3.1
+    "
+    );
+
+    Ok(())
+}
+
+/// The `requires-python` used for resolution is recorded in the `pylock.toml`, and enforced when
+/// syncing.
+#[test]
+fn pep_751_requires_python() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.13"]);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--format")
+        .arg("pylock.toml")
+        .arg("--python-version")
+        .arg("3.13")
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    let pylock_toml = context.read("pylock.toml");
+    assert!(pylock_toml.contains("requires-python = \">=3.13\""));
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested interpreter resolved to Python 3.12.[X], which is incompatible with the `pylock.toml`'s Python requirement: `>=3.13`
+    "
+    );
+
+    Ok(())
+}
+
+/// Avoid erroring for packages that only include wheels, and _don't_ include a wheel for the
+/// current platform, but are omitted by markers anyway.
+///
+/// See: <https://github.com/astral-sh/uv/issues/13127>
+#[test]
+fn pep_751_wheel_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12.0"
+        dependencies = ["torch"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    // If there's no compatible wheel for a package we _don't_ need to install (e.g., anything
+    // CUDA-related), succeed.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--dry-run")
+        .arg("--python-platform")
+        .arg("macos"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would download 9 packages
+    Would install 9 packages
+     + filelock==3.13.1
+     + fsspec==2024.3.1
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + mpmath==1.3.0
+     + networkx==3.2.1
+     + sympy==1.12
+     + torch==2.2.1
+     + typing-extensions==4.10.0
+    "
+    );
+
+    // However, if there's no compatible wheel for a package that we _do_ need to install, we should
+    // error
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--dry-run")
+        .arg("--python-platform")
+        .arg("macos")
+        .arg("--python-version")
+        .arg("3.8"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `torch` can't be installed because it doesn't have a source distribution or wheel for the current platform
+
+    hint: You're using CPython 3.8 (`cp38`), but `torch` (v2.2.1) only has wheels with the following Python implementation tag: `cp312`
+    "
+    );
+
+    Ok(())
+}
+
+/// Respect `--no-binary` et al when installing from a `pylock.toml`.
+#[test]
+fn pep_751_build_options() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--no-binary")
+        .arg("anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.8.0
+     + idna==3.10
+     + sniffio==1.3.1
+     + typing-extensions==4.12.2
+    "
+    );
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["odrive"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--no-binary")
+        .arg("odrive"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `odrive` can't be installed because it is marked as `--no-binary` but has no source distribution
+    "
+    );
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--only-binary")
+        .arg("source-distribution"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `source-distribution` can't be installed because it is marked as `--no-build` but has no binary distribution
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--no-binary")
+        .arg("source-distribution"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Uninstalled 4 packages in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==4.8.0
+     - idna==3.10
+     - sniffio==1.3.1
+     + source-distribution==0.0.3
+     - typing-extensions==4.12.2
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pep_751_direct_url_tags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["MarkupSafe @ https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--python-platform")
+        .arg("linux"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to determine installation plan
+      Caused by: A URL dependency is incompatible with the current platform: https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--python-platform")
+        .arg("macos"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 1 package in [TIME]
+     + markupsafe==3.0.2 (from https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl)
+    "
+    );
+
+    Ok(())
+}
+
+/// Skip a package that fails to build with `--ignore-build-failures`, installing the rest.
+#[test]
+fn ignore_build_failures() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package with static metadata, whose build backend always fails.
+    make_backend_project(
+        context.temp_dir.child("broken").path(),
+        "broken",
+        indoc! {r"
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                os._exit(1)
+        "},
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        ./broken
+        iniconfig==2.0.0
+    "})?;
+
+    // Without `--ignore-build-failures`, the sync fails entirely.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × Failed to build `broken @ file://[TEMP_DIR]/broken`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          hint: This usually indicates a problem with the package or the build environment.
+    "###
+    );
+
+    context.assert_command("import iniconfig").failure();
+
+    // With `--ignore-build-failures`, the remaining packages are installed.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--ignore-build-failures"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × Failed to build `broken @ file://[TEMP_DIR]/broken`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          hint: This usually indicates a problem with the package or the build environment.
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    warning: Skipped 1 package that failed to build: `broken @ file://[TEMP_DIR]/broken`
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Pass `--config-settings` through to a PEP 517 build backend.
+#[test]
+fn config_settings_backend() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package whose build backend records the `greeting` setting in the built module.
+    make_backend_project(
+        context.temp_dir.child("echo").path(),
+        "echo",
+        indoc! {r#"
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                greeting = (config_settings or {}).get("greeting")
+                return write_wheel(wheel_directory, "echo", {"echo.py": f"GREETING = {greeting!r}\n"})
+        "#},
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./echo")?;
+
+    // Pass the setting to every build backend.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("-C")
+        .arg("greeting=hello"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + echo==0.1.0 (from file://[TEMP_DIR]/echo)
+    "###
+    );
+
+    context
+        .assert_command("import echo; assert echo.GREETING == 'hello', echo.GREETING")
+        .success();
+
+    // Pass the setting to the backend of a specific package.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .arg("--config-settings-package")
+        .arg("echo:greeting=goodbye"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ echo==0.1.0 (from file://[TEMP_DIR]/echo)
+    "###
+    );
+
+    context
+        .assert_command("import echo; assert echo.GREETING == 'goodbye', echo.GREETING")
+        .success();
+
+    Ok(())
+}
+
+/// Run source distribution builds one at a time with `--max-parallel-builds 1`.
+#[test]
+fn max_parallel_builds() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let log = context.temp_dir.child("builds.log");
+
+    // Create two packages whose build backends record when each build starts and ends.
+    for name in ["alpha", "beta"] {
+        make_backend_project(
+            context.temp_dir.child(name).path(),
+            name,
+            &formatdoc! {r#"
+                import time
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    with open({log:?}, "a") as f:
+                        f.write("start {name}\n")
+                    time.sleep(1)
+                    filename = write_wheel(wheel_directory, "{name}", {{"{name}.py": ""}})
+                    with open({log:?}, "a") as f:
+                        f.write("end {name}\n")
+                    return filename
+                "#,
+                log = log.path().simplified_display().to_string(),
+            },
+        )?;
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        ./alpha
+        ./beta
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--max-parallel-builds")
+        .arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + alpha==0.1.0 (from file://[TEMP_DIR]/alpha)
+     + beta==0.1.0 (from file://[TEMP_DIR]/beta)
+    "###
+    );
+
+    // Each build should finish before the next one starts.
+    let log = fs_err::read_to_string(log.path())?;
+    let events = log.lines().collect::<Vec<_>>();
+    assert_eq!(events.len(), 4, "{log}");
+    for build in events.chunks(2) {
+        let name = build[0].strip_prefix("start ").expect(&log);
+        assert_eq!(build[1], format!("end {name}"), "{log}");
+    }
+
+    Ok(())
+}
+
+/// Assert that the environment is in sync with pinned requirements via `--locked`.
+#[test]
+fn locked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // The environment is empty, so `--locked` should fail without modifying it.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0
+    error: The environment is not in sync with the requirements, but `--locked` was provided
+    "###
+    );
+
+    context.assert_command("import iniconfig").failure();
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Once synced, `--locked` should succeed.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    Would make no changes
+    "###
+    );
+
+    // Unpinned requirements are rejected.
+    requirements_txt.write_str("iniconfig>=2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--locked` requires all requirements to be pinned to an exact version, but found: `iniconfig>=2.0.0`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install fully-pinned requirements with `--no-resolve`, without querying the index.
+#[tokio::test]
+async fn no_resolve() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Any request to the index is a failure.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--no-resolve"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    "
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    // Requirements that would need to be looked up on the index are rejected.
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--no-resolve"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--no-resolve` requires all requirements to be pinned to a direct URL or path, but found: `iniconfig==2.0.0`
+    "
+    );
+
+    Ok(())
+}
+
+/// Fall back to a mirror of the index if the first index is unavailable, with
+/// `--index-strategy failover`.
+#[tokio::test]
+async fn index_strategy_failover() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The first index is unavailable.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Without failover, the sync fails.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--extra-index-url")
+        .arg("https://pypi.org/simple")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .assert()
+        .failure();
+
+    // With failover, the second index is consulted instead.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--extra-index-url")
+        .arg("https://pypi.org/simple")
+        .arg("--index-strategy")
+        .arg("failover")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Failed to fetch `iniconfig` from http://127.0.0.1:"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("falling back to the next index"),
+        "{stderr}"
+    );
+    assert!(stderr.contains(" + iniconfig==2.0.0"), "{stderr}");
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Refuse to download artifacts that exceed the size given by `--max-artifact-size`.
+#[test]
+fn max_artifact_size() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // The wheel is larger than 1 KiB.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--max-artifact-size")
+        .arg("1KiB"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `iniconfig==2.0.0`
+      ╰─▶ Refusing to download `iniconfig-2.0.0-py3-none-any.whl`, which exceeds the maximum artifact size of 1024 bytes
+    "
+    );
+
+    context.assert_command("import iniconfig").failure();
+
+    // But smaller than 1 MiB.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--max-artifact-size")
+        .arg("1MiB"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Refuse to download a source distribution whose `Content-Length` exceeds the size given by
+/// `--max-artifact-size`.
+#[tokio::test]
+async fn max_artifact_size_content_length() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Serve a 2 KiB archive; its contents are irrelevant, since it should never be read.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0; 2048]))
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("example @ {}/example-1.0.0.tar.gz", server.uri()))?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--max-artifact-size")
+        .arg("1KiB")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("which exceeds the maximum artifact size of 1024 bytes"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Abort the download of a source distribution that exceeds the size given by
+/// `--max-artifact-size`, when the response doesn't declare its size up front.
+#[test]
+fn max_artifact_size_chunked() -> Result<()> {
+    use std::io::{Read, Write};
+
+    let context = TestContext::new("3.12");
+
+    // Build a valid archive with ~8 KiB of incompressible contents.
+    let archive = {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let data = (0..8192)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state.to_le_bytes()[0]
+            })
+            .collect::<Vec<u8>>();
+        let enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut tar = tar::Builder::new(enc);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, "example-1.0.0/data.bin", data.as_slice())?;
+        tar.into_inner()?.finish()?
+    };
+
+    // Serve the archive with chunked transfer encoding, i.e., without a `Content-Length`.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request);
+            let _ = stream.write_all(
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+            );
+            for chunk in archive.chunks(512) {
+                let _ = write!(stream, "{:x}\r\n", chunk.len());
+                let _ = stream.write_all(chunk);
+                let _ = stream.write_all(b"\r\n");
+            }
+            let _ = stream.write_all(b"0\r\n\r\n");
+        }
+    });
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("example @ http://{addr}/example-1.0.0.tar.gz"))?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--max-artifact-size")
+        .arg("1KiB")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("which exceeds the maximum artifact size of 1024 bytes"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Detect packages that ship the same file with `--strict` and `--strict-file-conflicts`.
+#[test]
+fn strict_file_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build two packages that both ship a top-level `config.py`.
+    for name in ["first", "second"] {
+        let project = context.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+
+            [tool.hatch.build.targets.wheel]
+            only-include = ["config.py"]
+        "#})?;
+        project
+            .child("config.py")
+            .write_str(&format!("OWNER = {name:?}\n"))?;
+        context
+            .build()
+            .arg("--wheel")
+            .arg("--out-dir")
+            .arg(context.temp_dir.child("dist").path())
+            .current_dir(&project)
+            .assert()
+            .success();
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        first==0.1.0
+        second==0.1.0
+    "})?;
+
+    // With `--strict`, the conflict is reported, but both packages are installed.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("dist").path())
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    warning: Installing `second` would overwrite `[SITE_PACKAGES]/config.py`, which is owned by `first`
+    Installed 2 packages in [TIME]
+     + first==0.1.0
+     + second==0.1.0
+    "
+    );
+
+    // With `--strict-file-conflicts`, the conflict is an error, and the environment is unchanged.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("dist").path())
+        .arg("--reinstall")
+        .arg("--strict-file-conflicts"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    error: Installing `second` would overwrite `[SITE_PACKAGES]/config.py`, which is owned by `first`
+    "
+    );
+
+    context
+        .assert_command("import config; assert config.OWNER == 'second'")
+        .success();
+
+    Ok(())
+}
+
+/// Detect packages that declare an entry point with the same name, since they'd generate the same
+/// launcher.
+#[test]
+#[cfg(unix)]
+fn strict_file_conflicts_entry_points() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build two packages that both declare a `tool` console script.
+    for name in ["first", "second"] {
+        let project = context.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [project.scripts]
+            tool = "{name}:main"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+
+            [tool.hatch.build.targets.wheel]
+            only-include = ["{name}.py"]
+        "#})?;
+        project
+            .child(format!("{name}.py"))
+            .write_str("def main(): pass\n")?;
+        context
+            .build()
+            .arg("--wheel")
+            .arg("--out-dir")
+            .arg(context.temp_dir.child("dist").path())
+            .current_dir(&project)
+            .assert()
+            .success();
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        first==0.1.0
+        second==0.1.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("dist").path())
+        .arg("--strict-file-conflicts"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    error: Installing `second` would overwrite `[VENV]/bin/tool`, which is owned by `first`
+    "
+    );
+
+    Ok(())
+}
+
+/// Refuse to remove installed files that were modified in place, with
+/// `--verify-records-on-uninstall`.
+#[test]
+fn verify_records_on_uninstall() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Patch an installed file in place.
+    let init = context
+        .site_packages()
+        .join("iniconfig")
+        .join("__init__.py");
+    let patched = format!("{}\n# Patched.\n", fs::read_to_string(&init)?);
+    fs::write(&init, &patched)?;
+
+    // Upgrading the package would remove the patched file.
+    requirements_txt.write_str("iniconfig==1.1.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-records-on-uninstall"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    error: The following installed file was modified after installation and would be removed, but `--verify-records-on-uninstall` was provided:
+      [SITE_PACKAGES]/iniconfig/__init__.py (from `iniconfig`)
+    "
+    );
+
+    // The environment should be left untouched.
+    assert_eq!(fs::read_to_string(&init)?, patched);
+
+    // Without the flag, the patched file is removed.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==1.1.1
+    "
+    );
+
+    Ok(())
+}
+
+/// Back up any installed files that were modified in place before reinstalling, with
+/// `--preserve-modified`.
+#[test]
+fn preserve_modified() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Patch an installed file in place.
+    let init = context
+        .site_packages()
+        .join("iniconfig")
+        .join("__init__.py");
+    let original = fs::read_to_string(&init)?;
+    let patched = format!("{original}\n# Patched.\n");
+    fs::write(&init, &patched)?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--preserve-modified"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    Backed up 1 modified file before reinstalling:
+      [SITE_PACKAGES]/iniconfig/__init__.py.uv-backup
+    "
+    );
+
+    // The patched file should be backed up, and the original restored.
+    assert_eq!(
+        fs::read_to_string(init.with_file_name("__init__.py.uv-backup"))?,
+        patched
+    );
+    assert_eq!(fs::read_to_string(&init)?, original);
+
+    // Patch the file again; the existing backup should be left untouched.
+    let repatched = format!("{original}\n# Patched again.\n");
+    fs::write(&init, &repatched)?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--preserve-modified"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    Backed up 1 modified file before reinstalling:
+      [SITE_PACKAGES]/iniconfig/__init__.py.uv-backup.1
+    "
+    );
+
+    assert_eq!(
+        fs::read_to_string(init.with_file_name("__init__.py.uv-backup"))?,
+        patched
+    );
+    assert_eq!(
+        fs::read_to_string(init.with_file_name("__init__.py.uv-backup.1"))?,
+        repatched
+    );
+    assert_eq!(fs::read_to_string(&init)?, original);
+
+    Ok(())
+}
+
+/// Refuse to downgrade installed packages with `--verify-no-downgrade`.
+#[test]
+fn verify_no_downgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==1.1.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Upgrading is allowed.
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-no-downgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Pinning an older version should fail, without modifying the environment.
+    requirements_txt.write_str("iniconfig==1.1.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-no-downgrade"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The following package would be downgraded, but `--verify-no-downgrade` was provided:
+      iniconfig (2.0.0 -> 1.1.1)
+    "###
+    );
+
+    context.assert_installed("iniconfig", "2.0.0");
+
+    // The check should also apply when only downloading the distributions.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-no-downgrade")
+        .arg("--download-only")
+        .arg("--dest")
+        .arg("wheelhouse"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The following package would be downgraded, but `--verify-no-downgrade` was provided:
+      iniconfig (2.0.0 -> 1.1.1)
+    "
+    );
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Without an interactive terminal, `--allow-sdist-fallback` can't prompt to build from source, so
+/// packages without compatible wheels still fail to resolve.
+#[test]
+fn allow_sdist_fallback_non_interactive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `django-allauth==0.51.0` is only available as a source distribution.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("django_allauth==0.51.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--allow-sdist-fallback"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because django-allauth==0.51.0 has no usable wheels and you require django-allauth==0.51.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Wheels are required for `django-allauth` because building from source is disabled for all packages (i.e., with `--no-build`)
+    "###
+    );
+
+    context.assert_not_installed("django_allauth");
+
+    Ok(())
+}
+
+/// Disable colored output with `NO_COLOR` or `--no-color`, even if `FORCE_COLOR` is set.
+#[test]
+fn no_color() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // With `FORCE_COLOR`, the summary is colored, even though stderr is not a terminal.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .env(EnvVars::FORCE_COLOR, "1")
+        .env_remove(EnvVars::NO_COLOR)
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("iniconfig"), "{stderr}");
+    assert!(stderr.contains('\x1b'), "{stderr}");
+
+    // `NO_COLOR` takes precedence over `FORCE_COLOR`.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .env(EnvVars::FORCE_COLOR, "1")
+        .env(EnvVars::NO_COLOR, "1")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("iniconfig"), "{stderr}");
+    assert!(!stderr.contains('\x1b'), "{stderr}");
+
+    // As does `--no-color`.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--no-color")
+        .env(EnvVars::FORCE_COLOR, "1")
+        .env_remove(EnvVars::NO_COLOR)
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("iniconfig"), "{stderr}");
+    assert!(!stderr.contains('\x1b'), "{stderr}");
+
+    Ok(())
+}
+
+/// Sync from a remote `requirements.txt` that includes other files by relative path, which
+/// should be resolved against the URL of the including file.
+#[tokio::test]
+async fn sync_remote_requirements_txt_nested() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/requirements/dev.txt"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("-r ../base.txt\n-c constraints.txt\n"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/base.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("iniconfig\n"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/requirements/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("iniconfig<2\n"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/requirements/missing.txt"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(format!("{}/requirements/dev.txt", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "
+    );
+
+    // A missing file should fail with the status code.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(format!("{}/requirements/missing.txt", server.uri())), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Error while accessing remote requirements file: `http://[LOCALHOST]/requirements/missing.txt`
+      Caused by: HTTP status client error (404 Not Found)
+    "
+    );
+
+    Ok(())
+}
+
+/// List packages that aren't included in the requirements before removing them.
+#[test]
+fn list_extraneous() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    requirements_txt.write_str("typing-extensions==4.10.0")?;
+
+    // With `--dry-run`, the extraneous packages are listed, but not removed.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--list-extraneous")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Found 1 extraneous package
+     ? iniconfig==2.0.0
+    Would download 1 package
+    Would uninstall 1 package
+    Would install 1 package
+     - iniconfig==2.0.0
+     + typing-extensions==4.10.0
+    "###
+    );
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--list-extraneous"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Found 1 extraneous package
+     ? iniconfig==2.0.0
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     + typing-extensions==4.10.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Fail if the environment has drifted from the reference manifest.
+#[test]
+fn verify_clean() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    let manifest_txt = context.temp_dir.child("manifest.txt");
+    manifest_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // The environment matches the manifest.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-clean")
+        .arg("manifest.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "
+    );
+
+    // Modify the environment out of band.
+    context
+        .pip_install()
+        .arg("iniconfig==1.1.1")
+        .arg("typing-extensions==4.10.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--verify-clean")
+        .arg("manifest.txt"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment does not match `manifest.txt`, but `--verify-clean` was provided:
+      - `iniconfig==1.1.1` is installed, but `iniconfig==2.0.0` was expected
+      - `typing-extensions==4.10.0` is installed, but not listed in the manifest
+    "
+    );
+
+    Ok(())
+}
+
+/// Leave packages that are already satisfied by the environment untouched.
+#[test]
+fn exclude_installed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==1.1.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    requirements_txt.write_str("iniconfig>=1")?;
+
+    // With `--exclude-installed`, the compatible installed version is audited, even though
+    // `--reinstall` was provided.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--exclude-installed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // Without `--exclude-installed`, the package is replaced.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Sync a dependency group that includes another group via `include-group`.
+#[test]
+fn dependency_group_include() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        test = ["iniconfig"]
+        lint = ["sniffio", { include-group = "test" }]
+    "#})?;
+
+    // Syncing `lint` should also install the members of `test`, but not the project dependencies.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--group").arg("lint"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "
+    );
+
+    // Syncing `test` alone should remove `sniffio`.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--group").arg("test"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - sniffio==1.3.1
+    "
+    );
+
+    // A cycle in the group includes should be rejected.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = [{ include-group = "lint" }]
+        lint = [{ include-group = "test" }]
+    "#})?;
+
+    let output = context.pip_sync().arg("--group").arg("lint").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Detected a cycle in `dependency-groups`"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+/// Sync only the optional dependencies of a project, omitting its required dependencies.
+#[test]
+fn only_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        dev = ["iniconfig==2.0.0"]
+        docs = ["typing-extensions==4.10.0"]
+    "#})?;
+
+    // Only the `dev` extra should be installed, without `anyio`.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("pyproject.toml")
+        .arg("--only-extras").arg("dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // `--extra` can be used to include additional extras.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("pyproject.toml")
+        .arg("--only-extras").arg("dev")
+        .arg("--extra").arg("docs"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.10.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Warn when the first response from an index exceeds `--warn-slow-index-ms`.
+#[tokio::test]
+async fn warn_slow_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Serve an index that takes 500ms to respond.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_delay(std::time::Duration::from_millis(500)))
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--warn-slow-index-ms")
+        .arg("100")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(
+        stderr
+            .matches("warning: The index at `127.0.0.1` took")
+            .count(),
+        1,
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("which exceeds the `--warn-slow-index-ms` threshold of 100ms"),
+        "{stderr}"
+    );
+
+    // With a generous threshold, no warning is shown.
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.uri())
+        .arg("--warn-slow-index-ms")
+        .arg("60000")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("warn-slow-index-ms"), "{stderr}");
+
+    Ok(())
+}
+
+/// Record the isolated build environment of a source build with `--record-build-env`.
+#[test]
+fn record_build_env() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a local project, which must be built into a wheel.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./project")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--record-build-env")
+        .arg("build-env")
+        .assert()
+        .success();
+
+    let record = context.temp_dir.child("build-env").child("project-0.1.0");
+
+    // The build requirements should include the backend.
+    let requirements = fs::read_to_string(record.child("build-requirements.txt"))?;
+    assert!(
+        requirements
+            .lines()
+            .any(|line| line.starts_with("hatchling==")),
+        "{requirements}"
+    );
+
+    // The hooks should include the wheel build.
+    let hooks = fs::read_to_string(record.child("hooks.txt"))?;
+    assert!(
+        hooks
+            .lines()
+            .any(|line| line.starts_with("hatchling.build.build_wheel(")),
+        "{hooks}"
+    );
+
+    Ok(())
+}
+
+/// With `--verify-no-yanked-in-lock`, refuse to install a lockfile that pins a yanked version.
+#[test]
+fn verify_no_yanked_in_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // This version is yanked.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--verify-no-yanked-in-lock"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following version is yanked, but `--verify-no-yanked-in-lock` was provided:
+      colorama==0.4.2 (reason: "Bad build, missing files, will not install")
+    "#
+    );
+
+    // Without the flag, the lockfile is installed as-is.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + requests==1.2.0
-    "###
+     + colorama==0.4.2
+    "
     );
 
     Ok(())
 }
 
+/// Load variables from an `--env-file` for `${VAR}` expansion in the requirements.
 #[test]
-fn sync_seed() -> Result<()> {
-    let context = TestContext::new("3.9");
+fn env_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let env_file = context.temp_dir.child(".env");
+    env_file.write_str(
+        "WHEEL_URL=https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+    )?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("requests==1.2")?;
+    requirements_txt.write_str("iniconfig @ ${WHEEL_URL}")?;
 
-    // Add `pip` to the environment.
-    uv_snapshot!(context.filters(), context.pip_install()
-        .arg("pip"), @r###"
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--env-file")
+        .arg(".env"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5603,72 +11025,163 @@ fn sync_seed() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + pip==24.0
-    "###
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    "
     );
 
-    // Syncing should remove the seed packages.
+    Ok(())
+}
+
+/// Explain why a transitive dependency is installed with `--explain`.
+#[test]
+fn explain() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        blinker==1.7.0
+        click==8.1.7
+        flask==3.0.2
+        itsdangerous==2.1.2
+        jinja2==3.1.3
+        markupsafe==2.1.5
+        werkzeug==3.0.1
+    "})?;
+
+    // The explanation is available before anything is installed.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt"), @r"
+        .arg("requirements.txt")
+        .arg("--explain")
+        .arg("markupsafe")
+        .arg("--dry-run"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
+    markupsafe v2.1.5
+    ├── jinja2 v3.1.3 [requires: markupsafe >=2.0]
+    │   └── flask v3.0.2 [requires: jinja2 >=3.1.2]
+    └── werkzeug v3.0.1 [requires: markupsafe >=2.1.1]
+        └── flask v3.0.2 [requires: werkzeug >=3.0.0]
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     - pip==24.0
-     + requests==1.2.0
+    Resolved 7 packages in [TIME]
+    Would download 7 packages
+    Would install 7 packages
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
     "
     );
 
-    // Re-create the environment with seed packages.
-    uv_snapshot!(context.filters(), context.venv().arg("--clear")
-        .arg("--seed"), @r"
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--explain")
+        .arg("markupsafe"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
+    markupsafe v2.1.5
+    ├── jinja2 v3.1.3 [requires: markupsafe >=2.0]
+    │   └── flask v3.0.2 [requires: jinja2 >=3.1.2]
+    └── werkzeug v3.0.1 [requires: markupsafe >=2.1.1]
+        └── flask v3.0.2 [requires: werkzeug >=3.0.0]
 
     ----- stderr -----
-    Using CPython 3.9.[X] interpreter at: [PYTHON-3.9]
-    Creating virtual environment with seed packages at: .venv
-     + pip==24.0
-     + setuptools==69.2.0
-     + wheel==0.43.0
-    Activate with: source .venv/[BIN]/activate
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
     "
     );
 
-    // Syncing should retain the seed packages.
+    Ok(())
+}
+
+/// With `--dry-run`, `--explain` doesn't build source distributions to read their requirements.
+#[test]
+fn explain_dry_run_source_distribution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        blinker==1.7.0
+        click==8.1.7
+        flask==3.0.2
+        itsdangerous==2.1.2
+        jinja2==3.1.3
+        markupsafe==2.1.5
+        werkzeug==3.0.1
+    "})?;
+
+    // `jinja2` is only available as a source distribution, so it's omitted from the explanation.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("requirements.txt"), @r"
+        .arg("requirements.txt")
+        .arg("--no-binary-package")
+        .arg("jinja2")
+        .arg("--explain")
+        .arg("markupsafe")
+        .arg("--dry-run"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
+    markupsafe v2.1.5
+    └── werkzeug v3.0.1 [requires: markupsafe >=2.1.1]
+        └── flask v3.0.2 [requires: werkzeug >=3.0.0]
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + requests==1.2.0
+    Resolved 7 packages in [TIME]
+    Would download 7 packages
+    Would install 7 packages
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    warning: Skipping the metadata for `jinja2`, since reading it would require a build
     "
     );
 
     Ok(())
 }
 
-/// Sanitize zip files during extraction.
+/// Ignore any discovered configuration files and `UV_*` environment variables with `--isolated`.
 #[test]
-fn sanitize() -> Result<()> {
+fn isolated() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Install a zip file that includes a path that extends outside the parent.
+    // Disable the index in a configuration file.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r"
+        [pip]
+        no-index = true
+    "})?;
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("payload-package @ https://github.com/astral-sh/sanitize-wheel-test/raw/bc59283d5b4b136a191792e32baa51b477fdf65e/payload_package-0.1.0-py3-none-any.whl")?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+    // By default, the configuration file is respected.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .failure();
+
+    // With `--isolated`, the configuration file has no effect.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--isolated"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5677,98 +11190,100 @@ fn sanitize() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + payload-package==0.1.0 (from https://github.com/astral-sh/sanitize-wheel-test/raw/bc59283d5b4b136a191792e32baa51b477fdf65e/payload_package-0.1.0-py3-none-any.whl)
-    "###
+     + iniconfig==2.0.0
+    "
     );
 
-    // There should be no `payload` file in the root.
-    if let Some(parent) = context.temp_dir.parent() {
-        assert!(!parent.join("payload").exists());
-    }
+    fs_err::remove_file(&uv_toml)?;
 
-    Ok(())
-}
+    // Exclude every version of the package via an environment variable.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .env(EnvVars::UV_EXCLUDE_NEWER, "2000-01-01T00:00:00Z")
+        .assert()
+        .failure();
 
-/// Allow semicolons attached to markers, as long as they're preceded by a space.
-#[test]
-fn semicolon_trailing_space() -> Result<()> {
-    let context = TestContext::new("3.12");
+    // With `--isolated`, the environment variable has no effect.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--isolated")
+        .env(EnvVars::UV_EXCLUDE_NEWER, "2000-01-01T00:00:00Z"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    let requirements = context.temp_dir.child("requirements.txt");
-    requirements.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl; python_version > '3.10'")?;
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "
+    );
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+    // Even if the environment variable is invalid.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .env(EnvVars::UV_EXCLUDE_NEWER, "not-a-date")
+        .assert()
+        .failure();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--isolated")
+        .env(EnvVars::UV_EXCLUDE_NEWER, "not-a-date"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
-    "###
+    Audited 1 package in [TIME]
+    "
     );
 
     Ok(())
 }
 
-/// Treat a semicolon that's not whitespace-separated as a part of the URL.
+/// Warn when the sync exceeds the `--warn-install-count` threshold.
 #[test]
-fn semicolon_no_space() -> Result<()> {
+fn warn_install_count() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements = context.temp_dir.child("requirements.txt");
-    requirements.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version > '3.10'")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
 
-    uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+    // With `--error-on-large-install`, the threshold is enforced, and the environment is left
+    // unchanged.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--warn-install-count")
+        .arg("2")
+        .arg("--error-on-large-install"), @r"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: Couldn't parse requirement in `requirements.txt` at position 0
-      Caused by: Expected direct URL (`https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version%20%3E%20'3.10'`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
-    iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl;python_version > '3.10'
-                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-    "###
+    Resolved 3 packages in [TIME]
+    error: The sync would install 3 packages, which exceeds the `--warn-install-count` threshold of 2, and `--error-on-large-install` was provided
+    "
     );
 
-    Ok(())
-}
-
-#[test]
-fn pep_751() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["anyio"]
-        "#,
-    )?;
-
-    context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
-        .assert()
-        .success();
-
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml"), @r"
+        .arg("requirements.txt")
+        .arg("--warn-install-count")
+        .arg("2"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: The sync would install 3 packages, which exceeds the `--warn-install-count` threshold of 2
     Prepared 3 packages in [TIME]
     Installed 3 packages in [TIME]
      + anyio==4.3.0
@@ -5777,313 +11292,365 @@ fn pep_751() -> Result<()> {
     "
     );
 
+    // Packages that are already installed don't count towards the threshold.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml"), @r"
+        .arg("requirements.txt")
+        .arg("--warn-install-count")
+        .arg("2")
+        .arg("--error-on-large-install"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Resolved 3 packages in [TIME]
     Audited 3 packages in [TIME]
     "
     );
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
-        "#,
-    )?;
+    Ok(())
+}
 
-    context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
-        .assert()
-        .success();
+/// Require wheels for specific packages with `--fail-on-missing-wheel-for`.
+#[test]
+fn fail_on_missing_wheel_for() -> Result<()> {
+    let context = TestContext::new("3.12");
 
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        source-distribution==0.0.1
+    "})?;
+
+    // `source-distribution` only publishes a source distribution.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml"), @r"
+        .arg("requirements.txt")
+        .arg("--fail-on-missing-wheel-for")
+        .arg("iniconfig,source-distribution"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The following package has no compatible wheel, but `--fail-on-missing-wheel-for` was provided:
+      source-distribution==0.0.1
+    "
+    );
+
+    // Unlisted packages can still be built from source.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--fail-on-missing-wheel-for")
+        .arg("iniconfig"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Prepared 1 package in [TIME]
-    Uninstalled 3 packages in [TIME]
-    Installed 1 package in [TIME]
-     - anyio==4.3.0
-     - idna==3.6
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
      + iniconfig==2.0.0
-     - sniffio==1.3.1
+     + source-distribution==0.0.1
     "
     );
 
     Ok(())
 }
 
-/// Avoid erroring for packages that only include wheels, and _don't_ include a wheel for the
-/// current platform, but are omitted by markers anyway.
-///
-/// See: <https://github.com/astral-sh/uv/issues/13127>
+/// Warn when an installed `.dist-info` directory doesn't match its `METADATA` with `--strict`.
 #[test]
-fn pep_751_wheel_only() -> Result<()> {
+fn strict_mismatched_dist_info() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12.0"
-        dependencies = ["torch"]
-        "#,
-    )?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
+        .pip_sync()
+        .arg("requirements.txt")
         .assert()
         .success();
 
-    // If there's no compatible wheel for a package we _don't_ need to install (e.g., anything
-    // CUDA-related), succeed.
+    // Modify the version in the `METADATA`, leaving the `.dist-info` directory name unchanged.
+    let metadata = context
+        .site_packages()
+        .join("iniconfig-2.0.0.dist-info")
+        .join("METADATA");
+    let contents = fs_err::read_to_string(&metadata)?;
+    fs_err::write(
+        &metadata,
+        contents.replace("Version: 2.0.0", "Version: 1.0.0"),
+    )?;
+
+    // Without `--strict`, the mismatch isn't reported.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--dry-run")
-        .arg("--python-platform")
-        .arg("macos"), @r"
+        .arg("requirements.txt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Would download 9 packages
-    Would install 9 packages
-     + filelock==3.13.1
-     + fsspec==2024.3.1
-     + jinja2==3.1.3
-     + markupsafe==2.1.5
-     + mpmath==1.3.0
-     + networkx==3.2.1
-     + sympy==1.12
-     + torch==2.2.1
-     + typing-extensions==4.10.0
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
     "
     );
 
-    // However, if there's no compatible wheel for a package that we _do_ need to install, we should
-    // error
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--dry-run")
-        .arg("--python-platform")
-        .arg("macos")
-        .arg("--python-version")
-        .arg("3.8"), @r"
-    success: false
-    exit_code: 2
+        .arg("requirements.txt")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Package `torch` can't be installed because it doesn't have a source distribution or wheel for the current platform
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    warning: The package `iniconfig==2.0.0` declares `iniconfig==1.0.0` in its `METADATA`. Consider reinstalling the package, or removing the package directory at: [SITE_PACKAGES]/iniconfig-2.0.0.dist-info.
+    "
+    );
 
-    hint: You're using CPython 3.8 (`cp38`), but `torch` (v2.2.1) only has wheels with the following Python implementation tag: `cp312`
+    // `uv pip check` doesn't report the mismatch.
+    uv_snapshot!(context.filters(), context.pip_check(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
     "
     );
 
     Ok(())
 }
 
-/// Respect `--no-binary` et al when installing from a `pylock.toml`.
+/// Set the default index with `UV_INDEX_URL`, which is overridden by `--index-url`.
 #[test]
-fn pep_751_build_options() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+fn index_url_env_var() -> Result<()> {
+    let context = TestContext::new("3.12");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["anyio"]
-        "#,
-    )?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
-    context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
-        .assert()
-        .success();
+    // Point `UV_INDEX_URL` at an empty local index.
+    let index = context.temp_dir.child("index");
+    index.create_dir_all()?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--no-binary")
-        .arg("anyio"), @r"
+        .arg("requirements.txt")
+        .env(EnvVars::UV_INDEX_URL, index.path()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and you require iniconfig==2.0.0, we can conclude that your requirements are unsatisfiable.
+    "
+    );
+
+    // The command-line argument takes precedence over the environment variable.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple")
+        .env(EnvVars::UV_INDEX_URL, index.path()), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Prepared 4 packages in [TIME]
-    Installed 4 packages in [TIME]
-     + anyio==4.8.0
-     + idna==3.10
-     + sniffio==1.3.1
-     + typing-extensions==4.12.2
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
     "
     );
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["odrive"]
-        "#,
-    )?;
+    Ok(())
+}
+
+/// Emit the `--dry-run` plan as JSON with `--output-format json`.
+#[test]
+fn dry_run_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
 
     context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
+        .pip_sync()
+        .arg("requirements.txt")
         .assert()
         .success();
 
+    // `source-distribution` only publishes a source distribution, so it would need to be built.
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        source-distribution==0.0.1
+    "})?;
+
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--no-binary")
-        .arg("odrive"), @r"
-    success: false
-    exit_code: 2
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    {"install":[{"name":"source-distribution","version":"0.0.1","source":"https://pypi.org/simple","requires_build":true}],"remove":[],"audit":[{"name":"iniconfig","version":"2.0.0"}]}
 
     ----- stderr -----
-    error: Package `odrive` can't be installed because it is marked as `--no-binary` but has no source distribution
-    "
+    Resolved 2 packages in [TIME]
+    "#
     );
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["source-distribution"]
-        "#,
-    )?;
+    // The environment is left unchanged.
+    context.assert_not_installed("source_distribution");
 
-    context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
-        .assert()
-        .success();
+    Ok(())
+}
+
+/// Abort resolution once it exceeds `--max-resolution-rounds`.
+#[test]
+fn max_resolution_rounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The extra is unbounded, but the base package is capped below the latest version, so the
+    // resolver has to backtrack through each of the newer versions of `anyio[trio]`.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio[trio]
+        anyio<4
+    "})?;
 
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--only-binary")
-        .arg("source-distribution"), @r"
+        .arg("requirements.txt")
+        .arg("--max-resolution-rounds")
+        .arg("4"), @r"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: Package `source-distribution` can't be installed because it is marked as `--no-build` but has no binary distribution
+    error: Resolution exceeded the limit of 4 rounds; the most constrained packages were: `anyio`
     "
     );
 
+    // With a sufficient budget, resolution succeeds.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--no-binary")
-        .arg("source-distribution"), @r"
+        .arg("requirements.txt")
+        .arg("--max-resolution-rounds")
+        .arg("100"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
-    Uninstalled 4 packages in [TIME]
     Installed 1 package in [TIME]
-     - anyio==4.8.0
-     - idna==3.10
-     - sniffio==1.3.1
-     + source-distribution==0.0.3
-     - typing-extensions==4.12.2
+     + anyio==3.7.1
     "
     );
 
     Ok(())
 }
 
+/// Reinstall packages whose files don't match their `RECORD` with `--reinstall-if-corrupt`.
 #[test]
-fn pep_751_direct_url_tags() -> Result<()> {
+fn reinstall_if_corrupt() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["MarkupSafe @ https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl"]
-        "#,
-    )?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0
+        sniffio==1.3.1
+    "})?;
 
     context
-        .export()
-        .arg("-o")
-        .arg("pylock.toml")
+        .pip_sync()
+        .arg("requirements.txt")
         .assert()
         .success();
 
+    // Truncate a file in `iniconfig`.
+    fs_err::write(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("__init__.py"),
+        "",
+    )?;
+
+    // Only the corrupt package is reinstalled.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--python-platform")
-        .arg("linux"), @r"
-    success: false
-    exit_code: 2
+        .arg("requirements.txt")
+        .arg("--reinstall-if-corrupt"), @r"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to determine installation plan
-      Caused by: A URL dependency is incompatible with the current platform: https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl
+    warning: The installed package `iniconfig` has files that are missing or don't match its `RECORD`; reinstalling
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
     "
     );
 
+    // The package is no longer corrupt.
     uv_snapshot!(context.filters(), context.pip_sync()
-        .arg("--preview")
-        .arg("pylock.toml")
-        .arg("--python-platform")
-        .arg("macos"), @r"
+        .arg("requirements.txt")
+        .arg("--reinstall-if-corrupt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "
+    );
+
+    // Remove a file from `sniffio`.
+    fs_err::remove_file(context.site_packages().join("sniffio").join("_impl.py"))?;
+
+    // Missing files are treated as corrupt.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall-if-corrupt"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    warning: The installed package `sniffio` has files that are missing or don't match its `RECORD`; reinstalling
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     + markupsafe==3.0.2 (from https://files.pythonhosted.org/packages/6b/b0/18f76bba336fa5aecf79d45dcd6c806c280ec44538b3c13671d49099fdd0/MarkupSafe-3.0.2-cp312-cp312-macosx_11_0_arm64.whl)
+     ~ sniffio==1.3.1
+    "
+    );
+
+    // Extraneous packages aren't verified, since they're removed regardless.
+    fs_err::remove_file(context.site_packages().join("sniffio").join("_impl.py"))?;
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall-if-corrupt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - sniffio==1.3.1
     "
     );
 
@@ -5,7 +5,7 @@ use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 
-use crate::common::{TestContext, get_bin, uv_snapshot};
+use crate::common::{TestContext, get_bin, site_packages_path, uv_snapshot};
 
 #[test]
 fn no_arguments() {
@@ -19,9 +19,9 @@ fn no_arguments() {
 
     ----- stderr -----
     error: the following required arguments were not provided:
-      <PACKAGE|--requirements <REQUIREMENTS>>
+      <PACKAGE|--requirements <REQUIREMENTS>|--all>
 
-    Usage: uv pip uninstall <PACKAGE|--requirements <REQUIREMENTS>>
+    Usage: uv pip uninstall <PACKAGE|--requirements <REQUIREMENTS>|--all>
 
     For more information, try '--help'.
     "###
@@ -132,6 +132,178 @@ fn uninstall() -> Result<()> {
     Ok(())
 }
 
+/// Uninstall a package from a `--prefix` directory.
+#[test]
+#[cfg(feature = "pypi")]
+fn uninstall_prefix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let prefix = context.temp_dir.child("prefix");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--prefix")
+        .arg(prefix.path())
+        .assert()
+        .success();
+
+    let site_packages = site_packages_path(prefix.path(), "python3.12");
+    assert!(site_packages.join("iniconfig").is_dir());
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    assert!(!site_packages.join("iniconfig").exists());
+    assert!(!site_packages.join("iniconfig-2.0.0.dist-info").exists());
+
+    Ok(())
+}
+
+/// Uninstall a package from a `--prefix` directory that was staged beneath a `--root` directory.
+#[test]
+#[cfg(feature = "pypi")]
+fn uninstall_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let prefix = context.temp_dir.child("prefix");
+    let root = context.temp_dir.child("stage");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--prefix")
+        .arg(prefix.path())
+        .arg("--root")
+        .arg(root.path())
+        .assert()
+        .success();
+
+    let staged = root.path().join(
+        prefix
+            .path()
+            .components()
+            .filter(|component| matches!(component, std::path::Component::Normal(_)))
+            .collect::<std::path::PathBuf>(),
+    );
+    let site_packages = site_packages_path(&staged, "python3.12");
+    assert!(site_packages.join("iniconfig").is_dir());
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig")
+        .arg("--prefix")
+        .arg(prefix.path())
+        .arg("--root")
+        .arg(root.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    assert!(!site_packages.join("iniconfig").exists());
+    assert!(!site_packages.join("iniconfig-2.0.0.dist-info").exists());
+
+    Ok(())
+}
+
+/// Uninstall a single-module package, removing any bytecode that isn't listed in the `RECORD`.
+#[test]
+#[cfg(feature = "pypi")]
+fn uninstall_stale_bytecode() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("typing-extensions==4.10.0")
+        .assert()
+        .success();
+
+    // Simulate bytecode compiled after installation, alongside bytecode for an unrelated module.
+    let pycache = context.site_packages().join("__pycache__");
+    fs_err::create_dir_all(&pycache)?;
+    fs_err::write(pycache.join("typing_extensions.cpython-312.pyc"), "")?;
+    fs_err::write(pycache.join("typing_extensions.cpython-312.opt-1.pyc"), "")?;
+    fs_err::write(pycache.join("unrelated.cpython-312.pyc"), "")?;
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("typing-extensions"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - typing-extensions==4.10.0
+    "###
+    );
+
+    assert!(!pycache.join("typing_extensions.cpython-312.pyc").exists());
+    assert!(
+        !pycache
+            .join("typing_extensions.cpython-312.opt-1.pyc")
+            .exists()
+    );
+    assert!(pycache.join("unrelated.cpython-312.pyc").exists());
+
+    Ok(())
+}
+
+/// Uninstall a package whose files are symlinked into an external, read-only store (as in Nix or
+/// conda); the symlink should be removed, but its target should be left untouched.
+#[test]
+#[cfg(all(unix, feature = "pypi"))]
+fn uninstall_external_symlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Move the package into an external "store", and symlink it back into `site-packages`.
+    let package = context.site_packages().join("iniconfig");
+    let store = context.temp_dir.child("store");
+    store.create_dir_all()?;
+    fs_err::rename(&package, store.join("iniconfig"))?;
+    fs_err::os::unix::fs::symlink(store.join("iniconfig"), &package)?;
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Removing symlink `[SITE_PACKAGES]/iniconfig` without removing its target, which is outside of the environment
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###
+    );
+
+    // The symlink should be removed, but the store should be untouched.
+    assert!(fs_err::symlink_metadata(&package).is_err());
+    assert!(store.join("iniconfig").join("__init__.py").exists());
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn missing_record() -> Result<()> {
@@ -389,11 +561,76 @@ fn uninstall_egg_info() -> Result<()> {
     exit_code: 0
     ----- stdout -----
 
+    ----- stderr -----
+    warning: `zstandard-0.22.0-py3.12.egg-info` does not include an `installed-files.txt`; removing its top-level modules on a best-effort basis
+    Uninstalled 1 package in [TIME]
+     - zstandard==0.22.0
+    "###);
+
+    Ok(())
+}
+
+/// Uninstall a `.egg-info` package that lists its files in `installed-files.txt`, as written by
+/// `setup.py install`.
+#[test]
+fn uninstall_egg_info_installed_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let site_packages = ChildPath::new(context.site_packages());
+
+    // Manually create a `.egg-info` directory.
+    let egg_info = site_packages.child("zstandard-0.22.0-py3.12.egg-info");
+    egg_info.create_dir_all()?;
+    egg_info.child("PKG-INFO").write_str("")?;
+    egg_info.child("top_level.txt").write_str("zstd")?;
+    egg_info
+        .child("installed-files.txt")
+        .write_str(indoc::indoc! {r"
+        ../zstd/__init__.py
+        ../zstd/__pycache__/__init__.cpython-312.pyc
+        ../zstd/backend.py
+        PKG-INFO
+        installed-files.txt
+        top_level.txt
+    "})?;
+
+    // Manually create the package directory, along with an unrelated module.
+    site_packages.child("zstd").create_dir_all()?;
+    site_packages
+        .child("zstd")
+        .child("__init__.py")
+        .write_str("")?;
+    site_packages
+        .child("zstd")
+        .child("backend.py")
+        .write_str("")?;
+    site_packages
+        .child("zstd")
+        .child("__pycache__")
+        .create_dir_all()?;
+    site_packages
+        .child("zstd")
+        .child("__pycache__")
+        .child("__init__.cpython-312.pyc")
+        .write_str("")?;
+    site_packages.child("unrelated.py").write_str("")?;
+
+    // Run `pip uninstall`.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("zstandard"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
     ----- stderr -----
     Uninstalled 1 package in [TIME]
      - zstandard==0.22.0
     "###);
 
+    assert!(!site_packages.child("zstd").exists());
+    assert!(!egg_info.exists());
+    assert!(site_packages.child("unrelated.py").exists());
+
     Ok(())
 }
 
@@ -524,3 +761,63 @@ fn dry_run_uninstall_egg_info() -> Result<()> {
 
     Ok(())
 }
+
+/// Uninstall every package with `--all`, retaining the base packages unless `--include-base` is
+/// provided.
+#[test]
+#[cfg(feature = "pypi")]
+fn uninstall_all() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        iniconfig==2.0.0
+        setuptools==69.2.0
+        typing-extensions==4.10.0
+    "})?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--all"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 packages in [TIME]
+     - iniconfig==2.0.0
+     - typing-extensions==4.10.0
+    "
+    );
+
+    // Only the base packages should remain.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    setuptools==69.2.0
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--all")
+        .arg("--include-base"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - setuptools==69.2.0
+    "
+    );
+
+    Ok(())
+}
@@ -64,6 +64,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -82,6 +84,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -97,6 +100,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -175,6 +182,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -186,6 +194,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -249,6 +258,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -267,6 +278,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -282,6 +294,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -360,6 +376,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -371,6 +388,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -435,6 +453,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -453,6 +473,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -468,6 +489,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -546,6 +571,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -557,6 +583,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -653,6 +680,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -671,6 +700,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -686,6 +716,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -764,6 +798,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -775,6 +810,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -840,6 +876,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -858,6 +896,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -873,6 +912,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -917,6 +960,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -928,6 +972,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -1003,6 +1048,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1021,6 +1068,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -1036,6 +1084,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1114,6 +1166,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -1125,6 +1178,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -1215,6 +1269,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1233,6 +1289,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -1248,6 +1305,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1359,6 +1420,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -1370,6 +1432,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -1435,6 +1498,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1453,6 +1518,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -1468,6 +1534,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1614,6 +1684,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -1625,6 +1696,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -1713,6 +1785,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1731,6 +1805,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -1746,6 +1821,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1824,6 +1903,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -1835,6 +1915,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -1922,6 +2003,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1940,6 +2023,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -1955,6 +2039,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1999,6 +2087,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2010,6 +2099,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -2090,6 +2180,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2108,6 +2200,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -2123,6 +2216,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2234,6 +2331,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2245,6 +2343,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -2308,6 +2407,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2326,6 +2427,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -2341,6 +2443,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2452,6 +2558,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2463,6 +2570,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -2549,6 +2657,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2567,6 +2677,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -2582,6 +2693,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2626,6 +2741,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2637,6 +2753,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -2707,6 +2824,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2725,6 +2844,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -2740,6 +2860,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2784,6 +2908,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2795,6 +2920,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -2865,6 +2991,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2883,6 +3011,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -2898,6 +3027,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2942,6 +3075,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -2953,6 +3087,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -3025,6 +3160,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3043,6 +3180,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -3058,6 +3196,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3102,6 +3244,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -3113,6 +3256,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -3204,6 +3348,8 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3222,6 +3368,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3373,6 +3520,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3391,6 +3540,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -3406,6 +3556,10 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3450,6 +3604,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -3461,6 +3616,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -3565,6 +3721,8 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3583,6 +3741,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -3598,6 +3757,10 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3676,6 +3839,7 @@ fn resolve_both() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -3687,6 +3851,7 @@ fn resolve_both() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -3795,6 +3960,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3813,6 +3980,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -3828,6 +3996,10 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3906,6 +4078,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -3917,6 +4090,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -4104,6 +4278,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4122,6 +4298,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -4137,6 +4314,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4215,6 +4396,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -4226,6 +4408,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -4389,6 +4572,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4407,6 +4592,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -4422,6 +4608,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4466,6 +4656,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -4477,6 +4668,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
@@ -4550,6 +4742,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4568,6 +4762,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -4583,6 +4778,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4627,6 +4826,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -4638,6 +4838,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -4730,6 +4931,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4748,6 +4951,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -4763,6 +4967,10 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4807,6 +5015,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -4818,6 +5027,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -4902,6 +5112,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4920,6 +5132,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -4935,6 +5148,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5048,6 +5265,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -5059,6 +5277,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -5122,6 +5341,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5140,6 +5361,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -5155,6 +5377,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5268,6 +5494,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -5279,6 +5506,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -5348,6 +5576,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5366,6 +5596,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -5381,6 +5612,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5494,6 +5729,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -5505,6 +5741,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -5569,6 +5806,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5587,6 +5826,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -5602,6 +5842,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5715,6 +5959,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -5726,6 +5971,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -5797,6 +6043,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5815,6 +6063,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -5830,6 +6079,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5943,6 +6196,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -5954,6 +6208,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6018,6 +6273,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6036,6 +6293,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipCompileSettings {
         format: None,
@@ -6051,6 +6309,10 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        required_environments: SupportedEnvironments(
+            [],
+        ),
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6164,6 +6426,7 @@ fn index_priority() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6175,6 +6438,7 @@ fn index_priority() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6252,6 +6516,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6270,6 +6536,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -6280,11 +6547,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6329,6 +6609,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6340,6 +6621,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6403,6 +6685,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6421,6 +6705,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -6431,11 +6716,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6480,6 +6778,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6491,6 +6790,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6552,6 +6852,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6570,6 +6872,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -6580,11 +6883,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6629,6 +6945,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6640,6 +6957,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6703,6 +7021,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6721,6 +7041,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -6731,11 +7052,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6780,6 +7114,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6791,6 +7126,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -6852,6 +7188,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6870,6 +7208,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -6880,11 +7219,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6929,6 +7281,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -6940,6 +7293,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -7002,6 +7356,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy: None,
+            timeout: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7020,6 +7376,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_cache_ttl: None,
     }
     PipInstallSettings {
         package: [],
@@ -7030,11 +7387,24 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        build_requirements: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        only_deps: false,
+        max_depth: None,
+        list_extraneous: false,
+        compile_package: [],
+        installer_name: None,
+        verify_python_version: None,
+        platform_tag: [],
+        warn_shadowing: false,
+        warn_import_conflicts: false,
+        verify_metadata_consistency: false,
+        no_wait: false,
+        report_unused_constraints: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7079,6 +7449,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             break_system_packages: false,
             target: None,
             prefix: None,
+            root: None,
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
@@ -7090,6 +7461,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             },
             allow_empty_requirements: false,
             strict: false,
+            warn_conflicts: true,
             dependency_mode: Transitive,
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
@@ -11269,3 +11269,45 @@ fn sync_config_settings_package() -> Result<()> {
 
     Ok(())
 }
+
+/// Files that fail to compile should be reported in a warning, without suggesting
+/// `--compile-strict`, which `uv sync` doesn't support.
+#[test]
+fn sync_compile_bytecode_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let src = context.temp_dir.child("src").child("project");
+    src.child("__init__.py").touch()?;
+    src.child("invalid.py").write_str("def f(:\n")?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable").arg("--compile-bytecode"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 1 file in [TIME]
+    warning: Failed to bytecode-compile 1 file:
+      - [SITE_PACKAGES]/project/invalid.py
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    Ok(())
+}